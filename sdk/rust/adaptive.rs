@@ -0,0 +1,196 @@
+//! Adaptive concurrency control.
+//!
+//! Static rate limits are wrong the moment the server is scaled up or
+//! down. [`AdaptiveLimiter`] discovers the sustainable concurrency by
+//! AIMD: the limit creeps up by one per window of healthy responses and
+//! is cut multiplicatively when the server signals overload (errors or
+//! latency well above the observed baseline). Callers acquire a permit
+//! around each request and report the outcome; everything else is
+//! automatic.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::{Mutex, Semaphore};
+
+use crate::{BrainAIError, Result};
+
+/// Tuning for [`AdaptiveLimiter`].
+#[derive(Debug, Clone)]
+pub struct AdaptiveOptions {
+    /// Starting concurrency limit.
+    pub initial_limit: usize,
+    /// Lower bound the limit never drops below.
+    pub min_limit: usize,
+    /// Upper bound the limit never exceeds.
+    pub max_limit: usize,
+    /// Multiplicative cut applied on overload (e.g. `0.7` keeps 70%).
+    pub backoff_ratio: f64,
+    /// Successes in a row required before the limit grows by one.
+    pub growth_window: usize,
+    /// Latency above `baseline * latency_factor` counts as overload.
+    pub latency_factor: f64,
+}
+
+impl Default for AdaptiveOptions {
+    fn default() -> Self {
+        AdaptiveOptions {
+            initial_limit: 8,
+            min_limit: 1,
+            max_limit: 256,
+            backoff_ratio: 0.7,
+            growth_window: 10,
+            latency_factor: 3.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    limit: usize,
+    /// Permits currently handed to the semaphore beyond those in flight.
+    successes_in_window: usize,
+    /// Exponentially weighted latency baseline.
+    baseline: Option<Duration>,
+}
+
+/// AIMD concurrency limiter.
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use brain_ai::adaptive::AdaptiveLimiter;
+/// # async fn example(limiter: &Arc<AdaptiveLimiter>) -> brain_ai::Result<()> {
+/// let permit = limiter.acquire().await?;
+/// // ... perform one SDK call ...
+/// permit.success().await;   // or permit.overload().await on errors
+/// # Ok(())
+/// # }
+/// ```
+pub struct AdaptiveLimiter {
+    semaphore: Arc<Semaphore>,
+    state: Mutex<LimiterState>,
+    options: AdaptiveOptions,
+}
+
+impl AdaptiveLimiter {
+    /// Creates a limiter with default tuning.
+    pub fn new() -> Arc<Self> {
+        AdaptiveLimiter::with_options(AdaptiveOptions::default())
+    }
+
+    /// Creates a limiter with explicit tuning.
+    pub fn with_options(options: AdaptiveOptions) -> Arc<Self> {
+        let initial = options
+            .initial_limit
+            .clamp(options.min_limit.max(1), options.max_limit.max(1));
+        Arc::new(AdaptiveLimiter {
+            semaphore: Arc::new(Semaphore::new(initial)),
+            state: Mutex::new(LimiterState {
+                limit: initial,
+                successes_in_window: 0,
+                baseline: None,
+            }),
+            options,
+        })
+    }
+
+    /// Current concurrency limit.
+    pub async fn limit(&self) -> usize {
+        self.state.lock().await.limit
+    }
+
+    /// Waits for a concurrency slot; the returned permit must be resolved
+    /// with [`Permit::success`] or [`Permit::overload`] (dropping it
+    /// without reporting releases the slot neutrally).
+    pub async fn acquire(self: &Arc<Self>) -> Result<Permit> {
+        let permit = self
+            .semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|_| BrainAIError::InvalidInput("limiter closed".to_string()))?;
+        Ok(Permit {
+            limiter: self.clone(),
+            permit: Some(permit),
+            started: Instant::now(),
+        })
+    }
+
+    /// Records a healthy response and grows the limit additively once per
+    /// window.
+    async fn on_success(&self, elapsed: Duration) {
+        let mut state = self.state.lock().await;
+        // Update the latency baseline (EWMA, 10% step).
+        state.baseline = Some(match state.baseline {
+            None => elapsed,
+            Some(baseline) => baseline.mul_f64(0.9) + elapsed.mul_f64(0.1),
+        });
+        if let Some(baseline) = state.baseline {
+            if elapsed > baseline.mul_f64(self.options.latency_factor)
+                && baseline > Duration::ZERO
+            {
+                // Slow response: treat as overload even though it succeeded.
+                drop(state);
+                self.on_overload().await;
+                return;
+            }
+        }
+        state.successes_in_window += 1;
+        if state.successes_in_window >= self.options.growth_window.max(1)
+            && state.limit < self.options.max_limit
+        {
+            state.successes_in_window = 0;
+            state.limit += 1;
+            self.semaphore.add_permits(1);
+        }
+    }
+
+    /// Cuts the limit multiplicatively.
+    async fn on_overload(&self) {
+        let mut state = self.state.lock().await;
+        state.successes_in_window = 0;
+        let target = ((state.limit as f64) * self.options.backoff_ratio).floor() as usize;
+        let target = target.max(self.options.min_limit.max(1));
+        let to_remove = state.limit - target;
+        state.limit = target;
+        drop(state);
+        // Shrink by consuming permits as they come free; forgetting them
+        // lowers the effective limit without blocking current requests.
+        for _ in 0..to_remove {
+            match self.semaphore.try_acquire() {
+                Ok(permit) => permit.forget(),
+                Err(_) => {
+                    let semaphore = self.semaphore.clone();
+                    tokio::spawn(async move {
+                        if let Ok(permit) = semaphore.acquire().await {
+                            permit.forget();
+                        }
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// One in-flight request slot.
+pub struct Permit {
+    limiter: Arc<AdaptiveLimiter>,
+    permit: Option<tokio::sync::OwnedSemaphorePermit>,
+    started: Instant,
+}
+
+impl Permit {
+    /// Reports a healthy response; latency feeds the baseline.
+    pub async fn success(mut self) {
+        let elapsed = self.started.elapsed();
+        self.permit.take();
+        self.limiter.on_success(elapsed).await;
+    }
+
+    /// Reports an overload signal (timeout, 429/503, transport error);
+    /// the limit is cut.
+    pub async fn overload(mut self) {
+        self.permit.take();
+        self.limiter.on_overload().await;
+    }
+}
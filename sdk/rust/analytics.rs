@@ -0,0 +1,331 @@
+//! Client-side graph analytics over subgraph snapshots.
+//!
+//! The server answers neighborhood queries but has no opinion about
+//! which nodes matter. [`analyze_subgraph`] pulls a snapshot with
+//! [`collect_subgraph`](crate::graphexport::collect_subgraph) and scores
+//! every node locally: PageRank for global importance, degree and
+//! betweenness centrality for local and broker importance, and Louvain
+//! communities for cluster structure. Scores can be written back as node
+//! properties (`importance`, `community`) so later graph queries can
+//! filter on them.
+
+use std::collections::{HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::client::BrainAIClient;
+use crate::graphexport::{collect_subgraph, Subgraph};
+use crate::{GraphNode, Result};
+
+/// PageRank damping factor.
+const DAMPING: f64 = 0.85;
+/// PageRank stops when the total rank movement drops below this.
+const PAGERANK_EPSILON: f64 = 1e-9;
+const PAGERANK_MAX_ITERATIONS: usize = 100;
+/// Louvain gives up after this many aggregation levels.
+const LOUVAIN_MAX_LEVELS: usize = 10;
+
+/// Per-node analytics results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeScore {
+    pub id: String,
+    /// PageRank share in `0.0..=1.0`; all shares sum to one.
+    pub pagerank: f64,
+    /// Degree divided by the maximum possible degree.
+    pub degree: f64,
+    /// Normalized betweenness centrality in `0.0..=1.0`.
+    pub betweenness: f64,
+    /// Louvain community index, numbered from zero by first appearance.
+    pub community: usize,
+}
+
+/// Analytics over one subgraph snapshot, sorted by PageRank descending.
+#[derive(Debug, Clone)]
+pub struct GraphAnalytics {
+    pub scores: Vec<NodeScore>,
+    /// The analyzed nodes, kept so scores can be written back.
+    nodes: Vec<GraphNode>,
+}
+
+impl GraphAnalytics {
+    /// Computes all scores for a snapshot. Directed edges are treated as
+    /// undirected for centrality and community detection; PageRank
+    /// follows direction where an edge declares one.
+    pub fn compute(subgraph: &Subgraph) -> Self {
+        let nodes = &subgraph.nodes;
+        let index: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.id.as_str(), i))
+            .collect();
+        let n = nodes.len();
+
+        // Undirected weighted adjacency, plus directed out-links for
+        // PageRank.
+        let mut undirected: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+        let mut out_links: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for edge in &subgraph.edges {
+            let (Some(&a), Some(&b)) = (index.get(edge.from.as_str()), index.get(edge.to.as_str()))
+            else {
+                continue;
+            };
+            if a == b {
+                continue;
+            }
+            let weight = edge.weight.max(0.0);
+            undirected[a].push((b, weight));
+            undirected[b].push((a, weight));
+            out_links[a].push(b);
+            if !edge.directed {
+                out_links[b].push(a);
+            }
+        }
+
+        let pagerank = pagerank(&out_links);
+        let betweenness = betweenness(&undirected);
+        let communities = louvain(&undirected);
+        let max_degree = (n.saturating_sub(1)).max(1) as f64;
+
+        let mut scores: Vec<NodeScore> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| NodeScore {
+                id: node.id.clone(),
+                pagerank: pagerank[i],
+                degree: undirected[i].len() as f64 / max_degree,
+                betweenness: betweenness[i],
+                community: communities[i],
+            })
+            .collect();
+        scores.sort_by(|a, b| b.pagerank.total_cmp(&a.pagerank));
+        GraphAnalytics {
+            scores,
+            nodes: nodes.clone(),
+        }
+    }
+
+    /// Writes `importance` (PageRank) and `community` back onto every
+    /// analyzed node's properties, returning how many nodes were
+    /// updated. Existing properties are preserved.
+    pub async fn write_back(&self, client: &dyn BrainAIClient) -> Result<usize> {
+        let by_id: HashMap<&str, &NodeScore> =
+            self.scores.iter().map(|s| (s.id.as_str(), s)).collect();
+        let mut updated = 0;
+        for node in &self.nodes {
+            let Some(score) = by_id.get(node.id.as_str()) else {
+                continue;
+            };
+            let mut properties = node.properties.clone();
+            properties.insert("importance".to_string(), json!(score.pagerank));
+            properties.insert("community".to_string(), json!(score.community));
+            client
+                .create_graph_node(
+                    &node.id,
+                    &node.label,
+                    node.node_type.clone(),
+                    Some(properties),
+                )
+                .await?;
+            updated += 1;
+        }
+        Ok(updated)
+    }
+}
+
+/// Pulls the neighborhood of `root_id` and analyzes it.
+pub async fn analyze_subgraph(
+    client: &dyn BrainAIClient,
+    root_id: &str,
+    depth: u32,
+) -> Result<GraphAnalytics> {
+    let subgraph = collect_subgraph(client, root_id, depth).await?;
+    Ok(GraphAnalytics::compute(&subgraph))
+}
+
+/// Power-iteration PageRank with dangling-mass redistribution.
+fn pagerank(out_links: &[Vec<usize>]) -> Vec<f64> {
+    let n = out_links.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let uniform = 1.0 / n as f64;
+    let mut rank = vec![uniform; n];
+    for _ in 0..PAGERANK_MAX_ITERATIONS {
+        let mut next = vec![(1.0 - DAMPING) * uniform; n];
+        let mut dangling = 0.0;
+        for (i, targets) in out_links.iter().enumerate() {
+            if targets.is_empty() {
+                dangling += rank[i];
+            } else {
+                let share = DAMPING * rank[i] / targets.len() as f64;
+                for &target in targets {
+                    next[target] += share;
+                }
+            }
+        }
+        let dangling_share = DAMPING * dangling * uniform;
+        let mut moved = 0.0;
+        for (i, value) in next.iter_mut().enumerate() {
+            *value += dangling_share;
+            moved += (*value - rank[i]).abs();
+        }
+        rank = next;
+        if moved < PAGERANK_EPSILON {
+            break;
+        }
+    }
+    rank
+}
+
+/// Brandes betweenness centrality over the undirected graph, normalized
+/// to `0.0..=1.0`. Edge weights are ignored: hops, not strengths, decide
+/// who sits on shortest paths.
+fn betweenness(adjacency: &[Vec<(usize, f64)>]) -> Vec<f64> {
+    let n = adjacency.len();
+    let mut centrality = vec![0.0; n];
+    for source in 0..n {
+        let mut stack = Vec::new();
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut sigma = vec![0.0f64; n];
+        let mut distance = vec![-1i64; n];
+        sigma[source] = 1.0;
+        distance[source] = 0;
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            stack.push(v);
+            for &(w, _) in &adjacency[v] {
+                if distance[w] < 0 {
+                    distance[w] = distance[v] + 1;
+                    queue.push_back(w);
+                }
+                if distance[w] == distance[v] + 1 {
+                    sigma[w] += sigma[v];
+                    predecessors[w].push(v);
+                }
+            }
+        }
+        let mut delta = vec![0.0f64; n];
+        while let Some(w) = stack.pop() {
+            for &v in &predecessors[w] {
+                delta[v] += sigma[v] / sigma[w] * (1.0 + delta[w]);
+            }
+            if w != source {
+                centrality[w] += delta[w];
+            }
+        }
+    }
+    // Each undirected path was counted from both endpoints; the scale
+    // factor also normalizes by the number of possible pairs.
+    if n > 2 {
+        let scale = 1.0 / ((n - 1) as f64 * (n - 2) as f64);
+        for value in &mut centrality {
+            *value *= scale;
+        }
+    }
+    centrality
+}
+
+/// Louvain community detection: local moving to maximize modularity,
+/// then aggregation, repeated until communities stop merging.
+fn louvain(adjacency: &[Vec<(usize, f64)>]) -> Vec<usize> {
+    let n = adjacency.len();
+    // membership[i] = community of original node i, refined per level.
+    let mut membership: Vec<usize> = (0..n).collect();
+    // The working graph starts as the input and shrinks per level.
+    let mut graph: Vec<Vec<(usize, f64)>> = adjacency
+        .iter()
+        .map(|list| {
+            list.iter()
+                .map(|&(other, weight)| (other, weight.max(1e-9)))
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..LOUVAIN_MAX_LEVELS {
+        let size = graph.len();
+        if size <= 1 {
+            break;
+        }
+        let two_m: f64 = graph.iter().flatten().map(|&(_, w)| w).sum::<f64>().max(1e-9);
+        let strengths: Vec<f64> = graph
+            .iter()
+            .map(|list| list.iter().map(|&(_, w)| w).sum())
+            .collect();
+        let mut community: Vec<usize> = (0..size).collect();
+        let mut community_total = strengths.clone();
+
+        // Local moving phase.
+        let mut improved = true;
+        let mut passes = 0;
+        while improved && passes < 20 {
+            improved = false;
+            passes += 1;
+            for v in 0..size {
+                let current = community[v];
+                community_total[current] -= strengths[v];
+                let mut links: HashMap<usize, f64> = HashMap::new();
+                for &(w, weight) in &graph[v] {
+                    if w != v {
+                        *links.entry(community[w]).or_insert(0.0) += weight;
+                    }
+                }
+                let mut best = current;
+                let mut best_gain = links.get(&current).copied().unwrap_or(0.0)
+                    - strengths[v] * community_total[current] / two_m;
+                for (&candidate, &link_weight) in &links {
+                    let gain =
+                        link_weight - strengths[v] * community_total[candidate] / two_m;
+                    if gain > best_gain + 1e-12 {
+                        best_gain = gain;
+                        best = candidate;
+                    }
+                }
+                community_total[best] += strengths[v];
+                if best != current {
+                    community[v] = best;
+                    improved = true;
+                }
+            }
+        }
+
+        // Renumber communities densely.
+        let mut renumber: HashMap<usize, usize> = HashMap::new();
+        for c in &mut community {
+            let next = renumber.len();
+            *c = *renumber.entry(*c).or_insert(next);
+        }
+        let merged = renumber.len();
+        if merged == size {
+            break;
+        }
+
+        // Fold the level into the original membership.
+        for m in &mut membership {
+            *m = community[*m];
+        }
+
+        // Aggregation phase: communities become nodes.
+        let mut folded: Vec<HashMap<usize, f64>> = vec![HashMap::new(); merged];
+        for (v, list) in graph.iter().enumerate() {
+            for &(w, weight) in list {
+                let (a, b) = (community[v], community[w]);
+                *folded[a].entry(b).or_insert(0.0) += weight;
+            }
+        }
+        graph = folded
+            .into_iter()
+            .map(|map| map.into_iter().collect())
+            .collect();
+    }
+
+    // Renumber final communities by first appearance.
+    let mut renumber: HashMap<usize, usize> = HashMap::new();
+    membership
+        .into_iter()
+        .map(|c| {
+            let next = renumber.len();
+            *renumber.entry(c).or_insert(next)
+        })
+        .collect()
+}
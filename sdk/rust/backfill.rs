@@ -0,0 +1,223 @@
+//! Backfill pipeline for legacy memories.
+//!
+//! Features added after a brain was populated — embeddings, metadata
+//! enrichment, graph links — only apply to new writes, leaving old data
+//! second-class: invisible to vector search, untagged, unconnected.
+//! [`Backfiller`] walks the existing memories, finds the ones missing a
+//! linked vector, enrichment metadata, or any relations, and runs the
+//! configured embedding/enrichment pipeline over them in rate-limited
+//! batches. Per-memory failures are collected rather than aborting the
+//! run, and the report says exactly how much caught up.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::embed::Embedder;
+use crate::enrich::{ContentLengthEnricher, Enricher, LanguageEnricher, SourceHostEnricher};
+use crate::{Memory, Result};
+
+/// What the backfill pass covers and how fast it runs.
+#[derive(Clone)]
+pub struct BackfillOptions {
+    /// Memories processed between rate-limit pauses.
+    pub batch_size: usize,
+    /// Pause between batches, bounding load on the server and any
+    /// embedding provider.
+    pub delay: Duration,
+    /// Memories examined per run; keeps runs bounded on large brains.
+    pub scan_limit: usize,
+    /// Embed memories missing a linked vector.
+    pub embed: bool,
+    /// Run the enrichment pipeline over memories missing its fields.
+    pub enrich: bool,
+    /// Connect memories with no relations to their most similar
+    /// neighbour when similarity reaches `link_threshold`.
+    pub link: bool,
+    pub link_threshold: f64,
+}
+
+impl Default for BackfillOptions {
+    fn default() -> Self {
+        BackfillOptions {
+            batch_size: 100,
+            delay: Duration::from_millis(500),
+            scan_limit: 50_000,
+            embed: true,
+            enrich: true,
+            link: false,
+            link_threshold: 0.8,
+        }
+    }
+}
+
+/// Outcome of one backfill run.
+#[derive(Debug, Default, Clone)]
+pub struct BackfillReport {
+    /// Memories examined.
+    pub scanned: usize,
+    /// Memories that needed any work.
+    pub candidates: usize,
+    /// Vectors created and linked.
+    pub embedded: usize,
+    /// Memories whose metadata the enrichment pipeline extended.
+    pub enriched: usize,
+    /// Relations created for previously unconnected memories.
+    pub linked: usize,
+    /// Memories that failed, with why; the run continues past them.
+    pub failed: Vec<(String, String)>,
+}
+
+/// Batched backfill driver; see the module docs.
+pub struct Backfiller<'a> {
+    client: &'a dyn BrainAIClient,
+    embedder: &'a dyn Embedder,
+    enrichers: Vec<Arc<dyn Enricher>>,
+    options: BackfillOptions,
+}
+
+impl<'a> Backfiller<'a> {
+    /// A backfiller with the default options and the standard
+    /// enrichment pipeline (language, content length, source host —
+    /// timestamps are left alone so legacy memories keep their real
+    /// store dates).
+    pub fn new(client: &'a dyn BrainAIClient, embedder: &'a dyn Embedder) -> Self {
+        Backfiller {
+            client,
+            embedder,
+            enrichers: vec![
+                Arc::new(LanguageEnricher),
+                Arc::new(ContentLengthEnricher),
+                Arc::new(SourceHostEnricher),
+            ],
+            options: BackfillOptions::default(),
+        }
+    }
+
+    /// Overrides the options.
+    pub fn with_options(mut self, options: BackfillOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Replaces the enrichment pipeline.
+    pub fn with_enrichers(mut self, enrichers: Vec<Arc<dyn Enricher>>) -> Self {
+        self.enrichers = enrichers;
+        self
+    }
+
+    /// Runs one backfill pass over the brain.
+    pub async fn backfill(&self) -> Result<BackfillReport> {
+        let memories = self
+            .client
+            .list_memories(None, self.options.scan_limit)
+            .await?;
+        let mut report = BackfillReport {
+            scanned: memories.len(),
+            ..BackfillReport::default()
+        };
+        let candidates: Vec<Memory> = memories
+            .into_iter()
+            .filter(|memory| {
+                (self.options.embed && !memory.metadata.contains_key("vector_id"))
+                    || self.options.enrich
+                    || self.options.link
+            })
+            .collect();
+        report.candidates = candidates.len();
+        for (done, batch) in candidates.chunks(self.options.batch_size).enumerate() {
+            for memory in batch {
+                if let Err(err) = self.process(memory, &mut report).await {
+                    report.failed.push((memory.id.clone(), err.to_string()));
+                }
+            }
+            eprintln!(
+                "[brain-ai] backfill: {} of {} candidates processed",
+                ((done + 1) * self.options.batch_size).min(report.candidates),
+                report.candidates
+            );
+            if !self.options.delay.is_zero() {
+                tokio::time::sleep(self.options.delay).await;
+            }
+        }
+        Ok(report)
+    }
+
+    /// Brings one memory up to date.
+    async fn process(&self, memory: &Memory, report: &mut BackfillReport) -> Result<()> {
+        let mut metadata = memory.metadata.clone();
+        let mut changed = false;
+
+        if self.options.embed && !metadata.contains_key("vector_id") {
+            let text = content_text(&memory.content);
+            if !text.trim().is_empty() {
+                let vector = self.embedder.embed(&text).await?;
+                let vector_id = self
+                    .client
+                    .store_vector(
+                        vector,
+                        Some(HashMap::from([(
+                            "memory_id".to_string(),
+                            json!(memory.id),
+                        )])),
+                    )
+                    .await?;
+                metadata.insert("vector_id".to_string(), json!(vector_id));
+                changed = true;
+                report.embedded += 1;
+            }
+        }
+
+        if self.options.enrich {
+            let before = metadata.len();
+            for enricher in &self.enrichers {
+                enricher
+                    .enrich(&memory.content, memory.memory_type, &mut metadata)
+                    .await?;
+            }
+            if metadata.len() > before {
+                changed = true;
+                report.enriched += 1;
+            }
+        }
+
+        if changed {
+            self.client
+                .update_memory(&memory.id, memory.content.clone(), Some(metadata))
+                .await?;
+        }
+
+        if self.options.link {
+            let relations = self.client.get_relations(&memory.id, None).await?;
+            if relations.is_empty() {
+                let text = content_text(&memory.content);
+                let hits = self.client.search_memories(json!(text), 3).await?;
+                if let Some(neighbour) = hits
+                    .iter()
+                    .find(|hit| hit.id != memory.id && hit.score >= self.options.link_threshold)
+                {
+                    self.client
+                        .connect_memories(&memory.id, &neighbour.id, neighbour.score)
+                        .await?;
+                    report.linked += 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn content_text(content: &Value) -> String {
+    match content {
+        Value::String(s) => s.clone(),
+        other => other
+            .get("text")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| other.to_string()),
+    }
+}
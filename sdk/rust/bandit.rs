@@ -0,0 +1,211 @@
+//! Online bandit for retrieval parameter selection.
+//!
+//! The best similarity threshold and fusion weights differ by corpus
+//! and by query shape, and offline replay (the [`tuning`](crate::tuning)
+//! module) only covers traffic already recorded. [`RetrievalBandit`]
+//! tunes online instead: it keeps a small set of candidate
+//! [`RetrievalConfig`]s, picks one per query with UCB1 — exploring
+//! under-tried arms, exploiting the front-runner — and learns from
+//! downstream feedback as reward. Statistics are kept per namespace and
+//! query class so distinct corpora and query shapes converge on their
+//! own winners, and the whole state round-trips through a JSON file
+//! between runs.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BrainAIError, Result};
+
+/// One retrieval configuration the bandit can choose.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetrievalConfig {
+    /// Name identifying the arm in persisted state; keep it stable
+    /// across runs or the arm's history is lost.
+    pub name: String,
+    pub similarity_threshold: f64,
+    /// Fusion weight of the keyword channel in hybrid search.
+    pub keyword_weight: f64,
+    /// Fusion weight of the vector channel in hybrid search.
+    pub vector_weight: f64,
+}
+
+/// Reward history of one arm within one context.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ArmStats {
+    pulls: u64,
+    total_reward: f64,
+}
+
+impl ArmStats {
+    fn mean(&self) -> f64 {
+        if self.pulls == 0 {
+            0.0
+        } else {
+            self.total_reward / self.pulls as f64
+        }
+    }
+}
+
+/// Persisted bandit statistics: per context (namespace + query class),
+/// per arm name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct BanditState {
+    contexts: HashMap<String, HashMap<String, ArmStats>>,
+}
+
+/// UCB1 bandit over a fixed set of [`RetrievalConfig`] arms.
+pub struct RetrievalBandit {
+    arms: Vec<RetrievalConfig>,
+    state: Mutex<BanditState>,
+    path: Option<PathBuf>,
+}
+
+impl RetrievalBandit {
+    /// A fresh bandit over `arms` with no persisted state.
+    pub fn new(arms: Vec<RetrievalConfig>) -> Result<Self> {
+        if arms.is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "a bandit needs at least one arm".to_string(),
+            ));
+        }
+        Ok(RetrievalBandit {
+            arms,
+            state: Mutex::new(BanditState::default()),
+            path: None,
+        })
+    }
+
+    /// A bandit over `arms` resuming from the state file at `path`; a
+    /// missing file starts fresh. [`save`](Self::save) writes back to
+    /// the same path. Arms are matched to history by name, so arms can
+    /// be added or removed between runs without invalidating the rest.
+    pub fn load(path: impl AsRef<Path>, arms: Vec<RetrievalConfig>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut bandit = RetrievalBandit::new(arms)?;
+        match std::fs::read_to_string(path) {
+            Ok(text) => {
+                *bandit.state.get_mut().unwrap() = serde_json::from_str(&text)?;
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "cannot read bandit state {}: {err}",
+                    path.display()
+                )))
+            }
+        }
+        bandit.path = Some(path.to_path_buf());
+        Ok(bandit)
+    }
+
+    /// Persists the statistics to the path the bandit was loaded from.
+    pub fn save(&self) -> Result<()> {
+        let path = self.path.as_ref().ok_or_else(|| {
+            BrainAIError::InvalidInput(
+                "bandit has no state path; construct it with RetrievalBandit::load".to_string(),
+            )
+        })?;
+        let text = serde_json::to_string_pretty(&*self.state.lock().unwrap())?;
+        let temp = path.with_extension("tmp");
+        std::fs::write(&temp, text)
+            .and_then(|_| std::fs::rename(&temp, path))
+            .map_err(|err| {
+                BrainAIError::InvalidInput(format!(
+                    "cannot write bandit state {}: {err}",
+                    path.display()
+                ))
+            })
+    }
+
+    /// Picks the configuration to use for one query in the given
+    /// namespace and query class, UCB1-style: each arm is tried once
+    /// first, then the arm maximizing `mean + sqrt(2 ln n / pulls)`.
+    /// Record the outcome with [`reward`](Self::reward).
+    pub fn select(&self, namespace: &str, query_class: &str) -> RetrievalConfig {
+        let state = self.state.lock().unwrap();
+        let stats = state.contexts.get(&context_key(namespace, query_class));
+        let pulls_of = |arm: &RetrievalConfig| {
+            stats
+                .and_then(|arms| arms.get(&arm.name))
+                .cloned()
+                .unwrap_or_default()
+        };
+        if let Some(untried) = self.arms.iter().find(|arm| pulls_of(arm).pulls == 0) {
+            return untried.clone();
+        }
+        let total: u64 = self.arms.iter().map(|arm| pulls_of(arm).pulls).sum();
+        self.arms
+            .iter()
+            .max_by(|a, b| {
+                ucb(&pulls_of(a), total)
+                    .total_cmp(&ucb(&pulls_of(b), total))
+                    .then_with(|| b.name.cmp(&a.name))
+            })
+            .expect("constructor rejects an empty arm set")
+            .clone()
+    }
+
+    /// Credits the arm named `arm` with the observed reward (feedback
+    /// mapped into `0.0..=1.0`, e.g. `1.0` for a confirmed hit) within
+    /// the context it was selected for.
+    pub fn reward(&self, namespace: &str, query_class: &str, arm: &str, reward: f64) {
+        let mut state = self.state.lock().unwrap();
+        let stats = state
+            .contexts
+            .entry(context_key(namespace, query_class))
+            .or_default()
+            .entry(arm.to_string())
+            .or_default();
+        stats.pulls += 1;
+        stats.total_reward += reward.clamp(0.0, 1.0);
+    }
+
+    /// The arm with the best observed mean reward for a context, with
+    /// its mean; `None` before any reward has been recorded there.
+    pub fn best(&self, namespace: &str, query_class: &str) -> Option<(RetrievalConfig, f64)> {
+        let state = self.state.lock().unwrap();
+        let arms = state.contexts.get(&context_key(namespace, query_class))?;
+        self.arms
+            .iter()
+            .filter_map(|arm| {
+                arms.get(&arm.name)
+                    .filter(|stats| stats.pulls > 0)
+                    .map(|stats| (arm.clone(), stats.mean()))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1).then_with(|| b.0.name.cmp(&a.0.name)))
+    }
+}
+
+fn context_key(namespace: &str, query_class: &str) -> String {
+    format!("{namespace}/{query_class}")
+}
+
+/// UCB1 score: exploitation term plus exploration bonus shrinking with
+/// pulls.
+fn ucb(stats: &ArmStats, total: u64) -> f64 {
+    stats.mean() + (2.0 * (total.max(1) as f64).ln() / stats.pulls as f64).sqrt()
+}
+
+/// Coarse query classifier for bucketing bandit statistics: questions,
+/// short keyword lookups, and longer prose queries behave differently
+/// enough to tune separately.
+pub fn query_class(query: &str) -> &'static str {
+    let trimmed = query.trim();
+    let first = trimmed
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+    if trimmed.ends_with('?')
+        || matches!(first.as_str(), "who" | "what" | "when" | "where" | "why" | "how")
+    {
+        "question"
+    } else if trimmed.split_whitespace().count() <= 3 {
+        "keyword"
+    } else {
+        "prose"
+    }
+}
@@ -0,0 +1,251 @@
+//! Contradiction detection and belief maintenance.
+//!
+//! A brain that stores "the deploy is safe" next to "the deploy is not
+//! safe" will ground reasoning on both. [`detect_contradictions`] finds
+//! existing memories that oppose a candidate before (or after) it is
+//! stored, combining three signals: explicit
+//! [`RelationType::Contradicts`] edges, high content overlap with flipped
+//! negation, and high overlap with mismatched numbers.
+//! [`resolve_contradiction`] then applies a [`ResolutionStrategy`] —
+//! keep the newest, keep the
+//! strongest, or flag both sides for human review — and records a
+//! contradiction relation either way so the conflict stays visible to
+//! the reasoning grounding mode.
+
+use std::collections::HashSet;
+
+use serde_json::{json, Value};
+
+use crate::{BrainAIClient, Memory, RelationType, Result};
+
+/// Content overlap above which two memories are considered to be about
+/// the same thing, making an opposition signal meaningful.
+const OVERLAP_THRESHOLD: f64 = 0.5;
+
+/// What made a pair of memories look contradictory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContradictionSignal {
+    /// A [`RelationType::Contradicts`] relation already links them.
+    ExistingRelation,
+    /// Near-identical content but opposite negation ("is" vs "is not").
+    Negation,
+    /// Near-identical content but different numbers ("port 8080" vs
+    /// "port 9090").
+    NumericMismatch,
+}
+
+/// An existing memory that opposes the candidate.
+#[derive(Debug, Clone)]
+pub struct Contradiction {
+    /// The stored memory on the other side of the conflict.
+    pub memory: Memory,
+    /// Content overlap between the two sides in `0.0..=1.0`.
+    pub overlap: f64,
+    pub signal: ContradictionSignal,
+}
+
+/// How [`resolve_contradiction`] settles a contradiction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolutionStrategy {
+    /// Keep the more recently created side; demote the other to zero
+    /// strength.
+    KeepNewest,
+    /// Keep the stronger side; demote the other to zero strength.
+    KeepStrongest,
+    /// Change no strengths; mark both sides `needs_review` in metadata.
+    FlagForReview,
+}
+
+/// What [`resolve_contradiction`] did.
+#[derive(Debug, Clone)]
+pub struct Resolution {
+    /// The side that survived at full strength, when one did.
+    pub kept: Option<String>,
+    /// The side demoted to zero strength, when one was.
+    pub demoted: Option<String>,
+    /// IDs flagged `needs_review`.
+    pub flagged: Vec<String>,
+}
+
+/// Finds stored memories that contradict `new_memory` (a stored memory's
+/// ID). Checks explicit contradiction relations first, then scans
+/// similar memories for negation and numeric opposition.
+pub async fn detect_contradictions(
+    client: &dyn BrainAIClient,
+    new_memory: &str,
+) -> Result<Vec<Contradiction>> {
+    let Some(candidate) = client.get_memory(new_memory).await? else {
+        return Ok(Vec::new());
+    };
+    let mut found: Vec<Contradiction> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::from([candidate.id.clone()]);
+
+    // Explicit contradiction relations are authoritative.
+    for relation in client
+        .get_relations(new_memory, Some(RelationType::Contradicts))
+        .await?
+    {
+        let other_id = if relation.from == candidate.id {
+            relation.to
+        } else {
+            relation.from
+        };
+        if let Some(other) = client.get_memory(&other_id).await? {
+            if seen.insert(other.id.clone()) {
+                found.push(Contradiction {
+                    overlap: jaccard(&tokens(&candidate.content), &tokens(&other.content)),
+                    memory: other,
+                    signal: ContradictionSignal::ExistingRelation,
+                });
+            }
+        }
+    }
+
+    // Heuristics over semantically similar memories.
+    let candidate_tokens = tokens(&candidate.content);
+    let hits = client.search_memories(candidate.content.clone(), 20).await?;
+    for hit in hits {
+        if !seen.contains(&hit.id) {
+            let hit_tokens = tokens(&hit.content);
+            let overlap = jaccard(&candidate_tokens, &hit_tokens);
+            if overlap < OVERLAP_THRESHOLD {
+                continue;
+            }
+            let signal = if negated(&candidate.content) != negated(&hit.content) {
+                Some(ContradictionSignal::Negation)
+            } else if numbers(&candidate.content) != numbers(&hit.content) {
+                Some(ContradictionSignal::NumericMismatch)
+            } else {
+                None
+            };
+            if let Some(signal) = signal {
+                if let Some(other) = client.get_memory(&hit.id).await? {
+                    seen.insert(other.id.clone());
+                    found.push(Contradiction {
+                        memory: other,
+                        overlap,
+                        signal,
+                    });
+                }
+            }
+        }
+    }
+    found.sort_by(|a, b| b.overlap.total_cmp(&a.overlap));
+    Ok(found)
+}
+
+/// Settles one contradiction between `memory_id` and the detected other
+/// side. A [`RelationType::Contradicts`] relation is recorded in every
+/// case so the conflict remains queryable afterwards.
+pub async fn resolve_contradiction(
+    client: &dyn BrainAIClient,
+    memory_id: &str,
+    contradiction: &Contradiction,
+    strategy: ResolutionStrategy,
+) -> Result<Resolution> {
+    let other = &contradiction.memory;
+    client
+        .relate_memories(memory_id, &other.id, RelationType::Contradicts, 1.0)
+        .await?;
+
+    match strategy {
+        ResolutionStrategy::FlagForReview => {
+            let mut flagged = Vec::new();
+            for id in [memory_id, other.id.as_str()] {
+                if let Some(mut memory) = client.get_memory(id).await? {
+                    memory
+                        .metadata
+                        .insert("needs_review".to_string(), json!(true));
+                    client
+                        .update_memory(id, memory.content, Some(memory.metadata))
+                        .await?;
+                    flagged.push(id.to_string());
+                }
+            }
+            Ok(Resolution {
+                kept: None,
+                demoted: None,
+                flagged,
+            })
+        }
+        ResolutionStrategy::KeepNewest | ResolutionStrategy::KeepStrongest => {
+            let Some(this) = client.get_memory(memory_id).await? else {
+                return Ok(Resolution {
+                    kept: Some(other.id.clone()),
+                    demoted: None,
+                    flagged: Vec::new(),
+                });
+            };
+            let this_wins = match strategy {
+                ResolutionStrategy::KeepNewest => this.created_at >= other.created_at,
+                _ => this.strength >= other.strength,
+            };
+            let (winner, loser) = if this_wins {
+                (&this, other)
+            } else {
+                (other, &this)
+            };
+            // The loser is demoted, not deleted: decay will eventually
+            // reclaim it, and until then the history stays auditable.
+            client.update_memory_strength(&loser.id, -1.0).await?;
+            let mut metadata = loser.metadata.clone();
+            metadata.insert("contradicted_by".to_string(), json!(winner.id));
+            client
+                .update_memory(&loser.id, loser.content.clone(), Some(metadata))
+                .await?;
+            Ok(Resolution {
+                kept: Some(winner.id.clone()),
+                demoted: Some(loser.id.clone()),
+                flagged: Vec::new(),
+            })
+        }
+    }
+}
+
+fn tokens(content: &Value) -> HashSet<String> {
+    content
+        .to_string()
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty() && !NEGATORS.contains(t))
+        .map(str::to_string)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let intersection = a.intersection(b).count();
+    let union = a.len() + b.len() - intersection;
+    if union == 0 {
+        0.0
+    } else {
+        intersection as f64 / union as f64
+    }
+}
+
+/// Negation markers excluded from overlap tokens and counted for parity.
+const NEGATORS: [&str; 9] = [
+    "not", "no", "never", "none", "cannot", "can't", "don't", "isn't", "false",
+];
+
+/// Whether the content carries an odd number of negation markers — "not
+/// unsafe" cancels out, "not safe" does not.
+fn negated(content: &Value) -> bool {
+    let text = content.to_string().to_lowercase();
+    let count = text
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|t| NEGATORS.contains(t))
+        .count();
+    count % 2 == 1
+}
+
+/// The multiset of numbers in the content, order-independent.
+fn numbers(content: &Value) -> Vec<String> {
+    let text = content.to_string();
+    let mut nums: Vec<String> = text
+        .split(|c: char| !c.is_ascii_digit() && c != '.')
+        .filter(|t| t.chars().any(|c| c.is_ascii_digit()))
+        .map(str::to_string)
+        .collect();
+    nums.sort();
+    nums
+}
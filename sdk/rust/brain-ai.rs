@@ -0,0 +1,2581 @@
+//! Brain AI Framework — Rust SDK
+//!
+//! Async client for the Brain AI server providing persistent memory,
+//! continuous learning, reasoning, vector similarity search, and graph
+//! knowledge operations. All methods mirror the unified API documented in
+//! `docs/api-reference.md` and return the shared response envelope.
+//!
+//! # Example
+//! ```no_run
+//! use brain_ai::{BrainAIConfig, BrainAISDK, MemoryType};
+//! use serde_json::json;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let sdk = BrainAISDK::new(BrainAIConfig::new("http://localhost:8000"))?;
+//!     let memory_id = sdk
+//!         .store_memory(json!({"text": "Hello"}), MemoryType::Semantic, None)
+//!         .await?;
+//!     let results = sdk.search_memories("hello", 5).await?;
+//!     println!("stored {memory_id}, found {} related memories", results.len());
+//!     Ok(())
+//! }
+//! ```
+
+// Portable SIMD is still a nightly API; the `simd` feature opts in for
+// builds that can use it (see `vector_utils`).
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+pub mod adaptive;
+pub mod analytics;
+pub mod backfill;
+pub mod bandit;
+pub mod belief;
+pub mod bulk;
+pub mod cache;
+pub mod calibration;
+pub mod circuit;
+pub mod cli;
+pub mod client;
+pub mod confidence;
+pub mod crawl;
+pub mod crypto;
+pub mod dataframe;
+pub mod decay;
+pub mod dedup;
+pub mod embed;
+pub mod endpoint;
+pub mod enrich;
+pub mod episodes;
+pub mod events;
+pub mod executor;
+pub mod expiry;
+pub mod explain;
+pub mod federate;
+pub mod filter;
+pub mod graphexport;
+pub mod graphload;
+pub mod graphquery;
+pub mod heatmap;
+pub mod hedge;
+pub mod hnsw;
+pub mod hybrid;
+pub mod idgen;
+pub mod ingest;
+pub mod metrics;
+pub mod middleware;
+pub mod mock;
+pub mod notebook;
+pub mod notify;
+pub mod offline;
+pub mod otel;
+pub mod pathfind;
+pub mod policy;
+pub mod pressure;
+pub mod probes;
+pub mod qa;
+pub mod quantize;
+pub mod quota;
+pub mod ratelimit;
+pub mod raw;
+pub mod reasoncache;
+pub mod refcount;
+pub mod refresh;
+pub mod rehearsal;
+pub mod rerank;
+pub mod reward;
+pub mod session;
+pub mod slowlog;
+pub mod snapshot;
+pub mod source;
+pub mod sparse;
+pub mod staging;
+pub mod stream;
+pub mod temporal;
+pub mod testkit;
+pub mod trace;
+pub mod transfer;
+pub mod trash;
+pub mod tuning;
+pub mod typed;
+pub mod watch;
+pub mod working_memory;
+pub mod writebuf;
+
+pub use adaptive::{AdaptiveLimiter, AdaptiveOptions};
+pub use analytics::{analyze_subgraph, GraphAnalytics, NodeScore};
+pub use backfill::{Backfiller, BackfillOptions, BackfillReport};
+pub use bandit::{query_class, RetrievalBandit, RetrievalConfig};
+pub use belief::{
+    detect_contradictions, resolve_contradiction, Contradiction, ResolutionStrategy,
+};
+pub use cache::{CacheConfig, ReadCache};
+pub use calibration::{CalibrationMethod, CalibrationOptions, ConfidenceCalibrator};
+pub use circuit::{CircuitBreaker, CircuitBreakerOptions, CircuitState};
+pub use client::BrainAIClient;
+pub use confidence::{search_with_min_confidence, store_with_confidence};
+pub use crawl::{CrawlOptions, CrawlReport, Crawler};
+#[cfg(feature = "crypto")]
+pub use crypto::{EncryptedStore, KeyProvider, StaticKeyProvider};
+#[cfg(feature = "arrow")]
+pub use dataframe::ToArrow;
+#[cfg(feature = "polars")]
+pub use dataframe::ToPolars;
+pub use embed::{Embedder, HashingEmbedder, OpenAiEmbedder};
+pub use enrich::{EnrichedStore, Enricher};
+pub use endpoint::Endpoint;
+pub use episodes::{load_episode, replay_episode, EpisodeRecorder, EpisodeStep, ReplayReport};
+pub use events::{EventFilter, EventSubscription, SubscribeOptions};
+pub use executor::{BatchExecProgress, BatchExecReport, BatchExecutor, BatchExecutorOptions};
+pub use federate::{FederatedHit, FederatedSearch, Federation, Fusion, HitOrigin};
+pub use filter::MemoryFilter;
+pub use graphexport::{export_subgraph, ExportFormat, Subgraph};
+pub use graphload::{EdgeRecord, GraphLoader, GraphLoadOptions, NodeRecord};
+pub use graphquery::{GraphQuery, Predicate};
+pub use hedge::{HedgeOptions, Hedger};
+pub use hybrid::{hybrid_search, HybridResult, HybridWeights};
+pub use idgen::deterministic_id;
+pub use metrics::ClientMetrics;
+pub use middleware::{
+    HeaderMiddleware, LoggingMiddleware, Middleware, MiddlewareChain, MiddlewareRequest,
+    MiddlewareResponse,
+};
+pub use mock::MockBrainAI;
+pub use notebook::SyncBrain;
+pub use notify::{BrainEvent, NotificationChannel, Notifier};
+pub use offline::{OfflineQueue, QueuedWrite, SyncReport, WriteOutcome};
+pub use pathfind::{GraphPath, WeightMode};
+pub use policy::{PolicyDecision, PolicyEngine, WritePolicy};
+pub use pressure::Pressure;
+pub use probes::{ProbeOptions, ProbeServer};
+pub use qa::{
+    ask, verify_citations, Answer, Answerer, Citation, CitationVerifier, QuestionAnswerer,
+};
+pub use quota::{EvictionPolicy, QuotaEnforcer, QuotaPolicy, QuotaReport};
+pub use ratelimit::{RateLimiter, RateLimitOptions};
+pub use raw::{MemoryRef, RawResponse, SearchResultRef};
+pub use reasoncache::{ReasoningCache, ReasoningCacheOptions};
+pub use refresh::{attach_refresh_policy, Refresher, RefreshPolicy};
+pub use rerank::{search_memories_reranked, OverlapReranker, Reranker};
+pub use reward::{RewardLearner, RewardOptions, ScoredAction};
+pub use session::LearningSession;
+pub use slowlog::{SlowCall, SlowLog, SlowLogOptions};
+pub use snapshot::{diff_snapshots, BrainSnapshot, EdgeChange, SnapshotDiff};
+pub use source::{SourceRegistry, SourceReport};
+pub use sparse::SparseVector;
+pub use staging::{ReviewStatus, StagedWrite, StagingArea};
+pub use stream::{ReasoningEvent, ReasoningStream};
+pub use temporal::{search_memories_as_of, store_fact, supersede_fact};
+pub use tuning::{replay, ProfileReport, ReplayEntry, ReplayLog, ScoringProfile};
+pub use typed::{MemoryNode, TypedSearchResult};
+pub use watch::{watch_patterns, PatternWatch, WatchOptions};
+pub use writebuf::{DeadLetter, WriteBuffer, WriteBufferOptions};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Client, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// Configuration shared by every Brain AI SDK.
+///
+/// Matches the common configuration structure used across all nine language
+/// SDKs; only `base_url` is required.
+#[derive(Debug, Clone)]
+pub struct BrainAIConfig {
+    /// Brain AI server URL, e.g. `http://localhost:8000`.
+    pub base_url: String,
+    /// Optional API key sent as a `Bearer` token.
+    pub api_key: Option<String>,
+    /// Total per-request timeout, covering connect, send, and read.
+    pub timeout: Duration,
+    /// Time allowed for the TCP/TLS handshake; a host that never answers
+    /// fails fast instead of burning the whole request budget.
+    pub connect_timeout: Duration,
+    /// Idle time allowed between bytes of the response. `None` leaves only
+    /// the total timeout in effect, which suits long reasoning calls that
+    /// are slow to produce their first byte.
+    pub read_timeout: Option<Duration>,
+    /// Header carrying a per-request correlation ID, e.g.
+    /// `X-Request-Id`; `None` sends no such header.
+    pub request_id_header: Option<String>,
+    /// Maximum memory entries the brain should retain.
+    pub memory_size: usize,
+    /// Learning rate in `0.0..=1.0`.
+    pub learning_rate: f64,
+    /// Similarity threshold in `0.0..=1.0` applied to searches.
+    pub similarity_threshold: f64,
+    /// Maximum reasoning depth.
+    pub max_reasoning_depth: u32,
+    /// Maximum serialized size of one `batch()` request body; larger
+    /// batches are split automatically instead of drawing a server 413.
+    pub max_batch_payload_bytes: usize,
+    /// When set, reasoning results are cached client-side (see the
+    /// `reasoncache` module). Off by default.
+    pub reasoning_cache: Option<ReasoningCacheOptions>,
+    /// When set, returned reasoning confidences are mapped through a
+    /// locally fitted calibration curve (see the `calibration` module).
+    /// Off by default.
+    pub confidence_calibration: Option<CalibrationOptions>,
+    /// When set, calls exceeding their latency budget are logged and
+    /// counted (see the `slowlog` module). Off by default.
+    pub slow_log: Option<SlowLogOptions>,
+    /// When set, requests are throttled through a client-side token
+    /// bucket and 429 answers are retried after their `Retry-After`
+    /// (see the `ratelimit` module). Off by default.
+    pub rate_limit: Option<RateLimitOptions>,
+    /// When set, repeated transport failures or 5xx answers open a
+    /// circuit breaker and calls fail fast with
+    /// [`BrainAIError::CircuitOpen`] until a cooldown probe succeeds
+    /// (see the `circuit` module). Off by default.
+    pub circuit_breaker: Option<CircuitBreakerOptions>,
+    /// When set, cacheable reads (memories by ID, relations, patterns)
+    /// are served from a local persistent TTL cache (see the `cache`
+    /// module). Off by default.
+    pub cache: Option<CacheConfig>,
+}
+
+impl BrainAIConfig {
+    /// Creates a configuration with the documented defaults.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        BrainAIConfig {
+            base_url: base_url.into(),
+            api_key: None,
+            timeout: Duration::from_millis(30_000),
+            connect_timeout: Duration::from_millis(5_000),
+            read_timeout: None,
+            request_id_header: None,
+            memory_size: 10_000,
+            learning_rate: 0.1,
+            similarity_threshold: 0.7,
+            max_reasoning_depth: 5,
+            max_batch_payload_bytes: 1_048_576,
+            reasoning_cache: None,
+            confidence_calibration: None,
+            slow_log: None,
+            rate_limit: None,
+            circuit_breaker: None,
+            cache: None,
+        }
+    }
+
+    /// Sets the API key used for authentication.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets the total per-request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the connection-establishment timeout.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets the idle read timeout between response bytes.
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum serialized size of one batch request body.
+    pub fn with_max_batch_payload_bytes(mut self, bytes: usize) -> Self {
+        self.max_batch_payload_bytes = bytes.max(1);
+        self
+    }
+
+    /// Enables client-side caching of reasoning results.
+    pub fn with_reasoning_cache(mut self, options: ReasoningCacheOptions) -> Self {
+        self.reasoning_cache = Some(options);
+        self
+    }
+
+    /// Enables calibration of returned reasoning confidences. Feed
+    /// outcomes into [`BrainAISDK::calibrator`] for the curve to learn.
+    pub fn with_confidence_calibration(mut self, options: CalibrationOptions) -> Self {
+        self.confidence_calibration = Some(options);
+        self
+    }
+
+    /// Sends a fresh correlation ID with every request in the named
+    /// header, so one call can be found in both client and server logs.
+    pub fn with_request_id_header(mut self, header: impl Into<String>) -> Self {
+        self.request_id_header = Some(header.into());
+        self
+    }
+
+    /// Enables slow-call logging against per-operation latency budgets.
+    pub fn with_slow_log(mut self, options: SlowLogOptions) -> Self {
+        self.slow_log = Some(options);
+        self
+    }
+
+    /// Enables client-side rate limiting and `Retry-After` handling.
+    pub fn with_rate_limit(mut self, options: RateLimitOptions) -> Self {
+        self.rate_limit = Some(options);
+        self
+    }
+
+    /// Enables the circuit breaker around the transport.
+    pub fn with_circuit_breaker(mut self, options: CircuitBreakerOptions) -> Self {
+        self.circuit_breaker = Some(options);
+        self
+    }
+
+    /// Enables the local persistent read cache.
+    pub fn with_cache(mut self, config: CacheConfig) -> Self {
+        self.cache = Some(config);
+        self
+    }
+}
+
+impl Default for BrainAIConfig {
+    fn default() -> Self {
+        BrainAIConfig::new("http://localhost:8000")
+    }
+}
+
+/// Errors returned by the Brain AI SDK.
+#[derive(Debug)]
+pub enum BrainAIError {
+    /// Transport-level failure (connection, timeout, TLS, ...).
+    Http(reqwest::Error),
+    /// The server answered with a non-success status or envelope.
+    Api { status: u16, message: String },
+    /// The circuit breaker is open after repeated failures; the value
+    /// is the time remaining until the next probe.
+    CircuitOpen(Duration),
+    /// The requested resource does not exist.
+    NotFound(String),
+    /// The caller supplied invalid parameters.
+    InvalidInput(String),
+    /// A payload could not be serialized or deserialized.
+    Serialization(serde_json::Error),
+}
+
+impl fmt::Display for BrainAIError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BrainAIError::Http(err) => write!(f, "http error: {err}"),
+            BrainAIError::Api { status, message } => {
+                write!(f, "api error ({status}): {message}")
+            }
+            BrainAIError::CircuitOpen(wait) => {
+                write!(f, "circuit open: next probe in {}ms", wait.as_millis())
+            }
+            BrainAIError::NotFound(what) => write!(f, "not found: {what}"),
+            BrainAIError::InvalidInput(why) => write!(f, "invalid input: {why}"),
+            BrainAIError::Serialization(err) => write!(f, "serialization error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for BrainAIError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BrainAIError::Http(err) => Some(err),
+            BrainAIError::Serialization(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for BrainAIError {
+    fn from(err: reqwest::Error) -> Self {
+        BrainAIError::Http(err)
+    }
+}
+
+impl From<serde_json::Error> for BrainAIError {
+    fn from(err: serde_json::Error) -> Self {
+        BrainAIError::Serialization(err)
+    }
+}
+
+/// Convenience alias used throughout the SDK.
+pub type Result<T> = std::result::Result<T, BrainAIError>;
+
+/// The four memory types supported by Brain AI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemoryType {
+    /// Specific experiences and events.
+    Episodic,
+    /// General knowledge and facts.
+    Semantic,
+    /// Skills and processes.
+    Procedural,
+    /// Preferences and associations.
+    Emotional,
+}
+
+impl MemoryType {
+    /// Wire representation of the memory type.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemoryType::Episodic => "episodic",
+            MemoryType::Semantic => "semantic",
+            MemoryType::Procedural => "procedural",
+            MemoryType::Emotional => "emotional",
+        }
+    }
+}
+
+impl fmt::Display for MemoryType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Node category in the knowledge graph.
+///
+/// Extensible: unknown categories round-trip through [`NodeType::Custom`]
+/// instead of failing to deserialize, but the well-known ones are typed
+/// so a typo can no longer create a phantom category.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NodeType {
+    Concept,
+    Entity,
+    Event,
+    /// A whole ingested document (see the `ingest` module).
+    Document,
+    /// One chunk of an ingested document.
+    Chunk,
+    /// Anything else, carried verbatim.
+    Custom(String),
+}
+
+impl NodeType {
+    /// Wire representation of the node type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            NodeType::Concept => "concept",
+            NodeType::Entity => "entity",
+            NodeType::Event => "event",
+            NodeType::Document => "document",
+            NodeType::Chunk => "chunk",
+            NodeType::Custom(name) => name,
+        }
+    }
+}
+
+impl From<&str> for NodeType {
+    fn from(name: &str) -> Self {
+        match name {
+            "concept" => NodeType::Concept,
+            "entity" => NodeType::Entity,
+            "event" => NodeType::Event,
+            "document" => NodeType::Document,
+            "chunk" => NodeType::Chunk,
+            other => NodeType::Custom(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for NodeType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NodeType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(NodeType::from(name.as_str()))
+    }
+}
+
+/// Operation kind inside a [`BatchOperation`].
+///
+/// Same extensibility contract as [`NodeType`]: known operations are
+/// typed, unknown ones ride through [`OperationType::Custom`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OperationType {
+    StoreMemory,
+    DeleteMemory,
+    Learn,
+    StoreVector,
+    /// Create a graph node (see the `graphload` module).
+    CreateNode,
+    /// Create a typed graph edge (see the `graphload` module).
+    CreateEdge,
+    Custom(String),
+}
+
+impl OperationType {
+    /// Wire representation of the operation type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            OperationType::StoreMemory => "store_memory",
+            OperationType::DeleteMemory => "delete_memory",
+            OperationType::Learn => "learn",
+            OperationType::StoreVector => "store_vector",
+            OperationType::CreateNode => "create_node",
+            OperationType::CreateEdge => "create_edge",
+            OperationType::Custom(name) => name,
+        }
+    }
+}
+
+impl From<&str> for OperationType {
+    fn from(name: &str) -> Self {
+        match name {
+            "store_memory" => OperationType::StoreMemory,
+            "delete_memory" => OperationType::DeleteMemory,
+            "learn" => OperationType::Learn,
+            "store_vector" => OperationType::StoreVector,
+            "create_node" => OperationType::CreateNode,
+            "create_edge" => OperationType::CreateEdge,
+            other => OperationType::Custom(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for OperationType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for OperationType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(OperationType::from(name.as_str()))
+    }
+}
+
+/// Semantic kind of a connection between two memories.
+///
+/// Same extensibility contract as [`NodeType`]. [`RelationType::Contradicts`]
+/// is the one the reasoning grounding mode treats specially: contradiction
+/// links mark beliefs that cannot both be relied on at full strength.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RelationType {
+    /// `from` causes or leads to `to`.
+    Causal,
+    /// `from` happened before `to`.
+    Temporal,
+    /// The two memories describe the same thing; direction carries no
+    /// meaning.
+    Similar,
+    /// The two memories cannot both hold; direction carries no meaning.
+    Contradicts,
+    /// `from` is evidence for `to`.
+    Supports,
+    /// Anything else, carried verbatim.
+    Custom(String),
+}
+
+impl RelationType {
+    /// Wire representation of the relation type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            RelationType::Causal => "causal",
+            RelationType::Temporal => "temporal",
+            RelationType::Similar => "similar",
+            RelationType::Contradicts => "contradicts",
+            RelationType::Supports => "supports",
+            RelationType::Custom(name) => name,
+        }
+    }
+}
+
+impl From<&str> for RelationType {
+    fn from(name: &str) -> Self {
+        match name {
+            "causal" => RelationType::Causal,
+            "temporal" => RelationType::Temporal,
+            "similar" => RelationType::Similar,
+            "contradicts" => RelationType::Contradicts,
+            "supports" => RelationType::Supports,
+            other => RelationType::Custom(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for RelationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for RelationType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RelationType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(RelationType::from(name.as_str()))
+    }
+}
+
+/// A typed connection between two memories.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryRelation {
+    pub from: String,
+    pub to: String,
+    pub relation: RelationType,
+    /// Connection strength in `0.0..=1.0`.
+    pub strength: f64,
+}
+
+/// A stored memory as returned by the server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Memory {
+    pub id: String,
+    pub content: Value,
+    #[serde(rename = "type")]
+    pub memory_type: MemoryType,
+    #[serde(default)]
+    pub metadata: HashMap<String, Value>,
+    /// Current strength in `0.0..=1.0`.
+    #[serde(default)]
+    pub strength: f64,
+    /// Creation time (unix milliseconds).
+    #[serde(default)]
+    pub created_at: i64,
+    /// Last access time (unix milliseconds).
+    #[serde(default)]
+    pub last_accessed: i64,
+}
+
+/// Components contributing to a search hit's score, when the server
+/// reports them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    /// Raw content similarity to the query.
+    #[serde(default)]
+    pub similarity: f64,
+    /// Bonus from the memory's current strength.
+    #[serde(default)]
+    pub strength_bonus: f64,
+    /// Bonus from recent access.
+    #[serde(default)]
+    pub recency_bonus: f64,
+}
+
+/// A single memory search hit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub id: String,
+    /// Combined score in `0.0..=1.0`.
+    pub score: f64,
+    pub content: Value,
+    #[serde(default)]
+    pub metadata: HashMap<String, Value>,
+    /// Per-component score breakdown; `None` on servers that only report
+    /// the combined score.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<ScoreBreakdown>,
+}
+
+/// Time spent in one stage of a search's execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageTiming {
+    /// Stage name, e.g. `"scan"`, `"score"`, `"rank"`.
+    pub stage: String,
+    pub duration_ms: f64,
+}
+
+/// How the server executed one search; see
+/// [`BrainAISDK::search_memories_profiled`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryProfile {
+    /// Candidate memories examined before scoring.
+    pub candidates_scanned: u64,
+    /// Metadata index the scan used, `None` for a full scan.
+    #[serde(default)]
+    pub index_used: Option<String>,
+    /// Fraction of scanned candidates surviving the filters.
+    #[serde(default)]
+    pub filter_selectivity: f64,
+    /// Per-stage timings, in execution order.
+    #[serde(default)]
+    pub stages: Vec<StageTiming>,
+}
+
+/// A search's hits together with its [`QueryProfile`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfiledSearch {
+    pub results: Vec<SearchResult>,
+    pub profile: QueryProfile,
+}
+
+/// One page of memories from [`BrainAISDK::list_memories_page`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryPage {
+    pub memories: Vec<Memory>,
+    /// Opaque cursor for the next page; `None` when the listing is exhausted.
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
+/// A server-side index over one metadata field.
+///
+/// Filters on indexed fields hit an index instead of scanning every
+/// memory; see [`MemoryFilter::lint`](crate::MemoryFilter::lint) for
+/// catching unindexed filter targets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataIndex {
+    /// Metadata field the index covers, without the `metadata.` prefix.
+    pub field: String,
+    /// When the index was created (unix milliseconds).
+    #[serde(default)]
+    pub created_at: i64,
+}
+
+/// Per-memory usage statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryStats {
+    pub access_count: u64,
+    pub last_accessed: i64,
+    pub creation_time: i64,
+    pub connection_count: u64,
+    pub average_relevance: f64,
+}
+
+/// A learned pattern and its statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearningPattern {
+    pub pattern: String,
+    pub frequency: u64,
+    /// Pattern strength in `0.0..=1.0`.
+    pub strength: f64,
+    #[serde(default)]
+    pub context: Vec<String>,
+    pub last_updated: i64,
+    pub confidence: f64,
+}
+
+/// Progress statistics for the learning system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearningProgress {
+    pub total_patterns: u64,
+    pub active_patterns: u64,
+    pub learning_accuracy: f64,
+    pub improvement_rate: f64,
+    pub last_training: i64,
+}
+
+/// What a piece of feedback is about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "id", rename_all = "lowercase")]
+pub enum FeedbackTarget {
+    /// A stored memory.
+    Memory(String),
+    /// A reasoning result.
+    Reasoning(String),
+    /// A learned pattern, by its pattern string.
+    Pattern(String),
+}
+
+/// Structured feedback; see [`BrainAISDK::submit_feedback`].
+///
+/// Unlike the free-text [`add_feedback`](BrainAISDK::add_feedback), this
+/// can name what it is about, carry the right answer, and weight how
+/// much it matters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Feedback {
+    /// `positive`, `negative`, or `neutral`.
+    #[serde(rename = "type")]
+    pub feedback_type: String,
+    /// What the feedback refers to, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<FeedbackTarget>,
+    /// The observation itself.
+    pub information: String,
+    /// The right answer, for corrective feedback.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correction: Option<Value>,
+    /// How much this matters, in `0.0..=1.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<f64>,
+    /// Why the feedback was given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reasoning: Option<String>,
+}
+
+impl Feedback {
+    /// Positive feedback with the given observation.
+    pub fn positive(information: impl Into<String>) -> Self {
+        Feedback::new("positive", information)
+    }
+
+    /// Negative feedback with the given observation.
+    pub fn negative(information: impl Into<String>) -> Self {
+        Feedback::new("negative", information)
+    }
+
+    fn new(feedback_type: &str, information: impl Into<String>) -> Self {
+        Feedback {
+            feedback_type: feedback_type.to_string(),
+            target: None,
+            information: information.into(),
+            correction: None,
+            severity: None,
+            reasoning: None,
+        }
+    }
+
+    /// Names what the feedback is about.
+    pub fn with_target(mut self, target: FeedbackTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Attaches the right answer.
+    pub fn with_correction(mut self, correction: impl Into<Value>) -> Self {
+        self.correction = Some(correction.into());
+        self
+    }
+
+    /// Weights the feedback, `0.0..=1.0`.
+    pub fn with_severity(mut self, severity: f64) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Explains why the feedback was given.
+    pub fn with_reasoning(mut self, reasoning: impl Into<String>) -> Self {
+        self.reasoning = Some(reasoning.into());
+        self
+    }
+}
+
+/// Result of a reasoning query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasoningResult {
+    pub conclusion: String,
+    /// Confidence in `0.0..=1.0`.
+    pub confidence: f64,
+    #[serde(default)]
+    pub reasoning_path: Vec<String>,
+    #[serde(default)]
+    pub supporting_evidence: Vec<String>,
+    /// How the conclusion was derived; `None` on servers that predate
+    /// reasoning strategies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<ReasoningStrategy>,
+    pub timestamp: i64,
+}
+
+/// How the reasoning engine derives conclusions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReasoningStrategy {
+    /// From general rules to a specific conclusion.
+    Deductive,
+    /// Generalizing from observed instances.
+    Inductive,
+    /// The most plausible explanation for the evidence.
+    Abductive,
+    /// Transferring structure from a similar known case.
+    Analogical,
+}
+
+/// Tuning for one reasoning run; every field falls back to the server
+/// or [`BrainAIConfig`] default when `None`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ReasoningOptions {
+    /// Derivation strategy; the server picks when absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<ReasoningStrategy>,
+    /// Overrides [`BrainAIConfig::max_reasoning_depth`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<u32>,
+    /// Cap on memories pulled in as evidence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence_limit: Option<usize>,
+    /// Exploration temperature in `0.0..=1.0`; higher entertains less
+    /// likely reasoning paths.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+}
+
+/// A stored vector with its data and metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorRecord {
+    pub id: String,
+    pub vector: Vec<f32>,
+    #[serde(default)]
+    pub metadata: HashMap<String, Value>,
+}
+
+/// A stored vector hit from similarity search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorMatch {
+    pub id: String,
+    /// Cosine similarity in `0.0..=1.0`.
+    pub score: f64,
+    #[serde(default)]
+    pub metadata: HashMap<String, Value>,
+}
+
+/// A node in the knowledge graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphNode {
+    pub id: String,
+    pub label: String,
+    #[serde(rename = "type")]
+    pub node_type: NodeType,
+    #[serde(default)]
+    pub properties: HashMap<String, Value>,
+    /// Edge weight when returned as a neighbor, `0.0..=1.0`.
+    #[serde(default)]
+    pub weight: f64,
+}
+
+/// A typed edge in the knowledge graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub id: String,
+    pub from: String,
+    pub to: String,
+    /// Relationship label, e.g. `"causes"` or `"part_of"`.
+    pub label: String,
+    /// `true` for `from → to` only; `false` for a symmetric relation.
+    #[serde(default)]
+    pub directed: bool,
+    #[serde(default)]
+    pub properties: HashMap<String, Value>,
+    /// Edge weight in `0.0..=1.0`.
+    #[serde(default)]
+    pub weight: f64,
+}
+
+/// Edge direction filter for [`BrainAISDK::get_edges`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EdgeDirection {
+    /// Edges leaving the node.
+    Outgoing,
+    /// Edges arriving at the node.
+    Incoming,
+    /// Both, plus undirected edges.
+    Any,
+}
+
+/// System status snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemStatus {
+    pub status: String,
+    pub version: String,
+    pub uptime: u64,
+    pub memory_usage: f64,
+    pub cpu_usage: f64,
+    pub active_connections: u64,
+    #[serde(default)]
+    pub issues: Vec<String>,
+}
+
+/// Comprehensive system statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemStatistics {
+    pub total_memories: u64,
+    #[serde(default)]
+    pub memory_types: HashMap<String, u64>,
+    pub learning_patterns: u64,
+    pub vector_count: u64,
+    pub graph_nodes: u64,
+    pub average_response_time: u64,
+    pub cache_hit_rate: f64,
+}
+
+/// Information about a created backup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupInfo {
+    pub backup_id: String,
+    pub size_mb: f64,
+    pub created_at: i64,
+}
+
+/// One operation inside a [`BrainAISDK::batch`] request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchOperation {
+    #[serde(rename = "type")]
+    pub operation_type: OperationType,
+    pub data: Value,
+}
+
+/// One memory to store via [`BrainAISDK::store_memories`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryWrite {
+    pub content: Value,
+    #[serde(rename = "type")]
+    pub memory_type: MemoryType,
+    #[serde(default)]
+    pub metadata: HashMap<String, Value>,
+}
+
+/// Outcome of a chunked bulk store, indexed against the input order.
+#[derive(Debug, Default, Clone)]
+pub struct StoreManyReport {
+    /// `(input_index, memory_id)` for stored memories.
+    pub stored: Vec<(usize, String)>,
+    /// `(input_index, error)` for rejected memories.
+    pub failed: Vec<(usize, String)>,
+}
+
+impl StoreManyReport {
+    /// Whether every input memory was stored.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Result of one batched operation, in request order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResult {
+    pub success: bool,
+    #[serde(default)]
+    pub data: Option<Value>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// The response envelope shared by every API endpoint.
+#[derive(Debug, Deserialize)]
+struct ApiResponse<T> {
+    success: bool,
+    #[serde(default = "Option::default")]
+    data: Option<T>,
+    #[serde(default)]
+    error: Option<String>,
+    #[allow(dead_code)]
+    #[serde(default)]
+    timestamp: i64,
+    #[allow(dead_code)]
+    #[serde(default)]
+    execution_time: u64,
+}
+
+/// Turns a parsed envelope into the payload or the error it reports.
+fn unwrap_envelope<T>(envelope: ApiResponse<T>, status: u16) -> Result<T> {
+    if !envelope.success {
+        return Err(BrainAIError::Api {
+            status,
+            message: envelope
+                .error
+                .unwrap_or_else(|| "unknown server error".to_string()),
+        });
+    }
+    envelope.data.ok_or(BrainAIError::Api {
+        status,
+        message: "response envelope contained no data".to_string(),
+    })
+}
+
+/// Async client for a Brain AI server.
+///
+/// The client is cheap to clone; clones share the underlying connection pool.
+#[derive(Debug, Clone)]
+pub struct BrainAISDK {
+    config: BrainAIConfig,
+    http: Client,
+    /// Present when the config enables reasoning caching; shared across
+    /// clones like the connection pool.
+    reasoning_cache: Option<Arc<ReasoningCache>>,
+    /// Present when the config enables confidence calibration; shared
+    /// across clones so feedback recorded anywhere benefits everyone.
+    calibrator: Option<Arc<ConfidenceCalibrator>>,
+    /// Present when the config enables slow-call logging; shared across
+    /// clones so counters cover the whole client.
+    slow_log: Option<Arc<SlowLog>>,
+    /// Interceptors around the request path; empty unless installed via
+    /// [`with_middleware`](Self::with_middleware).
+    middleware: MiddlewareChain,
+    /// Present when the config enables rate limiting; shared across
+    /// clones so the bucket covers the whole client.
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Present when the config enables the circuit breaker; shared
+    /// across clones so failures anywhere trip it for everyone.
+    circuit: Option<Arc<CircuitBreaker>>,
+    /// Present when the config enables the read cache; shared across
+    /// clones so hits and invalidations cover the whole client.
+    read_cache: Option<Arc<ReadCache>>,
+    /// Requests currently on the wire; shared across clones so
+    /// [`pressure`](Self::pressure) sees the whole client.
+    in_flight: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl BrainAISDK {
+    /// Creates a new SDK instance from the given configuration.
+    pub fn new(config: BrainAIConfig) -> Result<Self> {
+        let mut builder = Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout);
+        if let Some(read_timeout) = config.read_timeout {
+            builder = builder.read_timeout(read_timeout);
+        }
+        let http = builder.build()?;
+        let reasoning_cache = config
+            .reasoning_cache
+            .clone()
+            .map(|options| Arc::new(ReasoningCache::new(options)));
+        let calibrator = config
+            .confidence_calibration
+            .clone()
+            .map(|options| Arc::new(ConfidenceCalibrator::new(options)));
+        let slow_log = config
+            .slow_log
+            .clone()
+            .map(|options| Arc::new(SlowLog::new(options)));
+        let rate_limiter = config
+            .rate_limit
+            .clone()
+            .map(|options| Arc::new(RateLimiter::new(options)));
+        let circuit = config
+            .circuit_breaker
+            .clone()
+            .map(|options| Arc::new(CircuitBreaker::new(options)));
+        let read_cache = config
+            .cache
+            .clone()
+            .map(|options| ReadCache::new(options).map(Arc::new))
+            .transpose()?;
+        Ok(BrainAISDK {
+            config,
+            http,
+            reasoning_cache,
+            calibrator,
+            slow_log,
+            middleware: MiddlewareChain::default(),
+            rate_limiter,
+            circuit,
+            read_cache,
+            in_flight: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        })
+    }
+
+    /// Installs an interceptor chain around the request path; see the
+    /// [`middleware`] module for the hook semantics. Replaces any chain
+    /// installed earlier.
+    pub fn with_middleware(mut self, layers: Vec<Box<dyn Middleware>>) -> Self {
+        self.middleware = MiddlewareChain::new(layers);
+        self
+    }
+
+    /// Returns the configuration this client was built with.
+    pub fn config(&self) -> &BrainAIConfig {
+        &self.config
+    }
+
+    /// The reasoning cache, when enabled — wire its
+    /// [`handle_event`](ReasoningCache::handle_event) into an event
+    /// stream so memory changes invalidate dependent conclusions.
+    pub fn reasoning_cache(&self) -> Option<&ReasoningCache> {
+        self.reasoning_cache.as_deref()
+    }
+
+    /// The confidence calibrator, when enabled — record reasoning
+    /// outcomes through [`record`](ConfidenceCalibrator::record) so the
+    /// curve can learn how honest the engine's confidences are.
+    pub fn calibrator(&self) -> Option<&ConfidenceCalibrator> {
+        self.calibrator.as_deref()
+    }
+
+    /// The slow-call log, when enabled — inspect
+    /// [`recent`](SlowLog::recent) and [`counts`](SlowLog::counts) to
+    /// find which operations blow their latency budget.
+    pub fn slow_log(&self) -> Option<&SlowLog> {
+        self.slow_log.as_deref()
+    }
+
+    /// Times one call against the slow log, when enabled.
+    fn observe_latency(&self, path: &str, started: std::time::Instant) {
+        if let Some(slow_log) = &self.slow_log {
+            slow_log.record(&crate::slowlog::operation_label(path), started.elapsed());
+        }
+    }
+
+    /// The rate limiter, when enabled — [`available`](RateLimiter::available)
+    /// shows how much burst headroom remains.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_deref()
+    }
+
+    /// The circuit breaker, when enabled — [`state`](CircuitBreaker::state)
+    /// shows whether calls are currently failing fast.
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit.as_deref()
+    }
+
+    /// The read cache, when enabled — use
+    /// [`invalidate_memory`](ReadCache::invalidate_memory) or
+    /// [`clear`](ReadCache::clear) after writes made outside this client.
+    pub fn read_cache(&self) -> Option<&ReadCache> {
+        self.read_cache.as_deref()
+    }
+
+    /// Sends a built request through the rate limiter, when enabled:
+    /// waits for a bucket token first, and on a 429 answer freezes the
+    /// bucket for the server's `Retry-After` and retries within the
+    /// configured budget. Without a limiter the request goes straight
+    /// out and 429s surface to the caller.
+    async fn send_limited(&self, builder: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let _in_flight = crate::pressure::InFlightGuard::begin(&self.in_flight);
+        let Some(limiter) = &self.rate_limiter else {
+            return self.send_tracked(builder).await;
+        };
+        let mut attempt = 0;
+        loop {
+            limiter.acquire().await;
+            match builder.try_clone() {
+                Some(clone) => {
+                    let response = self.send_tracked(clone).await?;
+                    if response.status() == StatusCode::TOO_MANY_REQUESTS
+                        && attempt < limiter.max_retries()
+                    {
+                        let wait = crate::ratelimit::retry_after(response.headers())
+                            .unwrap_or(Duration::from_secs(1));
+                        limiter.freeze(wait);
+                        tokio::time::sleep(wait).await;
+                        attempt += 1;
+                        continue;
+                    }
+                    return Ok(response);
+                }
+                // Unclonable (streaming) bodies get one attempt.
+                None => return self.send_tracked(builder).await,
+            }
+        }
+    }
+
+    /// Dispatches one request with circuit-breaker accounting: fails
+    /// fast with [`BrainAIError::CircuitOpen`] while the circuit is
+    /// open, and counts transport failures and 5xx answers towards
+    /// opening it. A no-op pass-through when the breaker is off.
+    async fn send_tracked(&self, builder: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let Some(breaker) = &self.circuit else {
+            return Ok(builder.send().await?);
+        };
+        if let Err(wait) = breaker.admit() {
+            return Err(BrainAIError::CircuitOpen(wait));
+        }
+        match builder.send().await {
+            Ok(response) => {
+                if response.status().is_server_error() {
+                    breaker.record_failure();
+                } else {
+                    breaker.record_success();
+                }
+                Ok(response)
+            }
+            Err(err) => {
+                breaker.record_failure();
+                Err(err.into())
+            }
+        }
+    }
+
+    /// A fresh `(header, id)` pair when the config asks requests to
+    /// carry a correlation ID.
+    fn correlation(&self) -> Option<(String, String)> {
+        self.config
+            .request_id_header
+            .as_ref()
+            .map(|header| (header.clone(), crate::trace::next_request_id()))
+    }
+
+    /// Sends a request to a typed endpoint and unwraps the shared response
+    /// envelope.
+    async fn request<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint<'_>,
+        body: Option<Value>,
+    ) -> Result<T> {
+        let path = endpoint.path();
+        let started = std::time::Instant::now();
+        let correlation = self.correlation();
+        let span = crate::trace::CallSpan::begin(
+            &endpoint.method(),
+            &path,
+            correlation.as_ref().map(|(_, id)| id.as_str()),
+        );
+        // The fast path skips the middleware bookkeeping (and its
+        // extra pass through `Value`) when no chain is installed.
+        if self.middleware.is_empty() {
+            let (status, envelope) = span
+                .wrap(self.exchange::<T>(endpoint, &path, body.as_ref(), correlation.as_ref()))
+                .await
+                .inspect_err(|err| span.fail(err))?;
+            span.finish(status);
+            self.observe_latency(&path, started);
+            return unwrap_envelope(envelope, status);
+        }
+        let mut mw_request = MiddlewareRequest {
+            method: endpoint.method(),
+            path: path.clone(),
+            headers: Vec::new(),
+            body,
+        };
+        if let Some((header, id)) = &correlation {
+            mw_request.headers.push((header.clone(), id.clone()));
+        }
+        let short = self.middleware.before(&mut mw_request).await?;
+        let short_circuited = short.is_some();
+        let (status, body) = match short {
+            Some(body) => (200, body),
+            None => {
+                let (status, envelope) = span
+                    .wrap(self.exchange_with(&mw_request, &path))
+                    .await
+                    .inspect_err(|err| {
+                        span.fail(err);
+                        self.observe_latency(&path, started);
+                    })?;
+                (status, envelope)
+            }
+        };
+        span.finish(status);
+        let mw_response = MiddlewareResponse {
+            status,
+            body,
+            duration: started.elapsed(),
+            short_circuited,
+        };
+        self.middleware.after(&mw_request, &mw_response).await;
+        if !short_circuited {
+            self.observe_latency(&path, started);
+        }
+        let envelope: ApiResponse<T> = serde_json::from_value(mw_response.body)?;
+        unwrap_envelope(envelope, status)
+    }
+
+    /// One network exchange without middleware: sends and parses the
+    /// envelope directly.
+    async fn exchange<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint<'_>,
+        path: &str,
+        body: Option<&Value>,
+        correlation: Option<&(String, String)>,
+    ) -> Result<(u16, ApiResponse<T>)> {
+        let url = format!("{}{}", self.config.base_url.trim_end_matches('/'), path);
+        let mut trace_headers = Vec::new();
+        let otel = crate::otel::OtelCall::begin(&endpoint.method(), path, &mut trace_headers);
+        let mut builder = self.http.request(endpoint.method(), &url);
+        if let Some(key) = &self.config.api_key {
+            builder = builder.bearer_auth(key);
+        }
+        if let Some((header, id)) = correlation {
+            builder = builder.header(header, id);
+        }
+        for (header, value) in &trace_headers {
+            builder = builder.header(header, value);
+        }
+        if let Some(body) = body {
+            builder = builder.json(body);
+        }
+        let response = match self.send_limited(builder).await {
+            Ok(response) => response,
+            Err(err) => {
+                otel.fail(&err.to_string());
+                return Err(err);
+            }
+        };
+        let status = response.status();
+        otel.finish(status.as_u16());
+        if status == StatusCode::NOT_FOUND {
+            return Err(BrainAIError::NotFound(path.to_string()));
+        }
+        Ok((status.as_u16(), response.json().await?))
+    }
+
+    /// One network exchange carrying middleware mutations, returning the
+    /// raw body so `after` hooks can observe it before unwrapping.
+    async fn exchange_with(
+        &self,
+        mw_request: &MiddlewareRequest,
+        path: &str,
+    ) -> Result<(u16, Value)> {
+        let url = format!("{}{}", self.config.base_url.trim_end_matches('/'), path);
+        let mut trace_headers = Vec::new();
+        let otel = crate::otel::OtelCall::begin(&mw_request.method, path, &mut trace_headers);
+        let mut builder = self.http.request(mw_request.method.clone(), &url);
+        if let Some(key) = &self.config.api_key {
+            builder = builder.bearer_auth(key);
+        }
+        for (name, value) in mw_request.headers.iter().chain(&trace_headers) {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = &mw_request.body {
+            builder = builder.json(body);
+        }
+        let response = match self.send_limited(builder).await {
+            Ok(response) => response,
+            Err(err) => {
+                otel.fail(&err.to_string());
+                return Err(err);
+            }
+        };
+        let status = response.status();
+        otel.finish(status.as_u16());
+        if status == StatusCode::NOT_FOUND {
+            return Err(BrainAIError::NotFound(path.to_string()));
+        }
+        Ok((status.as_u16(), response.json().await?))
+    }
+
+    /// Sends a request and returns the body as a single undecoded buffer
+    /// for zero-copy deserialization via [`RawResponse::parse_data`].
+    ///
+    /// Useful for very large responses (bulk listings, exports) where
+    /// allocating an owned string per field is measurable overhead.
+    pub async fn request_raw(
+        &self,
+        endpoint: Endpoint<'_>,
+        body: Option<Value>,
+    ) -> Result<RawResponse> {
+        let path = endpoint.path();
+        let url = format!("{}{}", self.config.base_url.trim_end_matches('/'), path);
+        let correlation = self.correlation();
+        let span = crate::trace::CallSpan::begin(
+            &endpoint.method(),
+            &path,
+            correlation.as_ref().map(|(_, id)| id.as_str()),
+        );
+        let mut trace_headers = Vec::new();
+        let otel = crate::otel::OtelCall::begin(&endpoint.method(), &path, &mut trace_headers);
+        let mut builder = self.http.request(endpoint.method(), &url);
+        if let Some(key) = &self.config.api_key {
+            builder = builder.bearer_auth(key);
+        }
+        if let Some((header, id)) = &correlation {
+            builder = builder.header(header, id);
+        }
+        for (header, value) in &trace_headers {
+            builder = builder.header(header, value);
+        }
+        if let Some(body) = body {
+            builder = builder.json(&body);
+        }
+        let started = std::time::Instant::now();
+        let response = match span.wrap(self.send_limited(builder)).await {
+            Ok(response) => response,
+            Err(err) => {
+                otel.fail(&err.to_string());
+                span.fail(&err);
+                return Err(err);
+            }
+        };
+        let status = response.status();
+        otel.finish(status.as_u16());
+        if status == StatusCode::NOT_FOUND {
+            span.finish(status.as_u16());
+            self.observe_latency(&path, started);
+            return Err(BrainAIError::NotFound(path));
+        }
+        let bytes = span.wrap(response.bytes()).await?;
+        span.finish(status.as_u16());
+        self.observe_latency(&path, started);
+        Ok(RawResponse::new(bytes))
+    }
+
+    /// Sends a request and hands back the open response for incremental
+    /// consumption (SSE / chunked bodies). Used by the streaming APIs in
+    /// the `stream` module.
+    async fn request_stream(
+        &self,
+        endpoint: Endpoint<'_>,
+        body: Value,
+    ) -> Result<reqwest::Response> {
+        let path = endpoint.path();
+        let url = format!("{}{}", self.config.base_url.trim_end_matches('/'), path);
+        let correlation = self.correlation();
+        let span = crate::trace::CallSpan::begin(
+            &endpoint.method(),
+            &path,
+            correlation.as_ref().map(|(_, id)| id.as_str()),
+        );
+        let mut trace_headers = Vec::new();
+        let otel = crate::otel::OtelCall::begin(&endpoint.method(), &path, &mut trace_headers);
+        let mut builder = self
+            .http
+            .request(endpoint.method(), &url)
+            .header("Accept", "text/event-stream");
+        if let Some(key) = &self.config.api_key {
+            builder = builder.bearer_auth(key);
+        }
+        if let Some((header, id)) = &correlation {
+            builder = builder.header(header, id);
+        }
+        for (header, value) in &trace_headers {
+            builder = builder.header(header, value);
+        }
+        let started = std::time::Instant::now();
+        let response = match span.wrap(self.send_limited(builder.json(&body))).await {
+            Ok(response) => response,
+            Err(err) => {
+                otel.fail(&err.to_string());
+                span.fail(&err);
+                return Err(err);
+            }
+        };
+        // Streamed responses are timed to first byte; the body keeps
+        // flowing after the budget check.
+        self.observe_latency(&path, started);
+        let status = response.status();
+        span.finish(status.as_u16());
+        otel.finish(status.as_u16());
+        if status == StatusCode::NOT_FOUND {
+            return Err(BrainAIError::NotFound(path));
+        }
+        if !status.is_success() {
+            return Err(BrainAIError::Api {
+                status: status.as_u16(),
+                message: response.text().await.unwrap_or_default(),
+            });
+        }
+        Ok(response)
+    }
+
+    /// Like [`search_memories`](Self::search_memories) but returns the raw
+    /// body; parse it with [`RawResponse::parse_data`] into
+    /// `Vec<SearchResultRef>` to borrow instead of copy.
+    pub async fn search_memories_raw(
+        &self,
+        query: impl Into<Value>,
+        limit: usize,
+    ) -> Result<RawResponse> {
+        let body = json!({
+            "query": query.into(),
+            "limit": limit,
+            "similarity_threshold": self.config.similarity_threshold,
+        });
+        self.request_raw(Endpoint::SearchMemories, Some(body)).await
+    }
+
+    /// Like [`list_memories`](Self::list_memories) but returns the raw
+    /// body; parse it with [`RawResponse::parse_data`] into
+    /// `Vec<MemoryRef>` to borrow instead of copy.
+    pub async fn list_memories_raw(
+        &self,
+        filters: Option<HashMap<String, Value>>,
+        limit: usize,
+    ) -> Result<RawResponse> {
+        let body = json!({
+            "filters": filters.unwrap_or_default(),
+            "limit": limit,
+        });
+        self.request_raw(Endpoint::ListMemories, Some(body)).await
+    }
+
+    // ------------------------------------------------------------------
+    // Core methods
+    // ------------------------------------------------------------------
+
+    /// Stores a new memory in the brain and returns its ID.
+    pub async fn store_memory(
+        &self,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        let body = json!({
+            "content": content,
+            "type": memory_type,
+            "metadata": metadata.unwrap_or_default(),
+        });
+        let data: Value = self
+            .request(Endpoint::StoreMemory, Some(body))
+            .await?;
+        data.get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or(BrainAIError::Api {
+                status: 200,
+                message: "store_memory response missing id".to_string(),
+            })
+    }
+
+    /// Retrieves a memory by its ID, or `None` if it does not exist.
+    pub async fn get_memory(&self, id: &str) -> Result<Option<Memory>> {
+        let cache_key = ReadCache::memory_key(id);
+        if let Some(cached) = self.read_cache.as_ref().and_then(|c| c.get(&cache_key)) {
+            return Ok(Some(serde_json::from_value(cached)?));
+        }
+        match self
+            .request::<Memory>(Endpoint::GetMemory(id), None)
+            .await
+        {
+            Ok(memory) => {
+                if let Some(cache) = &self.read_cache {
+                    cache.put(cache_key, serde_json::to_value(&memory)?);
+                }
+                Ok(Some(memory))
+            }
+            Err(BrainAIError::NotFound(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Searches for memories similar to the query.
+    pub async fn search_memories(
+        &self,
+        query: impl Into<Value>,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let body = json!({
+            "query": query.into(),
+            "limit": limit,
+            "similarity_threshold": self.config.similarity_threshold,
+        });
+        self.request(Endpoint::SearchMemories, Some(body))
+            .await
+    }
+
+    /// Like [`search_memories`](Self::search_memories), asking the
+    /// server to explain itself: the hits come back with a
+    /// [`QueryProfile`] describing candidates scanned, the index used,
+    /// filter selectivity, and per-stage timings — enough to diagnose a
+    /// slow query without server-side log access.
+    pub async fn search_memories_profiled(
+        &self,
+        query: impl Into<Value>,
+        limit: usize,
+    ) -> Result<ProfiledSearch> {
+        let body = json!({
+            "query": query.into(),
+            "limit": limit,
+            "similarity_threshold": self.config.similarity_threshold,
+            "explain": true,
+        });
+        let result: ProfiledSearch = self.request(Endpoint::SearchMemories, Some(body)).await?;
+        if let Some(slow_log) = &self.slow_log {
+            let label = crate::slowlog::operation_label(&Endpoint::SearchMemories.path());
+            slow_log.attach_profile(&label, &result.profile);
+        }
+        Ok(result)
+    }
+
+    /// Creates a connection between two memories.
+    ///
+    /// Shorthand for [`BrainAISDK::relate_memories`] with
+    /// [`RelationType::Similar`].
+    pub async fn connect_memories(&self, id1: &str, id2: &str, strength: f64) -> Result<bool> {
+        self.relate_memories(id1, id2, RelationType::Similar, strength)
+            .await
+    }
+
+    /// Creates a typed connection between two memories.
+    ///
+    /// Direction runs `from` towards `to` for relations where it matters
+    /// (causal, temporal, supports). Relating the same pair again replaces
+    /// the existing relation.
+    pub async fn relate_memories(
+        &self,
+        from: &str,
+        to: &str,
+        relation: RelationType,
+        strength: f64,
+    ) -> Result<bool> {
+        let body = json!({
+            "id1": from,
+            "id2": to,
+            "relation": relation,
+            "strength": strength,
+        });
+        let connected: bool = self
+            .request(Endpoint::ConnectMemories, Some(body))
+            .await?;
+        if let Some(cache) = &self.read_cache {
+            cache.invalidate_relations(from);
+            cache.invalidate_relations(to);
+        }
+        Ok(connected)
+    }
+
+    /// Lists the relations a memory participates in, optionally limited to
+    /// one [`RelationType`] — `Some(RelationType::Contradicts)` is how
+    /// belief-maintenance code finds what a memory conflicts with.
+    pub async fn get_relations(
+        &self,
+        id: &str,
+        relation: Option<RelationType>,
+    ) -> Result<Vec<MemoryRelation>> {
+        let cache_key = ReadCache::relations_key(id, relation.as_ref());
+        if let Some(cached) = self.read_cache.as_ref().and_then(|c| c.get(&cache_key)) {
+            return Ok(serde_json::from_value(cached)?);
+        }
+        let body = json!({"id": id, "relation": relation});
+        let relations: Vec<MemoryRelation> = self
+            .request(Endpoint::MemoryRelations, Some(body))
+            .await?;
+        if let Some(cache) = &self.read_cache {
+            cache.put(cache_key, serde_json::to_value(&relations)?);
+        }
+        Ok(relations)
+    }
+
+    // ------------------------------------------------------------------
+    // Memory management
+    // ------------------------------------------------------------------
+
+    /// Updates the strength of a memory by `delta` (`-1.0..=1.0`).
+    pub async fn update_memory_strength(&self, id: &str, delta: f64) -> Result<bool> {
+        let body = json!({"delta": delta});
+        self.request(Endpoint::UpdateMemoryStrength(id), Some(body))
+            .await
+    }
+
+    /// Gets usage statistics for a memory.
+    pub async fn get_memory_stats(&self, id: &str) -> Result<MemoryStats> {
+        self.request(Endpoint::MemoryStats(id), None).await
+    }
+
+    /// Replaces the content (and optionally metadata) of an existing memory.
+    ///
+    /// Passing `None` for `metadata` leaves the stored metadata untouched.
+    pub async fn update_memory(
+        &self,
+        id: &str,
+        content: Value,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<bool> {
+        let body = json!({
+            "content": content,
+            "metadata": metadata,
+        });
+        if let Some(cache) = &self.read_cache {
+            cache.invalidate_memory(id);
+        }
+        self.request(Endpoint::UpdateMemory(id), Some(body))
+            .await
+    }
+
+    /// Deletes a memory from the brain.
+    pub async fn delete_memory(&self, id: &str) -> Result<bool> {
+        if let Some(cache) = &self.read_cache {
+            cache.invalidate_memory(id);
+        }
+        self.request(Endpoint::DeleteMemory(id), None)
+            .await
+    }
+
+    /// Deletes every memory matching the filters and returns the count
+    /// removed. Uses the same filter criteria as [`list_memories`].
+    ///
+    /// [`list_memories`]: BrainAISDK::list_memories
+    pub async fn delete_memories_by_filter(
+        &self,
+        filters: HashMap<String, Value>,
+    ) -> Result<u64> {
+        let body = json!({"filters": filters});
+        let data: Value = self
+            .request(Endpoint::DeleteMemoriesByFilter, Some(body))
+            .await?;
+        Ok(data.get("deleted").and_then(Value::as_u64).unwrap_or(0))
+    }
+
+    /// Lists memories with optional filtering.
+    pub async fn list_memories(
+        &self,
+        filters: Option<HashMap<String, Value>>,
+        limit: usize,
+    ) -> Result<Vec<Memory>> {
+        let body = json!({
+            "filters": filters.unwrap_or_default(),
+            "limit": limit,
+        });
+        self.request(Endpoint::ListMemories, Some(body))
+            .await
+    }
+
+    /// Lists memories one page at a time using an opaque cursor.
+    ///
+    /// Pass `None` as the cursor for the first page, then the returned
+    /// `next_cursor` for each subsequent page until it comes back `None`.
+    /// Cursors are stable across concurrent writes: a memory is returned at
+    /// most once per listing.
+    pub async fn list_memories_page(
+        &self,
+        filters: Option<HashMap<String, Value>>,
+        page_size: usize,
+        cursor: Option<&str>,
+    ) -> Result<MemoryPage> {
+        let body = json!({
+            "filters": filters.unwrap_or_default(),
+            "limit": page_size,
+            "cursor": cursor,
+        });
+        self.request(Endpoint::ListMemories, Some(body))
+            .await
+    }
+
+    /// Creates a server-side index over a metadata field so filters on
+    /// it stop scanning every memory. Returns whether the index was
+    /// newly created (`false` when it already existed).
+    pub async fn create_metadata_index(&self, field: &str) -> Result<bool> {
+        if field.trim().is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "index field must not be empty".to_string(),
+            ));
+        }
+        let body = json!({"field": field});
+        self.request(Endpoint::CreateMetadataIndex, Some(body)).await
+    }
+
+    /// Lists the metadata indexes the server maintains.
+    pub async fn list_indexes(&self) -> Result<Vec<MetadataIndex>> {
+        self.request(Endpoint::ListIndexes, None).await
+    }
+
+    // ------------------------------------------------------------------
+    // Learning system
+    // ------------------------------------------------------------------
+
+    /// Learns from new information and patterns.
+    pub async fn learn(&self, pattern: &str, context: Vec<String>) -> Result<bool> {
+        let body = json!({
+            "pattern": pattern,
+            "context": context,
+            "learning_rate": self.config.learning_rate,
+        });
+        self.request(Endpoint::Learn, Some(body))
+            .await
+    }
+
+    /// Retrieves learned patterns and their statistics.
+    pub async fn get_learning_patterns(&self) -> Result<Vec<LearningPattern>> {
+        let cache_key = ReadCache::patterns_key();
+        if let Some(cached) = self.read_cache.as_ref().and_then(|c| c.get(&cache_key)) {
+            return Ok(serde_json::from_value(cached)?);
+        }
+        let patterns: Vec<LearningPattern> = self
+            .request(Endpoint::LearningPatterns, None)
+            .await?;
+        if let Some(cache) = &self.read_cache {
+            cache.put(cache_key, serde_json::to_value(&patterns)?);
+        }
+        Ok(patterns)
+    }
+
+    /// Deletes one learned pattern by its pattern string. Returns whether
+    /// it existed.
+    pub async fn delete_pattern(&self, pattern: &str) -> Result<bool> {
+        let body = json!({"pattern": pattern});
+        self.request(Endpoint::DeletePattern, Some(body)).await
+    }
+
+    /// Multiplies every pattern's strength by `1.0 - rate`, so habits
+    /// fade unless re-reinforced. Returns the number of patterns decayed.
+    pub async fn decay_patterns(&self, rate: f64) -> Result<u64> {
+        if !(0.0..=1.0).contains(&rate) {
+            return Err(BrainAIError::InvalidInput(format!(
+                "decay rate must be in 0.0..=1.0, got {rate}"
+            )));
+        }
+        let body = json!({"rate": rate});
+        self.request(Endpoint::DecayPatterns, Some(body)).await
+    }
+
+    /// Removes patterns that are rare, weak, or stale: below
+    /// `min_frequency`, below `min_strength`, or not updated since
+    /// `older_than` (unix milliseconds). Unset criteria don't prune.
+    /// Returns the number removed.
+    pub async fn prune_patterns(
+        &self,
+        min_frequency: Option<u64>,
+        min_strength: Option<f64>,
+        older_than: Option<i64>,
+    ) -> Result<u64> {
+        let mut body = json!({});
+        if let Some(frequency) = min_frequency {
+            body["min_frequency"] = json!(frequency);
+        }
+        if let Some(strength) = min_strength {
+            body["min_strength"] = json!(strength);
+        }
+        if let Some(at) = older_than {
+            body["older_than"] = json!(at);
+        }
+        self.request(Endpoint::PrunePatterns, Some(body)).await
+    }
+
+    /// Provides feedback (`positive`, `negative`, or `neutral`) to improve
+    /// learning accuracy.
+    pub async fn add_feedback(
+        &self,
+        feedback_type: &str,
+        information: &str,
+        reasoning: Option<&str>,
+    ) -> Result<bool> {
+        let body = json!({
+            "type": feedback_type,
+            "information": information,
+            "reasoning": reasoning,
+        });
+        self.request(Endpoint::Feedback, Some(body))
+            .await
+    }
+
+    /// Submits structured feedback — targeted, optionally corrective,
+    /// optionally weighted — returning the feedback's ID so later calls
+    /// can reference or retract it.
+    pub async fn submit_feedback(&self, feedback: Feedback) -> Result<String> {
+        if let Some(severity) = feedback.severity {
+            if !(0.0..=1.0).contains(&severity) {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "feedback severity must be in 0.0..=1.0, got {severity}"
+                )));
+            }
+        }
+        let body = serde_json::to_value(&feedback)?;
+        self.request(Endpoint::Feedback, Some(body)).await
+    }
+
+    /// Retracts previously submitted feedback by its ID. Returns whether
+    /// it existed.
+    pub async fn retract_feedback(&self, feedback_id: &str) -> Result<bool> {
+        self.request(Endpoint::RetractFeedback(feedback_id), None)
+            .await
+    }
+
+    /// Gets information about the learning system's progress.
+    pub async fn get_learning_progress(&self) -> Result<LearningProgress> {
+        self.request(Endpoint::LearningProgress, None)
+            .await
+    }
+
+    // ------------------------------------------------------------------
+    // Reasoning engine
+    // ------------------------------------------------------------------
+
+    /// Performs reasoning on a query using stored knowledge.
+    pub async fn reason(&self, query: &str, context: Vec<String>) -> Result<ReasoningResult> {
+        self.reason_with_options(query, context, ReasoningOptions::default())
+            .await
+    }
+
+    /// Like [`reason`](Self::reason), with control over how the
+    /// conclusion is derived.
+    pub async fn reason_with_options(
+        &self,
+        query: &str,
+        context: Vec<String>,
+        options: ReasoningOptions,
+    ) -> Result<ReasoningResult> {
+        let cache_key = self
+            .reasoning_cache
+            .as_ref()
+            .map(|cache| {
+                let key = ReasoningCache::key(query, &context, &options);
+                (cache, key)
+            });
+        if let Some((cache, key)) = &cache_key {
+            if let Some(result) = cache.get(*key) {
+                return Ok(self.calibrated(result));
+            }
+        }
+        let mut body = json!({
+            "query": query,
+            "context": context,
+            "max_depth": options.max_depth.unwrap_or(self.config.max_reasoning_depth),
+        });
+        if let Some(strategy) = options.strategy {
+            body["strategy"] = json!(strategy);
+        }
+        if let Some(limit) = options.evidence_limit {
+            body["evidence_limit"] = json!(limit);
+        }
+        if let Some(temperature) = options.temperature {
+            body["temperature"] = json!(temperature);
+        }
+        let result: ReasoningResult = self.request(Endpoint::Reason, Some(body)).await?;
+        if let Some((cache, key)) = cache_key {
+            // Cache the raw result; calibration applies on the way out so
+            // a hit always reflects the current curve.
+            cache.insert(key, result.clone());
+        }
+        Ok(self.calibrated(result))
+    }
+
+    /// Maps a result's confidence through the calibration curve, when
+    /// one is enabled.
+    fn calibrated(&self, mut result: ReasoningResult) -> ReasoningResult {
+        if let Some(calibrator) = &self.calibrator {
+            result.confidence = calibrator.calibrate(result.confidence);
+        }
+        result
+    }
+
+    /// Gets a detailed explanation for a reasoning conclusion.
+    pub async fn explain_conclusion(&self, conclusion_id: &str) -> Result<Value> {
+        self.request(Endpoint::ExplainConclusion(conclusion_id), None)
+            .await
+    }
+
+    /// Validates the quality of a reasoning result.
+    pub async fn validate_reasoning(&self, reasoning_id: &str) -> Result<Value> {
+        self.request(Endpoint::ValidateReasoning(reasoning_id), None)
+            .await
+    }
+
+    // ------------------------------------------------------------------
+    // Vector operations
+    // ------------------------------------------------------------------
+
+    /// Stores a vector for similarity search and returns its ID.
+    pub async fn store_vector(
+        &self,
+        vector: Vec<f32>,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        let body = json!({
+            "vector": vector,
+            "metadata": metadata.unwrap_or_default(),
+        });
+        let data: Value = self
+            .request(Endpoint::StoreVector, Some(body))
+            .await?;
+        data.get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or(BrainAIError::Api {
+                status: 200,
+                message: "store_vector response missing id".to_string(),
+            })
+    }
+
+    /// Retrieves a stored vector by its ID, or `None` if it does not exist.
+    pub async fn get_vector(&self, id: &str) -> Result<Option<VectorRecord>> {
+        match self
+            .request(Endpoint::GetVector(id), None)
+            .await
+        {
+            Ok(record) => Ok(Some(record)),
+            Err(BrainAIError::NotFound(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Replaces a stored vector's data and/or metadata.
+    ///
+    /// `None` leaves the corresponding part untouched; passing `None` for
+    /// both is rejected.
+    pub async fn update_vector(
+        &self,
+        id: &str,
+        vector: Option<Vec<f32>>,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<bool> {
+        if vector.is_none() && metadata.is_none() {
+            return Err(BrainAIError::InvalidInput(
+                "update_vector requires a new vector or metadata".to_string(),
+            ));
+        }
+        let body = json!({"vector": vector, "metadata": metadata});
+        self.request(Endpoint::UpdateVector(id), Some(body))
+            .await
+    }
+
+    /// Deletes a stored vector.
+    pub async fn delete_vector(&self, id: &str) -> Result<bool> {
+        self.request(Endpoint::DeleteVector(id), None)
+            .await
+    }
+
+    /// Searches for similar vectors using cosine similarity.
+    pub async fn search_similar_vectors(
+        &self,
+        vector: Vec<f32>,
+        limit: usize,
+    ) -> Result<Vec<VectorMatch>> {
+        let body = json!({"vector": vector, "limit": limit});
+        self.request(Endpoint::SearchVectors, Some(body))
+            .await
+    }
+
+    /// Searches for similar vectors restricted to those whose metadata
+    /// matches every `(key, value)` pair in `filters` exactly.
+    ///
+    /// Filtering happens server-side before ranking, so the limit applies
+    /// to matching vectors rather than being eaten by filtered-out hits.
+    pub async fn search_vectors_filtered(
+        &self,
+        vector: Vec<f32>,
+        limit: usize,
+        filters: HashMap<String, Value>,
+    ) -> Result<Vec<VectorMatch>> {
+        let body = json!({"vector": vector, "limit": limit, "filters": filters});
+        self.request(Endpoint::SearchVectors, Some(body)).await
+    }
+
+    /// Computes cosine similarity between two vectors.
+    pub async fn compute_similarity(&self, vector1: Vec<f32>, vector2: Vec<f32>) -> Result<f64> {
+        let body = json!({"vector1": vector1, "vector2": vector2});
+        self.request(Endpoint::VectorSimilarity, Some(body))
+            .await
+    }
+
+    // ------------------------------------------------------------------
+    // Graph knowledge
+    // ------------------------------------------------------------------
+
+    /// Creates a node in the knowledge graph.
+    pub async fn create_graph_node(
+        &self,
+        id: &str,
+        label: &str,
+        node_type: NodeType,
+        properties: Option<HashMap<String, Value>>,
+    ) -> Result<bool> {
+        let body = json!({
+            "id": id,
+            "label": label,
+            "type": node_type,
+            "properties": properties.unwrap_or_default(),
+        });
+        self.request(Endpoint::CreateGraphNode, Some(body))
+            .await
+    }
+
+    /// Creates an edge between two graph nodes.
+    pub async fn connect_graph_nodes(
+        &self,
+        node_id1: &str,
+        node_id2: &str,
+        weight: f64,
+    ) -> Result<bool> {
+        let body = json!({"node_id1": node_id1, "node_id2": node_id2, "weight": weight});
+        self.request(Endpoint::ConnectGraphNodes, Some(body))
+            .await
+    }
+
+    /// Creates a typed edge between two nodes and returns its ID.
+    ///
+    /// Unlike [`connect_graph_nodes`](Self::connect_graph_nodes), the edge
+    /// carries a relationship label, a direction, and properties.
+    pub async fn create_edge(
+        &self,
+        from: &str,
+        to: &str,
+        label: &str,
+        directed: bool,
+        weight: f64,
+        properties: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        let body = json!({
+            "from": from,
+            "to": to,
+            "label": label,
+            "directed": directed,
+            "weight": weight,
+            "properties": properties.unwrap_or_default(),
+        });
+        let data: Value = self
+            .request(Endpoint::ConnectGraphNodes, Some(body))
+            .await?;
+        data.get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or(BrainAIError::Api {
+                status: 200,
+                message: "create_edge response missing id".to_string(),
+            })
+    }
+
+    /// Updates an edge's label, weight, and/or properties; `None` fields
+    /// are left unchanged.
+    pub async fn update_edge(
+        &self,
+        edge_id: &str,
+        label: Option<&str>,
+        weight: Option<f64>,
+        properties: Option<HashMap<String, Value>>,
+    ) -> Result<bool> {
+        let body = json!({
+            "label": label,
+            "weight": weight,
+            "properties": properties,
+        });
+        self.request(Endpoint::UpdateGraphEdge(edge_id), Some(body))
+            .await
+    }
+
+    /// Deletes an edge.
+    pub async fn delete_edge(&self, edge_id: &str) -> Result<bool> {
+        self.request(Endpoint::DeleteGraphEdge(edge_id), None)
+            .await
+    }
+
+    /// Lists a node's edges, filtered by direction and optionally by
+    /// relationship label.
+    pub async fn get_edges(
+        &self,
+        node_id: &str,
+        direction: EdgeDirection,
+        label_filter: Option<&str>,
+    ) -> Result<Vec<GraphEdge>> {
+        let body = json!({
+            "node_id": node_id,
+            "direction": direction,
+            "label": label_filter,
+        });
+        self.request(Endpoint::QueryGraphEdges, Some(body))
+            .await
+    }
+
+    /// Gets neighboring nodes up to `depth` hops away.
+    pub async fn get_graph_neighbors(&self, node_id: &str, depth: u32) -> Result<Vec<GraphNode>> {
+        self.request(Endpoint::GraphNeighbors { node_id, depth }, None)
+            .await
+    }
+
+    /// Finds a path between two nodes, returned as the node sequence.
+    pub async fn find_graph_path(
+        &self,
+        start_node: &str,
+        end_node: &str,
+    ) -> Result<Vec<GraphNode>> {
+        let body = json!({"start": start_node, "end": end_node});
+        self.request(Endpoint::FindGraphPath, Some(body))
+            .await
+    }
+
+    // ------------------------------------------------------------------
+    // System management
+    // ------------------------------------------------------------------
+
+    /// Returns the current system status.
+    pub async fn get_status(&self) -> Result<SystemStatus> {
+        self.request(Endpoint::SystemStatus, None).await
+    }
+
+    /// Returns comprehensive system statistics.
+    pub async fn get_statistics(&self) -> Result<SystemStatistics> {
+        self.request(Endpoint::SystemStatistics, None)
+            .await
+    }
+
+    /// Clears all data from the system.
+    ///
+    /// **Warning:** this operation is irreversible.
+    pub async fn clear_all(&self) -> Result<bool> {
+        self.request(Endpoint::ClearAll, None).await
+    }
+
+    /// Creates a backup of all system data.
+    pub async fn backup_data(&self) -> Result<BackupInfo> {
+        self.request(Endpoint::Backup, None).await
+    }
+
+    /// Restores data from a backup.
+    pub async fn restore_data(&self, backup_id: &str) -> Result<bool> {
+        self.request(Endpoint::Restore(backup_id), None).await
+    }
+
+    // ------------------------------------------------------------------
+    // Utility methods
+    // ------------------------------------------------------------------
+
+    /// Performs multiple operations, splitting oversized batches.
+    ///
+    /// The serialized body is kept under
+    /// [`BrainAIConfig::max_batch_payload_bytes`]; when the operations do
+    /// not fit in one request they are sent as several, in order, and the
+    /// results are concatenated so callers still get one result per
+    /// operation at the matching index. A single operation larger than the
+    /// limit is rejected up front with [`BrainAIError::InvalidInput`]
+    /// rather than drawing an opaque server 413.
+    pub async fn batch(&self, operations: Vec<BatchOperation>) -> Result<Vec<BatchResult>> {
+        // Fixed framing plus per-operation separator overhead, estimated
+        // generously so the guard never under-counts.
+        const FRAME_BYTES: usize = 32;
+        let limit = self.config.max_batch_payload_bytes;
+        let mut results = Vec::with_capacity(operations.len());
+        let mut chunk: Vec<BatchOperation> = Vec::new();
+        let mut chunk_bytes = FRAME_BYTES;
+        for (index, operation) in operations.into_iter().enumerate() {
+            let operation_bytes = serde_json::to_vec(&operation)
+                .map_err(BrainAIError::Serialization)?
+                .len()
+                + 1;
+            if FRAME_BYTES + operation_bytes > limit {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "batch operation {index} is {operation_bytes} bytes serialized, \
+                     exceeding the {limit}-byte payload limit on its own"
+                )));
+            }
+            if !chunk.is_empty() && chunk_bytes + operation_bytes > limit {
+                results.extend(self.send_batch_chunk(std::mem::take(&mut chunk)).await?);
+                chunk_bytes = FRAME_BYTES;
+            }
+            chunk_bytes += operation_bytes;
+            chunk.push(operation);
+        }
+        if !chunk.is_empty() {
+            results.extend(self.send_batch_chunk(chunk).await?);
+        }
+        Ok(results)
+    }
+
+    /// Sends one already-sized batch chunk.
+    async fn send_batch_chunk(&self, operations: Vec<BatchOperation>) -> Result<Vec<BatchResult>> {
+        let body = json!({"operations": operations});
+        self.request(Endpoint::Batch, Some(body)).await
+    }
+
+    /// Stores many memories via the batch endpoint, chunking automatically.
+    ///
+    /// Writes are split into chunks of `chunk_size` operations so arbitrary
+    /// input sizes stay under server request limits. Failures are reported
+    /// per input index instead of aborting the run: a rejected memory in
+    /// one chunk does not prevent later chunks from being sent.
+    pub async fn store_memories(
+        &self,
+        writes: Vec<MemoryWrite>,
+        chunk_size: usize,
+    ) -> Result<StoreManyReport> {
+        let chunk_size = chunk_size.max(1);
+        let mut report = StoreManyReport::default();
+        for (chunk_index, chunk) in writes.chunks(chunk_size).enumerate() {
+            let operations: Vec<BatchOperation> = chunk
+                .iter()
+                .map(|write| BatchOperation {
+                    operation_type: OperationType::StoreMemory,
+                    data: json!({
+                        "content": write.content,
+                        "type": write.memory_type,
+                        "metadata": write.metadata,
+                    }),
+                })
+                .collect();
+            let results = self.batch(operations).await?;
+            for (offset, result) in results.into_iter().enumerate() {
+                let index = chunk_index * chunk_size + offset;
+                if result.success {
+                    let id = result
+                        .data
+                        .as_ref()
+                        .and_then(|d| d.get("id"))
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string();
+                    report.stored.push((index, id));
+                } else {
+                    report.failed.push((
+                        index,
+                        result
+                            .error
+                            .unwrap_or_else(|| "unknown batch failure".to_string()),
+                    ));
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Performs a comprehensive health check.
+    pub async fn health_check(&self) -> Result<bool> {
+        match self.request::<Value>(Endpoint::Health, None).await {
+            Ok(data) => Ok(data.get("healthy").and_then(Value::as_bool).unwrap_or(true)),
+            Err(BrainAIError::Http(_)) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+pub mod vector_utils {
+    //! f32 vector math shared by the SDK and the local indexes.
+    //!
+    //! The fallible `try_*` functions return `Err` on length mismatches
+    //! instead of panicking or silently answering `0.0`. Hot loops run in
+    //! eight independent accumulator lanes, which the auto-vectorizer
+    //! compiles to packed SIMD on every mainstream target; the nightly-only
+    //! `simd` feature swaps in explicit `std::simd` kernels.
+
+    use crate::{BrainAIError, Result};
+
+    /// Computes cosine similarity between two equal-length vectors.
+    ///
+    /// Returns `0.0` for mismatched lengths or zero-magnitude inputs; use
+    /// [`try_cosine_similarity`] to surface mismatches as errors.
+    pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+        try_cosine_similarity(a, b).unwrap_or(0.0)
+    }
+
+    fn check_lengths(a: &[f32], b: &[f32]) -> Result<()> {
+        if a.len() != b.len() {
+            return Err(BrainAIError::InvalidInput(format!(
+                "vector length mismatch: {} vs {}",
+                a.len(),
+                b.len()
+            )));
+        }
+        if a.is_empty() {
+            return Err(BrainAIError::InvalidInput("empty vectors".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Cosine similarity, or an error on mismatched/empty inputs.
+    /// Zero-magnitude inputs yield `0.0`.
+    pub fn try_cosine_similarity(a: &[f32], b: &[f32]) -> Result<f64> {
+        check_lengths(a, b)?;
+        let dot = dot_kernel(a, b);
+        let norm_a = dot_kernel(a, a);
+        let norm_b = dot_kernel(b, b);
+        if norm_a == 0.0 || norm_b == 0.0 {
+            return Ok(0.0);
+        }
+        Ok(dot / (norm_a.sqrt() * norm_b.sqrt()))
+    }
+
+    /// Dot product, or an error on mismatched/empty inputs.
+    pub fn dot_product(a: &[f32], b: &[f32]) -> Result<f64> {
+        check_lengths(a, b)?;
+        Ok(dot_kernel(a, b))
+    }
+
+    /// Manhattan (L1) distance, or an error on mismatched/empty inputs.
+    pub fn manhattan_distance(a: &[f32], b: &[f32]) -> Result<f64> {
+        check_lengths(a, b)?;
+        Ok(manhattan_kernel(a, b))
+    }
+
+    /// Cosine similarity of one query against many candidates, checked
+    /// once and computed with the shared norm of the query hoisted out —
+    /// the right shape for scoring large candidate sets.
+    pub fn pairwise_cosine(query: &[f32], candidates: &[Vec<f32>]) -> Result<Vec<f64>> {
+        if query.is_empty() {
+            return Err(BrainAIError::InvalidInput("empty query vector".to_string()));
+        }
+        for (index, candidate) in candidates.iter().enumerate() {
+            if candidate.len() != query.len() {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "candidate {index} length mismatch: {} vs {}",
+                    candidate.len(),
+                    query.len()
+                )));
+            }
+        }
+        let query_norm = dot_kernel(query, query).sqrt();
+        if query_norm == 0.0 {
+            return Ok(vec![0.0; candidates.len()]);
+        }
+        Ok(candidates
+            .iter()
+            .map(|candidate| {
+                let norm = dot_kernel(candidate, candidate).sqrt();
+                if norm == 0.0 {
+                    0.0
+                } else {
+                    dot_kernel(query, candidate) / (query_norm * norm)
+                }
+            })
+            .collect())
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn dot_kernel(a: &[f32], b: &[f32]) -> f64 {
+        // Eight independent f32 lanes; the backend packs these into SIMD.
+        let mut lanes = [0.0f32; 8];
+        let chunks = a.len() / 8;
+        for chunk in 0..chunks {
+            let base = chunk * 8;
+            for (lane, acc) in lanes.iter_mut().enumerate() {
+                *acc += a[base + lane] * b[base + lane];
+            }
+        }
+        let mut total: f64 = lanes.iter().map(|&x| f64::from(x)).sum();
+        for i in chunks * 8..a.len() {
+            total += f64::from(a[i]) * f64::from(b[i]);
+        }
+        total
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn manhattan_kernel(a: &[f32], b: &[f32]) -> f64 {
+        let mut lanes = [0.0f32; 8];
+        let chunks = a.len() / 8;
+        for chunk in 0..chunks {
+            let base = chunk * 8;
+            for (lane, acc) in lanes.iter_mut().enumerate() {
+                *acc += (a[base + lane] - b[base + lane]).abs();
+            }
+        }
+        let mut total: f64 = lanes.iter().map(|&x| f64::from(x)).sum();
+        for i in chunks * 8..a.len() {
+            total += f64::from((a[i] - b[i]).abs());
+        }
+        total
+    }
+
+    #[cfg(feature = "simd")]
+    fn dot_kernel(a: &[f32], b: &[f32]) -> f64 {
+        use std::simd::f32x8;
+        use std::simd::num::SimdFloat;
+        let mut acc = f32x8::splat(0.0);
+        let chunks = a.len() / 8;
+        for chunk in 0..chunks {
+            let base = chunk * 8;
+            let va = f32x8::from_slice(&a[base..base + 8]);
+            let vb = f32x8::from_slice(&b[base..base + 8]);
+            acc += va * vb;
+        }
+        let mut total = f64::from(acc.reduce_sum());
+        for i in chunks * 8..a.len() {
+            total += f64::from(a[i]) * f64::from(b[i]);
+        }
+        total
+    }
+
+    #[cfg(feature = "simd")]
+    fn manhattan_kernel(a: &[f32], b: &[f32]) -> f64 {
+        use std::simd::f32x8;
+        use std::simd::num::SimdFloat;
+        let mut acc = f32x8::splat(0.0);
+        let chunks = a.len() / 8;
+        for chunk in 0..chunks {
+            let base = chunk * 8;
+            let va = f32x8::from_slice(&a[base..base + 8]);
+            let vb = f32x8::from_slice(&b[base..base + 8]);
+            acc += (va - vb).abs();
+        }
+        let mut total = f64::from(acc.reduce_sum());
+        for i in chunks * 8..a.len() {
+            total += f64::from((a[i] - b[i]).abs());
+        }
+        total
+    }
+
+    /// Returns the current unix time in milliseconds.
+    pub(crate) fn now_millis() -> i64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,183 @@
+//! Bulk delete with pacing, backoff, and safety checks.
+//!
+//! [`BulkDelete`] removes memories matching a filter in small paced
+//! batches rather than one destructive server call. Before touching
+//! anything it enumerates the matches and applies safety checks: empty
+//! filters are refused unless explicitly allowed, and deletions above a
+//! configurable fraction of the brain abort unless confirmed. Transient
+//! failures back off exponentially instead of hammering a struggling
+//! server.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::{BrainAIClient, BrainAIError, Result};
+
+/// Page size used when enumerating matches.
+const SCAN_PAGE_SIZE: usize = 500;
+
+/// Safety and pacing options for a bulk delete.
+#[derive(Debug, Clone)]
+pub struct BulkDeleteOptions {
+    /// Enumerate and report matches without deleting anything.
+    pub dry_run: bool,
+    /// Allow an empty filter (deleting every memory). Off by default.
+    pub allow_unfiltered: bool,
+    /// Abort when matches exceed this fraction of all memories, unless
+    /// `confirm_large_delete` is set.
+    pub max_fraction: f64,
+    /// Proceed even when the match set exceeds `max_fraction`.
+    pub confirm_large_delete: bool,
+    /// Deletes issued between pacing pauses.
+    pub batch_size: usize,
+    /// Pause between batches.
+    pub pace: Duration,
+    /// Retries per memory on transient failure, with exponential backoff.
+    pub max_retries: u32,
+    /// Initial backoff; doubles per retry.
+    pub initial_backoff: Duration,
+}
+
+impl Default for BulkDeleteOptions {
+    fn default() -> Self {
+        BulkDeleteOptions {
+            dry_run: false,
+            allow_unfiltered: false,
+            max_fraction: 0.25,
+            confirm_large_delete: false,
+            batch_size: 50,
+            pace: Duration::from_millis(200),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(250),
+        }
+    }
+}
+
+/// Outcome of a bulk delete run.
+#[derive(Debug, Default, Clone)]
+pub struct BulkDeleteReport {
+    /// IDs that matched the filter.
+    pub matched: Vec<String>,
+    /// IDs actually deleted.
+    pub deleted: Vec<String>,
+    /// IDs that failed after retries, with the final error.
+    pub failed: Vec<(String, String)>,
+    /// Set when a safety check stopped the run before any deletion.
+    pub aborted: Option<String>,
+}
+
+/// Paced, backoff-aware bulk delete over any client.
+pub struct BulkDelete<'a> {
+    client: &'a dyn BrainAIClient,
+    options: BulkDeleteOptions,
+}
+
+impl<'a> BulkDelete<'a> {
+    /// Creates a bulk delete with default safety options.
+    pub fn new(client: &'a dyn BrainAIClient) -> Self {
+        BulkDelete {
+            client,
+            options: BulkDeleteOptions::default(),
+        }
+    }
+
+    /// Overrides the safety and pacing options.
+    pub fn with_options(mut self, options: BulkDeleteOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Runs the delete for everything matching `filters`.
+    pub async fn run(&self, filters: HashMap<String, Value>) -> Result<BulkDeleteReport> {
+        let mut report = BulkDeleteReport::default();
+
+        if filters.is_empty() && !self.options.allow_unfiltered {
+            return Err(BrainAIError::InvalidInput(
+                "refusing bulk delete with an empty filter; set allow_unfiltered to override"
+                    .to_string(),
+            ));
+        }
+
+        // Enumerate matches and the total population before deleting.
+        let mut total = 0usize;
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = self
+                .client
+                .list_memories_page(None, SCAN_PAGE_SIZE, cursor.as_deref())
+                .await?;
+            total += page.memories.len();
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = self
+                .client
+                .list_memories_page(Some(filters.clone()), SCAN_PAGE_SIZE, cursor.as_deref())
+                .await?;
+            report
+                .matched
+                .extend(page.memories.into_iter().map(|m| m.id));
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        if total > 0 {
+            let fraction = report.matched.len() as f64 / total as f64;
+            if fraction > self.options.max_fraction && !self.options.confirm_large_delete {
+                report.aborted = Some(format!(
+                    "matched {:.0}% of all memories, above the {:.0}% safety threshold; \
+                     set confirm_large_delete to proceed",
+                    fraction * 100.0,
+                    self.options.max_fraction * 100.0
+                ));
+                return Ok(report);
+            }
+        }
+
+        if self.options.dry_run {
+            return Ok(report);
+        }
+
+        for (index, id) in report.matched.clone().into_iter().enumerate() {
+            if index > 0 && index % self.options.batch_size == 0 {
+                tokio::time::sleep(self.options.pace).await;
+            }
+            match self.delete_with_backoff(&id).await {
+                Ok(()) => report.deleted.push(id),
+                Err(err) => report.failed.push((id, err.to_string())),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Deletes one memory, retrying transient failures with exponential
+    /// backoff. A missing memory counts as success — another worker may
+    /// have removed it between the scan and the delete.
+    async fn delete_with_backoff(&self, id: &str) -> Result<()> {
+        let mut backoff = self.options.initial_backoff;
+        let mut attempt = 0;
+        loop {
+            match self.client.delete_memory(id).await {
+                Ok(_) => return Ok(()),
+                Err(BrainAIError::NotFound(_)) => return Ok(()),
+                Err(err @ BrainAIError::InvalidInput(_)) => return Err(err),
+                Err(err) => {
+                    if attempt >= self.options.max_retries {
+                        return Err(err);
+                    }
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
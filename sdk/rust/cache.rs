@@ -0,0 +1,214 @@
+//! Local persistent read cache.
+//!
+//! Read-heavy agents fetch the same memories, neighbors, and patterns
+//! over and over; every fetch costs a round trip and server load. When
+//! enabled via [`BrainAIConfig::with_cache`](crate::BrainAIConfig),
+//! responses for the cacheable reads — memories by ID, graph
+//! relations, learning patterns — are kept in a local embedded store
+//! (one JSON file, rewritten atomically) with a TTL, so repeated reads
+//! are served locally and the cache survives restarts. The SDK
+//! invalidates entries it knows it stales — its own updates and
+//! deletes — and [`ReadCache::invalidate_memory`] /
+//! [`clear`](ReadCache::clear) cover writes made elsewhere.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIError, RelationType, Result};
+
+/// Where the cache lives and how long entries stay fresh.
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    /// File the cache persists to.
+    pub path: PathBuf,
+    /// How long an entry is served before it expires.
+    pub ttl: Duration,
+    /// Entries kept at most; the oldest are evicted beyond this.
+    pub max_entries: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            path: PathBuf::from("brain-ai-cache.json"),
+            ttl: Duration::from_secs(300),
+            max_entries: 10_000,
+        }
+    }
+}
+
+/// One cached response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    value: Value,
+    /// When the entry was stored (unix milliseconds); TTLs are wall
+    /// clock so entries age correctly across restarts.
+    stored_at: i64,
+}
+
+/// Persistent TTL cache for read responses; see the module docs.
+#[derive(Debug)]
+pub struct ReadCache {
+    config: CacheConfig,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl ReadCache {
+    /// Opens the cache, loading any previously persisted entries; a
+    /// missing file starts empty.
+    pub fn new(config: CacheConfig) -> Result<Self> {
+        let entries = if config.path.exists() {
+            let text = std::fs::read_to_string(&config.path).map_err(|err| {
+                BrainAIError::InvalidInput(format!(
+                    "cannot read cache file {}: {err}",
+                    config.path.display()
+                ))
+            })?;
+            serde_json::from_str(&text).map_err(BrainAIError::Serialization)?
+        } else {
+            HashMap::new()
+        };
+        Ok(ReadCache {
+            config,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    /// Cache key for a memory fetched by ID.
+    pub(crate) fn memory_key(id: &str) -> String {
+        format!("memory:{id}")
+    }
+
+    /// Cache key for a memory's relations listing.
+    pub(crate) fn relations_key(id: &str, relation: Option<&RelationType>) -> String {
+        format!(
+            "relations:{id}:{}",
+            relation.map(|r| r.as_str()).unwrap_or_default()
+        )
+    }
+
+    /// Cache key for the learning patterns listing.
+    pub(crate) fn patterns_key() -> String {
+        "patterns".to_string()
+    }
+
+    /// Fetches a fresh entry; expired entries are dropped on the way.
+    pub(crate) fn get(&self, key: &str) -> Option<Value> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.get(key)?;
+        let age = now_millis().saturating_sub(entry.stored_at);
+        if age as u128 > self.config.ttl.as_millis() {
+            entries.remove(key);
+            return None;
+        }
+        Some(entry.value.clone())
+    }
+
+    /// Stores a response, evicting the oldest entries beyond the size
+    /// bound, and persists.
+    pub(crate) fn put(&self, key: String, value: Value) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.insert(
+            key,
+            CacheEntry {
+                value,
+                stored_at: now_millis(),
+            },
+        );
+        while entries.len() > self.config.max_entries.max(1) {
+            if let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.stored_at)
+                .map(|(key, _)| key.clone())
+            {
+                entries.remove(&oldest);
+            }
+        }
+        self.persist(&entries);
+    }
+
+    /// Removes one entry, returning whether it existed.
+    pub fn invalidate(&self, key: &str) -> bool {
+        let mut entries = self.entries.lock().unwrap();
+        let existed = entries.remove(key).is_some();
+        if existed {
+            self.persist(&entries);
+        }
+        existed
+    }
+
+    /// Removes everything cached about one memory: the memory itself
+    /// and all of its relations listings.
+    pub fn invalidate_memory(&self, id: &str) -> usize {
+        let memory_key = ReadCache::memory_key(id);
+        let relations_prefix = format!("relations:{id}:");
+        let mut entries = self.entries.lock().unwrap();
+        let before = entries.len();
+        entries.retain(|key, _| key != &memory_key && !key.starts_with(&relations_prefix));
+        let removed = before - entries.len();
+        if removed > 0 {
+            self.persist(&entries);
+        }
+        removed
+    }
+
+    /// Removes every cached relations listing for one memory, leaving
+    /// the memory itself cached; for mutations that only touch
+    /// connections.
+    pub fn invalidate_relations(&self, id: &str) -> usize {
+        let relations_prefix = format!("relations:{id}:");
+        let mut entries = self.entries.lock().unwrap();
+        let before = entries.len();
+        entries.retain(|key, _| !key.starts_with(&relations_prefix));
+        let removed = before - entries.len();
+        if removed > 0 {
+            self.persist(&entries);
+        }
+        removed
+    }
+
+    /// Removes the cached learning patterns.
+    pub fn invalidate_patterns(&self) -> bool {
+        self.invalidate(&ReadCache::patterns_key())
+    }
+
+    /// Drops every entry.
+    pub fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.clear();
+        self.persist(&entries);
+    }
+
+    /// Entries currently held (fresh or not yet expired-on-read).
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// Whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Rewrites the cache file atomically. Persistence is best-effort:
+    /// a full disk degrades the cache, it must not fail the read path.
+    fn persist(&self, entries: &HashMap<String, CacheEntry>) {
+        let Ok(text) = serde_json::to_string(entries) else {
+            return;
+        };
+        let tmp = self.config.path.with_extension("json.tmp");
+        if let Err(err) =
+            std::fs::write(&tmp, text).and_then(|()| std::fs::rename(&tmp, &self.config.path))
+        {
+            eprintln!(
+                "[brain-ai] cache persist to {} failed: {err}",
+                self.config.path.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Confidence calibration for reasoning results.
+//!
+//! Reasoning engines are routinely over- or under-confident: a reported
+//! 0.9 may be right only 70% of the time. [`ConfidenceCalibrator`]
+//! records `(predicted confidence, observed outcome)` pairs as feedback
+//! arrives and fits a calibration curve locally — Platt scaling (a
+//! logistic curve) or isotonic regression (a monotone step fit) — so
+//! [`calibrate`](ConfidenceCalibrator::calibrate) maps raw confidences
+//! to empirically honest ones. Enable automatic adjustment of every
+//! returned [`ReasoningResult`](crate::ReasoningResult) via
+//! [`BrainAIConfig::with_confidence_calibration`](crate::BrainAIConfig).
+
+use std::sync::Mutex;
+
+/// How the calibration curve is fitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalibrationMethod {
+    /// Logistic curve over the raw confidence. Smooth, needs few
+    /// samples, assumes a sigmoid-shaped miscalibration.
+    #[default]
+    Platt,
+    /// Pool-adjacent-violators monotone fit. Shape-free, needs more
+    /// samples to be stable.
+    Isotonic,
+}
+
+/// Knobs for [`ConfidenceCalibrator`].
+#[derive(Debug, Clone)]
+pub struct CalibrationOptions {
+    pub method: CalibrationMethod,
+    /// Below this many recorded outcomes, `calibrate` is the identity;
+    /// a curve fitted on a handful of points does more harm than good.
+    pub min_samples: usize,
+}
+
+impl Default for CalibrationOptions {
+    fn default() -> Self {
+        CalibrationOptions {
+            method: CalibrationMethod::default(),
+            min_samples: 20,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Curve {
+    Platt { a: f64, b: f64 },
+    /// `(raw confidence, calibrated value)` knots, ascending in both.
+    Isotonic(Vec<(f64, f64)>),
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// `(predicted, correct)` feedback pairs.
+    samples: Vec<(f64, bool)>,
+    fitted: Option<Curve>,
+    /// Samples arrived since the last fit.
+    dirty: bool,
+}
+
+/// Records prediction outcomes and maps raw confidences onto the
+/// observed accuracy at that confidence level.
+#[derive(Debug)]
+pub struct ConfidenceCalibrator {
+    options: CalibrationOptions,
+    inner: Mutex<Inner>,
+}
+
+impl ConfidenceCalibrator {
+    pub fn new(options: CalibrationOptions) -> Self {
+        ConfidenceCalibrator {
+            options,
+            inner: Mutex::new(Inner::default()),
+        }
+    }
+
+    /// Records one outcome: the confidence a result claimed and whether
+    /// it turned out correct. The curve is refitted lazily on the next
+    /// [`calibrate`](Self::calibrate) call.
+    pub fn record(&self, predicted: f64, correct: bool) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.samples.push((predicted.clamp(0.0, 1.0), correct));
+        inner.dirty = true;
+    }
+
+    /// Maps a raw confidence through the fitted curve. Identity until
+    /// [`min_samples`](CalibrationOptions::min_samples) outcomes have
+    /// been recorded.
+    pub fn calibrate(&self, confidence: f64) -> f64 {
+        let confidence = confidence.clamp(0.0, 1.0);
+        let mut inner = self.inner.lock().unwrap();
+        if inner.samples.len() < self.options.min_samples {
+            return confidence;
+        }
+        if inner.dirty || inner.fitted.is_none() {
+            inner.fitted = Some(match self.options.method {
+                CalibrationMethod::Platt => fit_platt(&inner.samples),
+                CalibrationMethod::Isotonic => fit_isotonic(&inner.samples),
+            });
+            inner.dirty = false;
+        }
+        match inner.fitted.as_ref().unwrap() {
+            Curve::Platt { a, b } => sigmoid(a * confidence + b),
+            Curve::Isotonic(knots) => interpolate(knots, confidence),
+        }
+    }
+
+    /// Recorded outcome count.
+    pub fn sample_count(&self) -> usize {
+        self.inner.lock().unwrap().samples.len()
+    }
+
+    /// Drops every recorded outcome and the fitted curve.
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.samples.clear();
+        inner.fitted = None;
+        inner.dirty = false;
+    }
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Platt scaling: fits `sigmoid(a * confidence + b)` to the outcomes by
+/// gradient descent on log loss, with Platt's target smoothing so a
+/// streak of all-correct feedback cannot push the curve to a hard 1.0.
+fn fit_platt(samples: &[(f64, bool)]) -> Curve {
+    let positives = samples.iter().filter(|(_, correct)| *correct).count() as f64;
+    let negatives = samples.len() as f64 - positives;
+    let target_hi = (positives + 1.0) / (positives + 2.0);
+    let target_lo = 1.0 / (negatives + 2.0);
+
+    let mut a = 1.0;
+    let mut b = 0.0;
+    let rate = 0.5;
+    let n = samples.len() as f64;
+    for _ in 0..1_000 {
+        let mut grad_a = 0.0;
+        let mut grad_b = 0.0;
+        for (x, correct) in samples {
+            let target = if *correct { target_hi } else { target_lo };
+            let error = sigmoid(a * x + b) - target;
+            grad_a += error * x;
+            grad_b += error;
+        }
+        a -= rate * grad_a / n;
+        b -= rate * grad_b / n;
+    }
+    Curve::Platt { a, b }
+}
+
+/// Isotonic regression via pool-adjacent-violators: sorts outcomes by
+/// predicted confidence and merges neighboring blocks until observed
+/// accuracy is non-decreasing, yielding interpolation knots.
+fn fit_isotonic(samples: &[(f64, bool)]) -> Curve {
+    let mut sorted: Vec<(f64, f64)> = samples
+        .iter()
+        .map(|(x, correct)| (*x, if *correct { 1.0 } else { 0.0 }))
+        .collect();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // (sum_x, sum_y, count) per block.
+    let mut blocks: Vec<(f64, f64, f64)> = Vec::with_capacity(sorted.len());
+    for (x, y) in sorted {
+        blocks.push((x, y, 1.0));
+        while blocks.len() >= 2 {
+            let last = blocks[blocks.len() - 1];
+            let prev = blocks[blocks.len() - 2];
+            if prev.1 / prev.2 <= last.1 / last.2 {
+                break;
+            }
+            blocks.pop();
+            blocks.pop();
+            blocks.push((prev.0 + last.0, prev.1 + last.1, prev.2 + last.2));
+        }
+    }
+    Curve::Isotonic(
+        blocks
+            .into_iter()
+            .map(|(sum_x, sum_y, count)| (sum_x / count, sum_y / count))
+            .collect(),
+    )
+}
+
+/// Piecewise-linear interpolation over ascending knots, clamped at the
+/// ends.
+fn interpolate(knots: &[(f64, f64)], x: f64) -> f64 {
+    match knots {
+        [] => x,
+        [only] => only.1,
+        _ => {
+            if x <= knots[0].0 {
+                return knots[0].1;
+            }
+            for pair in knots.windows(2) {
+                let (x0, y0) = pair[0];
+                let (x1, y1) = pair[1];
+                if x <= x1 {
+                    let span = x1 - x0;
+                    if span <= f64::EPSILON {
+                        return y1;
+                    }
+                    return y0 + (y1 - y0) * (x - x0) / span;
+                }
+            }
+            knots[knots.len() - 1].1
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Circuit breaker for a failing Brain AI server.
+//!
+//! When the server is down, every call otherwise waits out the full
+//! connect timeout before failing — and under load those waits stack up
+//! into a downstream outage of their own. When enabled via
+//! [`BrainAIConfig::with_circuit_breaker`](crate::BrainAIConfig), the
+//! SDK counts consecutive transport failures and 5xx answers; once they
+//! reach `failure_threshold` the circuit opens and further calls fail
+//! immediately with [`BrainAIError::CircuitOpen`](crate::BrainAIError).
+//! After `cooldown` a single probe request is let through: if it
+//! succeeds the circuit closes and traffic resumes, if it fails the
+//! cooldown starts over. Client-side errors (4xx) never trip the
+//! breaker — the server is answering, the request is just wrong.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Circuit breaker tuning.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerOptions {
+    /// Consecutive failures that open the circuit.
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before a probe is attempted.
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerOptions {
+    fn default() -> Self {
+        CircuitBreakerOptions {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Where the breaker currently stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// Requests fail fast; the cooldown has not elapsed.
+    Open,
+    /// The cooldown elapsed and one probe request is in flight.
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct Inner {
+    consecutive_failures: u32,
+    /// Set while the circuit is open or half-open.
+    opened_at: Option<Instant>,
+    /// A half-open probe is in flight; everyone else still fails fast.
+    probing: bool,
+}
+
+/// Consecutive-failure circuit breaker; see the module docs.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    options: CircuitBreakerOptions,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(options: CircuitBreakerOptions) -> Self {
+        CircuitBreaker {
+            options: CircuitBreakerOptions {
+                failure_threshold: options.failure_threshold.max(1),
+                ..options
+            },
+            inner: Mutex::new(Inner {
+                consecutive_failures: 0,
+                opened_at: None,
+                probing: false,
+            }),
+        }
+    }
+
+    /// Decides whether a request may go out right now. `Ok(())` means
+    /// proceed (possibly as the half-open probe); `Err(wait)` means the
+    /// circuit is open and `wait` remains until the next probe slot.
+    pub(crate) fn admit(&self) -> std::result::Result<(), Duration> {
+        let mut inner = self.inner.lock().unwrap();
+        let Some(opened_at) = inner.opened_at else {
+            return Ok(());
+        };
+        let elapsed = opened_at.elapsed();
+        if elapsed < self.options.cooldown {
+            return Err(self.options.cooldown - elapsed);
+        }
+        if inner.probing {
+            // A probe is already in flight; its outcome decides.
+            return Err(Duration::ZERO);
+        }
+        inner.probing = true;
+        Ok(())
+    }
+
+    /// Records a successful exchange: closes the circuit and resets the
+    /// failure count.
+    pub(crate) fn record_success(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+        inner.probing = false;
+    }
+
+    /// Records a failed exchange: a failed probe re-opens the circuit
+    /// for a fresh cooldown, and reaching the threshold opens it.
+    pub(crate) fn record_failure(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+        if inner.probing || inner.consecutive_failures >= self.options.failure_threshold {
+            inner.opened_at = Some(Instant::now());
+            inner.probing = false;
+        }
+    }
+
+    /// Time until the next probe slot while the circuit is open; `None`
+    /// when requests are flowing (closed or half-open).
+    pub fn retry_in(&self) -> Option<Duration> {
+        let inner = self.inner.lock().unwrap();
+        let opened_at = inner.opened_at?;
+        if inner.probing {
+            return None;
+        }
+        self.options.cooldown.checked_sub(opened_at.elapsed())
+    }
+
+    /// The breaker's current state, for introspection.
+    pub fn state(&self) -> CircuitState {
+        let inner = self.inner.lock().unwrap();
+        match inner.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if inner.probing || opened_at.elapsed() >= self.options.cooldown => {
+                CircuitState::HalfOpen
+            }
+            Some(_) => CircuitState::Open,
+        }
+    }
+}
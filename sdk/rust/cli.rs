@@ -0,0 +1,632 @@
+//! Command-line entry points shipped with the SDK.
+//!
+//! The `brain-ai` binary is a thin dispatcher over this module:
+//!
+//! - `export-metrics` — Prometheus exporter sidecar: polls a Brain AI
+//!   server's status and statistics and serves them in Prometheus text
+//!   exposition format on `/metrics`, so a scrape target can sit next to
+//!   any deployment without touching the server itself.
+//! - `diagnose` — collects a structured diagnostics bundle (health,
+//!   status, statistics, learning state) as JSON for support tickets.
+//! - `graph serve` — serves a local web page rendering the knowledge graph
+//!   around a root node with a force-directed layout and filter controls,
+//!   so analysts can explore the brain's structure without exporting to
+//!   external tools.
+//! - `ingest crawl` — politely crawls a site section (domain allowlist,
+//!   robots.txt, depth/page limits, delays between fetches) and ingests
+//!   each page into linked memories with the page URL as provenance.
+//!
+//! ```text
+//! brain-ai export-metrics --server http://localhost:8000 --listen 0.0.0.0:9464
+//! brain-ai diagnose --server http://localhost:8000 --output bundle.json
+//! brain-ai graph serve --server http://localhost:8000 --port 8080 --root concept_ai
+//! brain-ai ingest crawl https://docs.example.com/guide --depth 2 --max-pages 50
+//! ```
+
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::crawl::{CrawlOptions, Crawler};
+use crate::{BrainAIConfig, BrainAIError, BrainAISDK, Result};
+
+/// Runs the CLI with the given arguments (excluding the program name).
+pub async fn run(args: &[String]) -> Result<()> {
+    match args.first().map(String::as_str) {
+        Some("export-metrics") => {
+            let opts = ExporterOptions::parse(&args[1..])?;
+            let sdk = BrainAISDK::new(BrainAIConfig::new(opts.server.clone()))?;
+            MetricsExporter::new(sdk, opts).serve().await
+        }
+        Some("diagnose") => {
+            let opts = DiagnoseOptions::parse(&args[1..])?;
+            let sdk = BrainAISDK::new(BrainAIConfig::new(opts.server.clone()))?;
+            let bundle = collect_diagnostics(&sdk).await;
+            let rendered = serde_json::to_string_pretty(&bundle)?;
+            match &opts.output {
+                Some(path) => std::fs::write(path, rendered).map_err(|err| {
+                    BrainAIError::InvalidInput(format!("cannot write {path}: {err}"))
+                })?,
+                None => println!("{rendered}"),
+            }
+            Ok(())
+        }
+        Some("graph") if args.get(1).map(String::as_str) == Some("serve") => {
+            let opts = GraphServeOptions::parse(&args[2..])?;
+            let sdk = BrainAISDK::new(BrainAIConfig::new(opts.server.clone()))?;
+            GraphServer::new(sdk, opts).serve().await
+        }
+        Some("ingest") if args.get(1).map(String::as_str) == Some("crawl") => {
+            let opts = CrawlCliOptions::parse(&args[2..])?;
+            let sdk = BrainAISDK::new(BrainAIConfig::new(opts.server.clone()))?;
+            let embedder = crate::embed::HashingEmbedder::new(opts.dimension);
+            let report = Crawler::new(&sdk, &embedder)
+                .with_options(opts.crawl.clone())
+                .crawl(&opts.url)
+                .await?;
+            for page in &report.pages {
+                println!("ingested {} ({} chunks, depth {})", page.url, page.chunks, page.depth);
+            }
+            for (url, reason) in &report.skipped {
+                eprintln!("skipped {url}: {reason}");
+            }
+            println!(
+                "{} pages ingested, {} skipped",
+                report.pages.len(),
+                report.skipped.len()
+            );
+            Ok(())
+        }
+        Some(other) => Err(BrainAIError::InvalidInput(format!(
+            "unknown subcommand: {other}"
+        ))),
+        None => Err(BrainAIError::InvalidInput(
+            "usage: brain-ai <export-metrics|diagnose|graph serve|ingest crawl> [options]"
+                .to_string(),
+        )),
+    }
+}
+
+/// Options for the `export-metrics` sidecar.
+#[derive(Debug, Clone)]
+pub struct ExporterOptions {
+    /// Brain AI server to poll.
+    pub server: String,
+    /// Address to serve `/metrics` on.
+    pub listen: String,
+}
+
+impl ExporterOptions {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut opts = ExporterOptions {
+            server: "http://localhost:8000".to_string(),
+            listen: "0.0.0.0:9464".to_string(),
+        };
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--server" => {
+                    opts.server = iter
+                        .next()
+                        .ok_or_else(|| {
+                            BrainAIError::InvalidInput("--server requires a value".to_string())
+                        })?
+                        .clone();
+                }
+                "--listen" => {
+                    opts.listen = iter
+                        .next()
+                        .ok_or_else(|| {
+                            BrainAIError::InvalidInput("--listen requires a value".to_string())
+                        })?
+                        .clone();
+                }
+                other => {
+                    return Err(BrainAIError::InvalidInput(format!(
+                        "unknown option: {other}"
+                    )))
+                }
+            }
+        }
+        Ok(opts)
+    }
+}
+
+/// Options for the `diagnose` command.
+#[derive(Debug, Clone)]
+pub struct DiagnoseOptions {
+    /// Brain AI server to inspect.
+    pub server: String,
+    /// File to write the bundle to; stdout when unset.
+    pub output: Option<String>,
+}
+
+impl DiagnoseOptions {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut opts = DiagnoseOptions {
+            server: "http://localhost:8000".to_string(),
+            output: None,
+        };
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--server" => {
+                    opts.server = iter
+                        .next()
+                        .ok_or_else(|| {
+                            BrainAIError::InvalidInput("--server requires a value".to_string())
+                        })?
+                        .clone();
+                }
+                "--output" => {
+                    opts.output = Some(
+                        iter.next()
+                            .ok_or_else(|| {
+                                BrainAIError::InvalidInput(
+                                    "--output requires a value".to_string(),
+                                )
+                            })?
+                            .clone(),
+                    );
+                }
+                other => {
+                    return Err(BrainAIError::InvalidInput(format!(
+                        "unknown option: {other}"
+                    )))
+                }
+            }
+        }
+        Ok(opts)
+    }
+}
+
+/// Collects a structured diagnostics bundle for support tickets.
+///
+/// Every section is gathered independently: a failing endpoint records its
+/// error string in place of data, so a partially broken server still yields
+/// a useful bundle.
+pub async fn collect_diagnostics(sdk: &BrainAISDK) -> serde_json::Value {
+    fn section<T: serde::Serialize>(result: Result<T>) -> serde_json::Value {
+        match result {
+            Ok(data) => serde_json::json!({"ok": true, "data": data}),
+            Err(err) => serde_json::json!({"ok": false, "error": err.to_string()}),
+        }
+    }
+
+    serde_json::json!({
+        "bundle_version": 1,
+        "collected_at": crate::vector_utils::now_millis(),
+        "sdk_version": env!("CARGO_PKG_VERSION"),
+        "server": sdk.config().base_url,
+        "health": section(sdk.health_check().await),
+        "status": section(sdk.get_status().await),
+        "statistics": section(sdk.get_statistics().await),
+        "learning_progress": section(sdk.get_learning_progress().await),
+        "learning_patterns": section(sdk.get_learning_patterns().await),
+    })
+}
+
+/// Prometheus exporter sidecar serving `/metrics` over plain HTTP/1.1.
+pub struct MetricsExporter {
+    sdk: BrainAISDK,
+    options: ExporterOptions,
+}
+
+impl MetricsExporter {
+    /// Creates an exporter polling the given SDK.
+    pub fn new(sdk: BrainAISDK, options: ExporterOptions) -> Self {
+        MetricsExporter { sdk, options }
+    }
+
+    /// Serves `/metrics` until the process is terminated.
+    pub async fn serve(&self) -> Result<()> {
+        let listener = TcpListener::bind(&self.options.listen)
+            .await
+            .map_err(|err| BrainAIError::InvalidInput(format!(
+                "cannot listen on {}: {err}",
+                self.options.listen
+            )))?;
+        eprintln!(
+            "[brain-ai] exporting metrics for {} on http://{}/metrics",
+            self.options.server, self.options.listen
+        );
+        loop {
+            let (mut socket, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(_) => continue,
+            };
+            let body = match self.render().await {
+                Ok(body) => body,
+                Err(err) => {
+                    // Keep the scrape endpoint up even when the server is
+                    // unreachable; report availability through a gauge.
+                    format!(
+                        "# Brain AI server unreachable: {err}\nbrain_ai_up 0\n"
+                    )
+                }
+            };
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            // Drain the request before answering; scrapers expect HTTP/1.1.
+            let mut buf = [0u8; 4096];
+            let _ = tokio::time::timeout(
+                Duration::from_secs(2),
+                socket.read(&mut buf),
+            )
+            .await;
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    }
+
+    /// Renders one scrape in Prometheus text exposition format.
+    pub async fn render(&self) -> Result<String> {
+        let status = self.sdk.get_status().await?;
+        let stats = self.sdk.get_statistics().await?;
+        let mut out = String::new();
+        out.push_str("# HELP brain_ai_up Whether the Brain AI server answered the last poll.\n");
+        out.push_str("# TYPE brain_ai_up gauge\nbrain_ai_up 1\n");
+        out.push_str("# HELP brain_ai_uptime_seconds Server uptime.\n");
+        out.push_str("# TYPE brain_ai_uptime_seconds counter\n");
+        out.push_str(&format!("brain_ai_uptime_seconds {}\n", status.uptime));
+        out.push_str("# HELP brain_ai_memory_usage_ratio Server memory usage.\n");
+        out.push_str("# TYPE brain_ai_memory_usage_ratio gauge\n");
+        out.push_str(&format!(
+            "brain_ai_memory_usage_ratio {}\n",
+            status.memory_usage
+        ));
+        out.push_str("# HELP brain_ai_memories_total Stored memories by type.\n");
+        out.push_str("# TYPE brain_ai_memories_total gauge\n");
+        out.push_str(&format!(
+            "brain_ai_memories_total {}\n",
+            stats.total_memories
+        ));
+        let mut types: Vec<_> = stats.memory_types.iter().collect();
+        types.sort();
+        for (memory_type, count) in types {
+            out.push_str(&format!(
+                "brain_ai_memories_total{{type=\"{memory_type}\"}} {count}\n"
+            ));
+        }
+        out.push_str("# HELP brain_ai_learning_patterns Learned patterns.\n");
+        out.push_str("# TYPE brain_ai_learning_patterns gauge\n");
+        out.push_str(&format!(
+            "brain_ai_learning_patterns {}\n",
+            stats.learning_patterns
+        ));
+        out.push_str("# HELP brain_ai_vectors Stored vectors.\n");
+        out.push_str("# TYPE brain_ai_vectors gauge\n");
+        out.push_str(&format!("brain_ai_vectors {}\n", stats.vector_count));
+        out.push_str("# HELP brain_ai_graph_nodes Knowledge graph nodes.\n");
+        out.push_str("# TYPE brain_ai_graph_nodes gauge\n");
+        out.push_str(&format!("brain_ai_graph_nodes {}\n", stats.graph_nodes));
+        out.push_str(
+            "# HELP brain_ai_cache_hit_ratio Server-side cache hit rate.\n",
+        );
+        out.push_str("# TYPE brain_ai_cache_hit_ratio gauge\n");
+        out.push_str(&format!(
+            "brain_ai_cache_hit_ratio {}\n",
+            stats.cache_hit_rate
+        ));
+        Ok(out)
+    }
+}
+
+/// Options for `graph serve`.
+#[derive(Debug, Clone)]
+pub struct GraphServeOptions {
+    /// Brain AI server to read the graph from.
+    pub server: String,
+    /// Local port for the visualization page.
+    pub port: u16,
+    /// Node to start the traversal from.
+    pub root: String,
+    /// How many hops out from the root to fetch.
+    pub depth: u32,
+}
+
+impl GraphServeOptions {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut opts = GraphServeOptions {
+            server: "http://localhost:8000".to_string(),
+            port: 8080,
+            root: String::new(),
+            depth: 3,
+        };
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let mut value = |flag: &str| {
+                iter.next().cloned().ok_or_else(|| {
+                    BrainAIError::InvalidInput(format!("{flag} requires a value"))
+                })
+            };
+            match arg.as_str() {
+                "--server" => opts.server = value("--server")?,
+                "--port" => {
+                    opts.port = value("--port")?.parse().map_err(|_| {
+                        BrainAIError::InvalidInput("--port expects a number".to_string())
+                    })?;
+                }
+                "--root" => opts.root = value("--root")?,
+                "--depth" => {
+                    opts.depth = value("--depth")?.parse().map_err(|_| {
+                        BrainAIError::InvalidInput("--depth expects a number".to_string())
+                    })?;
+                }
+                other => {
+                    return Err(BrainAIError::InvalidInput(format!(
+                        "unknown option: {other}"
+                    )))
+                }
+            }
+        }
+        if opts.root.is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "--root <node_id> is required".to_string(),
+            ));
+        }
+        Ok(opts)
+    }
+}
+
+/// Options for `ingest crawl`.
+#[derive(Debug, Clone)]
+pub struct CrawlCliOptions {
+    /// Brain AI server to ingest into.
+    pub server: String,
+    /// URL the crawl starts from.
+    pub url: String,
+    /// Hashing-embedder output dimension for chunk vectors.
+    pub dimension: usize,
+    /// Crawl budgets and politeness settings.
+    pub crawl: CrawlOptions,
+}
+
+impl CrawlCliOptions {
+    fn parse(args: &[String]) -> Result<Self> {
+        /// Parses one numeric flag value; generic so each flag infers
+        /// its own integer type.
+        fn number<T: std::str::FromStr>(flag: &str, raw: String) -> Result<T> {
+            raw.parse().map_err(|_| {
+                BrainAIError::InvalidInput(format!("{flag} expects a number"))
+            })
+        }
+        let mut opts = CrawlCliOptions {
+            server: "http://localhost:8000".to_string(),
+            url: String::new(),
+            dimension: 256,
+            crawl: CrawlOptions::default(),
+        };
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let mut value = |flag: &str| {
+                iter.next().cloned().ok_or_else(|| {
+                    BrainAIError::InvalidInput(format!("{flag} requires a value"))
+                })
+            };
+            match arg.as_str() {
+                "--server" => opts.server = value("--server")?,
+                "--depth" => opts.crawl.max_depth = number("--depth", value("--depth")?)?,
+                "--max-pages" => {
+                    opts.crawl.max_pages = number("--max-pages", value("--max-pages")?)?;
+                }
+                "--delay-ms" => {
+                    opts.crawl.delay =
+                        Duration::from_millis(number("--delay-ms", value("--delay-ms")?)?);
+                }
+                "--allow" => opts.crawl.allowed_domains.push(value("--allow")?),
+                "--no-robots" => opts.crawl.respect_robots = false,
+                "--dimension" => {
+                    opts.dimension = number("--dimension", value("--dimension")?)?;
+                }
+                other if !other.starts_with("--") && opts.url.is_empty() => {
+                    opts.url = other.to_string();
+                }
+                other => {
+                    return Err(BrainAIError::InvalidInput(format!(
+                        "unknown option: {other}"
+                    )))
+                }
+            }
+        }
+        if opts.url.is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "usage: brain-ai ingest crawl <url> [options]".to_string(),
+            ));
+        }
+        Ok(opts)
+    }
+}
+
+/// Local visualization server for the knowledge graph.
+///
+/// Serves the page on `/` and the node/link data on `/graph.json`; the
+/// page lays the graph out with a small force simulation and offers
+/// filtering by node type and label substring.
+pub struct GraphServer {
+    sdk: BrainAISDK,
+    options: GraphServeOptions,
+}
+
+impl GraphServer {
+    /// Creates a graph server reading from the given SDK.
+    pub fn new(sdk: BrainAISDK, options: GraphServeOptions) -> Self {
+        GraphServer { sdk, options }
+    }
+
+    /// Serves the visualization until the process is terminated.
+    pub async fn serve(&self) -> Result<()> {
+        let listen = format!("127.0.0.1:{}", self.options.port);
+        let listener = TcpListener::bind(&listen).await.map_err(|err| {
+            BrainAIError::InvalidInput(format!("cannot listen on {listen}: {err}"))
+        })?;
+        eprintln!(
+            "[brain-ai] graph around '{}' from {} on http://{listen}/",
+            self.options.root, self.options.server
+        );
+        loop {
+            let (mut socket, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(_) => continue,
+            };
+            let mut buf = [0u8; 4096];
+            let read = tokio::time::timeout(Duration::from_secs(2), socket.read(&mut buf)).await;
+            let request = match read {
+                Ok(Ok(n)) => String::from_utf8_lossy(&buf[..n]).to_string(),
+                _ => continue,
+            };
+            let path = request
+                .split_whitespace()
+                .nth(1)
+                .unwrap_or("/")
+                .to_string();
+            let (status, content_type, body) = if path.starts_with("/graph.json") {
+                match self.collect_graph().await {
+                    Ok(graph) => ("200 OK", "application/json", graph.to_string()),
+                    Err(err) => (
+                        "502 Bad Gateway",
+                        "application/json",
+                        serde_json::json!({"error": err.to_string()}).to_string(),
+                    ),
+                }
+            } else {
+                ("200 OK", "text/html; charset=utf-8", GRAPH_PAGE.to_string())
+            };
+            let response = format!(
+                "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    }
+
+    /// Fetches the neighborhood of the root and reconstructs its edges by
+    /// asking for each node's direct neighbors.
+    async fn collect_graph(&self) -> Result<serde_json::Value> {
+        let mut nodes = self
+            .sdk
+            .get_graph_neighbors(&self.options.root, self.options.depth)
+            .await?;
+        if !nodes.iter().any(|n| n.id == self.options.root) {
+            if let Ok(mut root) = self
+                .sdk
+                .get_graph_neighbors(&self.options.root, 0)
+                .await
+            {
+                nodes.append(&mut root);
+            }
+        }
+        let ids: std::collections::HashSet<String> =
+            nodes.iter().map(|n| n.id.clone()).collect();
+        let mut links = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        for node in &nodes {
+            let neighbors = self.sdk.get_graph_neighbors(&node.id, 1).await?;
+            for neighbor in neighbors {
+                if !ids.contains(&neighbor.id) || neighbor.id == node.id {
+                    continue;
+                }
+                let key = if node.id < neighbor.id {
+                    (node.id.clone(), neighbor.id.clone())
+                } else {
+                    (neighbor.id.clone(), node.id.clone())
+                };
+                if seen.insert(key) {
+                    links.push(serde_json::json!({
+                        "source": node.id,
+                        "target": neighbor.id,
+                        "weight": neighbor.weight,
+                    }));
+                }
+            }
+        }
+        Ok(serde_json::json!({
+            "root": self.options.root,
+            "nodes": nodes,
+            "links": links,
+        }))
+    }
+}
+
+/// The single-file visualization page: canvas force-directed layout with
+/// node-type and label filters, no external assets.
+const GRAPH_PAGE: &str = r#"<!doctype html>
+<html><head><meta charset="utf-8"><title>Brain AI graph</title>
+<style>
+ body{margin:0;font:14px sans-serif;background:#111;color:#ddd}
+ #bar{padding:8px;background:#1b1b1b}
+ input,select{background:#222;color:#ddd;border:1px solid #444;padding:4px}
+ canvas{display:block}
+</style></head><body>
+<div id="bar">
+ <label>type <select id="type"><option value="">all</option></select></label>
+ <label>label <input id="label" placeholder="substring"></label>
+ <span id="count"></span>
+</div>
+<canvas id="c"></canvas>
+<script>
+const canvas=document.getElementById('c'),ctx=canvas.getContext('2d');
+let data={nodes:[],links:[]},sim=[];
+function resize(){canvas.width=innerWidth;canvas.height=innerHeight-40}
+addEventListener('resize',resize);resize();
+function filtered(){
+ const t=document.getElementById('type').value,
+       q=document.getElementById('label').value.toLowerCase();
+ const keep=new Set(data.nodes
+   .filter(n=>(!t||n.type===t)&&(!q||(n.label||n.id).toLowerCase().includes(q)))
+   .map(n=>n.id));
+ return {nodes:data.nodes.filter(n=>keep.has(n.id)),
+         links:data.links.filter(l=>keep.has(l.source)&&keep.has(l.target))};
+}
+function restart(){
+ const f=filtered();
+ document.getElementById('count').textContent=
+   f.nodes.length+' nodes / '+f.links.length+' edges';
+ sim=f.nodes.map(n=>({n,x:Math.random()*canvas.width,
+   y:Math.random()*canvas.height,vx:0,vy:0}));
+ sim.links=f.links.map(l=>({s:sim.find(p=>p.n.id===l.source),
+   t:sim.find(p=>p.n.id===l.target),w:l.weight||1}));
+}
+function tick(){
+ for(const a of sim){
+  for(const b of sim){
+   if(a===b)continue;
+   const dx=a.x-b.x,dy=a.y-b.y,d2=dx*dx+dy*dy+1,f=1200/d2;
+   a.vx+=dx*f/Math.sqrt(d2);a.vy+=dy*f/Math.sqrt(d2);
+  }
+  a.vx+=(canvas.width/2-a.x)*0.001;a.vy+=(canvas.height/2-a.y)*0.001;
+ }
+ for(const l of sim.links||[]){
+  const dx=l.t.x-l.s.x,dy=l.t.y-l.s.y,d=Math.sqrt(dx*dx+dy*dy)||1,
+        f=(d-90)*0.02*l.w;
+  l.s.vx+=dx/d*f;l.s.vy+=dy/d*f;l.t.vx-=dx/d*f;l.t.vy-=dy/d*f;
+ }
+ for(const a of sim){a.vx*=0.85;a.vy*=0.85;a.x+=a.vx;a.y+=a.vy}
+ ctx.clearRect(0,0,canvas.width,canvas.height);
+ ctx.strokeStyle='#555';
+ for(const l of sim.links||[]){
+  ctx.beginPath();ctx.moveTo(l.s.x,l.s.y);ctx.lineTo(l.t.x,l.t.y);ctx.stroke();
+ }
+ for(const a of sim){
+  ctx.fillStyle=a.n.id===data.root?'#f90':'#4af';
+  ctx.beginPath();ctx.arc(a.x,a.y,6,0,7);ctx.fill();
+  ctx.fillStyle='#ccc';ctx.fillText(a.n.label||a.n.id,a.x+8,a.y+4);
+ }
+ requestAnimationFrame(tick);
+}
+fetch('/graph.json').then(r=>r.json()).then(g=>{
+ if(g.error){document.getElementById('count').textContent=g.error;return}
+ data=g;
+ const types=[...new Set(g.nodes.map(n=>n.type).filter(Boolean))].sort();
+ const sel=document.getElementById('type');
+ for(const t of types){const o=document.createElement('option');
+   o.value=o.textContent=t;sel.appendChild(o)}
+ sel.onchange=restart;
+ document.getElementById('label').oninput=restart;
+ restart();tick();
+});
+</script></body></html>
+"#;
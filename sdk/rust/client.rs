@@ -0,0 +1,557 @@
+//! Client trait abstraction for dependency injection.
+//!
+//! [`BrainAIClient`] covers the memory, learning, reasoning, vector, and
+//! graph operations of the SDK so applications can depend on the trait and
+//! swap in fakes, wrappers, or [`MockBrainAI`](crate::MockBrainAI) in tests.
+//! Methods take concrete types (`Value` for queries) so the trait stays
+//! object-safe; the inherent SDK methods keep their more ergonomic
+//! signatures and the trait impls delegate to them.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::{
+    BrainAISDK, EdgeDirection, Feedback, GraphEdge, GraphNode, LearningPattern, LearningProgress,
+    Memory,
+    MemoryPage, MemoryRelation, MemoryStats, MemoryType, MetadataIndex, MockBrainAI, NodeType,
+    ProfiledSearch, ReasoningOptions,
+    ReasoningResult, RelationType, Result, SearchResult, VectorMatch, VectorRecord,
+};
+
+/// Common interface over a Brain AI backend.
+///
+/// Implemented by [`BrainAISDK`] (remote server) and
+/// [`MockBrainAI`](crate::MockBrainAI) (local, in-memory). Use
+/// `Arc<dyn BrainAIClient>` or a generic bound to inject either.
+#[async_trait]
+pub trait BrainAIClient: Send + Sync {
+    // --- Memory ---
+
+    /// Stores a new memory and returns its ID.
+    async fn store_memory(
+        &self,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String>;
+
+    /// Retrieves a memory by ID, or `None` if it does not exist.
+    async fn get_memory(&self, id: &str) -> Result<Option<Memory>>;
+
+    /// Searches for memories similar to the query.
+    async fn search_memories(&self, query: Value, limit: usize) -> Result<Vec<SearchResult>>;
+
+    /// Searches with execution profiling; the hits come back with a
+    /// [`QueryProfile`](crate::QueryProfile) for diagnosing slow queries.
+    async fn search_memories_profiled(
+        &self,
+        query: Value,
+        limit: usize,
+    ) -> Result<ProfiledSearch>;
+
+    /// Creates a connection between two memories.
+    async fn connect_memories(&self, id1: &str, id2: &str, strength: f64) -> Result<bool>;
+
+    /// Creates a typed connection between two memories.
+    async fn relate_memories(
+        &self,
+        from: &str,
+        to: &str,
+        relation: RelationType,
+        strength: f64,
+    ) -> Result<bool>;
+
+    /// Lists the relations a memory participates in, optionally limited to
+    /// one [`RelationType`].
+    async fn get_relations(
+        &self,
+        id: &str,
+        relation: Option<RelationType>,
+    ) -> Result<Vec<MemoryRelation>>;
+
+    /// Updates the strength of a memory by `delta`.
+    async fn update_memory_strength(&self, id: &str, delta: f64) -> Result<bool>;
+
+    /// Gets usage statistics for a memory.
+    async fn get_memory_stats(&self, id: &str) -> Result<MemoryStats>;
+
+    /// Replaces the content (and optionally metadata) of an existing memory.
+    async fn update_memory(
+        &self,
+        id: &str,
+        content: Value,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<bool>;
+
+    /// Deletes a memory.
+    async fn delete_memory(&self, id: &str) -> Result<bool>;
+
+    /// Deletes every memory matching the filters, returning the count removed.
+    async fn delete_memories_by_filter(&self, filters: HashMap<String, Value>) -> Result<u64>;
+
+    /// Lists memories with optional filtering.
+    async fn list_memories(
+        &self,
+        filters: Option<HashMap<String, Value>>,
+        limit: usize,
+    ) -> Result<Vec<Memory>>;
+
+    /// Lists memories one page at a time using an opaque cursor.
+    async fn list_memories_page(
+        &self,
+        filters: Option<HashMap<String, Value>>,
+        page_size: usize,
+        cursor: Option<&str>,
+    ) -> Result<MemoryPage>;
+
+    /// Creates a server-side index over a metadata field, returning
+    /// whether it was newly created.
+    async fn create_metadata_index(&self, field: &str) -> Result<bool>;
+
+    /// Lists the metadata indexes the server maintains.
+    async fn list_indexes(&self) -> Result<Vec<MetadataIndex>>;
+
+    // --- Learning ---
+
+    /// Learns from new information and patterns.
+    async fn learn(&self, pattern: &str, context: Vec<String>) -> Result<bool>;
+
+    /// Retrieves learned patterns and their statistics.
+    async fn get_learning_patterns(&self) -> Result<Vec<LearningPattern>>;
+
+    /// Deletes one learned pattern, returning whether it existed.
+    async fn delete_pattern(&self, pattern: &str) -> Result<bool>;
+
+    /// Multiplies every pattern's strength by `1.0 - rate`, returning
+    /// the number of patterns decayed.
+    async fn decay_patterns(&self, rate: f64) -> Result<u64>;
+
+    /// Removes patterns that are rare, weak, or stale, returning the
+    /// number removed.
+    async fn prune_patterns(
+        &self,
+        min_frequency: Option<u64>,
+        min_strength: Option<f64>,
+        older_than: Option<i64>,
+    ) -> Result<u64>;
+
+    /// Provides feedback to improve learning accuracy.
+    async fn add_feedback(
+        &self,
+        feedback_type: &str,
+        information: &str,
+        reasoning: Option<&str>,
+    ) -> Result<bool>;
+
+    /// Submits structured feedback, returning its ID.
+    async fn submit_feedback(&self, feedback: Feedback) -> Result<String>;
+
+    /// Retracts previously submitted feedback, returning whether it
+    /// existed.
+    async fn retract_feedback(&self, feedback_id: &str) -> Result<bool>;
+
+    /// Gets information about the learning system's progress.
+    async fn get_learning_progress(&self) -> Result<LearningProgress>;
+
+    // --- Reasoning ---
+
+    /// Performs reasoning on a query using stored knowledge.
+    async fn reason(&self, query: &str, context: Vec<String>) -> Result<ReasoningResult>;
+
+    /// Performs reasoning with control over how conclusions are derived.
+    async fn reason_with_options(
+        &self,
+        query: &str,
+        context: Vec<String>,
+        options: ReasoningOptions,
+    ) -> Result<ReasoningResult>;
+
+    // --- Vectors ---
+
+    /// Stores a vector for similarity search and returns its ID.
+    async fn store_vector(
+        &self,
+        vector: Vec<f32>,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String>;
+
+    /// Retrieves a stored vector by ID, or `None` if it does not exist.
+    async fn get_vector(&self, id: &str) -> Result<Option<VectorRecord>>;
+
+    /// Replaces a stored vector's data and/or metadata.
+    async fn update_vector(
+        &self,
+        id: &str,
+        vector: Option<Vec<f32>>,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<bool>;
+
+    /// Deletes a stored vector.
+    async fn delete_vector(&self, id: &str) -> Result<bool>;
+
+    /// Searches for similar vectors using cosine similarity.
+    async fn search_similar_vectors(
+        &self,
+        vector: Vec<f32>,
+        limit: usize,
+    ) -> Result<Vec<VectorMatch>>;
+
+    /// Searches for similar vectors whose metadata matches every filter
+    /// pair exactly.
+    async fn search_vectors_filtered(
+        &self,
+        vector: Vec<f32>,
+        limit: usize,
+        filters: HashMap<String, Value>,
+    ) -> Result<Vec<VectorMatch>>;
+
+    /// Computes cosine similarity between two vectors.
+    async fn compute_similarity(&self, vector1: Vec<f32>, vector2: Vec<f32>) -> Result<f64>;
+
+    // --- Graph ---
+
+    /// Creates a node in the knowledge graph.
+    async fn create_graph_node(
+        &self,
+        id: &str,
+        label: &str,
+        node_type: NodeType,
+        properties: Option<HashMap<String, Value>>,
+    ) -> Result<bool>;
+
+    /// Creates an edge between two graph nodes.
+    async fn connect_graph_nodes(&self, node_id1: &str, node_id2: &str, weight: f64)
+        -> Result<bool>;
+
+    /// Creates a typed, labeled edge between two nodes and returns its ID.
+    async fn create_edge(
+        &self,
+        from: &str,
+        to: &str,
+        label: &str,
+        directed: bool,
+        weight: f64,
+        properties: Option<HashMap<String, Value>>,
+    ) -> Result<String>;
+
+    /// Updates an edge's label, weight, and/or properties.
+    async fn update_edge(
+        &self,
+        edge_id: &str,
+        label: Option<&str>,
+        weight: Option<f64>,
+        properties: Option<HashMap<String, Value>>,
+    ) -> Result<bool>;
+
+    /// Deletes a typed edge.
+    async fn delete_edge(&self, edge_id: &str) -> Result<bool>;
+
+    /// Lists a node's typed edges, filtered by direction and label.
+    async fn get_edges(
+        &self,
+        node_id: &str,
+        direction: EdgeDirection,
+        label_filter: Option<&str>,
+    ) -> Result<Vec<GraphEdge>>;
+
+    /// Gets neighboring nodes up to `depth` hops away.
+    async fn get_graph_neighbors(&self, node_id: &str, depth: u32) -> Result<Vec<GraphNode>>;
+
+    /// Finds a path between two nodes, returned as the node sequence.
+    async fn find_graph_path(&self, start_node: &str, end_node: &str) -> Result<Vec<GraphNode>>;
+}
+
+macro_rules! delegate_client {
+    ($target:ty) => {
+        #[async_trait]
+        impl BrainAIClient for $target {
+            async fn store_memory(
+                &self,
+                content: Value,
+                memory_type: MemoryType,
+                metadata: Option<HashMap<String, Value>>,
+            ) -> Result<String> {
+                <$target>::store_memory(self, content, memory_type, metadata).await
+            }
+
+            async fn get_memory(&self, id: &str) -> Result<Option<Memory>> {
+                <$target>::get_memory(self, id).await
+            }
+
+            async fn search_memories(
+                &self,
+                query: Value,
+                limit: usize,
+            ) -> Result<Vec<SearchResult>> {
+                <$target>::search_memories(self, query, limit).await
+            }
+
+            async fn search_memories_profiled(
+                &self,
+                query: Value,
+                limit: usize,
+            ) -> Result<ProfiledSearch> {
+                <$target>::search_memories_profiled(self, query, limit).await
+            }
+
+            async fn connect_memories(&self, id1: &str, id2: &str, strength: f64) -> Result<bool> {
+                <$target>::connect_memories(self, id1, id2, strength).await
+            }
+
+            async fn relate_memories(
+                &self,
+                from: &str,
+                to: &str,
+                relation: RelationType,
+                strength: f64,
+            ) -> Result<bool> {
+                <$target>::relate_memories(self, from, to, relation, strength).await
+            }
+
+            async fn get_relations(
+                &self,
+                id: &str,
+                relation: Option<RelationType>,
+            ) -> Result<Vec<MemoryRelation>> {
+                <$target>::get_relations(self, id, relation).await
+            }
+
+            async fn update_memory_strength(&self, id: &str, delta: f64) -> Result<bool> {
+                <$target>::update_memory_strength(self, id, delta).await
+            }
+
+            async fn get_memory_stats(&self, id: &str) -> Result<MemoryStats> {
+                <$target>::get_memory_stats(self, id).await
+            }
+
+            async fn update_memory(
+                &self,
+                id: &str,
+                content: Value,
+                metadata: Option<HashMap<String, Value>>,
+            ) -> Result<bool> {
+                <$target>::update_memory(self, id, content, metadata).await
+            }
+
+            async fn delete_memory(&self, id: &str) -> Result<bool> {
+                <$target>::delete_memory(self, id).await
+            }
+
+            async fn delete_memories_by_filter(
+                &self,
+                filters: HashMap<String, Value>,
+            ) -> Result<u64> {
+                <$target>::delete_memories_by_filter(self, filters).await
+            }
+
+            async fn list_memories(
+                &self,
+                filters: Option<HashMap<String, Value>>,
+                limit: usize,
+            ) -> Result<Vec<Memory>> {
+                <$target>::list_memories(self, filters, limit).await
+            }
+
+            async fn list_memories_page(
+                &self,
+                filters: Option<HashMap<String, Value>>,
+                page_size: usize,
+                cursor: Option<&str>,
+            ) -> Result<MemoryPage> {
+                <$target>::list_memories_page(self, filters, page_size, cursor).await
+            }
+
+            async fn create_metadata_index(&self, field: &str) -> Result<bool> {
+                <$target>::create_metadata_index(self, field).await
+            }
+
+            async fn list_indexes(&self) -> Result<Vec<MetadataIndex>> {
+                <$target>::list_indexes(self).await
+            }
+
+            async fn learn(&self, pattern: &str, context: Vec<String>) -> Result<bool> {
+                <$target>::learn(self, pattern, context).await
+            }
+
+            async fn get_learning_patterns(&self) -> Result<Vec<LearningPattern>> {
+                <$target>::get_learning_patterns(self).await
+            }
+
+            async fn delete_pattern(&self, pattern: &str) -> Result<bool> {
+                <$target>::delete_pattern(self, pattern).await
+            }
+
+            async fn decay_patterns(&self, rate: f64) -> Result<u64> {
+                <$target>::decay_patterns(self, rate).await
+            }
+
+            async fn prune_patterns(
+                &self,
+                min_frequency: Option<u64>,
+                min_strength: Option<f64>,
+                older_than: Option<i64>,
+            ) -> Result<u64> {
+                <$target>::prune_patterns(self, min_frequency, min_strength, older_than).await
+            }
+
+            async fn add_feedback(
+                &self,
+                feedback_type: &str,
+                information: &str,
+                reasoning: Option<&str>,
+            ) -> Result<bool> {
+                <$target>::add_feedback(self, feedback_type, information, reasoning).await
+            }
+
+            async fn submit_feedback(&self, feedback: Feedback) -> Result<String> {
+                <$target>::submit_feedback(self, feedback).await
+            }
+
+            async fn retract_feedback(&self, feedback_id: &str) -> Result<bool> {
+                <$target>::retract_feedback(self, feedback_id).await
+            }
+
+            async fn get_learning_progress(&self) -> Result<LearningProgress> {
+                <$target>::get_learning_progress(self).await
+            }
+
+            async fn reason(&self, query: &str, context: Vec<String>) -> Result<ReasoningResult> {
+                <$target>::reason(self, query, context).await
+            }
+
+            async fn reason_with_options(
+                &self,
+                query: &str,
+                context: Vec<String>,
+                options: ReasoningOptions,
+            ) -> Result<ReasoningResult> {
+                <$target>::reason_with_options(self, query, context, options).await
+            }
+
+            async fn store_vector(
+                &self,
+                vector: Vec<f32>,
+                metadata: Option<HashMap<String, Value>>,
+            ) -> Result<String> {
+                <$target>::store_vector(self, vector, metadata).await
+            }
+
+            async fn get_vector(&self, id: &str) -> Result<Option<VectorRecord>> {
+                <$target>::get_vector(self, id).await
+            }
+
+            async fn update_vector(
+                &self,
+                id: &str,
+                vector: Option<Vec<f32>>,
+                metadata: Option<HashMap<String, Value>>,
+            ) -> Result<bool> {
+                <$target>::update_vector(self, id, vector, metadata).await
+            }
+
+            async fn delete_vector(&self, id: &str) -> Result<bool> {
+                <$target>::delete_vector(self, id).await
+            }
+
+            async fn search_similar_vectors(
+                &self,
+                vector: Vec<f32>,
+                limit: usize,
+            ) -> Result<Vec<VectorMatch>> {
+                <$target>::search_similar_vectors(self, vector, limit).await
+            }
+
+            async fn search_vectors_filtered(
+                &self,
+                vector: Vec<f32>,
+                limit: usize,
+                filters: HashMap<String, Value>,
+            ) -> Result<Vec<VectorMatch>> {
+                <$target>::search_vectors_filtered(self, vector, limit, filters).await
+            }
+
+            async fn compute_similarity(
+                &self,
+                vector1: Vec<f32>,
+                vector2: Vec<f32>,
+            ) -> Result<f64> {
+                <$target>::compute_similarity(self, vector1, vector2).await
+            }
+
+            async fn create_graph_node(
+                &self,
+                id: &str,
+                label: &str,
+                node_type: NodeType,
+                properties: Option<HashMap<String, Value>>,
+            ) -> Result<bool> {
+                <$target>::create_graph_node(self, id, label, node_type, properties).await
+            }
+
+            async fn connect_graph_nodes(
+                &self,
+                node_id1: &str,
+                node_id2: &str,
+                weight: f64,
+            ) -> Result<bool> {
+                <$target>::connect_graph_nodes(self, node_id1, node_id2, weight).await
+            }
+
+            async fn create_edge(
+                &self,
+                from: &str,
+                to: &str,
+                label: &str,
+                directed: bool,
+                weight: f64,
+                properties: Option<HashMap<String, Value>>,
+            ) -> Result<String> {
+                <$target>::create_edge(self, from, to, label, directed, weight, properties).await
+            }
+
+            async fn update_edge(
+                &self,
+                edge_id: &str,
+                label: Option<&str>,
+                weight: Option<f64>,
+                properties: Option<HashMap<String, Value>>,
+            ) -> Result<bool> {
+                <$target>::update_edge(self, edge_id, label, weight, properties).await
+            }
+
+            async fn delete_edge(&self, edge_id: &str) -> Result<bool> {
+                <$target>::delete_edge(self, edge_id).await
+            }
+
+            async fn get_edges(
+                &self,
+                node_id: &str,
+                direction: EdgeDirection,
+                label_filter: Option<&str>,
+            ) -> Result<Vec<GraphEdge>> {
+                <$target>::get_edges(self, node_id, direction, label_filter).await
+            }
+
+            async fn get_graph_neighbors(
+                &self,
+                node_id: &str,
+                depth: u32,
+            ) -> Result<Vec<GraphNode>> {
+                <$target>::get_graph_neighbors(self, node_id, depth).await
+            }
+
+            async fn find_graph_path(
+                &self,
+                start_node: &str,
+                end_node: &str,
+            ) -> Result<Vec<GraphNode>> {
+                <$target>::find_graph_path(self, start_node, end_node).await
+            }
+        }
+    };
+}
+
+delegate_client!(BrainAISDK);
+delegate_client!(MockBrainAI);
@@ -0,0 +1,123 @@
+//! Confidence on stored facts, distinct from strength.
+//!
+//! Strength measures how well a memory is retained; confidence measures
+//! how likely its content is true. A frequently recalled rumor is
+//! strong but not confident, and conflating the two corrupts both
+//! signals. Confidence lives in the reserved `confidence` metadata key
+//! (`0.0..=1.0`, [`DEFAULT_CONFIDENCE`] when absent), is nudged by
+//! feedback, and can gate retrieval via
+//! [`search_with_min_confidence`] so reasoning only grounds on facts
+//! believed to be true.
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::{BrainAIError, MemoryType, Result, SearchResult};
+
+/// Reserved metadata key holding the confidence score.
+pub const CONFIDENCE: &str = "confidence";
+
+/// Confidence assumed for memories that never received a score.
+pub const DEFAULT_CONFIDENCE: f64 = 0.5;
+
+/// How far one feedback signal moves confidence toward certainty.
+const FEEDBACK_RATE: f64 = 0.2;
+
+/// Over-fetch factor when filtering search results by confidence.
+const CONFIDENCE_POOL_FACTOR: usize = 4;
+
+/// Reads the confidence from a metadata map, defaulting when absent.
+pub fn confidence_of(metadata: &HashMap<String, Value>) -> f64 {
+    metadata
+        .get(CONFIDENCE)
+        .and_then(Value::as_f64)
+        .unwrap_or(DEFAULT_CONFIDENCE)
+        .clamp(0.0, 1.0)
+}
+
+/// Stores a memory with an explicit confidence score.
+pub async fn store_with_confidence(
+    client: &dyn BrainAIClient,
+    content: Value,
+    memory_type: MemoryType,
+    confidence: f64,
+    metadata: Option<HashMap<String, Value>>,
+) -> Result<String> {
+    if !(0.0..=1.0).contains(&confidence) {
+        return Err(BrainAIError::InvalidInput(format!(
+            "confidence must be in 0.0..=1.0, got {confidence}"
+        )));
+    }
+    let mut metadata = metadata.unwrap_or_default();
+    metadata.insert(CONFIDENCE.to_string(), json!(confidence));
+    client.store_memory(content, memory_type, Some(metadata)).await
+}
+
+/// Reads a memory's confidence; `None` when the memory does not exist.
+pub async fn get_confidence(client: &dyn BrainAIClient, id: &str) -> Result<Option<f64>> {
+    Ok(client
+        .get_memory(id)
+        .await?
+        .map(|memory| confidence_of(&memory.metadata)))
+}
+
+/// Sets a memory's confidence outright, leaving strength untouched.
+pub async fn set_confidence(
+    client: &dyn BrainAIClient,
+    id: &str,
+    confidence: f64,
+) -> Result<bool> {
+    let Some(memory) = client.get_memory(id).await? else {
+        return Ok(false);
+    };
+    let mut metadata = memory.metadata;
+    metadata.insert(CONFIDENCE.to_string(), json!(confidence.clamp(0.0, 1.0)));
+    client.update_memory(id, memory.content, Some(metadata)).await
+}
+
+/// Nudges confidence toward certainty (confirming feedback) or doubt
+/// (contradicting feedback). Moves a fixed fraction of the remaining
+/// distance so repeated signals converge without overshooting, and the
+/// optional `source_reliability` scales how far one signal moves it —
+/// a tip from an unreliable source barely registers.
+pub async fn apply_confidence_feedback(
+    client: &dyn BrainAIClient,
+    id: &str,
+    confirmed: bool,
+    source_reliability: Option<f64>,
+) -> Result<Option<f64>> {
+    let Some(memory) = client.get_memory(id).await? else {
+        return Ok(None);
+    };
+    let current = confidence_of(&memory.metadata);
+    let rate = FEEDBACK_RATE * source_reliability.unwrap_or(1.0).clamp(0.0, 1.0);
+    let updated = if confirmed {
+        current + rate * (1.0 - current)
+    } else {
+        current - rate * current
+    };
+    let mut metadata = memory.metadata;
+    metadata.insert(CONFIDENCE.to_string(), json!(updated));
+    client
+        .update_memory(id, memory.content, Some(metadata))
+        .await?;
+    Ok(Some(updated))
+}
+
+/// Searches memories, dropping hits below `min_confidence`. Over-fetches
+/// so the filter does not starve the result set; unscored memories
+/// count as [`DEFAULT_CONFIDENCE`].
+pub async fn search_with_min_confidence(
+    client: &dyn BrainAIClient,
+    query: Value,
+    limit: usize,
+    min_confidence: f64,
+) -> Result<Vec<SearchResult>> {
+    let pool = limit.saturating_mul(CONFIDENCE_POOL_FACTOR).max(limit);
+    let mut hits = client.search_memories(query, pool).await?;
+    hits.retain(|hit| confidence_of(&hit.metadata) >= min_confidence);
+    hits.truncate(limit);
+    Ok(hits)
+}
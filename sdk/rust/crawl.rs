@@ -0,0 +1,378 @@
+//! Polite background crawler for URL ingestion.
+//!
+//! [`Crawler`] walks a site section breadth-first from a start URL and
+//! feeds every page through the [`DocumentIngestor`] pipeline, so a
+//! documentation tree becomes linked, searchable memories with the page
+//! URL as provenance. Politeness is not optional: crawls stay on an
+//! allowlist of domains, honor `robots.txt` disallow rules, respect
+//! depth and page budgets, and pause between fetches. Runnable from the
+//! CLI as `brain-ai ingest crawl <url>`.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+use crate::client::BrainAIClient;
+use crate::embed::Embedder;
+use crate::ingest::DocumentIngestor;
+use crate::{BrainAIError, Result};
+
+/// Budgets and politeness settings for one crawl.
+#[derive(Debug, Clone)]
+pub struct CrawlOptions {
+    /// Link depth from the start URL; `0` ingests only the start page.
+    pub max_depth: u32,
+    /// Hard page budget for the whole crawl.
+    pub max_pages: usize,
+    /// Pause between fetches.
+    pub delay: Duration,
+    /// Domains the crawl may touch. Empty means "the start URL's domain".
+    pub allowed_domains: Vec<String>,
+    /// Honor `robots.txt` disallow rules. On by default; turning it off
+    /// is for crawling hosts you own.
+    pub respect_robots: bool,
+    /// User-agent announced to the crawled site.
+    pub user_agent: String,
+}
+
+impl Default for CrawlOptions {
+    fn default() -> Self {
+        CrawlOptions {
+            max_depth: 2,
+            max_pages: 50,
+            delay: Duration::from_secs(1),
+            allowed_domains: Vec::new(),
+            respect_robots: true,
+            user_agent: "brain-ai-crawler".to_string(),
+        }
+    }
+}
+
+/// One successfully ingested page.
+#[derive(Debug, Clone)]
+pub struct CrawledPage {
+    pub url: String,
+    /// Link distance from the start URL.
+    pub depth: u32,
+    /// Chunks the page was split into.
+    pub chunks: usize,
+}
+
+/// Outcome of one crawl.
+#[derive(Debug, Default, Clone)]
+pub struct CrawlReport {
+    pub pages: Vec<CrawledPage>,
+    /// URLs not ingested, with the reason (off-domain, robots, error).
+    pub skipped: Vec<(String, String)>,
+}
+
+/// Breadth-first site crawler feeding the ingestion pipeline.
+pub struct Crawler<'a> {
+    client: &'a dyn BrainAIClient,
+    embedder: &'a dyn Embedder,
+    options: CrawlOptions,
+    http: reqwest::Client,
+}
+
+impl<'a> Crawler<'a> {
+    pub fn new(client: &'a dyn BrainAIClient, embedder: &'a dyn Embedder) -> Self {
+        Crawler {
+            client,
+            embedder,
+            options: CrawlOptions::default(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Overrides the crawl budgets and politeness settings.
+    pub fn with_options(mut self, options: CrawlOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Crawls from `start_url`, ingesting each page as a document whose
+    /// ID is its URL.
+    pub async fn crawl(&self, start_url: &str) -> Result<CrawlReport> {
+        let start_domain = domain_of(start_url).ok_or_else(|| {
+            BrainAIError::InvalidInput(format!("not an absolute http(s) URL: {start_url}"))
+        })?;
+        let allowed: Vec<String> = if self.options.allowed_domains.is_empty() {
+            vec![start_domain]
+        } else {
+            self.options.allowed_domains.clone()
+        };
+
+        let mut report = CrawlReport::default();
+        let mut robots: HashMap<String, Vec<String>> = HashMap::new();
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut queue: VecDeque<(String, u32)> =
+            VecDeque::from([(normalize(start_url), 0)]);
+        let ingestor = DocumentIngestor::new(self.client, self.embedder);
+
+        while let Some((url, depth)) = queue.pop_front() {
+            if report.pages.len() >= self.options.max_pages {
+                break;
+            }
+            if !visited.insert(url.clone()) {
+                continue;
+            }
+            let Some(domain) = domain_of(&url) else {
+                continue;
+            };
+            if !allowed.iter().any(|d| d == &domain) {
+                report.skipped.push((url, "off-domain".to_string()));
+                continue;
+            }
+            if self.options.respect_robots && self.disallowed(&url, &domain, &mut robots).await
+            {
+                report.skipped.push((url, "robots.txt".to_string()));
+                continue;
+            }
+
+            if !report.pages.is_empty() {
+                tokio::time::sleep(self.options.delay).await;
+            }
+            let (text, links) = match self.fetch_page(&url).await {
+                Ok(page) => page,
+                Err(err) => {
+                    report.skipped.push((url, err.to_string()));
+                    continue;
+                }
+            };
+            match ingestor.ingest(&url, &text).await {
+                Ok(ingested) => report.pages.push(CrawledPage {
+                    url: url.clone(),
+                    depth,
+                    chunks: ingested.chunks.len(),
+                }),
+                Err(err) => {
+                    report.skipped.push((url.clone(), err.to_string()));
+                    continue;
+                }
+            }
+            if depth < self.options.max_depth {
+                for link in links {
+                    if let Some(resolved) = resolve(&url, &link) {
+                        let resolved = normalize(&resolved);
+                        if !visited.contains(&resolved) {
+                            queue.push_back((resolved, depth + 1));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Fetches one page, returning its visible text and raw hrefs.
+    /// Non-HTML content types are ingested whole with no links.
+    async fn fetch_page(&self, url: &str) -> Result<(String, Vec<String>)> {
+        let response = self
+            .http
+            .get(url)
+            .header("User-Agent", &self.options.user_agent)
+            .send()
+            .await?
+            .error_for_status()
+            .map_err(BrainAIError::Http)?;
+        let html = response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .is_none_or(|ct| ct.contains("html"));
+        let body = response.text().await?;
+        if html {
+            Ok((strip_html(&body), extract_links(&body)))
+        } else {
+            Ok((body, Vec::new()))
+        }
+    }
+
+    /// Checks `robots.txt` for the URL's domain, fetching and caching
+    /// its `User-agent: *` disallow prefixes on first contact. A
+    /// missing or unreadable robots file allows everything.
+    async fn disallowed(
+        &self,
+        url: &str,
+        domain: &str,
+        cache: &mut HashMap<String, Vec<String>>,
+    ) -> bool {
+        if !cache.contains_key(domain) {
+            let scheme = if url.starts_with("https://") { "https" } else { "http" };
+            let robots_url = format!("{scheme}://{domain}/robots.txt");
+            let rules = match self.http.get(&robots_url).send().await {
+                Ok(response) if response.status().is_success() => response
+                    .text()
+                    .await
+                    .map(|text| parse_robots(&text))
+                    .unwrap_or_default(),
+                _ => Vec::new(),
+            };
+            cache.insert(domain.to_string(), rules);
+        }
+        let path = path_of(url);
+        cache[domain].iter().any(|prefix| path.starts_with(prefix))
+    }
+}
+
+/// Disallow prefixes applying to `User-agent: *`.
+fn parse_robots(text: &str) -> Vec<String> {
+    let mut rules = Vec::new();
+    let mut applies = false;
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if let Some(agent) = strip_field(line, "user-agent") {
+            applies = agent == "*";
+        } else if let Some(prefix) = strip_field(line, "disallow") {
+            if applies && !prefix.is_empty() {
+                rules.push(prefix.to_string());
+            }
+        }
+    }
+    rules
+}
+
+fn strip_field<'a>(line: &'a str, field: &str) -> Option<&'a str> {
+    let (name, value) = line.split_once(':')?;
+    name.trim()
+        .eq_ignore_ascii_case(field)
+        .then(|| value.trim())
+}
+
+/// `host[:port]` of an absolute http(s) URL.
+fn domain_of(url: &str) -> Option<String> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let host = rest.split(['/', '?', '#']).next()?;
+    (!host.is_empty()).then(|| host.to_ascii_lowercase())
+}
+
+/// Path component of an absolute URL, `/` when absent.
+fn path_of(url: &str) -> String {
+    url.strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .and_then(|rest| rest.find('/').map(|at| rest[at..].to_string()))
+        .unwrap_or_else(|| "/".to_string())
+}
+
+/// Drops fragments and trailing slashes so revisits are detected.
+fn normalize(url: &str) -> String {
+    let no_fragment = url.split('#').next().unwrap_or(url);
+    no_fragment.trim_end_matches('/').to_string()
+}
+
+/// Resolves an href against the page it appeared on. Only http(s) and
+/// site-relative targets resolve; mailto, javascript, and protocol
+/// oddities are dropped.
+fn resolve(base: &str, href: &str) -> Option<String> {
+    let href = href.trim();
+    if href.is_empty() || href.starts_with('#') {
+        return None;
+    }
+    if href.starts_with("http://") || href.starts_with("https://") {
+        return Some(href.to_string());
+    }
+    if href.contains(':') {
+        // mailto:, javascript:, data:, ...
+        return None;
+    }
+    let domain = domain_of(base)?;
+    let scheme = if base.starts_with("https://") { "https" } else { "http" };
+    if let Some(rooted) = href.strip_prefix('/') {
+        return Some(format!("{scheme}://{domain}/{rooted}"));
+    }
+    // Relative to the page's directory.
+    let base_path = path_of(base);
+    let dir = match base_path.rfind('/') {
+        Some(at) => &base_path[..=at],
+        None => "/",
+    };
+    Some(format!("{scheme}://{domain}{dir}{href}"))
+}
+
+/// Case-insensitive (ASCII) substring search, returning a byte offset
+/// into `haystack` at or after `from`.
+fn find_ci(haystack: &str, needle: &str, from: usize) -> Option<usize> {
+    let haystack = haystack.as_bytes();
+    let needle = needle.as_bytes();
+    (from..haystack.len().checked_sub(needle.len())? + 1)
+        .find(|&at| haystack[at..at + needle.len()].eq_ignore_ascii_case(needle))
+}
+
+fn starts_with_ci(text: &str, prefix: &str) -> bool {
+    text.len() >= prefix.len() && text.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+}
+
+/// Strips tags, scripts, and styles from HTML, decoding the handful of
+/// entities that matter for text content.
+fn strip_html(html: &str) -> String {
+    let mut out = String::with_capacity(html.len() / 2);
+    let mut skip_until: Option<usize> = None;
+    for (at, c) in html.char_indices() {
+        if let Some(end) = skip_until {
+            if at < end {
+                continue;
+            }
+            skip_until = None;
+        }
+        if c == '<' {
+            // Swallow whole <script>/<style> blocks, otherwise just the tag.
+            let rest = &html[at..];
+            let block_end = if starts_with_ci(rest, "<script") {
+                find_ci(html, "</script>", at).map(|e| e + "</script>".len())
+            } else if starts_with_ci(rest, "<style") {
+                find_ci(html, "</style>", at).map(|e| e + "</style>".len())
+            } else {
+                None
+            };
+            let tag_end = block_end.or_else(|| rest.find('>').map(|e| at + e + 1));
+            match tag_end {
+                Some(end) => {
+                    skip_until = Some(end);
+                    // Tags usually break words; keep text readable.
+                    if !out.ends_with(char::is_whitespace) {
+                        out.push(' ');
+                    }
+                }
+                None => break,
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    for (entity, replacement) in [
+        ("&amp;", "&"),
+        ("&lt;", "<"),
+        ("&gt;", ">"),
+        ("&quot;", "\""),
+        ("&#39;", "'"),
+        ("&nbsp;", " "),
+    ] {
+        out = out.replace(entity, replacement);
+    }
+    // Collapse runs of whitespace left behind by markup.
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Pulls raw `href` attribute values out of HTML.
+fn extract_links(html: &str) -> Vec<String> {
+    let mut links = Vec::new();
+    let mut from = 0;
+    while let Some(at) = find_ci(html, "href", from) {
+        let start = at + "href".len();
+        from = start;
+        let rest = html[start..].trim_start();
+        let Some(rest) = rest.strip_prefix('=') else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let (quote, rest) = match rest.chars().next() {
+            Some(q @ ('"' | '\'')) => (q, &rest[1..]),
+            _ => continue,
+        };
+        if let Some(end) = rest.find(quote) {
+            links.push(rest[..end].to_string());
+        }
+    }
+    links
+}
@@ -0,0 +1,290 @@
+//! Client-side encryption, behind the `crypto` feature.
+//!
+//! Some deployments must guarantee that sensitive content never
+//! reaches the server in plaintext. [`EncryptedStore`] wraps any
+//! client and encrypts memory content — and vector metadata — with
+//! AES-256-GCM before upload, decrypting transparently on retrieval.
+//! Keys come from a [`KeyProvider`], so a KMS or vault can back them;
+//! every envelope records the key ID it was sealed with, which lets
+//! old data decrypt across key rotations.
+//!
+//! The trade-off is search: the server cannot match what it cannot
+//! read, so content-based memory search over encrypted memories finds
+//! nothing. Retrieval must go through plaintext metadata (which stays
+//! searchable — do not put secrets there) or vector similarity, since
+//! vectors are uploaded as-is; an embedding of sensitive text leaks
+//! less than the text, but is not nothing. Choose what to encrypt
+//! accordingly.
+
+#[cfg(feature = "crypto")]
+mod encrypted {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    use aes_gcm::aead::Aead;
+    use aes_gcm::{Aes256Gcm, Key, KeyInit, Nonce};
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+
+    use crate::client::BrainAIClient;
+    use crate::vector_utils::now_millis;
+    use crate::{BrainAIError, Memory, MemoryType, Result, VectorRecord};
+
+    /// Counter feeding nonce uniqueness within one process.
+    static NONCE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    /// Supplies AES-256 keys for sealing and unsealing envelopes.
+    ///
+    /// `key_id` labels the current sealing key and is stored in every
+    /// envelope; `key_by_id` must return the matching key for any ID
+    /// still present in stored data, so rotation keeps old envelopes
+    /// readable until they are re-encrypted.
+    pub trait KeyProvider: Send + Sync {
+        /// ID of the key new envelopes are sealed with.
+        fn key_id(&self) -> String;
+
+        /// The key for `key_id`, 32 bytes.
+        fn key_by_id(&self, key_id: &str) -> Result<[u8; 32]>;
+    }
+
+    /// A single fixed key; the simplest provider, for deployments that
+    /// manage rotation by re-encrypting.
+    pub struct StaticKeyProvider {
+        id: String,
+        key: [u8; 32],
+    }
+
+    impl StaticKeyProvider {
+        pub fn new(id: impl Into<String>, key: [u8; 32]) -> Self {
+            StaticKeyProvider { id: id.into(), key }
+        }
+    }
+
+    impl KeyProvider for StaticKeyProvider {
+        fn key_id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn key_by_id(&self, key_id: &str) -> Result<[u8; 32]> {
+            if key_id == self.id {
+                Ok(self.key)
+            } else {
+                Err(BrainAIError::InvalidInput(format!(
+                    "unknown encryption key: {key_id}"
+                )))
+            }
+        }
+    }
+
+    /// What an encrypted field looks like on the server.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Envelope {
+        /// Scheme marker; always `"aes256gcm"` today.
+        enc: String,
+        /// Key the ciphertext was sealed with.
+        key_id: String,
+        /// 96-bit nonce, hex.
+        nonce: String,
+        /// Ciphertext with the GCM tag appended, hex.
+        ciphertext: String,
+    }
+
+    /// Metadata keys never encrypted on vectors, preserving the
+    /// cross-links the rest of the SDK relies on.
+    const DEFAULT_PLAINTEXT_KEYS: [&str; 2] = ["memory_id", "vector_id"];
+
+    /// Encrypting client wrapper; see the module docs.
+    pub struct EncryptedStore<C: BrainAIClient> {
+        client: C,
+        provider: Arc<dyn KeyProvider>,
+        plaintext_keys: Vec<String>,
+    }
+
+    impl<C: BrainAIClient> EncryptedStore<C> {
+        /// Wraps a client; vector metadata keeps the default link keys
+        /// (`memory_id`, `vector_id`) in plaintext.
+        pub fn new(client: C, provider: Arc<dyn KeyProvider>) -> Self {
+            EncryptedStore {
+                client,
+                provider,
+                plaintext_keys: DEFAULT_PLAINTEXT_KEYS
+                    .iter()
+                    .map(|key| key.to_string())
+                    .collect(),
+            }
+        }
+
+        /// Replaces the set of vector metadata keys left readable.
+        pub fn with_plaintext_keys(mut self, keys: Vec<String>) -> Self {
+            self.plaintext_keys = keys;
+            self
+        }
+
+        /// The wrapped client, for operations that bypass encryption.
+        pub fn inner(&self) -> &C {
+            &self.client
+        }
+
+        /// Stores a memory with its content encrypted. The metadata is
+        /// uploaded as given — it stays searchable, so it must not
+        /// carry secrets.
+        pub async fn store_memory(
+            &self,
+            content: Value,
+            memory_type: MemoryType,
+            metadata: Option<HashMap<String, Value>>,
+        ) -> Result<String> {
+            let sealed = self.seal(&content)?;
+            self.client
+                .store_memory(sealed, memory_type, metadata)
+                .await
+        }
+
+        /// Retrieves a memory, decrypting its content; memories stored
+        /// without encryption pass through unchanged.
+        pub async fn get_memory(&self, id: &str) -> Result<Option<Memory>> {
+            let Some(mut memory) = self.client.get_memory(id).await? else {
+                return Ok(None);
+            };
+            memory.content = self.unseal(memory.content)?;
+            Ok(Some(memory))
+        }
+
+        /// Updates a memory, encrypting the new content.
+        pub async fn update_memory(
+            &self,
+            id: &str,
+            content: Value,
+            metadata: Option<HashMap<String, Value>>,
+        ) -> Result<bool> {
+            let sealed = self.seal(&content)?;
+            self.client.update_memory(id, sealed, metadata).await
+        }
+
+        /// Stores a vector with its metadata encrypted into a single
+        /// `encrypted` field; the configured plaintext keys and the
+        /// vector itself are uploaded as-is so similarity search and
+        /// cross-links keep working.
+        pub async fn store_vector(
+            &self,
+            vector: Vec<f32>,
+            metadata: Option<HashMap<String, Value>>,
+        ) -> Result<String> {
+            let mut metadata = metadata.unwrap_or_default();
+            let mut sensitive = HashMap::new();
+            let keys: Vec<String> = metadata
+                .keys()
+                .filter(|key| !self.plaintext_keys.contains(key))
+                .cloned()
+                .collect();
+            for key in keys {
+                if let Some(value) = metadata.remove(&key) {
+                    sensitive.insert(key, value);
+                }
+            }
+            if !sensitive.is_empty() {
+                let sealed = self.seal(&serde_json::to_value(&sensitive)?)?;
+                metadata.insert("encrypted".to_string(), sealed);
+            }
+            self.client.store_vector(vector, Some(metadata)).await
+        }
+
+        /// Retrieves a vector, restoring its encrypted metadata fields.
+        pub async fn get_vector(&self, id: &str) -> Result<Option<VectorRecord>> {
+            let Some(mut record) = self.client.get_vector(id).await? else {
+                return Ok(None);
+            };
+            if let Some(sealed) = record.metadata.remove("encrypted") {
+                let opened = self.unseal(sealed)?;
+                if let Value::Object(fields) = opened {
+                    record.metadata.extend(fields);
+                }
+            }
+            Ok(Some(record))
+        }
+
+        /// Encrypts a JSON value into its envelope.
+        fn seal(&self, value: &Value) -> Result<Value> {
+            let key_id = self.provider.key_id();
+            let key = self.provider.key_by_id(&key_id)?;
+            let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+            // GCM needs nonce *uniqueness*, not unpredictability: wall
+            // clock plus a process-local counter never repeats under
+            // one key in practice.
+            let mut nonce = [0u8; 12];
+            nonce[..8].copy_from_slice(&now_millis().to_be_bytes());
+            nonce[8..].copy_from_slice(
+                &(NONCE_COUNTER.fetch_add(1, Ordering::Relaxed) as u32).to_be_bytes(),
+            );
+            let ciphertext = cipher
+                .encrypt(Nonce::from_slice(&nonce), value.to_string().as_bytes())
+                .map_err(|_| {
+                    BrainAIError::InvalidInput("encryption failed".to_string())
+                })?;
+            serde_json::to_value(Envelope {
+                enc: "aes256gcm".to_string(),
+                key_id,
+                nonce: hex(&nonce),
+                ciphertext: hex(&ciphertext),
+            })
+            .map_err(BrainAIError::Serialization)
+        }
+
+        /// Decrypts an envelope back into its JSON value; values that
+        /// are not envelopes are returned unchanged.
+        fn unseal(&self, value: Value) -> Result<Value> {
+            let Ok(envelope) = serde_json::from_value::<Envelope>(value.clone()) else {
+                return Ok(value);
+            };
+            if envelope.enc != "aes256gcm" {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "unsupported encryption scheme: {}",
+                    envelope.enc
+                )));
+            }
+            let key = self.provider.key_by_id(&envelope.key_id)?;
+            let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+            let nonce = unhex(&envelope.nonce)?;
+            let ciphertext = unhex(&envelope.ciphertext)?;
+            // `from_slice` panics off-length; reject malformed envelopes
+            // (12-byte nonce, ciphertext at least one GCM tag long) here.
+            if nonce.len() != 12 || ciphertext.len() < 16 {
+                return Err(BrainAIError::InvalidInput(
+                    "malformed encryption envelope".to_string(),
+                ));
+            }
+            let plaintext = cipher
+                .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+                .map_err(|_| {
+                    BrainAIError::InvalidInput(
+                        "decryption failed: wrong key or tampered data".to_string(),
+                    )
+                })?;
+            serde_json::from_slice(&plaintext).map_err(BrainAIError::Serialization)
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn unhex(text: &str) -> Result<Vec<u8>> {
+        if !text.len().is_multiple_of(2) {
+            return Err(BrainAIError::InvalidInput(
+                "malformed encryption envelope".to_string(),
+            ));
+        }
+        (0..text.len())
+            .step_by(2)
+            .map(|i| {
+                u8::from_str_radix(&text[i..i + 2], 16).map_err(|_| {
+                    BrainAIError::InvalidInput("malformed encryption envelope".to_string())
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "crypto")]
+pub use encrypted::{EncryptedStore, KeyProvider, StaticKeyProvider};
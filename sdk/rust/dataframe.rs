@@ -0,0 +1,305 @@
+//! Arrow and Polars converters, behind the `arrow` / `polars` features.
+//!
+//! Analytical workflows want memory datasets as columns, not as vectors
+//! of structs. With the `arrow` feature on, [`ToArrow`] turns list,
+//! search, and graph-export results into an Arrow [`RecordBatch`];
+//! with the `polars` feature on, [`ToPolars`] builds a Polars
+//! [`DataFrame`] directly. Structured fields (`content`, `metadata`,
+//! `properties`) are carried as JSON text columns — lossless, and every
+//! dataframe engine can unpack JSON further — while scalars become
+//! native columns. Either feature can be enabled alone.
+//!
+//! [`RecordBatch`]: arrow::record_batch::RecordBatch
+//! [`DataFrame`]: polars::frame::DataFrame
+
+#[cfg(any(feature = "arrow", feature = "polars"))]
+use std::collections::HashMap;
+
+#[cfg(any(feature = "arrow", feature = "polars"))]
+use serde_json::Value;
+
+#[cfg(any(feature = "arrow", feature = "polars"))]
+use crate::{GraphEdge, GraphNode, Memory, SearchResult};
+
+/// Serializes a structured field to its JSON text column value.
+#[cfg(any(feature = "arrow", feature = "polars"))]
+fn json_text(value: &Value) -> String {
+    value.to_string()
+}
+
+#[cfg(any(feature = "arrow", feature = "polars"))]
+fn map_text(map: &HashMap<String, Value>) -> String {
+    serde_json::to_string(map).unwrap_or_else(|_| "{}".to_string())
+}
+
+#[cfg(feature = "arrow")]
+mod to_arrow {
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+
+    use super::{json_text, map_text, GraphEdge, GraphNode, Memory, SearchResult};
+    use crate::{BrainAIError, Result};
+
+    /// Columnar conversion into an Arrow [`RecordBatch`].
+    pub trait ToArrow {
+        fn to_arrow(&self) -> Result<RecordBatch>;
+    }
+
+    fn batch(fields: Vec<Field>, columns: Vec<ArrayRef>) -> Result<RecordBatch> {
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).map_err(|err| {
+            BrainAIError::InvalidInput(format!("arrow conversion failed: {err}"))
+        })
+    }
+
+    /// Columns: `id`, `type`, `content` (JSON), `metadata` (JSON),
+    /// `strength`, `created_at`, `last_accessed`.
+    impl ToArrow for [Memory] {
+        fn to_arrow(&self) -> Result<RecordBatch> {
+            batch(
+                vec![
+                    Field::new("id", DataType::Utf8, false),
+                    Field::new("type", DataType::Utf8, false),
+                    Field::new("content", DataType::Utf8, false),
+                    Field::new("metadata", DataType::Utf8, false),
+                    Field::new("strength", DataType::Float64, false),
+                    Field::new("created_at", DataType::Int64, false),
+                    Field::new("last_accessed", DataType::Int64, false),
+                ],
+                vec![
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|m| m.id.as_str()),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|m| m.memory_type.as_str()),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|m| json_text(&m.content)),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|m| map_text(&m.metadata)),
+                    )),
+                    Arc::new(Float64Array::from_iter_values(
+                        self.iter().map(|m| m.strength),
+                    )),
+                    Arc::new(Int64Array::from_iter_values(
+                        self.iter().map(|m| m.created_at),
+                    )),
+                    Arc::new(Int64Array::from_iter_values(
+                        self.iter().map(|m| m.last_accessed),
+                    )),
+                ],
+            )
+        }
+    }
+
+    /// Columns: `id`, `score`, the three breakdown components (null on
+    /// servers that only report the combined score), `content` (JSON),
+    /// `metadata` (JSON).
+    impl ToArrow for [SearchResult] {
+        fn to_arrow(&self) -> Result<RecordBatch> {
+            batch(
+                vec![
+                    Field::new("id", DataType::Utf8, false),
+                    Field::new("score", DataType::Float64, false),
+                    Field::new("similarity", DataType::Float64, true),
+                    Field::new("strength_bonus", DataType::Float64, true),
+                    Field::new("recency_bonus", DataType::Float64, true),
+                    Field::new("content", DataType::Utf8, false),
+                    Field::new("metadata", DataType::Utf8, false),
+                ],
+                vec![
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|r| r.id.as_str()),
+                    )),
+                    Arc::new(Float64Array::from_iter_values(
+                        self.iter().map(|r| r.score),
+                    )),
+                    Arc::new(Float64Array::from_iter(
+                        self.iter()
+                            .map(|r| r.breakdown.as_ref().map(|b| b.similarity)),
+                    )),
+                    Arc::new(Float64Array::from_iter(
+                        self.iter()
+                            .map(|r| r.breakdown.as_ref().map(|b| b.strength_bonus)),
+                    )),
+                    Arc::new(Float64Array::from_iter(
+                        self.iter()
+                            .map(|r| r.breakdown.as_ref().map(|b| b.recency_bonus)),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|r| json_text(&r.content)),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|r| map_text(&r.metadata)),
+                    )),
+                ],
+            )
+        }
+    }
+
+    /// Columns: `id`, `label`, `type`, `weight`, `properties` (JSON).
+    impl ToArrow for [GraphNode] {
+        fn to_arrow(&self) -> Result<RecordBatch> {
+            batch(
+                vec![
+                    Field::new("id", DataType::Utf8, false),
+                    Field::new("label", DataType::Utf8, false),
+                    Field::new("type", DataType::Utf8, false),
+                    Field::new("weight", DataType::Float64, false),
+                    Field::new("properties", DataType::Utf8, false),
+                ],
+                vec![
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|n| n.id.as_str()),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|n| n.label.as_str()),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|n| n.node_type.as_str()),
+                    )),
+                    Arc::new(Float64Array::from_iter_values(
+                        self.iter().map(|n| n.weight),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|n| map_text(&n.properties)),
+                    )),
+                ],
+            )
+        }
+    }
+
+    /// Columns: `id`, `from`, `to`, `label`, `directed`, `weight`,
+    /// `properties` (JSON).
+    impl ToArrow for [GraphEdge] {
+        fn to_arrow(&self) -> Result<RecordBatch> {
+            batch(
+                vec![
+                    Field::new("id", DataType::Utf8, false),
+                    Field::new("from", DataType::Utf8, false),
+                    Field::new("to", DataType::Utf8, false),
+                    Field::new("label", DataType::Utf8, false),
+                    Field::new("directed", DataType::Boolean, false),
+                    Field::new("weight", DataType::Float64, false),
+                    Field::new("properties", DataType::Utf8, false),
+                ],
+                vec![
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|e| e.id.as_str()),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|e| e.from.as_str()),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|e| e.to.as_str()),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|e| e.label.as_str()),
+                    )),
+                    Arc::new(BooleanArray::from_iter(
+                        self.iter().map(|e| Some(e.directed)),
+                    )),
+                    Arc::new(Float64Array::from_iter_values(
+                        self.iter().map(|e| e.weight),
+                    )),
+                    Arc::new(StringArray::from_iter_values(
+                        self.iter().map(|e| map_text(&e.properties)),
+                    )),
+                ],
+            )
+        }
+    }
+}
+
+#[cfg(feature = "arrow")]
+pub use to_arrow::ToArrow;
+
+#[cfg(feature = "polars")]
+mod to_polars {
+    use polars::frame::DataFrame;
+    use polars::prelude::df;
+
+    use super::{json_text, map_text, GraphEdge, GraphNode, Memory, SearchResult};
+    use crate::{BrainAIError, Result};
+
+    /// Columnar conversion into a Polars [`DataFrame`]. Column layouts
+    /// match the [`ToArrow`](super::ToArrow) conversions.
+    pub trait ToPolars {
+        fn to_polars(&self) -> Result<DataFrame>;
+    }
+
+    fn convert(frame: polars::error::PolarsResult<DataFrame>) -> Result<DataFrame> {
+        frame.map_err(|err| {
+            BrainAIError::InvalidInput(format!("polars conversion failed: {err}"))
+        })
+    }
+
+    impl ToPolars for [Memory] {
+        fn to_polars(&self) -> Result<DataFrame> {
+            convert(df!(
+                "id" => self.iter().map(|m| m.id.clone()).collect::<Vec<_>>(),
+                "type" => self.iter().map(|m| m.memory_type.as_str()).collect::<Vec<_>>(),
+                "content" => self.iter().map(|m| json_text(&m.content)).collect::<Vec<_>>(),
+                "metadata" => self.iter().map(|m| map_text(&m.metadata)).collect::<Vec<_>>(),
+                "strength" => self.iter().map(|m| m.strength).collect::<Vec<_>>(),
+                "created_at" => self.iter().map(|m| m.created_at).collect::<Vec<_>>(),
+                "last_accessed" => self.iter().map(|m| m.last_accessed).collect::<Vec<_>>(),
+            ))
+        }
+    }
+
+    impl ToPolars for [SearchResult] {
+        fn to_polars(&self) -> Result<DataFrame> {
+            convert(df!(
+                "id" => self.iter().map(|r| r.id.clone()).collect::<Vec<_>>(),
+                "score" => self.iter().map(|r| r.score).collect::<Vec<_>>(),
+                "similarity" => self
+                    .iter()
+                    .map(|r| r.breakdown.as_ref().map(|b| b.similarity))
+                    .collect::<Vec<_>>(),
+                "strength_bonus" => self
+                    .iter()
+                    .map(|r| r.breakdown.as_ref().map(|b| b.strength_bonus))
+                    .collect::<Vec<_>>(),
+                "recency_bonus" => self
+                    .iter()
+                    .map(|r| r.breakdown.as_ref().map(|b| b.recency_bonus))
+                    .collect::<Vec<_>>(),
+                "content" => self.iter().map(|r| json_text(&r.content)).collect::<Vec<_>>(),
+                "metadata" => self.iter().map(|r| map_text(&r.metadata)).collect::<Vec<_>>(),
+            ))
+        }
+    }
+
+    impl ToPolars for [GraphNode] {
+        fn to_polars(&self) -> Result<DataFrame> {
+            convert(df!(
+                "id" => self.iter().map(|n| n.id.clone()).collect::<Vec<_>>(),
+                "label" => self.iter().map(|n| n.label.clone()).collect::<Vec<_>>(),
+                "type" => self.iter().map(|n| n.node_type.as_str()).collect::<Vec<_>>(),
+                "weight" => self.iter().map(|n| n.weight).collect::<Vec<_>>(),
+                "properties" => self.iter().map(|n| map_text(&n.properties)).collect::<Vec<_>>(),
+            ))
+        }
+    }
+
+    impl ToPolars for [GraphEdge] {
+        fn to_polars(&self) -> Result<DataFrame> {
+            convert(df!(
+                "id" => self.iter().map(|e| e.id.clone()).collect::<Vec<_>>(),
+                "from" => self.iter().map(|e| e.from.clone()).collect::<Vec<_>>(),
+                "to" => self.iter().map(|e| e.to.clone()).collect::<Vec<_>>(),
+                "label" => self.iter().map(|e| e.label.clone()).collect::<Vec<_>>(),
+                "directed" => self.iter().map(|e| e.directed).collect::<Vec<_>>(),
+                "weight" => self.iter().map(|e| e.weight).collect::<Vec<_>>(),
+                "properties" => self.iter().map(|e| map_text(&e.properties)).collect::<Vec<_>>(),
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "polars")]
+pub use to_polars::ToPolars;
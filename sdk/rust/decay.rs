@@ -0,0 +1,148 @@
+//! Client-side memory decay and consolidation scheduler.
+//!
+//! Brain AI strengthens memories on access, but unused memories only fade
+//! if something weakens them. [`DecayScheduler`] runs periodic passes over
+//! the brain: memories untouched for longer than the access window decay by
+//! a fixed step, frequently-accessed memories get a consolidation boost,
+//! and memories that fall below an optional prune threshold are deleted.
+//! One pass can also be run on demand for cron-style deployments.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIClient, Result};
+
+/// Tuning for decay and consolidation passes.
+#[derive(Debug, Clone)]
+pub struct DecayPolicy {
+    /// A memory not accessed within this window decays.
+    pub access_window: Duration,
+    /// Strength subtracted per pass from decaying memories.
+    pub decay_step: f64,
+    /// Strength added per pass to memories accessed at least
+    /// `consolidation_accesses` times.
+    pub consolidation_boost: f64,
+    /// Access count at which a memory is considered consolidated.
+    pub consolidation_accesses: u64,
+    /// Delete memories whose strength falls below this value, if set.
+    pub prune_below: Option<f64>,
+    /// Memories examined per pass; keeps passes bounded on large brains.
+    pub scan_limit: usize,
+}
+
+impl Default for DecayPolicy {
+    fn default() -> Self {
+        DecayPolicy {
+            access_window: Duration::from_secs(7 * 24 * 3600),
+            decay_step: 0.05,
+            consolidation_boost: 0.05,
+            consolidation_accesses: 5,
+            prune_below: None,
+            scan_limit: 5_000,
+        }
+    }
+}
+
+/// Outcome of one decay pass.
+#[derive(Debug, Default, Clone)]
+pub struct DecayReport {
+    pub scanned: usize,
+    pub decayed: Vec<String>,
+    pub consolidated: Vec<String>,
+    pub pruned: Vec<String>,
+}
+
+/// Periodic decay/consolidation driver over any client.
+pub struct DecayScheduler {
+    client: Arc<dyn BrainAIClient>,
+    policy: DecayPolicy,
+}
+
+impl DecayScheduler {
+    /// Creates a scheduler with the default policy.
+    pub fn new(client: Arc<dyn BrainAIClient>) -> Self {
+        DecayScheduler {
+            client,
+            policy: DecayPolicy::default(),
+        }
+    }
+
+    /// Overrides the decay policy.
+    pub fn with_policy(mut self, policy: DecayPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Runs a single decay/consolidation pass.
+    pub async fn run_once(&self) -> Result<DecayReport> {
+        let mut report = DecayReport::default();
+        let now = now_millis();
+        let window_ms = self.policy.access_window.as_millis() as i64;
+        let memories = self
+            .client
+            .list_memories(None, self.policy.scan_limit)
+            .await?;
+        report.scanned = memories.len();
+        for memory in memories {
+            if let Some(threshold) = self.policy.prune_below {
+                if memory.strength < threshold {
+                    self.client.delete_memory(&memory.id).await?;
+                    report.pruned.push(memory.id);
+                    continue;
+                }
+            }
+            let stale = now - memory.last_accessed > window_ms;
+            if stale && memory.strength > 0.0 {
+                self.client
+                    .update_memory_strength(&memory.id, -self.policy.decay_step)
+                    .await?;
+                report.decayed.push(memory.id);
+                continue;
+            }
+            let stats = self.client.get_memory_stats(&memory.id).await?;
+            if stats.access_count >= self.policy.consolidation_accesses && memory.strength < 1.0 {
+                self.client
+                    .update_memory_strength(&memory.id, self.policy.consolidation_boost)
+                    .await?;
+                report.consolidated.push(memory.id);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Spawns a background task running a pass every `interval`.
+    ///
+    /// Failed passes are logged and the loop continues; abort the returned
+    /// handle to stop the scheduler.
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                match self.run_once().await {
+                    Ok(report) => {
+                        if !report.decayed.is_empty()
+                            || !report.consolidated.is_empty()
+                            || !report.pruned.is_empty()
+                        {
+                            eprintln!(
+                                "[brain-ai] decay pass: {}",
+                                json!({
+                                    "scanned": report.scanned,
+                                    "decayed": report.decayed.len(),
+                                    "consolidated": report.consolidated.len(),
+                                    "pruned": report.pruned.len(),
+                                })
+                            );
+                        }
+                    }
+                    Err(err) => eprintln!("[brain-ai] decay pass failed: {err}"),
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,221 @@
+//! Memory deduplication and merging.
+//!
+//! Repeated ingestion easily plants near-identical memories that dilute
+//! search results. [`find_duplicates`] scans the brain and clusters
+//! memories whose content overlaps beyond a threshold (token Jaccard
+//! similarity, computed client-side so it works against any backend).
+//! [`merge_memories`] collapses a cluster into one survivor — picked by
+//! strategy — unioning metadata and strength before the losers are
+//! deleted. [`dedup`] chains the two for one-call cleanup.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{BrainAIClient, BrainAIError, Memory, Result};
+
+/// Memories whose contents are near-duplicates of each other.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateGroup {
+    /// Member memory IDs, in listing order.
+    pub memory_ids: Vec<String>,
+    /// Lowest pairwise similarity inside the group.
+    pub similarity: f64,
+}
+
+/// Which member of a duplicate group survives a merge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Keep the most recently created memory.
+    KeepNewest,
+    /// Keep the oldest memory.
+    KeepOldest,
+    /// Keep the memory with the highest strength.
+    KeepStrongest,
+}
+
+/// Outcome of a deduplication run.
+#[derive(Debug, Default, Clone)]
+pub struct DedupReport {
+    /// Groups that were found.
+    pub groups: Vec<DuplicateGroup>,
+    /// `(survivor_id, merged_ids)` per merged group.
+    pub merged: Vec<(String, Vec<String>)>,
+}
+
+fn content_tokens(content: &Value) -> HashSet<String> {
+    content
+        .to_string()
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let intersection = a.intersection(b).count();
+    let union = a.len() + b.len() - intersection;
+    if union == 0 {
+        0.0
+    } else {
+        intersection as f64 / union as f64
+    }
+}
+
+/// Scans up to `scan_limit` memories and clusters near-duplicates whose
+/// pairwise token similarity is at least `threshold`.
+pub async fn find_duplicates(
+    client: &dyn BrainAIClient,
+    threshold: f64,
+    scan_limit: usize,
+) -> Result<Vec<DuplicateGroup>> {
+    let memories = client.list_memories(None, scan_limit).await?;
+    let tokens: Vec<HashSet<String>> = memories.iter().map(|m| content_tokens(&m.content)).collect();
+
+    // Union-find over pairwise matches keeps transitively similar
+    // memories in one group.
+    let mut parent: Vec<usize> = (0..memories.len()).collect();
+    fn find(parent: &mut Vec<usize>, i: usize) -> usize {
+        if parent[i] != i {
+            let root = find(parent, parent[i]);
+            parent[i] = root;
+        }
+        parent[i]
+    }
+    let mut pair_similarity = vec![1.0f64; memories.len()];
+    for i in 0..memories.len() {
+        for j in (i + 1)..memories.len() {
+            // Same type only; an episodic event and a semantic fact with
+            // similar wording are not duplicates.
+            if memories[i].memory_type != memories[j].memory_type {
+                continue;
+            }
+            let similarity = jaccard(&tokens[i], &tokens[j]);
+            if similarity >= threshold {
+                let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                if ri != rj {
+                    parent[rj] = ri;
+                }
+                pair_similarity[i] = pair_similarity[i].min(similarity);
+                pair_similarity[j] = pair_similarity[j].min(similarity);
+            }
+        }
+    }
+
+    let mut groups: std::collections::HashMap<usize, (Vec<String>, f64)> =
+        std::collections::HashMap::new();
+    for i in 0..memories.len() {
+        let root = find(&mut parent, i);
+        let entry = groups.entry(root).or_insert_with(|| (Vec::new(), 1.0));
+        entry.0.push(memories[i].id.clone());
+        entry.1 = entry.1.min(pair_similarity[i]);
+    }
+    let mut result: Vec<DuplicateGroup> = groups
+        .into_values()
+        .filter(|(ids, _)| ids.len() > 1)
+        .map(|(memory_ids, similarity)| DuplicateGroup {
+            memory_ids,
+            similarity,
+        })
+        .collect();
+    result.sort_by(|a, b| a.memory_ids.cmp(&b.memory_ids));
+    Ok(result)
+}
+
+fn pick_survivor(members: &[Memory], strategy: MergeStrategy) -> &Memory {
+    match strategy {
+        MergeStrategy::KeepNewest => members
+            .iter()
+            .max_by_key(|m| m.created_at)
+            .expect("non-empty group"),
+        MergeStrategy::KeepOldest => members
+            .iter()
+            .min_by_key(|m| m.created_at)
+            .expect("non-empty group"),
+        MergeStrategy::KeepStrongest => members
+            .iter()
+            .max_by(|a, b| a.strength.total_cmp(&b.strength))
+            .expect("non-empty group"),
+    }
+}
+
+/// Merges the given memories into one survivor and returns its ID.
+///
+/// The survivor absorbs metadata keys it lacks from the merged memories
+/// and is strengthened to the group's maximum; the other memories are
+/// deleted.
+pub async fn merge_memories(
+    client: &dyn BrainAIClient,
+    memory_ids: &[String],
+    strategy: MergeStrategy,
+) -> Result<String> {
+    if memory_ids.len() < 2 {
+        return Err(BrainAIError::InvalidInput(
+            "merge requires at least two memories".to_string(),
+        ));
+    }
+    let mut members = Vec::with_capacity(memory_ids.len());
+    for id in memory_ids {
+        let memory = client
+            .get_memory(id)
+            .await?
+            .ok_or_else(|| BrainAIError::NotFound(format!("memory {id}")))?;
+        members.push(memory);
+    }
+    let survivor = pick_survivor(&members, strategy).clone();
+
+    let mut metadata = survivor.metadata.clone();
+    let mut max_strength = survivor.strength;
+    for member in &members {
+        if member.id == survivor.id {
+            continue;
+        }
+        for (key, value) in &member.metadata {
+            metadata.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+        max_strength = max_strength.max(member.strength);
+    }
+    client
+        .update_memory(&survivor.id, survivor.content.clone(), Some(metadata))
+        .await?;
+    if max_strength > survivor.strength {
+        client
+            .update_memory_strength(&survivor.id, max_strength - survivor.strength)
+            .await?;
+    }
+    for member in &members {
+        if member.id != survivor.id {
+            client.delete_memory(&member.id).await?;
+        }
+    }
+    Ok(survivor.id)
+}
+
+/// Finds duplicate groups and merges each one.
+pub async fn dedup(
+    client: &dyn BrainAIClient,
+    threshold: f64,
+    scan_limit: usize,
+    strategy: MergeStrategy,
+) -> Result<DedupReport> {
+    let groups = find_duplicates(client, threshold, scan_limit).await?;
+    let mut report = DedupReport {
+        groups: groups.clone(),
+        merged: Vec::new(),
+    };
+    for group in groups {
+        let survivor = merge_memories(client, &group.memory_ids, strategy).await?;
+        let merged: Vec<String> = group
+            .memory_ids
+            .into_iter()
+            .filter(|id| *id != survivor)
+            .collect();
+        report.merged.push((survivor, merged));
+    }
+    Ok(report)
+}
@@ -0,0 +1,334 @@
+//! Embedding provider abstraction.
+//!
+//! [`Embedder`] turns text into f32 vectors without tying callers to one
+//! provider: [`OpenAiEmbedder`] speaks the OpenAI-compatible
+//! `/v1/embeddings` protocol (OpenAI itself, Azure, Ollama, vLLM, ...),
+//! [`HashingEmbedder`] is a dependency-free deterministic fallback for
+//! tests and offline work, and a candle-based local model backend is
+//! available behind the `local-embeddings` feature. [`store_text`] embeds
+//! and stores content plus vector in one call, linking the two.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::{BrainAIError, MemoryType, Result};
+
+/// Turns text into embedding vectors.
+#[async_trait]
+pub trait Embedder: Send + Sync {
+    /// Embeds a single text.
+    async fn embed(&self, text: &str) -> Result<Vec<f32>>;
+
+    /// Embeds several texts; the default implementation loops over
+    /// [`embed`](Self::embed), providers with batch endpoints override it.
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for text in texts {
+            vectors.push(self.embed(text).await?);
+        }
+        Ok(vectors)
+    }
+
+    /// Output dimensionality, if known up front.
+    fn dimension(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Embedder for OpenAI-compatible `/v1/embeddings` endpoints.
+pub struct OpenAiEmbedder {
+    base_url: String,
+    api_key: Option<String>,
+    model: String,
+    http: reqwest::Client,
+}
+
+impl OpenAiEmbedder {
+    /// Creates an embedder against `base_url` (e.g.
+    /// `https://api.openai.com`) using the given model name.
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>) -> Self {
+        OpenAiEmbedder {
+            base_url: base_url.into(),
+            api_key: None,
+            model: model.into(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Sets the API key sent as a `Bearer` token.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    async fn call(&self, input: Value) -> Result<Vec<Vec<f32>>> {
+        let url = format!("{}/v1/embeddings", self.base_url.trim_end_matches('/'));
+        let mut builder = self.http.post(&url).json(&json!({
+            "model": self.model,
+            "input": input,
+        }));
+        if let Some(key) = &self.api_key {
+            builder = builder.bearer_auth(key);
+        }
+        let response = builder.send().await?;
+        let status = response.status();
+        let body: Value = response.json().await?;
+        if !status.is_success() {
+            let message = body
+                .pointer("/error/message")
+                .and_then(Value::as_str)
+                .unwrap_or("embedding request failed")
+                .to_string();
+            return Err(BrainAIError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+        let mut rows: Vec<(usize, Vec<f32>)> = body
+            .get("data")
+            .and_then(Value::as_array)
+            .map(|data| {
+                data.iter()
+                    .enumerate()
+                    .map(|(i, row)| {
+                        let index = row
+                            .get("index")
+                            .and_then(Value::as_u64)
+                            .map(|n| n as usize)
+                            .unwrap_or(i);
+                        let vector = row
+                            .get("embedding")
+                            .and_then(Value::as_array)
+                            .map(|xs| {
+                                xs.iter()
+                                    .filter_map(Value::as_f64)
+                                    .map(|x| x as f32)
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        (index, vector)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Responses may arrive out of order; restore input order.
+        rows.sort_by_key(|(index, _)| *index);
+        Ok(rows.into_iter().map(|(_, vector)| vector).collect())
+    }
+}
+
+#[async_trait]
+impl Embedder for OpenAiEmbedder {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        self.call(json!(text)).await?.into_iter().next().ok_or(
+            BrainAIError::Api {
+                status: 200,
+                message: "embedding response contained no data".to_string(),
+            },
+        )
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.call(json!(texts)).await
+    }
+}
+
+/// Deterministic feature-hashing embedder with no model or network
+/// dependency. Quality is far below a learned model, but identical text
+/// always maps to the identical vector, which is exactly what tests and
+/// offline pipelines need.
+pub struct HashingEmbedder {
+    dimension: usize,
+}
+
+impl HashingEmbedder {
+    /// Creates a hashing embedder with the given output dimension.
+    pub fn new(dimension: usize) -> Self {
+        HashingEmbedder {
+            dimension: dimension.max(1),
+        }
+    }
+
+    fn token_hash(token: &str) -> u64 {
+        // FNV-1a; stable across platforms and runs.
+        let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+        for byte in token.as_bytes() {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+        }
+        hash
+    }
+}
+
+#[async_trait]
+impl Embedder for HashingEmbedder {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        let mut vector = vec![0.0f32; self.dimension];
+        for token in text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|t| !t.is_empty())
+        {
+            let hash = Self::token_hash(&token.to_lowercase());
+            let bucket = (hash % self.dimension as u64) as usize;
+            // Second hash bit picks the sign, as in classic feature hashing.
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            vector[bucket] += sign;
+        }
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for x in &mut vector {
+                *x /= norm;
+            }
+        }
+        Ok(vector)
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.dimension)
+    }
+}
+
+/// Local sentence-embedding backend running a candle BERT model on this
+/// machine; enable with the `local-embeddings` feature.
+#[cfg(feature = "local-embeddings")]
+pub mod local {
+    use async_trait::async_trait;
+    use candle_core::{Device, Tensor};
+    use candle_nn::VarBuilder;
+    use candle_transformers::models::bert::{BertModel, Config as BertConfig, DTYPE};
+    use tokenizers::Tokenizer;
+
+    use super::Embedder;
+    use crate::{BrainAIError, Result};
+
+    /// Embedder backed by a local BERT-family model (safetensors weights
+    /// plus a `tokenizer.json`), mean-pooling the last hidden state.
+    pub struct LocalEmbedder {
+        model: BertModel,
+        tokenizer: Tokenizer,
+        device: Device,
+        dimension: usize,
+    }
+
+    impl LocalEmbedder {
+        /// Loads a model from a directory containing `config.json`,
+        /// `tokenizer.json`, and `model.safetensors`.
+        pub fn load(model_dir: &std::path::Path) -> Result<Self> {
+            let local = |err: String| BrainAIError::InvalidInput(err);
+            let config: BertConfig = serde_json::from_str(
+                &std::fs::read_to_string(model_dir.join("config.json"))
+                    .map_err(|e| local(format!("reading config.json: {e}")))?,
+            )
+            .map_err(BrainAIError::Serialization)?;
+            let tokenizer = Tokenizer::from_file(model_dir.join("tokenizer.json"))
+                .map_err(|e| local(format!("loading tokenizer: {e}")))?;
+            let device = Device::Cpu;
+            let weights = unsafe {
+                VarBuilder::from_mmaped_safetensors(
+                    &[model_dir.join("model.safetensors")],
+                    DTYPE,
+                    &device,
+                )
+                .map_err(|e| local(format!("loading weights: {e}")))?
+            };
+            let dimension = config.hidden_size;
+            let model = BertModel::load(weights, &config)
+                .map_err(|e| local(format!("building model: {e}")))?;
+            Ok(LocalEmbedder {
+                model,
+                tokenizer,
+                device,
+                dimension,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl Embedder for LocalEmbedder {
+        async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+            let local = |err: String| BrainAIError::InvalidInput(err);
+            let encoding = self
+                .tokenizer
+                .encode(text, true)
+                .map_err(|e| local(format!("tokenizing: {e}")))?;
+            let ids = Tensor::new(encoding.get_ids(), &self.device)
+                .and_then(|t| t.unsqueeze(0))
+                .map_err(|e| local(format!("building input tensor: {e}")))?;
+            let type_ids = ids
+                .zeros_like()
+                .map_err(|e| local(format!("building type ids: {e}")))?;
+            let hidden = self
+                .model
+                .forward(&ids, &type_ids, None)
+                .map_err(|e| local(format!("running model: {e}")))?;
+            // Mean-pool token states into one sentence vector.
+            let pooled = hidden
+                .mean(1)
+                .and_then(|t| t.squeeze(0))
+                .and_then(|t| t.to_vec1::<f32>())
+                .map_err(|e| local(format!("pooling output: {e}")))?;
+            Ok(pooled)
+        }
+
+        fn dimension(&self) -> Option<usize> {
+            Some(self.dimension)
+        }
+    }
+}
+
+/// IDs produced by [`store_text`]: the stored memory and its linked vector.
+#[derive(Debug, Clone)]
+pub struct StoredText {
+    pub memory_id: String,
+    pub vector_id: String,
+}
+
+/// Embeds `text` and stores both the memory and its vector, cross-linking
+/// them through metadata (`vector_id` on the memory, `memory_id` on the
+/// vector) so either can be reached from the other.
+pub async fn store_text(
+    client: &dyn BrainAIClient,
+    embedder: &dyn Embedder,
+    text: &str,
+    memory_type: MemoryType,
+    metadata: Option<HashMap<String, Value>>,
+) -> Result<StoredText> {
+    if text.trim().is_empty() {
+        return Err(BrainAIError::InvalidInput("empty text".to_string()));
+    }
+    let vector = embedder.embed(text).await?;
+    let mut metadata = metadata.unwrap_or_default();
+    let vector_id = client
+        .store_vector(
+            vector,
+            Some(HashMap::from([(
+                "text".to_string(),
+                json!(text.chars().take(200).collect::<String>()),
+            )])),
+        )
+        .await?;
+    metadata.insert("vector_id".to_string(), json!(vector_id));
+    let memory_id = client
+        .store_memory(json!({ "text": text }), memory_type, Some(metadata))
+        .await?;
+    client
+        .update_vector(
+            &vector_id,
+            None,
+            Some(HashMap::from([(
+                "memory_id".to_string(),
+                json!(memory_id),
+            )])),
+        )
+        .await?;
+    Ok(StoredText {
+        memory_id,
+        vector_id,
+    })
+}
@@ -0,0 +1,140 @@
+//! Compile-time typed API endpoints.
+//!
+//! Every server route the SDK talks to is a variant of [`Endpoint`], which
+//! knows its own HTTP method and path. The internal request helper takes an
+//! `Endpoint` instead of a string, so a typo'd path or wrong verb is a
+//! compile error rather than a 404 at runtime, and the full API surface is
+//! enumerable in one place.
+
+use reqwest::Method;
+
+/// One route of the Brain AI HTTP API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endpoint<'a> {
+    // Memory
+    StoreMemory,
+    GetMemory(&'a str),
+    UpdateMemory(&'a str),
+    DeleteMemory(&'a str),
+    DeleteMemoriesByFilter,
+    SearchMemories,
+    ConnectMemories,
+    MemoryRelations,
+    UpdateMemoryStrength(&'a str),
+    MemoryStats(&'a str),
+    ListMemories,
+    CreateMetadataIndex,
+    ListIndexes,
+    // Learning
+    Learn,
+    LearningPatterns,
+    DeletePattern,
+    DecayPatterns,
+    PrunePatterns,
+    Feedback,
+    RetractFeedback(&'a str),
+    LearningProgress,
+    // Reasoning
+    Reason,
+    ReasonStream,
+    ExplainConclusion(&'a str),
+    ValidateReasoning(&'a str),
+    // Vectors
+    StoreVector,
+    GetVector(&'a str),
+    UpdateVector(&'a str),
+    DeleteVector(&'a str),
+    SearchVectors,
+    VectorSimilarity,
+    // Graph
+    CreateGraphNode,
+    ConnectGraphNodes,
+    UpdateGraphEdge(&'a str),
+    DeleteGraphEdge(&'a str),
+    QueryGraphEdges,
+    GraphQuery,
+    GraphNeighbors { node_id: &'a str, depth: u32 },
+    FindGraphPath,
+    FindPath,
+    FindAllPaths,
+    // System
+    SystemStatus,
+    SystemStatistics,
+    ClearAll,
+    Backup,
+    Restore(&'a str),
+    // Events
+    SubscribeEvents,
+    // Utility
+    Batch,
+    Health,
+}
+
+impl Endpoint<'_> {
+    /// HTTP method for this route.
+    pub fn method(&self) -> Method {
+        use Endpoint::*;
+        match self {
+            GetMemory(_) | MemoryStats(_) | ListIndexes | LearningPatterns | LearningProgress
+            | ExplainConclusion(_) | GetVector(_) | GraphNeighbors { .. } | SystemStatus
+            | SystemStatistics | Health => Method::GET,
+            UpdateMemory(_) | UpdateVector(_) | UpdateGraphEdge(_) => Method::PUT,
+            DeleteMemory(_) | DeleteVector(_) | DeleteGraphEdge(_) | RetractFeedback(_) => {
+                Method::DELETE
+            }
+            _ => Method::POST,
+        }
+    }
+
+    /// Path for this route, relative to the server base URL.
+    pub fn path(&self) -> String {
+        use Endpoint::*;
+        match self {
+            StoreMemory => "/api/memory".to_string(),
+            GetMemory(id) | UpdateMemory(id) | DeleteMemory(id) => format!("/api/memory/{id}"),
+            DeleteMemoriesByFilter => "/api/memory/delete".to_string(),
+            SearchMemories => "/api/memory/search".to_string(),
+            ConnectMemories => "/api/memory/connect".to_string(),
+            MemoryRelations => "/api/memory/relations/query".to_string(),
+            UpdateMemoryStrength(id) => format!("/api/memory/{id}/strength"),
+            MemoryStats(id) => format!("/api/memory/{id}/stats"),
+            ListMemories => "/api/memory/list".to_string(),
+            CreateMetadataIndex | ListIndexes => "/api/memory/indexes".to_string(),
+            Learn => "/api/learning/learn".to_string(),
+            LearningPatterns => "/api/learning/patterns".to_string(),
+            DeletePattern => "/api/learning/patterns/delete".to_string(),
+            DecayPatterns => "/api/learning/patterns/decay".to_string(),
+            PrunePatterns => "/api/learning/patterns/prune".to_string(),
+            Feedback => "/api/learning/feedback".to_string(),
+            RetractFeedback(id) => format!("/api/learning/feedback/{id}"),
+            LearningProgress => "/api/learning/progress".to_string(),
+            Reason => "/api/reasoning/reason".to_string(),
+            ReasonStream => "/api/reasoning/reason/stream".to_string(),
+            ExplainConclusion(id) => format!("/api/reasoning/{id}/explain"),
+            ValidateReasoning(id) => format!("/api/reasoning/{id}/validate"),
+            StoreVector => "/api/vector".to_string(),
+            GetVector(id) | UpdateVector(id) | DeleteVector(id) => format!("/api/vector/{id}"),
+            SearchVectors => "/api/vector/search".to_string(),
+            VectorSimilarity => "/api/vector/similarity".to_string(),
+            CreateGraphNode => "/api/graph/node".to_string(),
+            ConnectGraphNodes => "/api/graph/edge".to_string(),
+            UpdateGraphEdge(id) | DeleteGraphEdge(id) => format!("/api/graph/edge/{id}"),
+            QueryGraphEdges => "/api/graph/edges/query".to_string(),
+            GraphQuery => "/api/graph/query".to_string(),
+            GraphNeighbors { node_id, depth } => {
+                format!("/api/graph/{node_id}/neighbors?depth={depth}")
+            }
+            FindGraphPath => "/api/graph/path".to_string(),
+            FindPath => "/api/graph/path/find".to_string(),
+            FindAllPaths => "/api/graph/path/all".to_string(),
+            SystemStatus => "/api/system/status".to_string(),
+            SystemStatistics => "/api/system/statistics".to_string(),
+            ClearAll => "/api/system/clear".to_string(),
+            Backup => "/api/system/backup".to_string(),
+            Restore(id) => format!("/api/system/restore/{id}"),
+            SubscribeEvents => "/api/events/subscribe".to_string(),
+            Batch => "/api/batch".to_string(),
+            Health => "/api/health".to_string(),
+        }
+    }
+}
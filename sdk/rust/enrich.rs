@@ -0,0 +1,280 @@
+//! Automatic metadata enrichment pipeline.
+//!
+//! Metadata conventions drift when every callsite sets its own fields.
+//! [`Enricher`]s run at store time and add or normalize metadata;
+//! [`EnrichedStore`] wraps any client with an ordered pipeline so the
+//! conventions are enforced centrally. Built-ins cover timestamps in unix
+//! and ISO 8601 form, naive language detection, content length, and the
+//! source host extracted from a `source` URL; custom classifiers implement
+//! the trait.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::vector_utils::now_millis;
+use crate::{MemoryType, Result};
+
+/// Adds or normalizes metadata for a memory about to be stored.
+///
+/// Enrichers run in pipeline order; later enrichers see earlier output.
+/// An enricher should not remove fields the caller set explicitly.
+#[async_trait]
+pub trait Enricher: Send + Sync {
+    /// Short name, used in diagnostics.
+    fn name(&self) -> &str;
+
+    /// Inspects the content and mutates the metadata in place.
+    async fn enrich(
+        &self,
+        content: &Value,
+        memory_type: MemoryType,
+        metadata: &mut HashMap<String, Value>,
+    ) -> Result<()>;
+}
+
+/// Client wrapper that runs an enrichment pipeline before every store.
+pub struct EnrichedStore<C: BrainAIClient> {
+    client: C,
+    enrichers: Vec<Arc<dyn Enricher>>,
+}
+
+impl<C: BrainAIClient> EnrichedStore<C> {
+    /// Wraps a client with an empty pipeline.
+    pub fn new(client: C) -> Self {
+        EnrichedStore {
+            client,
+            enrichers: Vec::new(),
+        }
+    }
+
+    /// Appends an enricher; pipeline order is registration order.
+    pub fn with_enricher(mut self, enricher: impl Enricher + 'static) -> Self {
+        self.enrichers.push(Arc::new(enricher));
+        self
+    }
+
+    /// The standard pipeline: timestamps, language, content length, and
+    /// source host.
+    pub fn with_defaults(self) -> Self {
+        self.with_enricher(TimestampEnricher)
+            .with_enricher(LanguageEnricher)
+            .with_enricher(ContentLengthEnricher)
+            .with_enricher(SourceHostEnricher)
+    }
+
+    /// The wrapped client, for operations that bypass enrichment.
+    pub fn inner(&self) -> &C {
+        &self.client
+    }
+
+    /// Runs the pipeline over the metadata without storing; useful for
+    /// previewing what a store would write.
+    pub async fn enrich_only(
+        &self,
+        content: &Value,
+        memory_type: MemoryType,
+        mut metadata: HashMap<String, Value>,
+    ) -> Result<HashMap<String, Value>> {
+        for enricher in &self.enrichers {
+            enricher.enrich(content, memory_type, &mut metadata).await?;
+        }
+        Ok(metadata)
+    }
+
+    /// Stores a memory with the pipeline applied to its metadata.
+    pub async fn store_memory(
+        &self,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        let metadata = self
+            .enrich_only(&content, memory_type, metadata.unwrap_or_default())
+            .await?;
+        self.client
+            .store_memory(content, memory_type, Some(metadata))
+            .await
+    }
+}
+
+fn content_text(content: &Value) -> String {
+    match content {
+        Value::String(s) => s.clone(),
+        other => other
+            .get("text")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| other.to_string()),
+    }
+}
+
+/// Adds `stored_at` (unix milliseconds) and `stored_at_iso` (UTC ISO 8601).
+pub struct TimestampEnricher;
+
+/// Converts a day count since 1970-01-01 to a civil (year, month, day);
+/// Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let year = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+/// Formats unix milliseconds as `YYYY-MM-DDTHH:MM:SS.mmmZ`.
+fn iso8601(millis: i64) -> String {
+    let seconds = millis.div_euclid(1000);
+    let ms = millis.rem_euclid(1000);
+    let days = seconds.div_euclid(86_400);
+    let tod = seconds.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{ms:03}Z",
+        tod / 3600,
+        (tod % 3600) / 60,
+        tod % 60
+    )
+}
+
+#[async_trait]
+impl Enricher for TimestampEnricher {
+    fn name(&self) -> &str {
+        "timestamp"
+    }
+
+    async fn enrich(
+        &self,
+        _content: &Value,
+        _memory_type: MemoryType,
+        metadata: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        let now = now_millis();
+        metadata
+            .entry("stored_at".to_string())
+            .or_insert_with(|| json!(now));
+        metadata
+            .entry("stored_at_iso".to_string())
+            .or_insert_with(|| json!(iso8601(now)));
+        Ok(())
+    }
+}
+
+/// Adds a best-effort `language` tag from stopword frequencies. Covers
+/// English, Spanish, French, and German; anything else is left untagged
+/// rather than guessed wrong.
+pub struct LanguageEnricher;
+
+const STOPWORDS: &[(&str, &[&str])] = &[
+    ("en", &["the", "and", "is", "of", "to", "in", "that", "it"]),
+    ("es", &["el", "la", "de", "que", "y", "en", "los", "una"]),
+    ("fr", &["le", "la", "les", "de", "et", "est", "une", "que"]),
+    ("de", &["der", "die", "das", "und", "ist", "nicht", "ein", "zu"]),
+];
+
+#[async_trait]
+impl Enricher for LanguageEnricher {
+    fn name(&self) -> &str {
+        "language"
+    }
+
+    async fn enrich(
+        &self,
+        content: &Value,
+        _memory_type: MemoryType,
+        metadata: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        if metadata.contains_key("language") {
+            return Ok(());
+        }
+        let text = content_text(content).to_lowercase();
+        let words: Vec<&str> = text
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|w| !w.is_empty())
+            .collect();
+        if words.len() < 4 {
+            return Ok(());
+        }
+        let (best, hits) = STOPWORDS
+            .iter()
+            .map(|(lang, stops)| {
+                (*lang, words.iter().filter(|w| stops.contains(w)).count())
+            })
+            .max_by_key(|(_, hits)| *hits)
+            .unwrap_or(("en", 0));
+        // Require a real signal before tagging.
+        if hits * 20 >= words.len() {
+            metadata.insert("language".to_string(), json!(best));
+        }
+        Ok(())
+    }
+}
+
+/// Adds `content_length` in characters of the textual content.
+pub struct ContentLengthEnricher;
+
+#[async_trait]
+impl Enricher for ContentLengthEnricher {
+    fn name(&self) -> &str {
+        "content_length"
+    }
+
+    async fn enrich(
+        &self,
+        content: &Value,
+        _memory_type: MemoryType,
+        metadata: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        metadata
+            .entry("content_length".to_string())
+            .or_insert_with(|| json!(content_text(content).chars().count()));
+        Ok(())
+    }
+}
+
+/// Extracts `source_host` from a `source` metadata URL, if present.
+pub struct SourceHostEnricher;
+
+#[async_trait]
+impl Enricher for SourceHostEnricher {
+    fn name(&self) -> &str {
+        "source_host"
+    }
+
+    async fn enrich(
+        &self,
+        _content: &Value,
+        _memory_type: MemoryType,
+        metadata: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        if metadata.contains_key("source_host") {
+            return Ok(());
+        }
+        let Some(source) = metadata.get("source").and_then(Value::as_str) else {
+            return Ok(());
+        };
+        let rest = source.split("://").nth(1).unwrap_or(source);
+        let host = rest
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or("")
+            .split('@')
+            .next_back()
+            .unwrap_or("")
+            .split(':')
+            .next()
+            .unwrap_or("");
+        if !host.is_empty() && host.contains('.') {
+            metadata.insert("source_host".to_string(), json!(host));
+        }
+        Ok(())
+    }
+}
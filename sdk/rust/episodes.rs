@@ -0,0 +1,316 @@
+//! Agent episode recording and replay.
+//!
+//! "Why did the agent do that last week?" is unanswerable unless the
+//! interaction was captured. [`EpisodeRecorder`] writes an agent's full
+//! trace — inputs, retrievals, reasoning calls, actions, feedback — as
+//! episodic memories chained with temporal relations, so an episode is
+//! ordinary brain content: searchable, linkable, and inspectable with
+//! every other tool. [`replay_episode`] re-executes the recorded
+//! retrieval and reasoning steps against the *current* brain and
+//! reports where the answers drifted, turning last week's behaviour
+//! into a regression baseline.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::vector_utils::now_millis;
+use crate::{BrainAIError, MemoryType, RelationType, Result, SearchResult};
+
+/// Process-wide counter so two episodes begun in the same millisecond
+/// still get distinct IDs.
+static EPISODE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// One step of an agent's interaction trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "step", rename_all = "snake_case")]
+pub enum EpisodeStep {
+    /// Input the agent received from the outside world.
+    Input { content: String },
+    /// A memory search the agent ran, with the IDs it got back.
+    Retrieval {
+        query: String,
+        limit: usize,
+        result_ids: Vec<String>,
+    },
+    /// A reasoning call, with the conclusion the agent acted on.
+    Reasoning {
+        query: String,
+        context: Vec<String>,
+        conclusion: String,
+        confidence: f64,
+    },
+    /// An action the agent took.
+    Action { action: String, detail: String },
+    /// Feedback the agent received about its behaviour.
+    Feedback {
+        feedback_type: String,
+        information: String,
+    },
+}
+
+/// Records one agent episode as linked episodic memories.
+pub struct EpisodeRecorder<'a> {
+    client: &'a dyn BrainAIClient,
+    id: String,
+    /// Memory ID of the previously recorded step, target of the next
+    /// temporal link.
+    previous: Option<String>,
+    steps: usize,
+}
+
+impl<'a> EpisodeRecorder<'a> {
+    /// Starts recording a new episode.
+    pub fn begin(client: &'a dyn BrainAIClient) -> Self {
+        let id = format!(
+            "episode-{:x}-{:x}",
+            now_millis(),
+            EPISODE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        EpisodeRecorder {
+            client,
+            id,
+            previous: None,
+            steps: 0,
+        }
+    }
+
+    /// The episode ID; pass it to [`replay_episode`] later.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Steps recorded so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Records input the agent received.
+    pub async fn record_input(&mut self, content: impl Into<String>) -> Result<String> {
+        self.record(EpisodeStep::Input {
+            content: content.into(),
+        })
+        .await
+    }
+
+    /// Records a memory search and the hits it returned.
+    pub async fn record_retrieval(
+        &mut self,
+        query: impl Into<String>,
+        limit: usize,
+        results: &[SearchResult],
+    ) -> Result<String> {
+        self.record(EpisodeStep::Retrieval {
+            query: query.into(),
+            limit,
+            result_ids: results.iter().map(|hit| hit.id.clone()).collect(),
+        })
+        .await
+    }
+
+    /// Records a reasoning call and the conclusion the agent used.
+    pub async fn record_reasoning(
+        &mut self,
+        query: impl Into<String>,
+        context: Vec<String>,
+        conclusion: impl Into<String>,
+        confidence: f64,
+    ) -> Result<String> {
+        self.record(EpisodeStep::Reasoning {
+            query: query.into(),
+            context,
+            conclusion: conclusion.into(),
+            confidence,
+        })
+        .await
+    }
+
+    /// Records an action the agent took.
+    pub async fn record_action(
+        &mut self,
+        action: impl Into<String>,
+        detail: impl Into<String>,
+    ) -> Result<String> {
+        self.record(EpisodeStep::Action {
+            action: action.into(),
+            detail: detail.into(),
+        })
+        .await
+    }
+
+    /// Records feedback the agent received.
+    pub async fn record_feedback(
+        &mut self,
+        feedback_type: impl Into<String>,
+        information: impl Into<String>,
+    ) -> Result<String> {
+        self.record(EpisodeStep::Feedback {
+            feedback_type: feedback_type.into(),
+            information: information.into(),
+        })
+        .await
+    }
+
+    /// Stores one step as an episodic memory, linked to its predecessor
+    /// with a temporal relation. Returns the memory ID.
+    async fn record(&mut self, step: EpisodeStep) -> Result<String> {
+        let content = serde_json::to_value(&step)?;
+        let metadata = [
+            ("episode_id".to_string(), json!(self.id)),
+            ("episode_step".to_string(), json!(self.steps)),
+        ]
+        .into_iter()
+        .collect();
+        let memory_id = self
+            .client
+            .store_memory(content, MemoryType::Episodic, Some(metadata))
+            .await?;
+        if let Some(previous) = &self.previous {
+            self.client
+                .relate_memories(previous, &memory_id, RelationType::Temporal, 1.0)
+                .await?;
+        }
+        self.previous = Some(memory_id.clone());
+        self.steps += 1;
+        Ok(memory_id)
+    }
+}
+
+/// Loads a recorded episode's steps in recording order.
+pub async fn load_episode(
+    client: &dyn BrainAIClient,
+    episode_id: &str,
+) -> Result<Vec<EpisodeStep>> {
+    let filters = [(
+        "metadata.episode_id".to_string(),
+        Value::String(episode_id.to_string()),
+    )]
+    .into_iter()
+    .collect();
+    let memories = client.list_memories(Some(filters), usize::MAX).await?;
+    if memories.is_empty() {
+        return Err(BrainAIError::NotFound(format!("episode {episode_id}")));
+    }
+    let mut ordered: Vec<(u64, EpisodeStep)> = Vec::with_capacity(memories.len());
+    for memory in memories {
+        let index = memory
+            .metadata
+            .get("episode_step")
+            .and_then(Value::as_u64)
+            .unwrap_or(u64::MAX);
+        ordered.push((index, serde_json::from_value(memory.content)?));
+    }
+    ordered.sort_by_key(|(index, _)| *index);
+    Ok(ordered.into_iter().map(|(_, step)| step).collect())
+}
+
+/// One step whose replayed outcome differs from the recording.
+#[derive(Debug, Clone)]
+pub struct StepDrift {
+    /// Index of the step within the episode.
+    pub step: usize,
+    /// Human-readable description of what changed.
+    pub description: String,
+}
+
+/// Outcome of replaying one episode against the current brain.
+#[derive(Debug, Clone)]
+pub struct ReplayReport {
+    pub episode_id: String,
+    /// Retrieval and reasoning steps re-executed.
+    pub replayed: usize,
+    /// Steps whose outcome differed, with what changed.
+    pub drift: Vec<StepDrift>,
+}
+
+impl ReplayReport {
+    /// `true` when every re-executed step matched the recording.
+    pub fn is_clean(&self) -> bool {
+        self.drift.is_empty()
+    }
+}
+
+/// Re-executes the retrieval and reasoning steps of a recorded episode
+/// against the current brain and reports where the outcomes drifted.
+///
+/// Inputs, actions, and feedback are context, not queries; they are
+/// loaded but not re-executed. Retrieval drift is reported as hits lost
+/// and gained; reasoning drift as a changed conclusion or a confidence
+/// shift beyond `0.05`.
+pub async fn replay_episode(
+    client: &dyn BrainAIClient,
+    episode_id: &str,
+) -> Result<ReplayReport> {
+    let steps = load_episode(client, episode_id).await?;
+    let mut replayed = 0;
+    let mut drift = Vec::new();
+    for (index, step) in steps.iter().enumerate() {
+        match step {
+            EpisodeStep::Retrieval {
+                query,
+                limit,
+                result_ids,
+            } => {
+                replayed += 1;
+                let hits = client.search_memories(json!(query), *limit).await?;
+                let now: Vec<&str> = hits.iter().map(|hit| hit.id.as_str()).collect();
+                let lost: Vec<&str> = result_ids
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|id| !now.contains(id))
+                    .collect();
+                let gained: Vec<&str> = now
+                    .iter()
+                    .filter(|id| !result_ids.iter().any(|old| old == *id))
+                    .copied()
+                    .collect();
+                if !lost.is_empty() || !gained.is_empty() {
+                    drift.push(StepDrift {
+                        step: index,
+                        description: format!(
+                            "retrieval \"{query}\" changed: lost [{}], gained [{}]",
+                            lost.join(", "),
+                            gained.join(", ")
+                        ),
+                    });
+                }
+            }
+            EpisodeStep::Reasoning {
+                query,
+                context,
+                conclusion,
+                confidence,
+            } => {
+                replayed += 1;
+                let result = client.reason(query, context.clone()).await?;
+                if result.conclusion != *conclusion {
+                    drift.push(StepDrift {
+                        step: index,
+                        description: format!(
+                            "reasoning \"{query}\" concluded \"{}\" instead of \"{conclusion}\"",
+                            result.conclusion
+                        ),
+                    });
+                } else if (result.confidence - confidence).abs() > 0.05 {
+                    drift.push(StepDrift {
+                        step: index,
+                        description: format!(
+                            "reasoning \"{query}\" confidence moved {confidence:.2} -> {:.2}",
+                            result.confidence
+                        ),
+                    });
+                }
+            }
+            EpisodeStep::Input { .. }
+            | EpisodeStep::Action { .. }
+            | EpisodeStep::Feedback { .. } => {}
+        }
+    }
+    Ok(ReplayReport {
+        episode_id: episode_id.to_string(),
+        replayed,
+        drift,
+    })
+}
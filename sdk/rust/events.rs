@@ -0,0 +1,249 @@
+//! Live subscription to brain events over SSE.
+//!
+//! [`BrainAISDK::subscribe_events`] opens the server's event stream and
+//! yields [`BrainEvent`]s as they happen — memory stored, memory
+//! decayed, pattern learned, reasoning completed — so reactive
+//! pipelines run off a push feed instead of polling. The subscription
+//! reconnects automatically with capped exponential backoff, resuming
+//! from the last SSE event ID so no events are lost across a dropped
+//! connection. Pair it with a [`Notifier`](crate::Notifier) to fan
+//! received events out to external channels.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{BrainAIError, BrainAISDK, BrainEvent, Endpoint, Result};
+
+/// Which event kinds a subscription receives.
+///
+/// Kinds match the wire tag of [`BrainEvent`] variants
+/// (`"memory_stored"`, `"memory_decayed"`, `"pattern_learned"`,
+/// `"reasoning_completed"`, ...). An empty filter receives everything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventFilter {
+    pub kinds: Vec<String>,
+}
+
+impl EventFilter {
+    /// A filter receiving every event kind.
+    pub fn all() -> Self {
+        EventFilter::default()
+    }
+
+    /// Adds one event kind to the filter.
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.kinds.push(kind.into());
+        self
+    }
+
+    fn matches(&self, event: &BrainEvent) -> bool {
+        if self.kinds.is_empty() {
+            return true;
+        }
+        let tag = serde_json::to_value(event)
+            .ok()
+            .and_then(|value| value.get("event").and_then(|tag| tag.as_str().map(String::from)));
+        tag.is_some_and(|tag| self.kinds.contains(&tag))
+    }
+}
+
+/// Reconnect behaviour for an event subscription.
+#[derive(Debug, Clone, Copy)]
+pub struct SubscribeOptions {
+    /// Whether a dropped connection is re-established; when `false` the
+    /// stream ends with `None` instead.
+    pub reconnect: bool,
+    /// Backoff before the first reconnect attempt; doubles per
+    /// consecutive failure up to `max_backoff`.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Consecutive failed connection attempts before the subscription
+    /// gives up with an error.
+    pub max_attempts: u32,
+}
+
+impl Default for SubscribeOptions {
+    fn default() -> Self {
+        SubscribeOptions {
+            reconnect: true,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            max_attempts: 5,
+        }
+    }
+}
+
+/// A live event subscription. Drop it to unsubscribe.
+pub struct EventSubscription<'a> {
+    sdk: &'a BrainAISDK,
+    filter: EventFilter,
+    options: SubscribeOptions,
+    response: Option<reqwest::Response>,
+    buffer: Vec<u8>,
+    /// Last SSE event ID seen; sent on reconnect so the server resumes
+    /// where the previous connection dropped.
+    resume_token: Option<String>,
+    failures: u32,
+}
+
+impl EventSubscription<'_> {
+    /// The next matching event. `None` only when reconnect is disabled
+    /// and the server ended the stream; with reconnect on, connection
+    /// loss is retried with backoff and surfaces as an error only after
+    /// [`SubscribeOptions::max_attempts`] consecutive failures.
+    pub async fn next_event(&mut self) -> Result<Option<BrainEvent>> {
+        loop {
+            if self.response.is_none() {
+                self.connect().await?;
+            }
+            match self.read_line().await {
+                Ok(Some(line)) => {
+                    self.failures = 0;
+                    if let Some(event) = self.parse_line(&line)? {
+                        if self.filter.matches(&event) {
+                            return Ok(Some(event));
+                        }
+                    }
+                }
+                // Stream ended or the connection broke; reconnect (or
+                // finish) on the next pass.
+                Ok(None) => {
+                    self.response = None;
+                    if !self.options.reconnect {
+                        return Ok(None);
+                    }
+                }
+                Err(err) => {
+                    eprintln!("[brain-ai] event stream dropped: {err}");
+                    self.response = None;
+                    self.buffer.clear();
+                    if !self.options.reconnect {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+    }
+
+    /// (Re)establishes the connection, resuming from the last event ID.
+    async fn connect(&mut self) -> Result<()> {
+        loop {
+            let body = json!({
+                "filter": self.filter,
+                "resume": self.resume_token,
+            });
+            match self.sdk.request_stream(Endpoint::SubscribeEvents, body).await {
+                Ok(response) => {
+                    self.failures = 0;
+                    self.response = Some(response);
+                    return Ok(());
+                }
+                Err(err) => {
+                    self.failures += 1;
+                    if self.failures >= self.options.max_attempts {
+                        return Err(err);
+                    }
+                    let backoff = self
+                        .options
+                        .initial_backoff
+                        .saturating_mul(1 << (self.failures - 1).min(16))
+                        .min(self.options.max_backoff);
+                    eprintln!(
+                        "[brain-ai] event subscription connect failed (attempt {}): {err}; \
+                         retrying in {backoff:?}",
+                        self.failures
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+            }
+        }
+    }
+
+    /// Pulls the next complete line off the connection; `None` at end of
+    /// stream.
+    async fn read_line(&mut self) -> Result<Option<Vec<u8>>> {
+        loop {
+            if let Some(at) = self.buffer.iter().position(|&b| b == b'\n') {
+                return Ok(Some(self.buffer.drain(..=at).collect()));
+            }
+            let response = self
+                .response
+                .as_mut()
+                .expect("read_line is only called while connected");
+            match response.chunk().await? {
+                Some(chunk) => self.buffer.extend_from_slice(&chunk),
+                None => {
+                    let rest = std::mem::take(&mut self.buffer);
+                    if rest.iter().any(|b| !b.is_ascii_whitespace()) {
+                        return Ok(Some(rest));
+                    }
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    /// Parses one SSE line: `id:` lines update the resume token, `data:`
+    /// lines carry an event. Payloads that don't parse as a known event
+    /// are skipped for forward compatibility with newer servers.
+    fn parse_line(&mut self, line: &[u8]) -> Result<Option<BrainEvent>> {
+        let text = std::str::from_utf8(line)
+            .map_err(|err| BrainAIError::Api {
+                status: 200,
+                message: format!("non-UTF-8 event chunk: {err}"),
+            })?
+            .trim();
+        if let Some(id) = text.strip_prefix("id:") {
+            self.resume_token = Some(id.trim().to_string());
+            return Ok(None);
+        }
+        let payload = if let Some(rest) = text.strip_prefix("data:") {
+            rest.trim()
+        } else if text.starts_with('{') {
+            text
+        } else {
+            return Ok(None);
+        };
+        if payload.is_empty() || payload == "[DONE]" {
+            return Ok(None);
+        }
+        match serde_json::from_str(payload) {
+            Ok(event) => Ok(Some(event)),
+            Err(err) => {
+                eprintln!("[brain-ai] skipping unrecognized event: {err}");
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl BrainAISDK {
+    /// Subscribes to live brain events matching `filter`; see the module
+    /// docs for the reconnect and resume semantics.
+    pub async fn subscribe_events(&self, filter: EventFilter) -> Result<EventSubscription<'_>> {
+        self.subscribe_events_with_options(filter, SubscribeOptions::default())
+            .await
+    }
+
+    /// Like [`subscribe_events`](Self::subscribe_events) with explicit
+    /// reconnect behaviour.
+    pub async fn subscribe_events_with_options(
+        &self,
+        filter: EventFilter,
+        options: SubscribeOptions,
+    ) -> Result<EventSubscription<'_>> {
+        let body = json!({ "filter": filter, "resume": null });
+        let response = self.request_stream(Endpoint::SubscribeEvents, body).await?;
+        Ok(EventSubscription {
+            sdk: self,
+            filter,
+            options,
+            response: Some(response),
+            buffer: Vec::new(),
+            resume_token: None,
+            failures: 0,
+        })
+    }
+}
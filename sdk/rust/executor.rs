@@ -0,0 +1,243 @@
+//! Concurrency-limited parallel batch execution.
+//!
+//! [`BrainAISDK::batch`](crate::BrainAISDK::batch) forwards one JSON
+//! request and stops at the first transport failure — fine for a dozen
+//! operations, not for ingesting a corpus. [`BatchExecutor`] takes any
+//! iterator of operations, packs them into batch calls, keeps at most
+//! `concurrency` calls in flight behind a semaphore, retries failed
+//! items individually with exponential backoff, and reports progress
+//! through a callback so multi-hour ingestions are observable. Items
+//! that still fail after retries are collected by input index rather
+//! than aborting the run.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::{BatchOperation, BrainAISDK, Result};
+
+/// Concurrency and retry knobs for a batch execution.
+#[derive(Debug, Clone)]
+pub struct BatchExecutorOptions {
+    /// Operations per `/api/batch` call.
+    pub batch_size: usize,
+    /// Batch calls in flight at once.
+    pub concurrency: usize,
+    /// Retries per failed item, with exponential backoff.
+    pub max_retries: u32,
+    /// Initial backoff; doubles per retry.
+    pub initial_backoff: Duration,
+}
+
+impl Default for BatchExecutorOptions {
+    fn default() -> Self {
+        BatchExecutorOptions {
+            batch_size: 100,
+            concurrency: 8,
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(250),
+        }
+    }
+}
+
+/// Snapshot passed to the progress callback after each batch completes.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchExecProgress {
+    /// Operations whose batch has finished (succeeded or failed).
+    pub completed: usize,
+    /// Total operations in this run.
+    pub total: usize,
+    /// Operations that failed so far (after their retries).
+    pub failed: usize,
+}
+
+/// Outcome of one execution run.
+#[derive(Debug, Default, Clone)]
+pub struct BatchExecReport {
+    /// Operations that succeeded, first try or after retries.
+    pub succeeded: usize,
+    /// Operations that only succeeded on a retry.
+    pub retried: usize,
+    /// Operations that failed after retries, as `(input index, error)`.
+    pub failed: Vec<(usize, String)>,
+}
+
+impl BatchExecReport {
+    /// Whether every operation eventually succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+type ProgressCallback = Arc<dyn Fn(BatchExecProgress) + Send + Sync>;
+
+/// Semaphore-bounded parallel executor; see the module docs.
+pub struct BatchExecutor {
+    sdk: Arc<BrainAISDK>,
+    options: BatchExecutorOptions,
+    progress: Option<ProgressCallback>,
+}
+
+impl BatchExecutor {
+    /// Creates an executor with the default options.
+    pub fn new(sdk: Arc<BrainAISDK>) -> Self {
+        BatchExecutor {
+            sdk,
+            options: BatchExecutorOptions::default(),
+            progress: None,
+        }
+    }
+
+    /// Overrides the concurrency and retry options.
+    pub fn with_options(mut self, options: BatchExecutorOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Registers a callback invoked after every completed batch.
+    pub fn with_progress(
+        mut self,
+        callback: impl Fn(BatchExecProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Executes every operation in the iterator.
+    pub async fn run(
+        &self,
+        operations: impl IntoIterator<Item = BatchOperation>,
+    ) -> Result<BatchExecReport> {
+        let operations: Vec<BatchOperation> = operations.into_iter().collect();
+        let total = operations.len();
+        let batch_size = self.options.batch_size.max(1);
+        let semaphore = Arc::new(Semaphore::new(self.options.concurrency.max(1)));
+
+        let mut batches = Vec::new();
+        let mut base = 0usize;
+        let mut pending = operations;
+        while !pending.is_empty() {
+            let rest = pending.split_off(pending.len().min(batch_size));
+            batches.push((base, std::mem::replace(&mut pending, rest)));
+            base += batch_size;
+        }
+
+        let mut in_flight: JoinSet<(usize, BatchExecReport)> = JoinSet::new();
+        for (batch_base, chunk) in batches {
+            let sdk = Arc::clone(&self.sdk);
+            let semaphore = Arc::clone(&semaphore);
+            let options = self.options.clone();
+            in_flight.spawn(async move {
+                // Closed only when the semaphore is dropped, which we never do.
+                let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+                let size = chunk.len();
+                (size, execute_chunk(&sdk, &options, batch_base, chunk).await)
+            });
+        }
+
+        let mut report = BatchExecReport::default();
+        let mut completed = 0usize;
+        while let Some(joined) = in_flight.join_next().await {
+            let (size, partial) = match joined {
+                Ok(pair) => pair,
+                Err(err) => {
+                    // A panicked task loses its index range; surface it
+                    // rather than under-reporting silently.
+                    report
+                        .failed
+                        .push((usize::MAX, format!("batch task failed: {err}")));
+                    continue;
+                }
+            };
+            completed += size;
+            report.succeeded += partial.succeeded;
+            report.retried += partial.retried;
+            report.failed.extend(partial.failed);
+            if let Some(callback) = &self.progress {
+                callback(BatchExecProgress {
+                    completed,
+                    total,
+                    failed: report.failed.len(),
+                });
+            }
+        }
+        report.failed.sort_by_key(|(index, _)| *index);
+        Ok(report)
+    }
+}
+
+/// Sends one chunk and retries its failed items individually.
+async fn execute_chunk(
+    sdk: &BrainAISDK,
+    options: &BatchExecutorOptions,
+    batch_base: usize,
+    chunk: Vec<BatchOperation>,
+) -> BatchExecReport {
+    let mut report = BatchExecReport::default();
+    let mut to_retry: Vec<(usize, BatchOperation)> = Vec::new();
+
+    match sdk.batch(chunk.clone()).await {
+        Ok(results) => {
+            for (offset, (result, operation)) in results.iter().zip(&chunk).enumerate() {
+                if result.success {
+                    report.succeeded += 1;
+                } else {
+                    to_retry.push((batch_base + offset, operation.clone()));
+                }
+            }
+        }
+        Err(_) => {
+            // The whole call failed; every item gets its retry budget.
+            to_retry.extend(
+                chunk
+                    .into_iter()
+                    .enumerate()
+                    .map(|(offset, operation)| (batch_base + offset, operation)),
+            );
+        }
+    }
+
+    for (index, operation) in to_retry {
+        match retry_item(sdk, options, operation).await {
+            Ok(()) => {
+                report.succeeded += 1;
+                report.retried += 1;
+            }
+            Err(message) => report.failed.push((index, message)),
+        }
+    }
+    report
+}
+
+/// Retries one operation with exponential backoff until it succeeds or
+/// the budget runs out.
+async fn retry_item(
+    sdk: &BrainAISDK,
+    options: &BatchExecutorOptions,
+    operation: BatchOperation,
+) -> std::result::Result<(), String> {
+    let mut backoff = options.initial_backoff;
+    let mut last_error = "unknown error".to_string();
+    for attempt in 0..=options.max_retries {
+        if attempt > 0 {
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+        match sdk.batch(vec![operation.clone()]).await {
+            Ok(results) => match results.first() {
+                Some(result) if result.success => return Ok(()),
+                Some(result) => {
+                    last_error = result
+                        .error
+                        .clone()
+                        .unwrap_or_else(|| "unknown error".to_string());
+                }
+                None => last_error = "empty batch response".to_string(),
+            },
+            Err(err) => last_error = err.to_string(),
+        }
+    }
+    Err(last_error)
+}
@@ -0,0 +1,134 @@
+//! Memory expiry with first-class callbacks.
+//!
+//! [`ExpiryWatcher`] tracks a time-to-live per memory. When a deadline
+//! passes, the memory is removed from the backend and every registered
+//! [`ExpiryHandler`] is invoked with the expired memory — letting
+//! applications archive it, notify someone, or derive a summary before the
+//! content is gone. Expiry is evaluated by [`poll_once`] on demand or by a
+//! spawned background loop.
+//!
+//! [`poll_once`]: ExpiryWatcher::poll_once
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIClient, Memory, Result};
+
+/// Invoked with each memory that expired, before it is unreachable.
+#[async_trait]
+pub trait ExpiryHandler: Send + Sync {
+    /// Handles one expired memory. Errors are logged, not propagated; an
+    /// expiry must not be blocked by a failing handler.
+    async fn on_expired(&self, memory: &Memory) -> Result<()>;
+}
+
+/// Blanket impl so closures work as handlers for the synchronous case.
+#[async_trait]
+impl<F> ExpiryHandler for F
+where
+    F: Fn(&Memory) + Send + Sync,
+{
+    async fn on_expired(&self, memory: &Memory) -> Result<()> {
+        self(memory);
+        Ok(())
+    }
+}
+
+/// TTL tracker and expiry dispatcher over any client.
+pub struct ExpiryWatcher<C: BrainAIClient> {
+    client: Arc<C>,
+    /// Expiry deadlines (unix milliseconds), keyed by memory ID.
+    deadlines: Mutex<HashMap<String, i64>>,
+    handlers: Vec<Arc<dyn ExpiryHandler>>,
+}
+
+impl<C: BrainAIClient + 'static> ExpiryWatcher<C> {
+    /// Creates a watcher with no tracked memories.
+    pub fn new(client: Arc<C>) -> Self {
+        ExpiryWatcher {
+            client,
+            deadlines: Mutex::new(HashMap::new()),
+            handlers: Vec::new(),
+        }
+    }
+
+    /// Registers a callback invoked for every expired memory.
+    pub fn on_expiry(mut self, handler: impl ExpiryHandler + 'static) -> Self {
+        self.handlers.push(Arc::new(handler));
+        self
+    }
+
+    /// Expires the memory `ttl` from now.
+    pub fn expire_in(&self, memory_id: &str, ttl: Duration) {
+        self.expire_at(memory_id, now_millis() + ttl.as_millis() as i64);
+    }
+
+    /// Expires the memory at an absolute unix-millisecond deadline.
+    /// Re-registering replaces any earlier deadline.
+    pub fn expire_at(&self, memory_id: &str, deadline: i64) {
+        self.deadlines
+            .lock()
+            .unwrap()
+            .insert(memory_id.to_string(), deadline);
+    }
+
+    /// Cancels a pending expiry; returns whether one was registered.
+    pub fn cancel(&self, memory_id: &str) -> bool {
+        self.deadlines.lock().unwrap().remove(memory_id).is_some()
+    }
+
+    /// Deadline registered for a memory, if any.
+    pub fn deadline(&self, memory_id: &str) -> Option<i64> {
+        self.deadlines.lock().unwrap().get(memory_id).copied()
+    }
+
+    /// Evaluates deadlines once, expiring everything overdue.
+    ///
+    /// Each expired memory is fetched, handlers run with its final state,
+    /// and then it is deleted. Returns the IDs expired this pass.
+    pub async fn poll_once(&self) -> Result<Vec<String>> {
+        let now = now_millis();
+        let due: Vec<String> = {
+            let deadlines = self.deadlines.lock().unwrap();
+            deadlines
+                .iter()
+                .filter(|(_, deadline)| **deadline <= now)
+                .map(|(id, _)| id.clone())
+                .collect()
+        };
+        let mut expired = Vec::with_capacity(due.len());
+        for id in due {
+            let memory = self.client.get_memory(&id).await?;
+            if let Some(memory) = memory {
+                for handler in &self.handlers {
+                    if let Err(err) = handler.on_expired(&memory).await {
+                        eprintln!("[brain-ai] expiry handler failed for {id}: {err}");
+                    }
+                }
+                self.client.delete_memory(&id).await?;
+            }
+            self.deadlines.lock().unwrap().remove(&id);
+            expired.push(id);
+        }
+        Ok(expired)
+    }
+
+    /// Spawns a background loop polling every `interval`. Failed polls are
+    /// logged and the loop continues; abort the handle to stop it.
+    pub fn spawn(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                if let Err(err) = self.poll_once().await {
+                    eprintln!("[brain-ai] expiry poll failed: {err}");
+                }
+            }
+        })
+    }
+}
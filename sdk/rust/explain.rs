@@ -0,0 +1,272 @@
+//! Explainable retrieval: why was this memory returned?
+//!
+//! [`explain_retrieval`] runs a search and annotates every hit with the
+//! evidence behind it — which query terms matched the content, how the
+//! score decomposes, and how the memory's strength and recency contributed
+//! — plus a one-sentence narrative suitable for showing end users. The
+//! explanation is computed client-side from the same signals the server
+//! scores on, so it works against any backend.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIClient, Result, ScoreBreakdown, SearchResult};
+
+/// Why one memory appeared in a search result, at which rank.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetrievalExplanation {
+    pub memory_id: String,
+    /// Position in the result list, 1-based.
+    pub rank: usize,
+    /// Combined score the hit was ranked by.
+    pub score: f64,
+    /// Query terms found in the memory content.
+    pub matched_terms: Vec<String>,
+    /// Query terms absent from the memory content.
+    pub missing_terms: Vec<String>,
+    /// Per-component breakdown, when available.
+    pub breakdown: Option<ScoreBreakdown>,
+    /// Memory strength at explanation time, when the memory still exists.
+    pub strength: Option<f64>,
+    /// Days since the memory was last accessed, when known.
+    pub days_since_access: Option<f64>,
+    /// One-sentence human-readable explanation.
+    pub narrative: String,
+}
+
+fn query_terms(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn explain_hit(
+    query: &str,
+    rank: usize,
+    hit: &SearchResult,
+    strength: Option<f64>,
+    last_accessed: Option<i64>,
+) -> RetrievalExplanation {
+    let haystack = hit.content.to_string().to_lowercase();
+    let mut matched = Vec::new();
+    let mut missing = Vec::new();
+    for term in query_terms(query) {
+        if haystack.contains(&term) {
+            matched.push(term);
+        } else {
+            missing.push(term);
+        }
+    }
+    let days_since_access = last_accessed.map(|t| {
+        ((now_millis() - t).max(0) as f64) / (24.0 * 3600.0 * 1000.0)
+    });
+    let mut narrative = format!(
+        "Ranked #{rank} with score {:.2}: matched {} of {} query terms",
+        hit.score,
+        matched.len(),
+        matched.len() + missing.len(),
+    );
+    if !matched.is_empty() {
+        narrative.push_str(&format!(" ({})", matched.join(", ")));
+    }
+    if let Some(strength) = strength {
+        narrative.push_str(&format!("; memory strength {strength:.2}"));
+    }
+    if let Some(days) = days_since_access {
+        narrative.push_str(&format!(", last accessed {days:.1} days ago"));
+    }
+    narrative.push('.');
+    RetrievalExplanation {
+        memory_id: hit.id.clone(),
+        rank,
+        score: hit.score,
+        matched_terms: matched,
+        missing_terms: missing,
+        breakdown: hit.breakdown.clone(),
+        strength,
+        days_since_access,
+        narrative,
+    }
+}
+
+/// Searches and explains every hit.
+///
+/// Each explanation includes matched and missing query terms, the score
+/// breakdown when the server provides one, and the memory's current
+/// strength and access recency fetched after the search.
+pub async fn explain_retrieval(
+    client: &dyn BrainAIClient,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<RetrievalExplanation>> {
+    let hits = client
+        .search_memories(Value::String(query.to_string()), limit)
+        .await?;
+    let mut explanations = Vec::with_capacity(hits.len());
+    for (index, hit) in hits.iter().enumerate() {
+        let memory = client.get_memory(&hit.id).await?;
+        let (strength, last_accessed) = match &memory {
+            Some(m) => (Some(m.strength), Some(m.last_accessed)),
+            None => (None, None),
+        };
+        explanations.push(explain_hit(query, index + 1, hit, strength, last_accessed));
+    }
+    Ok(explanations)
+}
+
+/// Explains already-fetched results without extra backend calls; strength
+/// and recency are omitted.
+pub fn explain_results(query: &str, results: &[SearchResult]) -> Vec<RetrievalExplanation> {
+    results
+        .iter()
+        .enumerate()
+        .map(|(index, hit)| explain_hit(query, index + 1, hit, None, None))
+        .collect()
+}
+
+/// One node in a [`ReasoningTree`]: a step, a piece of evidence, or the
+/// conclusion itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasoningTreeNode {
+    /// Index into [`ReasoningTree::nodes`].
+    pub id: usize,
+    /// Human-readable step or conclusion text.
+    pub description: String,
+    /// Memory or graph node this step rests on, when it references one.
+    pub reference: Option<String>,
+    /// Per-step confidence; steps that do not state their own inherit
+    /// the result's overall confidence.
+    pub confidence: f64,
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+}
+
+/// A reasoning path as a navigable tree instead of a flat `Vec<String>`.
+///
+/// The conclusion is the root; reasoning steps chain beneath it in
+/// order, and supporting evidence hangs off the step that mentions it
+/// (or the deepest step when none does). Built with
+/// [`ReasoningResult::to_tree`], rendered with
+/// [`ReasoningTree::to_text`] or [`ReasoningTree::to_mermaid`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasoningTree {
+    pub nodes: Vec<ReasoningTreeNode>,
+}
+
+impl ReasoningTree {
+    /// The conclusion node.
+    pub fn root(&self) -> &ReasoningTreeNode {
+        &self.nodes[0]
+    }
+
+    fn push(&mut self, description: String, reference: Option<String>, confidence: f64, parent: Option<usize>) -> usize {
+        let id = self.nodes.len();
+        if let Some(parent) = parent {
+            self.nodes[parent].children.push(id);
+        }
+        self.nodes.push(ReasoningTreeNode {
+            id,
+            description,
+            reference,
+            confidence,
+            parent,
+            children: Vec::new(),
+        });
+        id
+    }
+
+    /// Renders the tree as indented text, one node per line with its
+    /// confidence.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        self.render_text(0, 0, &mut out);
+        out
+    }
+
+    fn render_text(&self, id: usize, depth: usize, out: &mut String) {
+        let node = &self.nodes[id];
+        for _ in 0..depth {
+            out.push_str("  ");
+        }
+        out.push_str(&format!(
+            "- {} ({:.0}%)",
+            node.description,
+            node.confidence * 100.0
+        ));
+        if let Some(reference) = &node.reference {
+            out.push_str(&format!(" [{reference}]"));
+        }
+        out.push('\n');
+        for &child in &node.children {
+            self.render_text(child, depth + 1, out);
+        }
+    }
+
+    /// Renders the tree as a Mermaid `graph TD` snippet for docs and
+    /// issue reports.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("graph TD\n");
+        for node in &self.nodes {
+            let label = node.description.replace('"', "'");
+            out.push_str(&format!("    n{}[\"{}\"]\n", node.id, label));
+        }
+        for node in &self.nodes {
+            for &child in &node.children {
+                out.push_str(&format!("    n{} --> n{}\n", node.id, child));
+            }
+        }
+        out
+    }
+}
+
+impl crate::ReasoningResult {
+    /// Structures the flat reasoning path into a [`ReasoningTree`].
+    ///
+    /// Steps become a chain under the conclusion, in path order. Each
+    /// supporting-evidence entry is attached to the first step whose
+    /// text mentions its ID, falling back to the deepest step. A step
+    /// stating its own confidence (`... (confidence 0.8)`) keeps it;
+    /// others inherit the overall confidence.
+    pub fn to_tree(&self) -> ReasoningTree {
+        let mut tree = ReasoningTree { nodes: Vec::new() };
+        let root = tree.push(self.conclusion.clone(), None, self.confidence, None);
+        let mut parent = root;
+        for step in &self.reasoning_path {
+            let confidence = stated_confidence(step).unwrap_or(self.confidence);
+            parent = tree.push(step.clone(), None, confidence, Some(parent));
+        }
+        let deepest = parent;
+        for evidence in &self.supporting_evidence {
+            let reference = evidence
+                .rsplit(|c: char| c.is_whitespace() || c == ':')
+                .next()
+                .unwrap_or(evidence)
+                .to_string();
+            let at = self
+                .reasoning_path
+                .iter()
+                .position(|step| step.contains(&reference))
+                // Node 0 is the root; step i sits at node i + 1.
+                .map(|i| i + 1)
+                .unwrap_or(deepest);
+            let confidence = tree.nodes[at].confidence;
+            tree.push(evidence.clone(), Some(reference), confidence, Some(at));
+        }
+        tree
+    }
+}
+
+/// Extracts a confidence a step states about itself, e.g.
+/// `"rain implies wet roads (confidence 0.8)"`.
+fn stated_confidence(step: &str) -> Option<f64> {
+    let at = step.to_lowercase().rfind("confidence")?;
+    step[at + "confidence".len()..]
+        .trim_start_matches(|c: char| !c.is_ascii_digit())
+        .split(|c: char| !c.is_ascii_digit() && c != '.')
+        .next()
+        .and_then(|raw| raw.parse::<f64>().ok())
+        .filter(|value| (0.0..=1.0).contains(value))
+}
@@ -0,0 +1,201 @@
+//! Cross-namespace search federation.
+//!
+//! Per-team brains keep ownership clean, but some questions span all of
+//! them. [`Federation`] holds a set of named members — namespaces on one
+//! deployment or entirely separate deployments — and
+//! [`search_across`](Federation::search_across) fans a query out to the
+//! selected members concurrently, fuses the rankings (reciprocal rank
+//! fusion by default, so the members' score scales need not agree), and
+//! labels every hit with where it came from. Members that fail are
+//! reported rather than failing the whole query: one team's brain being
+//! down should not blind the rest of the org.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::client::BrainAIClient;
+use crate::{BrainAIError, Result};
+
+/// RRF constant, as in the `hybrid` module.
+const RRF_K: f64 = 60.0;
+
+/// How per-member rankings are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Fusion {
+    /// Reciprocal rank fusion: ranks are fused, scores are ignored.
+    /// Safe when members score on different scales.
+    #[default]
+    ReciprocalRank,
+    /// Native scores are summed across members. Only meaningful when
+    /// all members share a comparable scoring scale.
+    NativeScore,
+}
+
+/// One member's sighting of a hit.
+#[derive(Debug, Clone)]
+pub struct HitOrigin {
+    /// Member name the hit came from.
+    pub member: String,
+    /// 1-based rank within that member's result list.
+    pub rank: usize,
+    /// The member's native score.
+    pub score: f64,
+}
+
+/// A fused hit with every origin that reported it. The same ID can
+/// surface from several members — deterministic content IDs make
+/// cross-deployment duplicates fuse into one entry.
+#[derive(Debug, Clone)]
+pub struct FederatedHit {
+    pub id: String,
+    /// Fused score under the chosen [`Fusion`].
+    pub score: f64,
+    pub content: Value,
+    pub metadata: HashMap<String, Value>,
+    /// Members that returned this hit, best rank first.
+    pub origins: Vec<HitOrigin>,
+}
+
+/// Outcome of one federated search.
+#[derive(Debug, Clone, Default)]
+pub struct FederatedSearch {
+    /// Fused hits, best first.
+    pub hits: Vec<FederatedHit>,
+    /// Members that failed, with why; their results are simply absent.
+    pub failed: Vec<(String, String)>,
+}
+
+impl FederatedSearch {
+    /// `true` when every queried member answered.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// A set of named brains searched as one; see the module docs.
+pub struct Federation {
+    members: Vec<(String, Arc<dyn BrainAIClient>)>,
+}
+
+impl Federation {
+    /// Creates a federation over the given members. Names must be
+    /// non-empty and unique; they label hit origins.
+    pub fn new(members: Vec<(String, Arc<dyn BrainAIClient>)>) -> Result<Self> {
+        if members.is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "federation needs at least one member".to_string(),
+            ));
+        }
+        for (index, (name, _)) in members.iter().enumerate() {
+            if name.trim().is_empty() {
+                return Err(BrainAIError::InvalidInput(
+                    "federation member names must be non-empty".to_string(),
+                ));
+            }
+            if members[..index].iter().any(|(other, _)| other == name) {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "duplicate federation member name: {name}"
+                )));
+            }
+        }
+        Ok(Federation { members })
+    }
+
+    /// The member names, in registration order.
+    pub fn member_names(&self) -> Vec<&str> {
+        self.members.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Fans `query` out to the named members (an empty slice means all
+    /// of them), asks each for `limit` candidates, and fuses the
+    /// rankings; the fused list is truncated to `limit`. Naming a
+    /// member the federation does not have is an error; a member
+    /// failing at query time is recorded in the report instead.
+    pub async fn search_across(
+        &self,
+        namespaces: &[&str],
+        query: Value,
+        fusion: Fusion,
+        limit: usize,
+    ) -> Result<FederatedSearch> {
+        let selected: Vec<&(String, Arc<dyn BrainAIClient>)> = if namespaces.is_empty() {
+            self.members.iter().collect()
+        } else {
+            namespaces
+                .iter()
+                .map(|wanted| {
+                    self.members
+                        .iter()
+                        .find(|(name, _)| name == wanted)
+                        .ok_or_else(|| {
+                            BrainAIError::InvalidInput(format!(
+                                "unknown federation member: {wanted}"
+                            ))
+                        })
+                })
+                .collect::<Result<_>>()?
+        };
+
+        let mut handles = Vec::with_capacity(selected.len());
+        for (name, client) in selected {
+            let name = name.clone();
+            let client = client.clone();
+            let query = query.clone();
+            handles.push(tokio::spawn(async move {
+                (name, client.search_memories(query, limit).await)
+            }));
+        }
+
+        let mut search = FederatedSearch::default();
+        let mut fused: HashMap<String, FederatedHit> = HashMap::new();
+        for handle in handles {
+            let (name, outcome) = match handle.await {
+                Ok(pair) => pair,
+                Err(err) => {
+                    search.failed.push(("(task)".to_string(), err.to_string()));
+                    continue;
+                }
+            };
+            let hits = match outcome {
+                Ok(hits) => hits,
+                Err(err) => {
+                    search.failed.push((name, err.to_string()));
+                    continue;
+                }
+            };
+            for (rank, hit) in hits.into_iter().enumerate() {
+                let contribution = match fusion {
+                    Fusion::ReciprocalRank => 1.0 / (RRF_K + rank as f64 + 1.0),
+                    Fusion::NativeScore => hit.score,
+                };
+                let entry = fused
+                    .entry(hit.id.clone())
+                    .or_insert_with(|| FederatedHit {
+                        id: hit.id.clone(),
+                        score: 0.0,
+                        content: hit.content.clone(),
+                        metadata: hit.metadata.clone(),
+                        origins: Vec::new(),
+                    });
+                entry.score += contribution;
+                entry.origins.push(HitOrigin {
+                    member: name.clone(),
+                    rank: rank + 1,
+                    score: hit.score,
+                });
+            }
+        }
+
+        search.hits = fused.into_values().collect();
+        for hit in &mut search.hits {
+            hit.origins.sort_by_key(|origin| origin.rank);
+        }
+        search
+            .hits
+            .sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        search.hits.truncate(limit);
+        Ok(search)
+    }
+}
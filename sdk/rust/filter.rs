@@ -0,0 +1,147 @@
+//! Builder for memory query filters.
+//!
+//! [`MemoryFilter`] assembles the filter criteria accepted by
+//! [`list_memories`](crate::BrainAIClient::list_memories),
+//! [`list_memories_page`](crate::BrainAIClient::list_memories_page), and
+//! [`delete_memories_by_filter`](crate::BrainAIClient::delete_memories_by_filter)
+//! without hand-writing JSON maps: memory type, creation time range,
+//! metadata equality, and strength bounds.
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::{Memory, MemoryType, MetadataIndex};
+
+/// Fluent builder for memory filter criteria.
+///
+/// # Example
+/// ```
+/// use brain_ai::{MemoryFilter, MemoryType};
+///
+/// let filters = MemoryFilter::new()
+///     .memory_type(MemoryType::Episodic)
+///     .created_after(1_700_000_000_000)
+///     .metadata("source", "calendar")
+///     .min_strength(0.5)
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFilter {
+    filters: HashMap<String, Value>,
+}
+
+impl MemoryFilter {
+    /// Creates an empty filter matching every memory.
+    pub fn new() -> Self {
+        MemoryFilter::default()
+    }
+
+    /// Restricts results to one memory type.
+    pub fn memory_type(mut self, memory_type: MemoryType) -> Self {
+        self.filters
+            .insert("type".to_string(), json!(memory_type.as_str()));
+        self
+    }
+
+    /// Keeps memories created at or after the given unix-millisecond time.
+    pub fn created_after(mut self, timestamp: i64) -> Self {
+        self.filters.insert("created_after".to_string(), json!(timestamp));
+        self
+    }
+
+    /// Keeps memories created at or before the given unix-millisecond time.
+    pub fn created_before(mut self, timestamp: i64) -> Self {
+        self.filters
+            .insert("created_before".to_string(), json!(timestamp));
+        self
+    }
+
+    /// Requires a metadata key to equal the given value.
+    pub fn metadata(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.filters
+            .insert(format!("metadata.{key}"), value.into());
+        self
+    }
+
+    /// Keeps memories with strength at or above the bound.
+    pub fn min_strength(mut self, strength: f64) -> Self {
+        self.filters.insert("min_strength".to_string(), json!(strength));
+        self
+    }
+
+    /// Keeps memories with strength at or below the bound.
+    pub fn max_strength(mut self, strength: f64) -> Self {
+        self.filters.insert("max_strength".to_string(), json!(strength));
+        self
+    }
+
+    /// Finishes the builder, producing the filter map the APIs accept.
+    pub fn build(self) -> HashMap<String, Value> {
+        self.filters
+    }
+
+    /// Warns about metadata filters the server would satisfy with a full
+    /// scan: one message per `metadata.<field>` criterion whose field is
+    /// not in `indexes` (from
+    /// [`list_indexes`](crate::BrainAIClient::list_indexes)). Built-in
+    /// criteria like `type` and the strength bounds never warn; empty
+    /// means every metadata filter is index-backed.
+    pub fn lint(&self, indexes: &[MetadataIndex]) -> Vec<String> {
+        let mut warnings: Vec<String> = self
+            .filters
+            .keys()
+            .filter_map(|key| key.strip_prefix("metadata."))
+            .filter(|field| !indexes.iter().any(|index| index.field == *field))
+            .map(|field| {
+                format!(
+                    "filter on metadata field '{field}' is not index-backed and will \
+                     scan every memory; consider create_metadata_index(\"{field}\")"
+                )
+            })
+            .collect();
+        warnings.sort();
+        warnings
+    }
+}
+
+/// Whether a memory matches the given filter criteria.
+///
+/// Shared by the mock backend and client-side filtering helpers; mirrors
+/// the server's filter semantics.
+pub(crate) fn matches_filters(memory: &Memory, filters: &HashMap<String, Value>) -> bool {
+    for (key, expected) in filters {
+        let ok = match key.as_str() {
+            "type" => expected
+                .as_str()
+                .map(|t| memory.memory_type.as_str() == t)
+                .unwrap_or(true),
+            "created_after" => expected
+                .as_i64()
+                .map(|t| memory.created_at >= t)
+                .unwrap_or(true),
+            "created_before" => expected
+                .as_i64()
+                .map(|t| memory.created_at <= t)
+                .unwrap_or(true),
+            "min_strength" => expected
+                .as_f64()
+                .map(|s| memory.strength >= s)
+                .unwrap_or(true),
+            "max_strength" => expected
+                .as_f64()
+                .map(|s| memory.strength <= s)
+                .unwrap_or(true),
+            key => match key.strip_prefix("metadata.") {
+                Some(meta_key) => memory.metadata.get(meta_key) == Some(expected),
+                // Unknown keys are ignored rather than failing the match,
+                // matching the server's lenient handling.
+                None => true,
+            },
+        };
+        if !ok {
+            return false;
+        }
+    }
+    true
+}
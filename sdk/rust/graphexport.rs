@@ -0,0 +1,278 @@
+//! Subgraph export to GraphML, Graphviz DOT, and JSON.
+//!
+//! [`export_subgraph`] collects the neighborhood around a root node and
+//! serializes it for external tools: GraphML for Gephi and yEd, DOT for
+//! Graphviz renders in docs, and JSON for everything else. The writer
+//! variants stream node by node so large neighborhoods never have to be
+//! formatted into one in-memory string.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::BrainAIClient;
+use crate::{BrainAIError, EdgeDirection, GraphEdge, GraphNode, Result};
+
+/// Output format for [`export_subgraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// GraphML XML, the interchange format Gephi and yEd read natively.
+    GraphMl,
+    /// Graphviz DOT, for `dot`/`neato` renders.
+    Dot,
+    /// `{"nodes": [...], "edges": [...]}` with full node and edge records.
+    Json,
+}
+
+/// A collected neighborhood, ready to serialize.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Subgraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl Subgraph {
+    /// Serializes the subgraph to a string in the given format.
+    pub fn export(&self, format: ExportFormat) -> Result<String> {
+        let mut out = Vec::new();
+        self.write(format, &mut out)?;
+        String::from_utf8(out)
+            .map_err(|err| BrainAIError::InvalidInput(format!("export is not UTF-8: {err}")))
+    }
+
+    /// Streams the subgraph into `writer` in the given format, one node or
+    /// edge per write.
+    pub fn write<W: Write>(&self, format: ExportFormat, writer: &mut W) -> Result<()> {
+        let result = match format {
+            ExportFormat::GraphMl => self.write_graphml(writer),
+            ExportFormat::Dot => self.write_dot(writer),
+            ExportFormat::Json => self.write_json(writer),
+        };
+        result.map_err(|err| BrainAIError::InvalidInput(format!("cannot write export: {err}")))
+    }
+
+    fn write_graphml<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            w,
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+        )?;
+        writeln!(
+            w,
+            r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#
+        )?;
+        writeln!(
+            w,
+            r#"  <key id="type" for="node" attr.name="type" attr.type="string"/>"#
+        )?;
+        writeln!(
+            w,
+            r#"  <key id="weight" for="all" attr.name="weight" attr.type="double"/>"#
+        )?;
+        writeln!(
+            w,
+            r#"  <key id="elabel" for="edge" attr.name="label" attr.type="string"/>"#
+        )?;
+        writeln!(w, r#"  <graph id="subgraph" edgedefault="undirected">"#)?;
+        for node in &self.nodes {
+            writeln!(w, r#"    <node id="{}">"#, xml_escape(&node.id))?;
+            writeln!(
+                w,
+                r#"      <data key="label">{}</data>"#,
+                xml_escape(&node.label)
+            )?;
+            writeln!(
+                w,
+                r#"      <data key="type">{}</data>"#,
+                xml_escape(node.node_type.as_str())
+            )?;
+            writeln!(w, r#"      <data key="weight">{}</data>"#, node.weight)?;
+            writeln!(w, "    </node>")?;
+        }
+        for edge in &self.edges {
+            writeln!(
+                w,
+                r#"    <edge source="{}" target="{}" directed="{}">"#,
+                xml_escape(&edge.from),
+                xml_escape(&edge.to),
+                edge.directed
+            )?;
+            if !edge.label.is_empty() {
+                writeln!(
+                    w,
+                    r#"      <data key="elabel">{}</data>"#,
+                    xml_escape(&edge.label)
+                )?;
+            }
+            writeln!(w, r#"      <data key="weight">{}</data>"#, edge.weight)?;
+            writeln!(w, "    </edge>")?;
+        }
+        writeln!(w, "  </graph>")?;
+        writeln!(w, "</graphml>")
+    }
+
+    fn write_dot<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        // A digraph can draw undirected edges with `dir=none`; the reverse
+        // is not true, so emit a digraph whenever any edge is directed.
+        let any_directed = self.edges.iter().any(|e| e.directed);
+        let (kind, arrow) = if any_directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        writeln!(w, "{kind} subgraph_export {{")?;
+        for node in &self.nodes {
+            writeln!(
+                w,
+                "  {} [label={}, shape=ellipse];",
+                dot_quote(&node.id),
+                dot_quote(&format!("{} ({})", node.label, node.node_type))
+            )?;
+        }
+        for edge in &self.edges {
+            let mut attrs = vec![format!("weight={}", edge.weight)];
+            if !edge.label.is_empty() {
+                attrs.push(format!("label={}", dot_quote(&edge.label)));
+            }
+            if any_directed && !edge.directed {
+                attrs.push("dir=none".to_string());
+            }
+            writeln!(
+                w,
+                "  {} {arrow} {} [{}];",
+                dot_quote(&edge.from),
+                dot_quote(&edge.to),
+                attrs.join(", ")
+            )?;
+        }
+        writeln!(w, "}}")
+    }
+
+    fn write_json<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(b"{\"nodes\":[")?;
+        for (index, node) in self.nodes.iter().enumerate() {
+            if index > 0 {
+                w.write_all(b",")?;
+            }
+            serde_json::to_writer(&mut *w, node)?;
+        }
+        w.write_all(b"],\"edges\":[")?;
+        for (index, edge) in self.edges.iter().enumerate() {
+            if index > 0 {
+                w.write_all(b",")?;
+            }
+            serde_json::to_writer(&mut *w, edge)?;
+        }
+        w.write_all(b"]}")
+    }
+}
+
+/// Escapes the five XML special characters.
+fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Quotes a DOT identifier, escaping embedded quotes.
+fn dot_quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Order-independent key so each undirected pair is exported once.
+fn undirected_key(a: &str, b: &str) -> (String, String) {
+    if a < b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+/// Collects the subgraph within `depth` hops of `root_id`.
+///
+/// Typed edges are fetched per node where the server supports them;
+/// otherwise edges are reconstructed from each node's direct neighbors,
+/// the same way the CLI graph viewer does.
+pub async fn collect_subgraph(
+    client: &dyn BrainAIClient,
+    root_id: &str,
+    depth: u32,
+) -> Result<Subgraph> {
+    let mut nodes = client.get_graph_neighbors(root_id, depth).await?;
+    if !nodes.iter().any(|n| n.id == root_id) {
+        if let Ok(mut root) = client.get_graph_neighbors(root_id, 0).await {
+            nodes.append(&mut root);
+        }
+    }
+    let ids: HashSet<String> = nodes.iter().map(|n| n.id.clone()).collect();
+    let mut edges: Vec<GraphEdge> = Vec::new();
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+    for node in &nodes {
+        // Prefer typed edges; older servers answer NotFound.
+        let typed = match client
+            .get_edges(&node.id, EdgeDirection::Outgoing, None)
+            .await
+        {
+            Ok(found) => found,
+            Err(BrainAIError::NotFound(_)) => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        let mut typed_pairs: HashSet<(String, String)> = HashSet::new();
+        for edge in typed {
+            if !ids.contains(&edge.from) || !ids.contains(&edge.to) {
+                continue;
+            }
+            typed_pairs.insert(undirected_key(&edge.from, &edge.to));
+            if seen.insert(undirected_key(&edge.from, &edge.to)) {
+                edges.push(edge);
+            }
+        }
+        let neighbors = client.get_graph_neighbors(&node.id, 1).await?;
+        for neighbor in neighbors {
+            if !ids.contains(&neighbor.id) || neighbor.id == node.id {
+                continue;
+            }
+            let key = undirected_key(&node.id, &neighbor.id);
+            if typed_pairs.contains(&key) {
+                continue;
+            }
+            if seen.insert(key) {
+                edges.push(GraphEdge {
+                    id: String::new(),
+                    from: node.id.clone(),
+                    to: neighbor.id.clone(),
+                    label: String::new(),
+                    directed: false,
+                    properties: HashMap::new(),
+                    weight: neighbor.weight,
+                });
+            }
+        }
+    }
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+    edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+    Ok(Subgraph { nodes, edges })
+}
+
+/// Collects the neighborhood of `root_id` and serializes it.
+pub async fn export_subgraph(
+    client: &dyn BrainAIClient,
+    root_id: &str,
+    depth: u32,
+    format: ExportFormat,
+) -> Result<String> {
+    collect_subgraph(client, root_id, depth)
+        .await?
+        .export(format)
+}
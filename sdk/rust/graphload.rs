@@ -0,0 +1,379 @@
+//! Bulk graph loading from edge lists and CSV files.
+//!
+//! Creating a million-edge graph one HTTP call at a time takes hours.
+//! [`GraphLoader`] packs node and edge creation into `/api/batch`
+//! operations, keeps a bounded number of batches in flight, and reports
+//! progress through a callback so long loads are observable.
+//!
+//! CSV layout (header row required, column order free):
+//! nodes `id,label,type,weight` and edges `from,to,label,weight,directed`;
+//! `label`, `type`, `weight`, and `directed` may be omitted.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use serde_json::json;
+use tokio::task::JoinSet;
+
+use crate::{
+    BatchOperation, BrainAIError, BrainAISDK, NodeType, OperationType, Result,
+};
+
+/// One node to create.
+#[derive(Debug, Clone)]
+pub struct NodeRecord {
+    pub id: String,
+    pub label: String,
+    pub node_type: NodeType,
+    pub weight: f64,
+}
+
+/// One edge to create.
+#[derive(Debug, Clone)]
+pub struct EdgeRecord {
+    pub from: String,
+    pub to: String,
+    pub label: String,
+    pub weight: f64,
+    pub directed: bool,
+}
+
+/// Batching and concurrency knobs for a graph load.
+#[derive(Debug, Clone)]
+pub struct GraphLoadOptions {
+    /// Operations per `/api/batch` call.
+    pub batch_size: usize,
+    /// Batch calls kept in flight at once.
+    pub concurrency: usize,
+}
+
+impl Default for GraphLoadOptions {
+    fn default() -> Self {
+        GraphLoadOptions {
+            batch_size: 500,
+            concurrency: 4,
+        }
+    }
+}
+
+/// Snapshot passed to the progress callback after each batch completes.
+#[derive(Debug, Clone, Copy)]
+pub struct GraphLoadProgress {
+    /// Operations whose batch has finished (succeeded or failed).
+    pub completed: usize,
+    /// Total operations in this load.
+    pub total: usize,
+    /// Operations that failed so far.
+    pub failed: usize,
+}
+
+/// Outcome of one load call.
+#[derive(Debug, Default, Clone)]
+pub struct GraphLoadReport {
+    /// Operations that succeeded.
+    pub loaded: usize,
+    /// Failed operations as `(operation index, error)`.
+    pub failed: Vec<(usize, String)>,
+}
+
+type ProgressCallback = Arc<dyn Fn(GraphLoadProgress) + Send + Sync>;
+
+/// Concurrent batched loader for nodes and edges.
+pub struct GraphLoader {
+    sdk: Arc<BrainAISDK>,
+    options: GraphLoadOptions,
+    progress: Option<ProgressCallback>,
+}
+
+impl GraphLoader {
+    /// Creates a loader with default batching.
+    pub fn new(sdk: Arc<BrainAISDK>) -> Self {
+        GraphLoader {
+            sdk,
+            options: GraphLoadOptions::default(),
+            progress: None,
+        }
+    }
+
+    /// Overrides the batching and concurrency options.
+    pub fn with_options(mut self, options: GraphLoadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Registers a callback invoked after every completed batch.
+    pub fn with_progress(
+        mut self,
+        callback: impl Fn(GraphLoadProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Creates every node in the iterator.
+    pub async fn load_nodes(
+        &self,
+        nodes: impl IntoIterator<Item = NodeRecord>,
+    ) -> Result<GraphLoadReport> {
+        let operations: Vec<BatchOperation> = nodes
+            .into_iter()
+            .map(|node| BatchOperation {
+                operation_type: OperationType::CreateNode,
+                data: json!({
+                    "id": node.id,
+                    "label": node.label,
+                    "type": node.node_type,
+                    "weight": node.weight,
+                }),
+            })
+            .collect();
+        self.run(operations).await
+    }
+
+    /// Creates every edge in the iterator.
+    pub async fn load_edge_list(
+        &self,
+        edges: impl IntoIterator<Item = EdgeRecord>,
+    ) -> Result<GraphLoadReport> {
+        let operations: Vec<BatchOperation> = edges
+            .into_iter()
+            .map(|edge| BatchOperation {
+                operation_type: OperationType::CreateEdge,
+                data: json!({
+                    "from": edge.from,
+                    "to": edge.to,
+                    "label": edge.label,
+                    "weight": edge.weight,
+                    "directed": edge.directed,
+                }),
+            })
+            .collect();
+        self.run(operations).await
+    }
+
+    /// Loads a whole graph from CSV files: all nodes first, then all
+    /// edges, so edges never reference nodes that do not exist yet.
+    /// `nodes_path` may be `None` when nodes are created implicitly by
+    /// the server.
+    pub async fn load_graph_from_csv(
+        &self,
+        nodes_path: Option<&Path>,
+        edges_path: &Path,
+    ) -> Result<GraphLoadReport> {
+        let mut report = GraphLoadReport::default();
+        if let Some(path) = nodes_path {
+            let nodes = parse_node_csv(&read_file(path)?)?;
+            let node_report = self.load_nodes(nodes).await?;
+            report.loaded += node_report.loaded;
+            report.failed.extend(node_report.failed);
+        }
+        let edges = parse_edge_csv(&read_file(edges_path)?)?;
+        let offset = report.loaded + report.failed.len();
+        let edge_report = self.load_edge_list(edges).await?;
+        report.loaded += edge_report.loaded;
+        report
+            .failed
+            .extend(edge_report.failed.into_iter().map(|(i, e)| (i + offset, e)));
+        Ok(report)
+    }
+
+    /// Sends the operations in fixed-size batches with bounded
+    /// concurrency, collecting per-operation failures by index.
+    async fn run(&self, operations: Vec<BatchOperation>) -> Result<GraphLoadReport> {
+        let total = operations.len();
+        let batch_size = self.options.batch_size.max(1);
+        let concurrency = self.options.concurrency.max(1);
+        let mut report = GraphLoadReport::default();
+        let mut completed = 0usize;
+
+        let mut batches = Vec::new();
+        let mut base = 0usize;
+        let mut pending = operations;
+        while !pending.is_empty() {
+            let rest = pending.split_off(pending.len().min(batch_size));
+            batches.push((base, std::mem::replace(&mut pending, rest)));
+            base += batch_size;
+        }
+
+        let mut in_flight: JoinSet<(usize, usize, Result<Vec<crate::BatchResult>>)> =
+            JoinSet::new();
+        let mut next = batches.into_iter();
+        loop {
+            while in_flight.len() < concurrency {
+                match next.next() {
+                    Some((batch_base, chunk)) => {
+                        let sdk = Arc::clone(&self.sdk);
+                        let size = chunk.len();
+                        in_flight.spawn(async move {
+                            (batch_base, size, sdk.batch(chunk).await)
+                        });
+                    }
+                    None => break,
+                }
+            }
+            let Some(joined) = in_flight.join_next().await else {
+                break;
+            };
+            let (batch_base, size, outcome) = joined.map_err(|err| {
+                BrainAIError::InvalidInput(format!("graph load batch task failed: {err}"))
+            })?;
+            completed += size;
+            match outcome {
+                Ok(results) => {
+                    for (offset, result) in results.iter().enumerate() {
+                        if result.success {
+                            report.loaded += 1;
+                        } else {
+                            report.failed.push((
+                                batch_base + offset,
+                                result
+                                    .error
+                                    .clone()
+                                    .unwrap_or_else(|| "unknown error".to_string()),
+                            ));
+                        }
+                    }
+                }
+                Err(err) => {
+                    // The whole batch call failed; charge every operation.
+                    let message = err.to_string();
+                    for offset in 0..size {
+                        report.failed.push((batch_base + offset, message.clone()));
+                    }
+                }
+            }
+            if let Some(callback) = &self.progress {
+                callback(GraphLoadProgress {
+                    completed,
+                    total,
+                    failed: report.failed.len(),
+                });
+            }
+        }
+        report.failed.sort_by_key(|(index, _)| *index);
+        Ok(report)
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|err| {
+        BrainAIError::InvalidInput(format!("cannot read {}: {err}", path.display()))
+    })
+}
+
+/// Parses the node CSV (`id,label,type,weight`, header required).
+fn parse_node_csv(text: &str) -> Result<Vec<NodeRecord>> {
+    let (header, rows) = split_csv(text)?;
+    let id = require_column(&header, "id")?;
+    let label = header.iter().position(|c| c == "label");
+    let node_type = header.iter().position(|c| c == "type");
+    let weight = header.iter().position(|c| c == "weight");
+    rows.into_iter()
+        .enumerate()
+        .map(|(line, row)| {
+            let id = field(&row, id, line, "id")?;
+            Ok(NodeRecord {
+                label: label
+                    .and_then(|i| row.get(i).cloned())
+                    .unwrap_or_else(|| id.clone()),
+                node_type: node_type
+                    .and_then(|i| row.get(i))
+                    .map(|t| NodeType::from(t.as_str()))
+                    .unwrap_or(NodeType::Concept),
+                weight: parse_weight(&row, weight, line)?,
+                id,
+            })
+        })
+        .collect()
+}
+
+/// Parses the edge CSV (`from,to,label,weight,directed`, header required).
+fn parse_edge_csv(text: &str) -> Result<Vec<EdgeRecord>> {
+    let (header, rows) = split_csv(text)?;
+    let from = require_column(&header, "from")?;
+    let to = require_column(&header, "to")?;
+    let label = header.iter().position(|c| c == "label");
+    let weight = header.iter().position(|c| c == "weight");
+    let directed = header.iter().position(|c| c == "directed");
+    rows.into_iter()
+        .enumerate()
+        .map(|(line, row)| {
+            Ok(EdgeRecord {
+                from: field(&row, from, line, "from")?,
+                to: field(&row, to, line, "to")?,
+                label: label.and_then(|i| row.get(i).cloned()).unwrap_or_default(),
+                weight: parse_weight(&row, weight, line)?,
+                directed: directed
+                    .and_then(|i| row.get(i))
+                    .map(|v| matches!(v.trim(), "true" | "1" | "yes"))
+                    .unwrap_or(false),
+            })
+        })
+        .collect()
+}
+
+fn require_column(header: &[String], name: &str) -> Result<usize> {
+    header.iter().position(|c| c == name).ok_or_else(|| {
+        BrainAIError::InvalidInput(format!("CSV is missing the required '{name}' column"))
+    })
+}
+
+fn field(row: &[String], index: usize, line: usize, name: &str) -> Result<String> {
+    match row.get(index).map(|v| v.trim()) {
+        Some(value) if !value.is_empty() => Ok(value.to_string()),
+        _ => Err(BrainAIError::InvalidInput(format!(
+            "CSV row {} has an empty '{name}' field",
+            line + 2
+        ))),
+    }
+}
+
+fn parse_weight(row: &[String], index: Option<usize>, line: usize) -> Result<f64> {
+    match index.and_then(|i| row.get(i)).map(|v| v.trim()) {
+        None | Some("") => Ok(1.0),
+        Some(raw) => raw.parse().map_err(|_| {
+            BrainAIError::InvalidInput(format!(
+                "CSV row {} has a non-numeric weight '{raw}'",
+                line + 2
+            ))
+        }),
+    }
+}
+
+/// Splits CSV text into a lowercased header and data rows. Handles quoted
+/// fields with embedded commas and doubled quotes; blank lines are
+/// skipped.
+fn split_csv(text: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+    let header = lines
+        .next()
+        .map(|l| {
+            parse_csv_line(l)
+                .into_iter()
+                .map(|c| c.trim().to_ascii_lowercase())
+                .collect::<Vec<_>>()
+        })
+        .ok_or_else(|| BrainAIError::InvalidInput("CSV file is empty".to_string()))?;
+    let rows = lines.map(parse_csv_line).collect();
+    Ok((header, rows))
+}
+
+fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                current.push('"');
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut current)),
+            other => current.push(other),
+        }
+    }
+    fields.push(current);
+    fields
+}
@@ -0,0 +1,189 @@
+//! Graph query builder with a Cypher-like shape.
+//!
+//! Anything beyond "neighbors of X" used to mean hand-built JSON and many
+//! round-trips. [`GraphQuery`] expresses the common pattern — match nodes,
+//! traverse labeled edges, filter on properties, project fields — as a
+//! builder that compiles to one request against the server's query
+//! endpoint:
+//!
+//! ```no_run
+//! # use brain_ai::graphquery::{GraphQuery, Predicate};
+//! let query = GraphQuery::match_nodes("person")
+//!     .where_property("age", Predicate::gt(30))
+//!     .traverse("works_at", 1)
+//!     .to_nodes("company")
+//!     .ret(&["id", "label", "properties.city"])
+//!     .limit(50);
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{BrainAISDK, Endpoint, Result};
+
+/// A property predicate in a `where` clause.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Predicate {
+    Eq { value: Value },
+    Ne { value: Value },
+    Gt { value: Value },
+    Lt { value: Value },
+    Gte { value: Value },
+    Lte { value: Value },
+    /// Substring match on string properties.
+    Contains { value: String },
+    /// Property must exist, any value.
+    Exists,
+}
+
+impl Predicate {
+    pub fn eq(value: impl Into<Value>) -> Self {
+        Predicate::Eq { value: value.into() }
+    }
+
+    pub fn ne(value: impl Into<Value>) -> Self {
+        Predicate::Ne { value: value.into() }
+    }
+
+    pub fn gt(value: impl Into<Value>) -> Self {
+        Predicate::Gt { value: value.into() }
+    }
+
+    pub fn lt(value: impl Into<Value>) -> Self {
+        Predicate::Lt { value: value.into() }
+    }
+
+    pub fn gte(value: impl Into<Value>) -> Self {
+        Predicate::Gte { value: value.into() }
+    }
+
+    pub fn lte(value: impl Into<Value>) -> Self {
+        Predicate::Lte { value: value.into() }
+    }
+
+    pub fn contains(value: impl Into<String>) -> Self {
+        Predicate::Contains { value: value.into() }
+    }
+}
+
+/// One traversal step: follow edges with a label for a number of hops,
+/// optionally constraining the nodes reached.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Step {
+    edge_label: String,
+    hops: u32,
+    /// Node type required at the end of the step, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    node_type: Option<String>,
+    /// Property predicates on the nodes reached.
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    wheres: HashMap<String, Predicate>,
+}
+
+/// Cypher-like graph query, compiled to the server's query endpoint.
+#[derive(Debug, Clone)]
+pub struct GraphQuery {
+    start_type: Option<String>,
+    start_wheres: HashMap<String, Predicate>,
+    steps: Vec<Step>,
+    projection: Vec<String>,
+    limit: Option<usize>,
+}
+
+impl GraphQuery {
+    /// Starts from all nodes of a type (`MATCH (n:type)`).
+    pub fn match_nodes(node_type: impl Into<String>) -> Self {
+        GraphQuery {
+            start_type: Some(node_type.into()),
+            start_wheres: HashMap::new(),
+            steps: Vec::new(),
+            projection: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Starts from every node (`MATCH (n)`).
+    pub fn match_any() -> Self {
+        GraphQuery {
+            start_type: None,
+            start_wheres: HashMap::new(),
+            steps: Vec::new(),
+            projection: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Adds a property predicate to the current pattern element — the
+    /// start nodes before any [`traverse`](Self::traverse), the nodes
+    /// reached by the last step afterwards.
+    pub fn where_property(mut self, key: impl Into<String>, predicate: Predicate) -> Self {
+        match self.steps.last_mut() {
+            Some(step) => step.wheres.insert(key.into(), predicate),
+            None => self.start_wheres.insert(key.into(), predicate),
+        };
+        self
+    }
+
+    /// Traverses edges with the given label for up to `hops` hops
+    /// (`-[:label*1..hops]->`).
+    pub fn traverse(mut self, edge_label: impl Into<String>, hops: u32) -> Self {
+        self.steps.push(Step {
+            edge_label: edge_label.into(),
+            hops: hops.max(1),
+            node_type: None,
+            wheres: HashMap::new(),
+        });
+        self
+    }
+
+    /// Constrains the node type reached by the last traversal
+    /// (`(m:type)`).
+    pub fn to_nodes(mut self, node_type: impl Into<String>) -> Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.node_type = Some(node_type.into());
+        }
+        self
+    }
+
+    /// Projects the returned fields (`RETURN ...`); dotted paths reach
+    /// into properties. Empty projection returns whole nodes.
+    pub fn ret(mut self, fields: &[&str]) -> Self {
+        self.projection = fields.iter().map(|f| f.to_string()).collect();
+        self
+    }
+
+    /// Caps the number of rows.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Compiles to the wire representation accepted by the query
+    /// endpoint.
+    pub fn compile(&self) -> Value {
+        json!({
+            "match": {
+                "type": self.start_type,
+                "where": self.start_wheres,
+            },
+            "steps": self.steps,
+            "return": self.projection,
+            "limit": self.limit,
+        })
+    }
+}
+
+/// One result row: the projected fields (or the whole node) for each
+/// pattern element, keyed by position (`n0`, `n1`, ...).
+pub type GraphQueryRow = HashMap<String, Value>;
+
+impl BrainAISDK {
+    /// Executes a [`GraphQuery`] in one round-trip.
+    pub async fn query_graph(&self, query: &GraphQuery) -> Result<Vec<GraphQueryRow>> {
+        self.request(Endpoint::GraphQuery, Some(query.compile()))
+            .await
+    }
+}
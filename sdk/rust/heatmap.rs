@@ -0,0 +1,155 @@
+//! Memory access heatmap export.
+//!
+//! [`access_heatmap`] aggregates retrieval counts into a time-bucketed
+//! matrix — one row per memory, memory type, or metadata tag, one column
+//! per time bucket — exportable as CSV for a spreadsheet or plotting
+//! library. The point is operational: see which knowledge is actually
+//! used and which is dead weight worth pruning.
+//!
+//! The server reports each memory's cumulative `access_count` and its
+//! `last_accessed` time, not a per-access log, so a memory's count lands
+//! in the bucket of its most recent access. That is exact for hot/cold
+//! analysis (the usual question) and approximate for fine-grained traffic
+//! shaping.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::client::BrainAIClient;
+use crate::{BrainAIError, Result};
+
+/// How heatmap rows are keyed.
+#[derive(Debug, Clone)]
+pub enum HeatmapGroupBy {
+    /// One row per memory ID.
+    Memory,
+    /// One row per memory type.
+    MemoryType,
+    /// One row per distinct value of this metadata key (e.g. a tag or
+    /// namespace field); memories missing the key group under `"(none)"`.
+    MetadataKey(String),
+}
+
+/// Time-bucketed access counts; rows × buckets.
+#[derive(Debug, Clone)]
+pub struct HeatmapMatrix {
+    /// Row labels, sorted by total count descending.
+    pub rows: Vec<String>,
+    /// Bucket start times (unix milliseconds), ascending.
+    pub bucket_starts: Vec<i64>,
+    /// Bucket width in milliseconds.
+    pub bucket_millis: i64,
+    /// `counts[row][bucket]` accesses.
+    pub counts: Vec<Vec<u64>>,
+}
+
+impl HeatmapMatrix {
+    /// Total accesses in a row.
+    pub fn row_total(&self, row: usize) -> u64 {
+        self.counts[row].iter().sum()
+    }
+
+    /// Renders the matrix as CSV with a header of bucket start times.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("key");
+        for start in &self.bucket_starts {
+            let _ = write!(out, ",{start}");
+        }
+        out.push_str(",total\n");
+        for (row, label) in self.rows.iter().enumerate() {
+            // Quote labels so tags containing commas stay one cell.
+            let _ = write!(out, "\"{}\"", label.replace('"', "\"\""));
+            for count in &self.counts[row] {
+                let _ = write!(out, ",{count}");
+            }
+            let _ = writeln!(out, ",{}", self.row_total(row));
+        }
+        out
+    }
+}
+
+/// Builds an access heatmap over `range` (start, end in unix
+/// milliseconds), bucketed by `bucket` width and grouped per `group_by`.
+///
+/// Memories whose last access falls outside the range are skipped; rows
+/// are ordered hottest first.
+pub async fn access_heatmap(
+    client: &dyn BrainAIClient,
+    range: (i64, i64),
+    bucket: Duration,
+    group_by: HeatmapGroupBy,
+) -> Result<HeatmapMatrix> {
+    let (start, end) = range;
+    if end <= start {
+        return Err(BrainAIError::InvalidInput(
+            "heatmap range end must be after start".to_string(),
+        ));
+    }
+    let bucket_millis = bucket.as_millis().max(1) as i64;
+    let bucket_count = ((end - start + bucket_millis - 1) / bucket_millis) as usize;
+    if bucket_count > 10_000 {
+        return Err(BrainAIError::InvalidInput(format!(
+            "range/bucket combination yields {bucket_count} buckets; coarsen the bucket"
+        )));
+    }
+
+    let mut totals: HashMap<String, Vec<u64>> = HashMap::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = client
+            .list_memories_page(None, 500, cursor.as_deref())
+            .await?;
+        for memory in &page.memories {
+            if memory.last_accessed < start || memory.last_accessed >= end {
+                continue;
+            }
+            let key = match &group_by {
+                HeatmapGroupBy::Memory => memory.id.clone(),
+                HeatmapGroupBy::MemoryType => memory.memory_type.as_str().to_string(),
+                HeatmapGroupBy::MetadataKey(tag) => memory
+                    .metadata
+                    .get(tag)
+                    .map(|v| match v {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    })
+                    .unwrap_or_else(|| "(none)".to_string()),
+            };
+            let accesses = match client.get_memory_stats(&memory.id).await {
+                Ok(stats) => stats.access_count,
+                // Stats may lag a listing; count the access we can see.
+                Err(BrainAIError::NotFound(_)) => 1,
+                Err(err) => return Err(err),
+            };
+            let bucket_index = ((memory.last_accessed - start) / bucket_millis) as usize;
+            let row = totals
+                .entry(key)
+                .or_insert_with(|| vec![0; bucket_count]);
+            row[bucket_index.min(bucket_count - 1)] += accesses;
+        }
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    let mut entries: Vec<(String, Vec<u64>)> = totals.into_iter().collect();
+    entries.sort_by(|a, b| {
+        let ta: u64 = a.1.iter().sum();
+        let tb: u64 = b.1.iter().sum();
+        tb.cmp(&ta).then_with(|| a.0.cmp(&b.0))
+    });
+    let bucket_starts = (0..bucket_count)
+        .map(|i| start + i as i64 * bucket_millis)
+        .collect();
+    let (rows, counts) = entries.into_iter().unzip();
+    Ok(HeatmapMatrix {
+        rows,
+        bucket_starts,
+        bucket_millis,
+        counts,
+    })
+}
@@ -0,0 +1,187 @@
+//! Request hedging for tail latency.
+//!
+//! p99 on reads is usually dominated by the occasional slow replica, not
+//! by median performance. [`Hedger`] sends an idempotent read to one
+//! replica and, if no answer arrives within a percentile-based delay,
+//! fires the same request at a second replica and takes whichever
+//! responds first — the loser is cancelled by drop. Only reads are
+//! hedged; duplicating writes is not safe.
+//!
+//! The hedge delay adapts: observed latencies feed a rolling window and
+//! the delay tracks the configured percentile, so hedges fire for true
+//! stragglers instead of doubling all traffic.
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use tokio::sync::Mutex;
+
+use crate::client::BrainAIClient;
+use crate::{BrainAIError, Memory, MemoryStats, Result, SearchResult};
+
+/// Tuning for [`Hedger`].
+#[derive(Debug, Clone)]
+pub struct HedgeOptions {
+    /// Latency percentile the hedge delay tracks (e.g. `0.95`).
+    pub percentile: f64,
+    /// Floor for the hedge delay, also used before enough samples exist.
+    pub min_delay: Duration,
+    /// Ceiling for the hedge delay.
+    pub max_delay: Duration,
+    /// Rolling latency window size.
+    pub max_samples: usize,
+}
+
+impl Default for HedgeOptions {
+    fn default() -> Self {
+        HedgeOptions {
+            percentile: 0.95,
+            min_delay: Duration::from_millis(20),
+            max_delay: Duration::from_secs(2),
+            max_samples: 500,
+        }
+    }
+}
+
+/// Hedging read client over two or more replicas.
+pub struct Hedger {
+    replicas: Vec<Arc<dyn BrainAIClient>>,
+    options: HedgeOptions,
+    latencies: Mutex<VecDeque<Duration>>,
+    /// Rotates which replica serves as the hedge target.
+    next_backup: std::sync::atomic::AtomicUsize,
+}
+
+impl Hedger {
+    /// Creates a hedger over the given replicas; the first is the primary.
+    /// At least two replicas are required for hedging to do anything.
+    pub fn new(replicas: Vec<Arc<dyn BrainAIClient>>) -> Result<Self> {
+        Hedger::with_options(replicas, HedgeOptions::default())
+    }
+
+    /// Creates a hedger with explicit options.
+    pub fn with_options(
+        replicas: Vec<Arc<dyn BrainAIClient>>,
+        options: HedgeOptions,
+    ) -> Result<Self> {
+        if replicas.is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "hedger needs at least one replica".to_string(),
+            ));
+        }
+        Ok(Hedger {
+            replicas,
+            options,
+            latencies: Mutex::new(VecDeque::new()),
+            next_backup: std::sync::atomic::AtomicUsize::new(1),
+        })
+    }
+
+    /// Current hedge delay: the tracked percentile of observed latencies,
+    /// clamped to the configured bounds.
+    pub async fn hedge_delay(&self) -> Duration {
+        let samples = self.latencies.lock().await;
+        if samples.len() < 20 {
+            return self.options.min_delay;
+        }
+        let mut sorted: Vec<Duration> = samples.iter().copied().collect();
+        sorted.sort();
+        let rank = ((sorted.len() as f64 - 1.0) * self.options.percentile.clamp(0.0, 1.0))
+            .round() as usize;
+        sorted[rank].clamp(self.options.min_delay, self.options.max_delay)
+    }
+
+    async fn record(&self, elapsed: Duration) {
+        let mut samples = self.latencies.lock().await;
+        if samples.len() >= self.options.max_samples.max(1) {
+            samples.pop_front();
+        }
+        samples.push_back(elapsed);
+    }
+
+    /// Runs `call` against the primary, hedging to the next replica after
+    /// the current delay; first response (success or failure) wins.
+    async fn hedged<T, Fut>(
+        &self,
+        call: impl Fn(Arc<dyn BrainAIClient>) -> Fut,
+    ) -> Result<T>
+    where
+        Fut: Future<Output = Result<T>>,
+    {
+        let started = Instant::now();
+        let primary = call(self.replicas[0].clone());
+        if self.replicas.len() == 1 {
+            let outcome = primary.await;
+            self.record(started.elapsed()).await;
+            return outcome;
+        }
+        let backup_index = 1 + self
+            .next_backup
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+            % (self.replicas.len() - 1);
+        let delay = self.hedge_delay().await;
+        tokio::pin!(primary);
+        let outcome = tokio::select! {
+            outcome = &mut primary => outcome,
+            () = tokio::time::sleep(delay) => {
+                let backup = call(self.replicas[backup_index].clone());
+                tokio::pin!(backup);
+                tokio::select! {
+                    outcome = &mut primary => outcome,
+                    outcome = &mut backup => outcome,
+                }
+            }
+        };
+        self.record(started.elapsed()).await;
+        outcome
+    }
+
+    /// Hedged [`BrainAIClient::get_memory`].
+    pub async fn get_memory(&self, id: &str) -> Result<Option<Memory>> {
+        let id = id.to_string();
+        self.hedged(move |client| {
+            let id = id.clone();
+            async move { client.get_memory(&id).await }
+        })
+        .await
+    }
+
+    /// Hedged [`BrainAIClient::get_memory_stats`].
+    pub async fn get_memory_stats(&self, id: &str) -> Result<MemoryStats> {
+        let id = id.to_string();
+        self.hedged(move |client| {
+            let id = id.clone();
+            async move { client.get_memory_stats(&id).await }
+        })
+        .await
+    }
+
+    /// Hedged [`BrainAIClient::search_memories`].
+    pub async fn search_memories(
+        &self,
+        query: Value,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        self.hedged(move |client| {
+            let query = query.clone();
+            async move { client.search_memories(query, limit).await }
+        })
+        .await
+    }
+
+    /// Hedged [`BrainAIClient::list_memories`].
+    pub async fn list_memories(
+        &self,
+        filters: Option<HashMap<String, Value>>,
+        limit: usize,
+    ) -> Result<Vec<Memory>> {
+        self.hedged(move |client| {
+            let filters = filters.clone();
+            async move { client.list_memories(filters, limit).await }
+        })
+        .await
+    }
+}
@@ -0,0 +1,249 @@
+//! Local HNSW index for offline similarity search.
+//!
+//! [`HnswIndex`] is a self-contained Hierarchical Navigable Small World
+//! graph over f32 vectors: inserts assign each vector a random level,
+//! queries greedily descend the upper layers and beam-search the bottom
+//! one. It lets applications run similarity search over exported or cached
+//! vectors with no server round-trip — ideal for offline ranking, edge
+//! deployments, and tests. Distances use the same cosine math as the
+//! backend, so local and server rankings agree.
+
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::vector_utils::cosine_similarity;
+use crate::{BrainAIError, Result, VectorMatch};
+
+/// An index entry's neighbors, one list per layer it participates in.
+#[derive(Debug, Clone)]
+struct Node {
+    vector: Vec<f32>,
+    /// `neighbors[layer]` holds the IDs linked at that layer.
+    neighbors: Vec<Vec<usize>>,
+}
+
+/// Candidate ordered by similarity for the search heaps.
+#[derive(PartialEq)]
+struct Candidate {
+    similarity: f64,
+    index: usize,
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.similarity.total_cmp(&other.similarity)
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hierarchical Navigable Small World index over f32 vectors.
+pub struct HnswIndex {
+    /// Max neighbors per node per layer.
+    m: usize,
+    /// Beam width during construction.
+    ef_construction: usize,
+    /// Beam width during search; raise for better recall.
+    ef_search: usize,
+    nodes: Vec<Node>,
+    ids: Vec<String>,
+    id_lookup: HashMap<String, usize>,
+    entry_point: Option<usize>,
+    dimension: Option<usize>,
+    rng_state: u64,
+}
+
+impl HnswIndex {
+    /// Creates an index with typical defaults (`m = 16`,
+    /// `ef_construction = 100`, `ef_search = 50`).
+    pub fn new() -> Self {
+        HnswIndex::with_params(16, 100, 50)
+    }
+
+    /// Creates an index with explicit HNSW parameters.
+    pub fn with_params(m: usize, ef_construction: usize, ef_search: usize) -> Self {
+        HnswIndex {
+            m: m.max(2),
+            ef_construction: ef_construction.max(m),
+            ef_search: ef_search.max(1),
+            nodes: Vec::new(),
+            ids: Vec::new(),
+            id_lookup: HashMap::new(),
+            entry_point: None,
+            dimension: None,
+            rng_state: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// Number of indexed vectors.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn random_level(&mut self) -> usize {
+        // xorshift64*; geometric level distribution with p = 1/2.
+        let mut x = self.rng_state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.rng_state = x;
+        (x.wrapping_mul(0x2545_F491_4F6C_DD1D).trailing_ones() as usize).min(16)
+    }
+
+    /// Beam search on one layer from `entry`, returning up to `ef`
+    /// candidates ordered best-first.
+    fn search_layer(&self, query: &[f32], entry: usize, layer: usize, ef: usize) -> Vec<Candidate> {
+        let mut visited: HashSet<usize> = HashSet::from([entry]);
+        let entry_sim = cosine_similarity(query, &self.nodes[entry].vector);
+        // Max-heap of frontier candidates; results kept as a sorted vec.
+        let mut frontier = BinaryHeap::from([Candidate {
+            similarity: entry_sim,
+            index: entry,
+        }]);
+        let mut results: Vec<Candidate> = vec![Candidate {
+            similarity: entry_sim,
+            index: entry,
+        }];
+        while let Some(current) = frontier.pop() {
+            let worst = results.last().map(|c| c.similarity).unwrap_or(f64::MIN);
+            if results.len() >= ef && current.similarity < worst {
+                break;
+            }
+            if layer >= self.nodes[current.index].neighbors.len() {
+                continue;
+            }
+            for &neighbor in &self.nodes[current.index].neighbors[layer] {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+                let similarity = cosine_similarity(query, &self.nodes[neighbor].vector);
+                let worst = results.last().map(|c| c.similarity).unwrap_or(f64::MIN);
+                if results.len() < ef || similarity > worst {
+                    frontier.push(Candidate { similarity, index: neighbor });
+                    results.push(Candidate { similarity, index: neighbor });
+                    results.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+                    results.truncate(ef);
+                }
+            }
+        }
+        results
+    }
+
+    /// Inserts a vector under an ID; re-inserting an existing ID replaces
+    /// its vector in place.
+    pub fn insert(&mut self, id: &str, vector: Vec<f32>) -> Result<()> {
+        if vector.is_empty() {
+            return Err(BrainAIError::InvalidInput("empty vector".to_string()));
+        }
+        if let Some(dimension) = self.dimension {
+            if vector.len() != dimension {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "vector has {} dimensions, index expects {dimension}",
+                    vector.len()
+                )));
+            }
+        } else {
+            self.dimension = Some(vector.len());
+        }
+        if let Some(&existing) = self.id_lookup.get(id) {
+            self.nodes[existing].vector = vector;
+            return Ok(());
+        }
+
+        let level = self.random_level();
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            vector: vector.clone(),
+            neighbors: vec![Vec::new(); level + 1],
+        });
+        self.ids.push(id.to_string());
+        self.id_lookup.insert(id.to_string(), index);
+
+        let Some(mut entry) = self.entry_point else {
+            self.entry_point = Some(index);
+            return Ok(());
+        };
+        let top_level = self.nodes[entry].neighbors.len() - 1;
+
+        // Greedy descent through layers above the new node's level.
+        for layer in ((level + 1)..=top_level).rev() {
+            entry = self
+                .search_layer(&vector, entry, layer, 1)
+                .first()
+                .map(|c| c.index)
+                .unwrap_or(entry);
+        }
+        // Connect on each layer the new node participates in.
+        for layer in (0..=level.min(top_level)).rev() {
+            let candidates = self.search_layer(&vector, entry, layer, self.ef_construction);
+            entry = candidates.first().map(|c| c.index).unwrap_or(entry);
+            let chosen: Vec<usize> = candidates
+                .iter()
+                .take(self.m)
+                .map(|c| c.index)
+                .filter(|&n| n != index)
+                .collect();
+            for &neighbor in &chosen {
+                self.nodes[index].neighbors[layer].push(neighbor);
+                let peers = &mut self.nodes[neighbor].neighbors[layer];
+                peers.push(index);
+                // Trim overfull neighbor lists, keeping the most similar.
+                if peers.len() > self.m * 2 {
+                    let anchor = self.nodes[neighbor].vector.clone();
+                    let mut peers = std::mem::take(&mut self.nodes[neighbor].neighbors[layer]);
+                    peers.sort_by(|&a, &b| {
+                        cosine_similarity(&anchor, &self.nodes[b].vector)
+                            .total_cmp(&cosine_similarity(&anchor, &self.nodes[a].vector))
+                    });
+                    peers.truncate(self.m * 2);
+                    self.nodes[neighbor].neighbors[layer] = peers;
+                }
+            }
+        }
+        if level > top_level {
+            self.entry_point = Some(index);
+        }
+        Ok(())
+    }
+
+    /// Finds the `k` most similar vectors to the query.
+    pub fn search(&self, query: &[f32], k: usize) -> Vec<VectorMatch> {
+        let Some(mut entry) = self.entry_point else {
+            return Vec::new();
+        };
+        let top_level = self.nodes[entry].neighbors.len() - 1;
+        for layer in (1..=top_level).rev() {
+            entry = self
+                .search_layer(query, entry, layer, 1)
+                .first()
+                .map(|c| c.index)
+                .unwrap_or(entry);
+        }
+        let ef = self.ef_search.max(k);
+        self.search_layer(query, entry, 0, ef)
+            .into_iter()
+            .take(k)
+            .map(|c| VectorMatch {
+                id: self.ids[c.index].clone(),
+                score: c.similarity,
+                metadata: HashMap::new(),
+            })
+            .collect()
+    }
+}
+
+impl Default for HnswIndex {
+    fn default() -> Self {
+        HnswIndex::new()
+    }
+}
@@ -0,0 +1,118 @@
+//! Hybrid search fusing vector similarity and keyword matching.
+//!
+//! Keyword search finds exact terminology, vector search finds meaning;
+//! [`hybrid_search`] runs both and fuses the rankings client-side with
+//! reciprocal rank fusion (RRF), which combines lists without needing the
+//! two score scales to be comparable. Results carry the per-source scores
+//! so callers can see which channel surfaced each hit.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::client::BrainAIClient;
+use crate::{BrainAIError, Result};
+
+/// Relative weights for the two search channels.
+#[derive(Debug, Clone, Copy)]
+pub struct HybridWeights {
+    /// Weight of the keyword (memory search) ranking.
+    pub keyword: f64,
+    /// Weight of the vector similarity ranking.
+    pub vector: f64,
+}
+
+impl Default for HybridWeights {
+    fn default() -> Self {
+        HybridWeights {
+            keyword: 1.0,
+            vector: 1.0,
+        }
+    }
+}
+
+/// A fused hit with its combined and per-source scores.
+#[derive(Debug, Clone)]
+pub struct HybridResult {
+    pub id: String,
+    /// Weighted reciprocal-rank-fusion score.
+    pub score: f64,
+    /// Keyword channel: (rank, score), if this ID appeared there.
+    pub keyword: Option<(usize, f64)>,
+    /// Vector channel: (rank, score), if this ID appeared there.
+    pub vector: Option<(usize, f64)>,
+    pub content: serde_json::Value,
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+/// RRF constant; dampens the advantage of rank 1 over rank 2, per the
+/// original Cormack et al. formulation.
+const RRF_K: f64 = 60.0;
+
+/// Runs a keyword memory search and a vector search for the same query
+/// and fuses the two rankings with reciprocal rank fusion.
+///
+/// Each channel is asked for `limit` candidates and the fused list is
+/// truncated to `limit`. Vector hits that match no stored memory keep an
+/// empty content payload; their ID still identifies the vector record.
+pub async fn hybrid_search(
+    client: &dyn BrainAIClient,
+    query_text: &str,
+    query_vector: Vec<f32>,
+    weights: HybridWeights,
+    limit: usize,
+) -> Result<Vec<HybridResult>> {
+    if query_text.trim().is_empty() && query_vector.is_empty() {
+        return Err(BrainAIError::InvalidInput(
+            "hybrid search needs query text, a query vector, or both".to_string(),
+        ));
+    }
+    let mut fused: HashMap<String, HybridResult> = HashMap::new();
+
+    if !query_text.trim().is_empty() {
+        let hits = client.search_memories(json!(query_text), limit).await?;
+        for (rank, hit) in hits.into_iter().enumerate() {
+            let contribution = weights.keyword / (RRF_K + rank as f64 + 1.0);
+            let entry = fused.entry(hit.id.clone()).or_insert_with(|| HybridResult {
+                id: hit.id.clone(),
+                score: 0.0,
+                keyword: None,
+                vector: None,
+                content: hit.content.clone(),
+                metadata: hit.metadata.clone(),
+            });
+            entry.score += contribution;
+            entry.keyword = Some((rank + 1, hit.score));
+        }
+    }
+
+    if !query_vector.is_empty() {
+        let hits = client.search_similar_vectors(query_vector, limit).await?;
+        for (rank, hit) in hits.into_iter().enumerate() {
+            let contribution = weights.vector / (RRF_K + rank as f64 + 1.0);
+            // Vector metadata may carry a memory_id link (as written by
+            // store_text); fuse on that ID so both channels agree.
+            let id = hit
+                .metadata
+                .get("memory_id")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or(&hit.id)
+                .to_string();
+            let entry = fused.entry(id.clone()).or_insert_with(|| HybridResult {
+                id,
+                score: 0.0,
+                keyword: None,
+                vector: None,
+                content: serde_json::Value::Null,
+                metadata: hit.metadata.clone(),
+            });
+            entry.score += contribution;
+            entry.vector = Some((rank + 1, hit.score));
+        }
+    }
+
+    let mut results: Vec<HybridResult> = fused.into_values().collect();
+    results.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+    results.truncate(limit);
+    Ok(results)
+}
@@ -0,0 +1,182 @@
+//! Deterministic IDs derived from content.
+//!
+//! Server-assigned IDs make repeated ingestion of the same source
+//! produce duplicates, and the "same" memory gets a different ID in
+//! every environment. [`deterministic_id`] derives a UUIDv5 from a
+//! namespace and the content itself, so identical content always maps
+//! to the identical ID — re-ingestion becomes an upsert by
+//! construction, and staging and production can reference the same
+//! logical memory by the same stable ID.
+//! [`BrainAISDK::store_memory_deterministic`] and
+//! [`store_vector_deterministic`](crate::BrainAISDK::store_vector_deterministic)
+//! store under such an ID in one call.
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::{BrainAIError, BrainAISDK, Endpoint, MemoryType, Result};
+
+/// Root namespace UUID for Brain AI deterministic IDs; caller
+/// namespaces are themselves UUIDv5 names under this root.
+const ROOT_NAMESPACE: [u8; 16] = [
+    0x6b, 0xa7, 0xb8, 0x14, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30,
+    0xc8,
+];
+
+/// Derives the UUIDv5 for `content` within `namespace`.
+///
+/// The content is hashed in its canonical JSON serialization (object
+/// keys sorted, which `serde_json` guarantees), so semantically equal
+/// values produce the same ID regardless of construction order. The
+/// same content in two namespaces yields two distinct IDs.
+pub fn deterministic_id(namespace: &str, content: &Value) -> String {
+    let ns = uuid_v5(&ROOT_NAMESPACE, namespace.as_bytes());
+    let canonical = content.to_string();
+    format_uuid(&uuid_v5(&ns, canonical.as_bytes()))
+}
+
+/// UUIDv5: SHA-1 of namespace + name with the version and variant bits
+/// set, per RFC 4122 §4.3.
+fn uuid_v5(namespace: &[u8; 16], name: &[u8]) -> [u8; 16] {
+    let mut input = Vec::with_capacity(16 + name.len());
+    input.extend_from_slice(namespace);
+    input.extend_from_slice(name);
+    let digest = sha1(&input);
+    let mut uuid = [0u8; 16];
+    uuid.copy_from_slice(&digest[..16]);
+    uuid[6] = (uuid[6] & 0x0f) | 0x50; // version 5
+    uuid[8] = (uuid[8] & 0x3f) | 0x80; // RFC 4122 variant
+    uuid
+}
+
+fn format_uuid(uuid: &[u8; 16]) -> String {
+    let hex: String = uuid.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// SHA-1 as specified in RFC 3174. Fine here: UUIDv5 needs SHA-1 for
+/// interoperability and uses it for naming, not security.
+fn sha1(message: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476, 0xc3d2_e1f0];
+    let mut data = message.to_vec();
+    let bit_len = (message.len() as u64) * 8;
+    data.push(0x80);
+    while data.len() % 64 != 56 {
+        data.push(0);
+    }
+    data.extend_from_slice(&bit_len.to_be_bytes());
+    for chunk in data.chunks_exact(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in chunk.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+        let (mut a, mut b, mut c, mut d, mut e) = (h[0], h[1], h[2], h[3], h[4]);
+        for (i, word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5a82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ed9_eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1b_bcdc),
+                _ => (b ^ c ^ d, 0xca62_c1d6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        h[0] = h[0].wrapping_add(a);
+        h[1] = h[1].wrapping_add(b);
+        h[2] = h[2].wrapping_add(c);
+        h[3] = h[3].wrapping_add(d);
+        h[4] = h[4].wrapping_add(e);
+    }
+    let mut digest = [0u8; 20];
+    for (i, word) in h.iter().enumerate() {
+        digest[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+impl BrainAISDK {
+    /// Like [`store_memory`](Self::store_memory) under a deterministic
+    /// content-derived ID; storing the same content in the same
+    /// namespace again overwrites rather than duplicates. The ID is
+    /// recorded in the metadata as `content_id` alongside the
+    /// `id_namespace`, and returned.
+    pub async fn store_memory_deterministic(
+        &self,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+        namespace: &str,
+    ) -> Result<String> {
+        if namespace.trim().is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "deterministic IDs need a non-empty namespace".to_string(),
+            ));
+        }
+        let id = deterministic_id(namespace, &content);
+        let mut metadata = metadata.unwrap_or_default();
+        metadata.insert("content_id".to_string(), json!(id));
+        metadata.insert("id_namespace".to_string(), json!(namespace));
+        let body = json!({
+            "id": id,
+            "content": content,
+            "type": memory_type,
+            "metadata": metadata,
+        });
+        let data: Value = self.request(Endpoint::StoreMemory, Some(body)).await?;
+        Ok(data
+            .get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or(id))
+    }
+
+    /// Like [`store_vector`](Self::store_vector) under a deterministic
+    /// ID derived from the vector's values and the namespace.
+    pub async fn store_vector_deterministic(
+        &self,
+        vector: Vec<f32>,
+        metadata: Option<HashMap<String, Value>>,
+        namespace: &str,
+    ) -> Result<String> {
+        if namespace.trim().is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "deterministic IDs need a non-empty namespace".to_string(),
+            ));
+        }
+        let id = deterministic_id(namespace, &json!(vector));
+        let mut metadata = metadata.unwrap_or_default();
+        metadata.insert("content_id".to_string(), json!(id));
+        metadata.insert("id_namespace".to_string(), json!(namespace));
+        let body = json!({
+            "id": id,
+            "vector": vector,
+            "metadata": metadata,
+        });
+        let data: Value = self.request(Endpoint::StoreVector, Some(body)).await?;
+        Ok(data
+            .get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or(id))
+    }
+}
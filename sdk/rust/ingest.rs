@@ -0,0 +1,213 @@
+//! Text chunking and document ingestion pipeline.
+//!
+//! [`DocumentIngestor`] implements the standard RAG ingestion flow users
+//! currently hand-roll: split raw text (plain, Markdown, or PDF-extracted)
+//! into overlapping chunks on natural boundaries, embed each chunk with an
+//! [`Embedder`], store a memory plus vector per chunk, and wire the chunks
+//! together in the knowledge graph — `next_chunk` edges between adjacent
+//! chunks and `part_of` edges up to a document node.
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::embed::{store_text, Embedder};
+use crate::{BrainAIError, MemoryType, NodeType, Result};
+
+/// Chunking parameters for [`DocumentIngestor`].
+#[derive(Debug, Clone)]
+pub struct ChunkingOptions {
+    /// Target chunk size in characters.
+    pub chunk_size: usize,
+    /// Characters of trailing context repeated at the start of the next
+    /// chunk so sentences split across a boundary stay searchable.
+    pub overlap: usize,
+}
+
+impl Default for ChunkingOptions {
+    fn default() -> Self {
+        ChunkingOptions {
+            chunk_size: 1_000,
+            overlap: 150,
+        }
+    }
+}
+
+/// Splits text into overlapping chunks, preferring paragraph breaks, then
+/// sentence ends, then whitespace, and only cutting mid-word as a last
+/// resort. Boundaries are always kept on `char` edges.
+pub fn chunk_text(text: &str, options: &ChunkingOptions) -> Vec<String> {
+    let chunk_size = options.chunk_size.max(1);
+    let overlap = options.overlap.min(chunk_size.saturating_sub(1));
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < text.len() {
+        let hard_end = floor_char_boundary(text, (start + chunk_size).min(text.len()));
+        let end = if hard_end == text.len() {
+            hard_end
+        } else {
+            best_break(&text[start..hard_end]).map(|at| start + at).unwrap_or(hard_end)
+        };
+        let chunk = text[start..end].trim();
+        if !chunk.is_empty() {
+            chunks.push(chunk.to_string());
+        }
+        if end == text.len() {
+            break;
+        }
+        let next = end.saturating_sub(overlap).max(start + 1);
+        start = floor_char_boundary(text, next);
+    }
+    chunks
+}
+
+/// Largest char boundary at or below `index`.
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while index > 0 && !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Best break position in `window`, searched from the back: paragraph
+/// break, then sentence end, then any whitespace. Returns `None` when the
+/// window has no usable boundary in its second half.
+fn best_break(window: &str) -> Option<usize> {
+    let floor = window.len() / 2;
+    if let Some(at) = window.rfind("\n\n").filter(|&at| at > floor) {
+        return Some(at);
+    }
+    for pattern in [". ", ".\n", "! ", "? ", "; "] {
+        if let Some(at) = window.rfind(pattern).filter(|&at| at > floor) {
+            return Some(at + 1);
+        }
+    }
+    window
+        .char_indices()
+        .rev()
+        .find(|(at, c)| c.is_whitespace() && *at > floor)
+        .map(|(at, _)| at)
+}
+
+/// One ingested chunk: its position and the IDs it was stored under.
+#[derive(Debug, Clone)]
+pub struct IngestedChunk {
+    pub index: usize,
+    pub memory_id: String,
+    pub vector_id: String,
+}
+
+/// Outcome of ingesting one document.
+#[derive(Debug, Clone)]
+pub struct IngestReport {
+    /// Graph node representing the whole document.
+    pub document_node: String,
+    pub chunks: Vec<IngestedChunk>,
+}
+
+/// Splits, embeds, stores, and graph-links documents.
+pub struct DocumentIngestor<'a> {
+    client: &'a dyn BrainAIClient,
+    embedder: &'a dyn Embedder,
+    options: ChunkingOptions,
+    memory_type: MemoryType,
+}
+
+impl<'a> DocumentIngestor<'a> {
+    /// Creates an ingestor storing chunks as semantic memories with
+    /// default chunking.
+    pub fn new(client: &'a dyn BrainAIClient, embedder: &'a dyn Embedder) -> Self {
+        DocumentIngestor {
+            client,
+            embedder,
+            options: ChunkingOptions::default(),
+            memory_type: MemoryType::Semantic,
+        }
+    }
+
+    /// Overrides the chunking parameters.
+    pub fn with_options(mut self, options: ChunkingOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Overrides the memory type chunks are stored under.
+    pub fn with_memory_type(mut self, memory_type: MemoryType) -> Self {
+        self.memory_type = memory_type;
+        self
+    }
+
+    /// Ingests one document under a caller-chosen ID (used as the graph
+    /// document node), returning what was stored where.
+    pub async fn ingest(&self, document_id: &str, text: &str) -> Result<IngestReport> {
+        if document_id.trim().is_empty() {
+            return Err(BrainAIError::InvalidInput("empty document id".to_string()));
+        }
+        let pieces = chunk_text(text, &self.options);
+        if pieces.is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "document produced no chunks".to_string(),
+            ));
+        }
+        self.client
+            .create_graph_node(
+                document_id,
+                document_id,
+                NodeType::Document,
+                Some(HashMap::from([(
+                    "chunk_count".to_string(),
+                    json!(pieces.len()),
+                )])),
+            )
+            .await?;
+
+        let mut chunks: Vec<IngestedChunk> = Vec::with_capacity(pieces.len());
+        for (index, piece) in pieces.iter().enumerate() {
+            let metadata: HashMap<String, Value> = HashMap::from([
+                ("document_id".to_string(), json!(document_id)),
+                ("chunk_index".to_string(), json!(index)),
+            ]);
+            let stored = store_text(
+                self.client,
+                self.embedder,
+                piece,
+                self.memory_type,
+                Some(metadata),
+            )
+            .await?;
+
+            let chunk_node = format!("{document_id}#chunk-{index}");
+            self.client
+                .create_graph_node(
+                    &chunk_node,
+                    &chunk_node,
+                    NodeType::Chunk,
+                    Some(HashMap::from([
+                        ("memory_id".to_string(), json!(stored.memory_id)),
+                        ("vector_id".to_string(), json!(stored.vector_id)),
+                        ("relation".to_string(), json!("part_of")),
+                    ])),
+                )
+                .await?;
+            self.client
+                .connect_graph_nodes(&chunk_node, document_id, 1.0)
+                .await?;
+            if index > 0 {
+                let previous = format!("{document_id}#chunk-{}", index - 1);
+                self.client
+                    .connect_graph_nodes(&previous, &chunk_node, 1.0)
+                    .await?;
+            }
+            chunks.push(IngestedChunk {
+                index,
+                memory_id: stored.memory_id,
+                vector_id: stored.vector_id,
+            });
+        }
+        Ok(IngestReport {
+            document_node: document_id.to_string(),
+            chunks,
+        })
+    }
+}
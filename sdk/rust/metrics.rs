@@ -0,0 +1,189 @@
+//! Client-side metrics with Prometheus text exposition.
+//!
+//! [`ClientMetrics`] is a [`Middleware`] layer counting requests,
+//! errors by status, latency histograms, cache hits, and bytes
+//! transferred, per endpoint. Install it in the chain and scrape
+//! [`gather`](ClientMetrics::gather) — Prometheus text format, ready to
+//! serve from a `/metrics` handler — and client behaviour shows up on
+//! the same dashboards as the server's own metrics.
+//!
+//! Endpoint labels use the coarse operation label (IDs stripped) so
+//! cardinality stays bounded. Byte counts are approximate: they measure
+//! the JSON re-serialization of each body. Transport failures never
+//! produce a response and are therefore absent from the error counters;
+//! watch the request counter stall instead.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::middleware::{Middleware, MiddlewareRequest, MiddlewareResponse};
+use crate::slowlog::operation_label;
+
+/// Histogram bucket bounds in seconds, the standard Prometheus ladder.
+const BUCKET_BOUNDS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Cumulative latency histogram for one endpoint.
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    /// Observations at or under each bound in [`BUCKET_BOUNDS`].
+    buckets: [u64; BUCKET_BOUNDS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        for (bucket, bound) in self.buckets.iter_mut().zip(BUCKET_BOUNDS) {
+            if seconds <= bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+/// Counters for one endpoint label.
+#[derive(Debug, Clone, Default)]
+struct EndpointMetrics {
+    requests: u64,
+    /// Requests answered by a short-circuiting middleware (a cache)
+    /// without a network round trip.
+    cache_hits: u64,
+    errors: HashMap<u16, u64>,
+    latency: Histogram,
+    bytes_sent: u64,
+    bytes_received: u64,
+}
+
+/// Per-endpoint client metrics; see the module docs. Cheap to clone —
+/// clones share the same counters, so one instance can sit in the
+/// middleware chain while another serves the scrape endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct ClientMetrics {
+    endpoints: Arc<Mutex<HashMap<String, EndpointMetrics>>>,
+}
+
+impl ClientMetrics {
+    pub fn new() -> Self {
+        ClientMetrics::default()
+    }
+
+    /// Renders every counter in Prometheus text exposition format.
+    pub fn gather(&self) -> String {
+        let endpoints = self.endpoints.lock().unwrap();
+        let mut labels: Vec<&String> = endpoints.keys().collect();
+        labels.sort();
+        let mut out = String::new();
+
+        out.push_str("# TYPE brain_ai_requests_total counter\n");
+        for label in &labels {
+            let m = &endpoints[*label];
+            out.push_str(&format!(
+                "brain_ai_requests_total{{endpoint=\"{label}\"}} {}\n",
+                m.requests
+            ));
+        }
+
+        out.push_str("# TYPE brain_ai_cache_hits_total counter\n");
+        for label in &labels {
+            let m = &endpoints[*label];
+            out.push_str(&format!(
+                "brain_ai_cache_hits_total{{endpoint=\"{label}\"}} {}\n",
+                m.cache_hits
+            ));
+        }
+
+        out.push_str("# TYPE brain_ai_errors_total counter\n");
+        for label in &labels {
+            let m = &endpoints[*label];
+            let mut statuses: Vec<&u16> = m.errors.keys().collect();
+            statuses.sort();
+            for status in statuses {
+                out.push_str(&format!(
+                    "brain_ai_errors_total{{endpoint=\"{label}\",status=\"{status}\"}} {}\n",
+                    m.errors[status]
+                ));
+            }
+        }
+
+        out.push_str("# TYPE brain_ai_request_duration_seconds histogram\n");
+        for label in &labels {
+            let m = &endpoints[*label];
+            // Buckets are cumulative already; `observe` increments every
+            // bucket whose bound covers the observation.
+            for (bucket, bound) in m.latency.buckets.iter().zip(BUCKET_BOUNDS) {
+                out.push_str(&format!(
+                    "brain_ai_request_duration_seconds_bucket{{endpoint=\"{label}\",le=\"{bound}\"}} {bucket}\n",
+                ));
+            }
+            out.push_str(&format!(
+                "brain_ai_request_duration_seconds_bucket{{endpoint=\"{label}\",le=\"+Inf\"}} {}\n",
+                m.latency.count
+            ));
+            out.push_str(&format!(
+                "brain_ai_request_duration_seconds_sum{{endpoint=\"{label}\"}} {}\n",
+                m.latency.sum
+            ));
+            out.push_str(&format!(
+                "brain_ai_request_duration_seconds_count{{endpoint=\"{label}\"}} {}\n",
+                m.latency.count
+            ));
+        }
+
+        out.push_str("# TYPE brain_ai_bytes_sent_total counter\n");
+        for label in &labels {
+            let m = &endpoints[*label];
+            out.push_str(&format!(
+                "brain_ai_bytes_sent_total{{endpoint=\"{label}\"}} {}\n",
+                m.bytes_sent
+            ));
+        }
+
+        out.push_str("# TYPE brain_ai_bytes_received_total counter\n");
+        for label in &labels {
+            let m = &endpoints[*label];
+            out.push_str(&format!(
+                "brain_ai_bytes_received_total{{endpoint=\"{label}\"}} {}\n",
+                m.bytes_received
+            ));
+        }
+
+        out
+    }
+
+    /// Total requests observed across every endpoint.
+    pub fn total_requests(&self) -> u64 {
+        self.endpoints
+            .lock()
+            .unwrap()
+            .values()
+            .map(|m| m.requests)
+            .sum()
+    }
+}
+
+#[async_trait]
+impl Middleware for ClientMetrics {
+    async fn after(&self, request: &MiddlewareRequest, response: &MiddlewareResponse) {
+        let label = operation_label(&request.path);
+        let mut endpoints = self.endpoints.lock().unwrap();
+        let m = endpoints.entry(label).or_default();
+        m.requests += 1;
+        if response.short_circuited {
+            m.cache_hits += 1;
+        }
+        if response.status >= 400 {
+            *m.errors.entry(response.status).or_default() += 1;
+        }
+        m.latency.observe(response.duration.as_secs_f64());
+        if let Some(body) = &request.body {
+            m.bytes_sent += body.to_string().len() as u64;
+        }
+        m.bytes_received += response.body.to_string().len() as u64;
+    }
+}
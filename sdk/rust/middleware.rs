@@ -0,0 +1,160 @@
+//! Request middleware: an interceptor chain around the SDK's HTTP path.
+//!
+//! [`Middleware`] is the extension point around the central request
+//! helper. Each middleware sees the outgoing request before it is sent —
+//! it can add headers, rewrite the body, or short-circuit with a cached
+//! response — and observes the exchange after it completes, for logging,
+//! latency measurement, or tracing-ID bookkeeping. Chains are installed
+//! with [`BrainAISDK::with_middleware`](crate::BrainAISDK::with_middleware);
+//! `before` hooks run in installation order, `after` hooks in reverse,
+//! the usual onion layering.
+//!
+//! [`HeaderMiddleware`] and [`LoggingMiddleware`] are built in; custom
+//! concerns (caches, tracing propagation) implement the trait.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::Result;
+
+/// An outgoing API request as middleware sees it, before sending.
+#[derive(Debug, Clone)]
+pub struct MiddlewareRequest {
+    pub method: reqwest::Method,
+    /// Path relative to the server base URL, e.g. `/api/memory/search`.
+    pub path: String,
+    /// Extra headers to send; middleware appends here.
+    pub headers: Vec<(String, String)>,
+    /// JSON body, mutable in place. `None` for bodyless requests.
+    pub body: Option<Value>,
+}
+
+/// A completed exchange as middleware sees it: the full response body
+/// before envelope unwrapping, with status and wall time.
+#[derive(Debug, Clone)]
+pub struct MiddlewareResponse {
+    pub status: u16,
+    pub body: Value,
+    pub duration: Duration,
+    /// `true` when a `before` hook answered the request without a
+    /// network round trip.
+    pub short_circuited: bool,
+}
+
+/// One interceptor around the SDK's request path. Both hooks default to
+/// no-ops, so implementations override only the side they care about.
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Runs before the request is sent. Mutate the request freely;
+    /// return `Some(body)` (a full response body, envelope included) to
+    /// short-circuit the exchange — later `before` hooks and the network
+    /// round trip are skipped, `after` hooks still run.
+    async fn before(&self, request: &mut MiddlewareRequest) -> Result<Option<Value>> {
+        let _ = request;
+        Ok(None)
+    }
+
+    /// Runs after the exchange completes, network or short-circuit.
+    /// Transport failures never reach this hook; they surface as errors
+    /// before a response exists.
+    async fn after(&self, request: &MiddlewareRequest, response: &MiddlewareResponse) {
+        let _ = (request, response);
+    }
+}
+
+/// The installed chain. Shared across SDK clones like the connection
+/// pool.
+#[derive(Clone, Default)]
+pub struct MiddlewareChain {
+    layers: Arc<Vec<Box<dyn Middleware>>>,
+}
+
+impl MiddlewareChain {
+    pub(crate) fn new(layers: Vec<Box<dyn Middleware>>) -> Self {
+        MiddlewareChain {
+            layers: Arc::new(layers),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Runs the `before` hooks in order; `Some` when one short-circuits.
+    pub(crate) async fn before(&self, request: &mut MiddlewareRequest) -> Result<Option<Value>> {
+        for layer in self.layers.iter() {
+            if let Some(body) = layer.before(request).await? {
+                return Ok(Some(body));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Runs the `after` hooks in reverse order.
+    pub(crate) async fn after(&self, request: &MiddlewareRequest, response: &MiddlewareResponse) {
+        for layer in self.layers.iter().rev() {
+            layer.after(request, response).await;
+        }
+    }
+}
+
+impl std::fmt::Debug for MiddlewareChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MiddlewareChain")
+            .field("layers", &self.layers.len())
+            .finish()
+    }
+}
+
+/// Adds a fixed set of headers to every request — API gateway keys,
+/// tenant identifiers, and the like.
+#[derive(Debug, Clone, Default)]
+pub struct HeaderMiddleware {
+    headers: Vec<(String, String)>,
+}
+
+impl HeaderMiddleware {
+    pub fn new() -> Self {
+        HeaderMiddleware::default()
+    }
+
+    /// Adds one header sent with every request.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+#[async_trait]
+impl Middleware for HeaderMiddleware {
+    async fn before(&self, request: &mut MiddlewareRequest) -> Result<Option<Value>> {
+        request.headers.extend(self.headers.iter().cloned());
+        Ok(None)
+    }
+}
+
+/// Logs one line per exchange — method, path, status, wall time — to
+/// standard error; useful in development.
+#[derive(Debug, Clone, Default)]
+pub struct LoggingMiddleware;
+
+#[async_trait]
+impl Middleware for LoggingMiddleware {
+    async fn after(&self, request: &MiddlewareRequest, response: &MiddlewareResponse) {
+        let source = if response.short_circuited {
+            " (short-circuited)"
+        } else {
+            ""
+        };
+        eprintln!(
+            "[brain-ai] {} {} -> {} in {}ms{source}",
+            request.method,
+            request.path,
+            response.status,
+            response.duration.as_millis(),
+        );
+    }
+}
@@ -0,0 +1,1295 @@
+//! Local in-memory backend mirroring the full SDK surface.
+//!
+//! [`MockBrainAI`] exposes the same methods as [`BrainAISDK`](crate::BrainAISDK)
+//! with identical signatures, but stores memories, vectors, and graph nodes in
+//! local maps. It lets downstream code be unit-tested without a running
+//! Brain AI server and behaves deterministically: IDs are sequential and
+//! similarity is computed locally with the same cosine math the server uses.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde_json::{json, Value};
+
+use crate::filter::matches_filters;
+use crate::vector_utils::{cosine_similarity, now_millis};
+use crate::{
+    BackupInfo, BatchOperation, BatchResult, BrainAIConfig, BrainAIError, EdgeDirection,
+    Feedback, GraphEdge, GraphNode, NodeType, OperationType,
+    LearningPattern, LearningProgress, Memory, MemoryPage, MemoryRelation, MemoryStats,
+    MemoryType, MemoryWrite, MetadataIndex, ProfiledSearch, QueryProfile, ReasoningOptions,
+    ReasoningResult, RelationType, Result,
+    ScoreBreakdown, SearchResult, StageTiming,
+    StoreManyReport, SystemStatistics, SystemStatus, VectorMatch, VectorRecord,
+};
+
+#[derive(Debug, Default)]
+struct MockState {
+    memories: HashMap<String, Memory>,
+    memory_stats: HashMap<String, MemoryStats>,
+    /// Memory connections keyed by `(low_id, high_id)`; the stored
+    /// [`MemoryRelation`] keeps the original `from`/`to` orientation.
+    connections: HashMap<(String, String), MemoryRelation>,
+    vectors: HashMap<String, VectorRecord>,
+    graph_nodes: HashMap<String, GraphNode>,
+    /// Undirected graph edges keyed by `(low_id, high_id)`.
+    graph_edges: HashMap<(String, String), f64>,
+    /// Typed edges by ID; mirrored into `graph_edges` for traversal.
+    typed_edges: HashMap<String, GraphEdge>,
+    patterns: HashMap<String, LearningPattern>,
+    feedback: Vec<Value>,
+    backups: HashMap<String, Value>,
+    /// Metadata indexes by field name. The mock never scans, but tracks
+    /// them so index management and filter linting are testable.
+    indexes: HashMap<String, MetadataIndex>,
+}
+
+/// In-memory stand-in for a Brain AI server.
+///
+/// # Example
+/// ```
+/// use brain_ai::{MockBrainAI, MemoryType};
+/// use serde_json::json;
+///
+/// # tokio_test::block_on(async {
+/// let brain = MockBrainAI::new();
+/// let id = brain
+///     .store_memory(json!({"text": "hello world"}), MemoryType::Semantic, None)
+///     .await
+///     .unwrap();
+/// let results = brain.search_memories("hello", 5).await.unwrap();
+/// assert_eq!(results[0].id, id);
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct MockBrainAI {
+    config: BrainAIConfig,
+    state: Mutex<MockState>,
+    next_id: AtomicU64,
+    started_at: i64,
+}
+
+impl Default for MockBrainAI {
+    fn default() -> Self {
+        MockBrainAI::new()
+    }
+}
+
+impl MockBrainAI {
+    /// Creates an empty mock brain with the default configuration.
+    pub fn new() -> Self {
+        MockBrainAI::with_config(BrainAIConfig::default())
+    }
+
+    /// Creates an empty mock brain honoring `similarity_threshold` and
+    /// `memory_size` from the given configuration.
+    pub fn with_config(config: BrainAIConfig) -> Self {
+        MockBrainAI {
+            config,
+            state: Mutex::new(MockState::default()),
+            next_id: AtomicU64::new(1),
+            started_at: now_millis(),
+        }
+    }
+
+    /// Returns the configuration this mock was built with.
+    pub fn config(&self) -> &BrainAIConfig {
+        &self.config
+    }
+
+    fn next_id(&self, prefix: &str) -> String {
+        format!("{prefix}_{}", self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn edge_key(a: &str, b: &str) -> (String, String) {
+        if a <= b {
+            (a.to_string(), b.to_string())
+        } else {
+            (b.to_string(), a.to_string())
+        }
+    }
+
+    /// Scores `content` against a textual query with token overlap; mirrors
+    /// the server's lexical fallback closely enough for tests.
+    fn score_content(query: &str, content: &Value) -> f64 {
+        let haystack = content.to_string().to_lowercase();
+        let tokens: Vec<&str> = query
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if tokens.is_empty() {
+            return 0.0;
+        }
+        let hits = tokens
+            .iter()
+            .filter(|t| haystack.contains(&t.to_lowercase()))
+            .count();
+        hits as f64 / tokens.len() as f64
+    }
+
+    // ------------------------------------------------------------------
+    // Core methods
+    // ------------------------------------------------------------------
+
+    /// Stores a new memory and returns its ID.
+    pub async fn store_memory(
+        &self,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        let id = self.next_id("memory");
+        let now = now_millis();
+        let mut state = self.state.lock().unwrap();
+        if state.memories.len() >= self.config.memory_size {
+            return Err(BrainAIError::Api {
+                status: 507,
+                message: "memory capacity exceeded".to_string(),
+            });
+        }
+        state.memories.insert(
+            id.clone(),
+            Memory {
+                id: id.clone(),
+                content,
+                memory_type,
+                metadata: metadata.unwrap_or_default(),
+                strength: 1.0,
+                created_at: now,
+                last_accessed: now,
+            },
+        );
+        state.memory_stats.insert(
+            id.clone(),
+            MemoryStats {
+                access_count: 0,
+                last_accessed: now,
+                creation_time: now,
+                connection_count: 0,
+                average_relevance: 0.0,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Retrieves a memory by ID, or `None` if it does not exist.
+    pub async fn get_memory(&self, id: &str) -> Result<Option<Memory>> {
+        let mut state = self.state.lock().unwrap();
+        let now = now_millis();
+        if let Some(stats) = state.memory_stats.get_mut(id) {
+            stats.access_count += 1;
+            stats.last_accessed = now;
+        }
+        if let Some(memory) = state.memories.get_mut(id) {
+            memory.last_accessed = now;
+            return Ok(Some(memory.clone()));
+        }
+        Ok(None)
+    }
+
+    /// Searches stored memories with local token-overlap scoring, applying
+    /// the configured similarity threshold.
+    pub async fn search_memories(
+        &self,
+        query: impl Into<Value>,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let query = query.into();
+        let query_text = match &query {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        let state = self.state.lock().unwrap();
+        let mut results: Vec<SearchResult> = state
+            .memories
+            .values()
+            .filter_map(|memory| {
+                let score = Self::score_content(&query_text, &memory.content);
+                (score >= self.config.similarity_threshold).then(|| SearchResult {
+                    id: memory.id.clone(),
+                    score,
+                    content: memory.content.clone(),
+                    metadata: memory.metadata.clone(),
+                    breakdown: Some(ScoreBreakdown {
+                        similarity: score,
+                        ..ScoreBreakdown::default()
+                    }),
+                })
+            })
+            .collect();
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        results.truncate(limit);
+        Ok(results)
+    }
+
+    /// Searches with a synthesized [`QueryProfile`]: the mock always
+    /// full-scans, so candidates equal the stored memory count and no
+    /// index is reported.
+    pub async fn search_memories_profiled(
+        &self,
+        query: impl Into<Value>,
+        limit: usize,
+    ) -> Result<ProfiledSearch> {
+        let started = std::time::Instant::now();
+        let candidates = self.state.lock().unwrap().memories.len() as u64;
+        let results = self.search_memories(query, limit).await?;
+        let elapsed = started.elapsed().as_secs_f64() * 1_000.0;
+        let profile = QueryProfile {
+            candidates_scanned: candidates,
+            index_used: None,
+            filter_selectivity: if candidates == 0 {
+                1.0
+            } else {
+                results.len() as f64 / candidates as f64
+            },
+            stages: vec![
+                StageTiming {
+                    stage: "scan".to_string(),
+                    duration_ms: elapsed,
+                },
+                StageTiming {
+                    stage: "rank".to_string(),
+                    duration_ms: 0.0,
+                },
+            ],
+        };
+        Ok(ProfiledSearch { results, profile })
+    }
+
+    /// Creates a connection between two memories.
+    pub async fn connect_memories(&self, id1: &str, id2: &str, strength: f64) -> Result<bool> {
+        self.relate_memories(id1, id2, RelationType::Similar, strength)
+            .await
+    }
+
+    /// Creates a typed connection between two memories. Relating the same
+    /// pair again replaces the existing relation.
+    pub async fn relate_memories(
+        &self,
+        from: &str,
+        to: &str,
+        relation: RelationType,
+        strength: f64,
+    ) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        if !state.memories.contains_key(from) || !state.memories.contains_key(to) {
+            return Err(BrainAIError::NotFound(format!("memory {from} or {to}")));
+        }
+        let replaced = state
+            .connections
+            .insert(
+                Self::edge_key(from, to),
+                MemoryRelation {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    relation,
+                    strength: strength.clamp(0.0, 1.0),
+                },
+            )
+            .is_some();
+        if !replaced {
+            for id in [from, to] {
+                if let Some(stats) = state.memory_stats.get_mut(id) {
+                    stats.connection_count += 1;
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Lists the relations a memory participates in, optionally limited to
+    /// one [`RelationType`], ordered by the other endpoint's ID.
+    pub async fn get_relations(
+        &self,
+        id: &str,
+        relation: Option<RelationType>,
+    ) -> Result<Vec<MemoryRelation>> {
+        let state = self.state.lock().unwrap();
+        if !state.memories.contains_key(id) {
+            return Err(BrainAIError::NotFound(format!("memory {id}")));
+        }
+        let mut relations: Vec<MemoryRelation> = state
+            .connections
+            .values()
+            .filter(|r| r.from == id || r.to == id)
+            .filter(|r| relation.as_ref().is_none_or(|wanted| r.relation == *wanted))
+            .cloned()
+            .collect();
+        relations.sort_by(|a, b| {
+            let other_a = if a.from == id { &a.to } else { &a.from };
+            let other_b = if b.from == id { &b.to } else { &b.from };
+            other_a.cmp(other_b)
+        });
+        Ok(relations)
+    }
+
+    // ------------------------------------------------------------------
+    // Memory management
+    // ------------------------------------------------------------------
+
+    /// Updates the strength of a memory by `delta`, clamped to `0.0..=1.0`.
+    pub async fn update_memory_strength(&self, id: &str, delta: f64) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        let memory = state
+            .memories
+            .get_mut(id)
+            .ok_or_else(|| BrainAIError::NotFound(format!("memory {id}")))?;
+        memory.strength = (memory.strength + delta).clamp(0.0, 1.0);
+        Ok(true)
+    }
+
+    /// Gets usage statistics for a memory.
+    pub async fn get_memory_stats(&self, id: &str) -> Result<MemoryStats> {
+        let state = self.state.lock().unwrap();
+        state
+            .memory_stats
+            .get(id)
+            .cloned()
+            .ok_or_else(|| BrainAIError::NotFound(format!("memory {id}")))
+    }
+
+    /// Replaces the content (and optionally metadata) of an existing memory.
+    pub async fn update_memory(
+        &self,
+        id: &str,
+        content: Value,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        let memory = state
+            .memories
+            .get_mut(id)
+            .ok_or_else(|| BrainAIError::NotFound(format!("memory {id}")))?;
+        memory.content = content;
+        if let Some(metadata) = metadata {
+            memory.metadata = metadata;
+        }
+        memory.last_accessed = now_millis();
+        Ok(true)
+    }
+
+    /// Deletes a memory and its connections.
+    pub async fn delete_memory(&self, id: &str) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        if state.memories.remove(id).is_none() {
+            return Err(BrainAIError::NotFound(format!("memory {id}")));
+        }
+        state.memory_stats.remove(id);
+        state
+            .connections
+            .retain(|(a, b), _| a != id && b != id);
+        Ok(true)
+    }
+
+    /// Deletes memories matching the filters and returns the count removed.
+    /// Honors the same filter criteria as [`MockBrainAI::list_memories`].
+    pub async fn delete_memories_by_filter(
+        &self,
+        filters: HashMap<String, Value>,
+    ) -> Result<u64> {
+        let mut state = self.state.lock().unwrap();
+        let doomed: Vec<String> = state
+            .memories
+            .values()
+            .filter(|m| matches_filters(m, &filters))
+            .map(|m| m.id.clone())
+            .collect();
+        for id in &doomed {
+            state.memories.remove(id);
+            state.memory_stats.remove(id);
+            state.connections.retain(|(a, b), _| a != id && b != id);
+        }
+        Ok(doomed.len() as u64)
+    }
+
+    /// Lists memories, honoring the server's filter criteria (type, time
+    /// range, metadata equality, strength bounds).
+    pub async fn list_memories(
+        &self,
+        filters: Option<HashMap<String, Value>>,
+        limit: usize,
+    ) -> Result<Vec<Memory>> {
+        let filters = filters.unwrap_or_default();
+        let state = self.state.lock().unwrap();
+        let mut memories: Vec<Memory> = state
+            .memories
+            .values()
+            .filter(|m| matches_filters(m, &filters))
+            .cloned()
+            .collect();
+        memories.sort_by_key(|m| std::cmp::Reverse(m.created_at));
+        memories.truncate(limit);
+        Ok(memories)
+    }
+
+    /// Lists memories one page at a time; the cursor is the ID of the last
+    /// memory on the previous page.
+    pub async fn list_memories_page(
+        &self,
+        filters: Option<HashMap<String, Value>>,
+        page_size: usize,
+        cursor: Option<&str>,
+    ) -> Result<MemoryPage> {
+        let filters = filters.unwrap_or_default();
+        let state = self.state.lock().unwrap();
+        let mut memories: Vec<Memory> = state
+            .memories
+            .values()
+            .filter(|m| matches_filters(m, &filters))
+            .cloned()
+            .collect();
+        // Stable order so a memory is returned at most once per listing.
+        memories.sort_by(|a, b| a.id.cmp(&b.id));
+        let start = match cursor {
+            Some(cursor) => memories
+                .iter()
+                .position(|m| m.id.as_str() > cursor)
+                .unwrap_or(memories.len()),
+            None => 0,
+        };
+        let page: Vec<Memory> = memories
+            .into_iter()
+            .skip(start)
+            .take(page_size)
+            .collect();
+        let next_cursor = if page.len() == page_size {
+            page.last().map(|m| m.id.clone())
+        } else {
+            None
+        };
+        Ok(MemoryPage {
+            memories: page,
+            next_cursor,
+        })
+    }
+
+    /// Records a metadata index, returning whether it was newly created.
+    pub async fn create_metadata_index(&self, field: &str) -> Result<bool> {
+        if field.trim().is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "index field must not be empty".to_string(),
+            ));
+        }
+        let mut state = self.state.lock().unwrap();
+        if state.indexes.contains_key(field) {
+            return Ok(false);
+        }
+        state.indexes.insert(
+            field.to_string(),
+            MetadataIndex {
+                field: field.to_string(),
+                created_at: now_millis(),
+            },
+        );
+        Ok(true)
+    }
+
+    /// Lists recorded metadata indexes, sorted by field.
+    pub async fn list_indexes(&self) -> Result<Vec<MetadataIndex>> {
+        let state = self.state.lock().unwrap();
+        let mut indexes: Vec<MetadataIndex> = state.indexes.values().cloned().collect();
+        indexes.sort_by(|a, b| a.field.cmp(&b.field));
+        Ok(indexes)
+    }
+
+    // ------------------------------------------------------------------
+    // Learning system
+    // ------------------------------------------------------------------
+
+    /// Records a pattern, reinforcing it if already known.
+    pub async fn learn(&self, pattern: &str, context: Vec<String>) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        let rate = self.config.learning_rate;
+        let entry = state
+            .patterns
+            .entry(pattern.to_string())
+            .or_insert_with(|| LearningPattern {
+                pattern: pattern.to_string(),
+                frequency: 0,
+                strength: 0.0,
+                context: Vec::new(),
+                last_updated: 0,
+                confidence: 0.5,
+            });
+        entry.frequency += 1;
+        entry.strength = (entry.strength + rate).clamp(0.0, 1.0);
+        entry.last_updated = now_millis();
+        for c in context {
+            if !entry.context.contains(&c) {
+                entry.context.push(c);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Returns all learned patterns.
+    pub async fn get_learning_patterns(&self) -> Result<Vec<LearningPattern>> {
+        let state = self.state.lock().unwrap();
+        let mut patterns: Vec<LearningPattern> = state.patterns.values().cloned().collect();
+        patterns.sort_by(|a, b| b.strength.total_cmp(&a.strength));
+        Ok(patterns)
+    }
+
+    /// Deletes one pattern, returning whether it existed.
+    pub async fn delete_pattern(&self, pattern: &str) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        Ok(state.patterns.remove(pattern).is_some())
+    }
+
+    /// Multiplies every pattern's strength by `1.0 - rate`.
+    pub async fn decay_patterns(&self, rate: f64) -> Result<u64> {
+        if !(0.0..=1.0).contains(&rate) {
+            return Err(BrainAIError::InvalidInput(format!(
+                "decay rate must be in 0.0..=1.0, got {rate}"
+            )));
+        }
+        let mut state = self.state.lock().unwrap();
+        let factor = 1.0 - rate;
+        for pattern in state.patterns.values_mut() {
+            pattern.strength *= factor;
+        }
+        Ok(state.patterns.len() as u64)
+    }
+
+    /// Removes patterns failing any of the given criteria.
+    pub async fn prune_patterns(
+        &self,
+        min_frequency: Option<u64>,
+        min_strength: Option<f64>,
+        older_than: Option<i64>,
+    ) -> Result<u64> {
+        let mut state = self.state.lock().unwrap();
+        let before = state.patterns.len();
+        state.patterns.retain(|_, pattern| {
+            if min_frequency.is_some_and(|min| pattern.frequency < min) {
+                return false;
+            }
+            if min_strength.is_some_and(|min| pattern.strength < min) {
+                return false;
+            }
+            if older_than.is_some_and(|at| pattern.last_updated < at) {
+                return false;
+            }
+            true
+        });
+        Ok((before - state.patterns.len()) as u64)
+    }
+
+    /// Records feedback; positive feedback nudges overall confidence up.
+    pub async fn add_feedback(
+        &self,
+        feedback_type: &str,
+        information: &str,
+        reasoning: Option<&str>,
+    ) -> Result<bool> {
+        if !matches!(feedback_type, "positive" | "negative" | "neutral") {
+            return Err(BrainAIError::InvalidInput(format!(
+                "unknown feedback type: {feedback_type}"
+            )));
+        }
+        let mut state = self.state.lock().unwrap();
+        state.feedback.push(json!({
+            "type": feedback_type,
+            "information": information,
+            "reasoning": reasoning,
+            "timestamp": now_millis(),
+        }));
+        Ok(true)
+    }
+
+    /// Records structured feedback, returning its ID.
+    pub async fn submit_feedback(&self, feedback: Feedback) -> Result<String> {
+        if !matches!(
+            feedback.feedback_type.as_str(),
+            "positive" | "negative" | "neutral"
+        ) {
+            return Err(BrainAIError::InvalidInput(format!(
+                "unknown feedback type: {}",
+                feedback.feedback_type
+            )));
+        }
+        if let Some(severity) = feedback.severity {
+            if !(0.0..=1.0).contains(&severity) {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "feedback severity must be in 0.0..=1.0, got {severity}"
+                )));
+            }
+        }
+        let id = self.next_id("feedback");
+        let mut entry = serde_json::to_value(&feedback)?;
+        entry["id"] = json!(id);
+        entry["timestamp"] = json!(now_millis());
+        let mut state = self.state.lock().unwrap();
+        state.feedback.push(entry);
+        Ok(id)
+    }
+
+    /// Retracts feedback by ID, returning whether it existed.
+    pub async fn retract_feedback(&self, feedback_id: &str) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        let before = state.feedback.len();
+        state
+            .feedback
+            .retain(|entry| entry.get("id").and_then(Value::as_str) != Some(feedback_id));
+        Ok(state.feedback.len() < before)
+    }
+
+    /// Gets learning progress derived from recorded patterns and feedback.
+    pub async fn get_learning_progress(&self) -> Result<LearningProgress> {
+        let state = self.state.lock().unwrap();
+        let total = state.patterns.len() as u64;
+        let active = state
+            .patterns
+            .values()
+            .filter(|p| p.strength >= 0.5)
+            .count() as u64;
+        let positive = state
+            .feedback
+            .iter()
+            .filter(|f| f.get("type").and_then(Value::as_str) == Some("positive"))
+            .count();
+        let accuracy = if state.feedback.is_empty() {
+            0.0
+        } else {
+            positive as f64 / state.feedback.len() as f64
+        };
+        Ok(LearningProgress {
+            total_patterns: total,
+            active_patterns: active,
+            learning_accuracy: accuracy,
+            improvement_rate: self.config.learning_rate,
+            last_training: state
+                .patterns
+                .values()
+                .map(|p| p.last_updated)
+                .max()
+                .unwrap_or(0),
+        })
+    }
+
+    // ------------------------------------------------------------------
+    // Reasoning engine
+    // ------------------------------------------------------------------
+
+    /// Produces a deterministic reasoning result from matching memories.
+    pub async fn reason(&self, query: &str, context: Vec<String>) -> Result<ReasoningResult> {
+        self.reason_with_options(query, context, ReasoningOptions::default())
+            .await
+    }
+
+    /// Like [`MockBrainAI::reason`]; the mock honors the evidence limit
+    /// and echoes the strategy, but derives conclusions the same way
+    /// regardless.
+    pub async fn reason_with_options(
+        &self,
+        query: &str,
+        context: Vec<String>,
+        options: ReasoningOptions,
+    ) -> Result<ReasoningResult> {
+        let limit = options.evidence_limit.unwrap_or(5).max(1);
+        let results = self.search_memories(query, limit).await?;
+        let confidence = results.first().map(|r| r.score).unwrap_or(0.0);
+        let conclusion = results
+            .first()
+            .map(|r| r.content.to_string())
+            .unwrap_or_else(|| "insufficient knowledge to conclude".to_string());
+        Ok(ReasoningResult {
+            conclusion,
+            confidence,
+            reasoning_path: context,
+            supporting_evidence: results
+                .iter()
+                .map(|r| format!("Memory: {}", r.id))
+                .collect(),
+            strategy: options.strategy,
+            timestamp: now_millis(),
+        })
+    }
+
+    /// Returns a canned explanation; the mock records no conclusion history.
+    pub async fn explain_conclusion(&self, conclusion_id: &str) -> Result<Value> {
+        Ok(json!({
+            "conclusion_id": conclusion_id,
+            "explanation": "mock backend: conclusions are derived from local memory matches",
+        }))
+    }
+
+    /// Returns a canned validation result.
+    pub async fn validate_reasoning(&self, reasoning_id: &str) -> Result<Value> {
+        Ok(json!({
+            "reasoning_id": reasoning_id,
+            "valid": true,
+            "quality": 1.0,
+        }))
+    }
+
+    // ------------------------------------------------------------------
+    // Vector operations
+    // ------------------------------------------------------------------
+
+    /// Stores a vector and returns its ID.
+    pub async fn store_vector(
+        &self,
+        vector: Vec<f32>,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        if vector.is_empty() {
+            return Err(BrainAIError::InvalidInput("empty vector".to_string()));
+        }
+        let id = self.next_id("vector");
+        let mut state = self.state.lock().unwrap();
+        state.vectors.insert(
+            id.clone(),
+            VectorRecord {
+                id: id.clone(),
+                vector,
+                metadata: metadata.unwrap_or_default(),
+            },
+        );
+        Ok(id)
+    }
+
+    /// Retrieves a stored vector by ID, or `None` if it does not exist.
+    pub async fn get_vector(&self, id: &str) -> Result<Option<VectorRecord>> {
+        Ok(self.state.lock().unwrap().vectors.get(id).cloned())
+    }
+
+    /// Replaces a stored vector's data and/or metadata.
+    pub async fn update_vector(
+        &self,
+        id: &str,
+        vector: Option<Vec<f32>>,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<bool> {
+        if vector.is_none() && metadata.is_none() {
+            return Err(BrainAIError::InvalidInput(
+                "update_vector requires a new vector or metadata".to_string(),
+            ));
+        }
+        let mut state = self.state.lock().unwrap();
+        let record = state
+            .vectors
+            .get_mut(id)
+            .ok_or_else(|| BrainAIError::NotFound(format!("vector {id}")))?;
+        if let Some(vector) = vector {
+            if vector.is_empty() {
+                return Err(BrainAIError::InvalidInput("empty vector".to_string()));
+            }
+            record.vector = vector;
+        }
+        if let Some(metadata) = metadata {
+            record.metadata = metadata;
+        }
+        Ok(true)
+    }
+
+    /// Deletes a stored vector.
+    pub async fn delete_vector(&self, id: &str) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        if state.vectors.remove(id).is_none() {
+            return Err(BrainAIError::NotFound(format!("vector {id}")));
+        }
+        Ok(true)
+    }
+
+    /// Searches stored vectors with local cosine similarity.
+    pub async fn search_similar_vectors(
+        &self,
+        vector: Vec<f32>,
+        limit: usize,
+    ) -> Result<Vec<VectorMatch>> {
+        let state = self.state.lock().unwrap();
+        let mut matches: Vec<VectorMatch> = state
+            .vectors
+            .values()
+            .map(|stored| VectorMatch {
+                id: stored.id.clone(),
+                score: cosine_similarity(&vector, &stored.vector),
+                metadata: stored.metadata.clone(),
+            })
+            .collect();
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches.truncate(limit);
+        Ok(matches)
+    }
+
+    /// Searches stored vectors restricted to those whose metadata matches
+    /// every `(key, value)` pair in `filters` exactly.
+    pub async fn search_vectors_filtered(
+        &self,
+        vector: Vec<f32>,
+        limit: usize,
+        filters: HashMap<String, Value>,
+    ) -> Result<Vec<VectorMatch>> {
+        let state = self.state.lock().unwrap();
+        let mut matches: Vec<VectorMatch> = state
+            .vectors
+            .values()
+            .filter(|stored| {
+                filters
+                    .iter()
+                    .all(|(key, value)| stored.metadata.get(key) == Some(value))
+            })
+            .map(|stored| VectorMatch {
+                id: stored.id.clone(),
+                score: cosine_similarity(&vector, &stored.vector),
+                metadata: stored.metadata.clone(),
+            })
+            .collect();
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches.truncate(limit);
+        Ok(matches)
+    }
+
+    /// Computes cosine similarity locally.
+    pub async fn compute_similarity(&self, vector1: Vec<f32>, vector2: Vec<f32>) -> Result<f64> {
+        Ok(cosine_similarity(&vector1, &vector2))
+    }
+
+    // ------------------------------------------------------------------
+    // Graph knowledge
+    // ------------------------------------------------------------------
+
+    /// Creates a node in the local knowledge graph.
+    pub async fn create_graph_node(
+        &self,
+        id: &str,
+        label: &str,
+        node_type: NodeType,
+        properties: Option<HashMap<String, Value>>,
+    ) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        state.graph_nodes.insert(
+            id.to_string(),
+            GraphNode {
+                id: id.to_string(),
+                label: label.to_string(),
+                node_type,
+                properties: properties.unwrap_or_default(),
+                weight: 0.0,
+            },
+        );
+        Ok(true)
+    }
+
+    /// Creates an undirected edge between two nodes.
+    pub async fn connect_graph_nodes(
+        &self,
+        node_id1: &str,
+        node_id2: &str,
+        weight: f64,
+    ) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        if !state.graph_nodes.contains_key(node_id1) || !state.graph_nodes.contains_key(node_id2) {
+            return Err(BrainAIError::NotFound(format!(
+                "graph node {node_id1} or {node_id2}"
+            )));
+        }
+        state
+            .graph_edges
+            .insert(Self::edge_key(node_id1, node_id2), weight.clamp(0.0, 1.0));
+        Ok(true)
+    }
+
+    /// Creates a typed edge between two existing nodes.
+    pub async fn create_edge(
+        &self,
+        from: &str,
+        to: &str,
+        label: &str,
+        directed: bool,
+        weight: f64,
+        properties: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        let id = self.next_id("edge");
+        let mut state = self.state.lock().unwrap();
+        if !state.graph_nodes.contains_key(from) || !state.graph_nodes.contains_key(to) {
+            return Err(BrainAIError::NotFound(format!(
+                "graph node {from} or {to}"
+            )));
+        }
+        let weight = weight.clamp(0.0, 1.0);
+        state.typed_edges.insert(
+            id.clone(),
+            GraphEdge {
+                id: id.clone(),
+                from: from.to_string(),
+                to: to.to_string(),
+                label: label.to_string(),
+                directed,
+                properties: properties.unwrap_or_default(),
+                weight,
+            },
+        );
+        // Mirror for the traversal APIs, which see untyped edges.
+        state
+            .graph_edges
+            .insert(Self::edge_key(from, to), weight);
+        Ok(id)
+    }
+
+    /// Updates a typed edge; `None` fields are left unchanged.
+    pub async fn update_edge(
+        &self,
+        edge_id: &str,
+        label: Option<&str>,
+        weight: Option<f64>,
+        properties: Option<HashMap<String, Value>>,
+    ) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        let edge = state
+            .typed_edges
+            .get_mut(edge_id)
+            .ok_or_else(|| BrainAIError::NotFound(format!("graph edge {edge_id}")))?;
+        if let Some(label) = label {
+            edge.label = label.to_string();
+        }
+        if let Some(weight) = weight {
+            edge.weight = weight.clamp(0.0, 1.0);
+        }
+        if let Some(properties) = properties {
+            edge.properties = properties;
+        }
+        let (from, to, weight) = (edge.from.clone(), edge.to.clone(), edge.weight);
+        state.graph_edges.insert(Self::edge_key(&from, &to), weight);
+        Ok(true)
+    }
+
+    /// Deletes a typed edge; the traversal mirror stays only while other
+    /// typed edges still connect the same pair.
+    pub async fn delete_edge(&self, edge_id: &str) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        let edge = state
+            .typed_edges
+            .remove(edge_id)
+            .ok_or_else(|| BrainAIError::NotFound(format!("graph edge {edge_id}")))?;
+        let key = Self::edge_key(&edge.from, &edge.to);
+        let still_linked = state.typed_edges.values().any(|other| {
+            Self::edge_key(&other.from, &other.to) == key
+        });
+        if !still_linked {
+            state.graph_edges.remove(&key);
+        }
+        Ok(true)
+    }
+
+    /// Lists a node's typed edges, filtered by direction and label.
+    pub async fn get_edges(
+        &self,
+        node_id: &str,
+        direction: EdgeDirection,
+        label_filter: Option<&str>,
+    ) -> Result<Vec<GraphEdge>> {
+        let state = self.state.lock().unwrap();
+        if !state.graph_nodes.contains_key(node_id) {
+            return Err(BrainAIError::NotFound(format!("graph node {node_id}")));
+        }
+        let mut edges: Vec<GraphEdge> = state
+            .typed_edges
+            .values()
+            .filter(|edge| match direction {
+                EdgeDirection::Outgoing => {
+                    edge.from == node_id || (!edge.directed && edge.to == node_id)
+                }
+                EdgeDirection::Incoming => {
+                    edge.to == node_id || (!edge.directed && edge.from == node_id)
+                }
+                EdgeDirection::Any => edge.from == node_id || edge.to == node_id,
+            })
+            .filter(|edge| label_filter.is_none_or(|label| edge.label == label))
+            .cloned()
+            .collect();
+        edges.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(edges)
+    }
+
+    /// Gets neighbors up to `depth` hops away via breadth-first traversal.
+    pub async fn get_graph_neighbors(&self, node_id: &str, depth: u32) -> Result<Vec<GraphNode>> {
+        let state = self.state.lock().unwrap();
+        if !state.graph_nodes.contains_key(node_id) {
+            return Err(BrainAIError::NotFound(format!("graph node {node_id}")));
+        }
+        let mut visited: HashMap<String, f64> = HashMap::new();
+        let mut frontier = vec![node_id.to_string()];
+        for _ in 0..depth {
+            let mut next = Vec::new();
+            for current in frontier.drain(..) {
+                for ((a, b), weight) in &state.graph_edges {
+                    let other = if *a == current {
+                        b
+                    } else if *b == current {
+                        a
+                    } else {
+                        continue;
+                    };
+                    if other != node_id && !visited.contains_key(other) {
+                        visited.insert(other.clone(), *weight);
+                        next.push(other.clone());
+                    }
+                }
+            }
+            frontier = next;
+        }
+        let mut neighbors: Vec<GraphNode> = visited
+            .into_iter()
+            .filter_map(|(id, weight)| {
+                state.graph_nodes.get(&id).map(|node| {
+                    let mut node = node.clone();
+                    node.weight = weight;
+                    node
+                })
+            })
+            .collect();
+        neighbors.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(neighbors)
+    }
+
+    /// Finds a shortest path (by hop count) between two nodes.
+    pub async fn find_graph_path(
+        &self,
+        start_node: &str,
+        end_node: &str,
+    ) -> Result<Vec<GraphNode>> {
+        let state = self.state.lock().unwrap();
+        for id in [start_node, end_node] {
+            if !state.graph_nodes.contains_key(id) {
+                return Err(BrainAIError::NotFound(format!("graph node {id}")));
+            }
+        }
+        let mut previous: HashMap<String, String> = HashMap::new();
+        let mut queue = std::collections::VecDeque::from([start_node.to_string()]);
+        while let Some(current) = queue.pop_front() {
+            if current == end_node {
+                break;
+            }
+            for (a, b) in state.graph_edges.keys() {
+                let other = if *a == current {
+                    b
+                } else if *b == current {
+                    a
+                } else {
+                    continue;
+                };
+                if other != start_node && !previous.contains_key(other) {
+                    previous.insert(other.clone(), current.clone());
+                    queue.push_back(other.clone());
+                }
+            }
+        }
+        if start_node != end_node && !previous.contains_key(end_node) {
+            return Ok(Vec::new());
+        }
+        let mut path = vec![end_node.to_string()];
+        while let Some(prev) = previous.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Ok(path
+            .into_iter()
+            .filter_map(|id| state.graph_nodes.get(&id).cloned())
+            .collect())
+    }
+
+    // ------------------------------------------------------------------
+    // System management
+    // ------------------------------------------------------------------
+
+    /// Returns a healthy status for the local backend.
+    pub async fn get_status(&self) -> Result<SystemStatus> {
+        Ok(SystemStatus {
+            status: "healthy".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            uptime: ((now_millis() - self.started_at) / 1000).max(0) as u64,
+            memory_usage: 0.0,
+            cpu_usage: 0.0,
+            active_connections: 0,
+            issues: Vec::new(),
+        })
+    }
+
+    /// Returns statistics over the local state.
+    pub async fn get_statistics(&self) -> Result<SystemStatistics> {
+        let state = self.state.lock().unwrap();
+        let mut memory_types = HashMap::new();
+        for memory in state.memories.values() {
+            *memory_types
+                .entry(memory.memory_type.as_str().to_string())
+                .or_insert(0) += 1;
+        }
+        Ok(SystemStatistics {
+            total_memories: state.memories.len() as u64,
+            memory_types,
+            learning_patterns: state.patterns.len() as u64,
+            vector_count: state.vectors.len() as u64,
+            graph_nodes: state.graph_nodes.len() as u64,
+            average_response_time: 0,
+            cache_hit_rate: 0.0,
+        })
+    }
+
+    /// Clears all local data.
+    pub async fn clear_all(&self) -> Result<bool> {
+        *self.state.lock().unwrap() = MockState::default();
+        Ok(true)
+    }
+
+    /// Snapshots the local state as a backup.
+    pub async fn backup_data(&self) -> Result<BackupInfo> {
+        let backup_id = self.next_id("backup");
+        let mut state = self.state.lock().unwrap();
+        let snapshot = json!({
+            "memories": state.memories.values().collect::<Vec<_>>(),
+            "patterns": state.patterns.values().collect::<Vec<_>>(),
+        });
+        let size_mb = snapshot.to_string().len() as f64 / (1024.0 * 1024.0);
+        state.backups.insert(backup_id.clone(), snapshot);
+        Ok(BackupInfo {
+            backup_id,
+            size_mb,
+            created_at: now_millis(),
+        })
+    }
+
+    /// Restores memories and patterns from a backup snapshot.
+    pub async fn restore_data(&self, backup_id: &str) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        let snapshot = state
+            .backups
+            .get(backup_id)
+            .cloned()
+            .ok_or_else(|| BrainAIError::NotFound(format!("backup {backup_id}")))?;
+        if let Some(memories) = snapshot.get("memories").and_then(Value::as_array) {
+            state.memories.clear();
+            for value in memories {
+                if let Ok(memory) = serde_json::from_value::<Memory>(value.clone()) {
+                    state.memories.insert(memory.id.clone(), memory);
+                }
+            }
+        }
+        if let Some(patterns) = snapshot.get("patterns").and_then(Value::as_array) {
+            state.patterns.clear();
+            for value in patterns {
+                if let Ok(pattern) = serde_json::from_value::<LearningPattern>(value.clone()) {
+                    state.patterns.insert(pattern.pattern.clone(), pattern);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    // ------------------------------------------------------------------
+    // Utility methods
+    // ------------------------------------------------------------------
+
+    /// Dispatches batched operations against the local state, in order.
+    pub async fn batch(&self, operations: Vec<BatchOperation>) -> Result<Vec<BatchResult>> {
+        let mut results = Vec::with_capacity(operations.len());
+        for op in operations {
+            let outcome = match &op.operation_type {
+                OperationType::StoreMemory => {
+                    let content = op.data.get("content").cloned().unwrap_or(Value::Null);
+                    let memory_type = op
+                        .data
+                        .get("type")
+                        .cloned()
+                        .map(serde_json::from_value)
+                        .transpose()?
+                        .unwrap_or(MemoryType::Semantic);
+                    self.store_memory(content, memory_type, None)
+                        .await
+                        .map(|id| json!({"id": id}))
+                }
+                OperationType::Learn => {
+                    let pattern = op
+                        .data
+                        .get("pattern")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string();
+                    let context = op
+                        .data
+                        .get("context")
+                        .and_then(Value::as_array)
+                        .map(|ctx| {
+                            ctx.iter()
+                                .filter_map(Value::as_str)
+                                .map(str::to_string)
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    self.learn(&pattern, context).await.map(|ok| json!(ok))
+                }
+                OperationType::CreateNode => {
+                    let id = op.data.get("id").and_then(Value::as_str).unwrap_or_default();
+                    let label = op.data.get("label").and_then(Value::as_str).unwrap_or(id);
+                    let node_type = op
+                        .data
+                        .get("type")
+                        .and_then(Value::as_str)
+                        .map(NodeType::from)
+                        .unwrap_or(NodeType::Concept);
+                    self.create_graph_node(id, label, node_type, None)
+                        .await
+                        .map(|ok| json!(ok))
+                }
+                OperationType::CreateEdge => {
+                    let from = op.data.get("from").and_then(Value::as_str).unwrap_or_default();
+                    let to = op.data.get("to").and_then(Value::as_str).unwrap_or_default();
+                    let label = op.data.get("label").and_then(Value::as_str).unwrap_or_default();
+                    let directed = op
+                        .data
+                        .get("directed")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false);
+                    let weight = op.data.get("weight").and_then(Value::as_f64).unwrap_or(1.0);
+                    self.create_edge(from, to, label, directed, weight, None)
+                        .await
+                        .map(|id| json!({"id": id}))
+                }
+                other => Err(BrainAIError::InvalidInput(format!(
+                    "unsupported batch operation: {}",
+                    other.as_str()
+                ))),
+            };
+            results.push(match outcome {
+                Ok(data) => BatchResult {
+                    success: true,
+                    data: Some(data),
+                    error: None,
+                },
+                Err(err) => BatchResult {
+                    success: false,
+                    data: None,
+                    error: Some(err.to_string()),
+                },
+            });
+        }
+        Ok(results)
+    }
+
+    /// Stores many memories, mirroring the chunked batch semantics of
+    /// [`BrainAISDK::store_memories`](crate::BrainAISDK::store_memories).
+    pub async fn store_memories(
+        &self,
+        writes: Vec<MemoryWrite>,
+        _chunk_size: usize,
+    ) -> Result<StoreManyReport> {
+        let mut report = StoreManyReport::default();
+        for (index, write) in writes.into_iter().enumerate() {
+            match self
+                .store_memory(write.content, write.memory_type, Some(write.metadata))
+                .await
+            {
+                Ok(id) => report.stored.push((index, id)),
+                Err(err) => report.failed.push((index, err.to_string())),
+            }
+        }
+        Ok(report)
+    }
+
+    /// The local backend is always healthy.
+    pub async fn health_check(&self) -> Result<bool> {
+        Ok(true)
+    }
+}
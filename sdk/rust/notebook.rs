@@ -0,0 +1,224 @@
+//! Jupyter/evcxr-friendly helpers.
+//!
+//! Notebooks want quick visual feedback and no async plumbing. This module
+//! provides plain-text tables for search results and memories, an inline
+//! summary of reasoning output, truncation of huge content fields, and
+//! [`SyncBrain`] — a blocking facade over the SDK that owns its own tokio
+//! runtime so every call is a plain method in an evcxr cell.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+use crate::{
+    BrainAIConfig, BrainAIError, BrainAISDK, Memory, MemoryType, ReasoningResult, Result,
+    SearchResult,
+};
+
+/// Truncates a JSON content value to a single-line preview of at most
+/// `max_chars` characters, ellipsizing on a char boundary.
+pub fn preview(content: &Value, max_chars: usize) -> String {
+    let text = match content {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    let flat: String = text
+        .chars()
+        .map(|c| if c == '\n' || c == '\r' { ' ' } else { c })
+        .collect();
+    if flat.chars().count() <= max_chars {
+        flat
+    } else {
+        let cut: String = flat.chars().take(max_chars.saturating_sub(1)).collect();
+        format!("{cut}…")
+    }
+}
+
+fn pad(text: &str, width: usize) -> String {
+    let len = text.chars().count();
+    if len >= width {
+        text.to_string()
+    } else {
+        format!("{text}{}", " ".repeat(width - len))
+    }
+}
+
+/// Renders search results as a plain-text table for notebook output.
+pub fn results_table(results: &[SearchResult]) -> String {
+    let mut out = String::new();
+    let id_width = results
+        .iter()
+        .map(|r| r.id.chars().count())
+        .max()
+        .unwrap_or(2)
+        .max(2);
+    let _ = writeln!(
+        out,
+        "{}  {}  content",
+        pad("id", id_width),
+        pad("score", 6),
+    );
+    let _ = writeln!(out, "{}", "-".repeat(id_width + 50));
+    for result in results {
+        let _ = writeln!(
+            out,
+            "{}  {}  {}",
+            pad(&result.id, id_width),
+            pad(&format!("{:.3}", result.score), 6),
+            preview(&result.content, 60)
+        );
+    }
+    if results.is_empty() {
+        out.push_str("(no results)\n");
+    }
+    out
+}
+
+/// Renders memories as a plain-text table for notebook output.
+pub fn memories_table(memories: &[Memory]) -> String {
+    let mut out = String::new();
+    let id_width = memories
+        .iter()
+        .map(|m| m.id.chars().count())
+        .max()
+        .unwrap_or(2)
+        .max(2);
+    let _ = writeln!(
+        out,
+        "{}  {}  {}  content",
+        pad("id", id_width),
+        pad("type", 10),
+        pad("strength", 8),
+    );
+    let _ = writeln!(out, "{}", "-".repeat(id_width + 60));
+    for memory in memories {
+        let _ = writeln!(
+            out,
+            "{}  {}  {}  {}",
+            pad(&memory.id, id_width),
+            pad(memory.memory_type.as_str(), 10),
+            pad(&format!("{:.2}", memory.strength), 8),
+            preview(&memory.content, 50)
+        );
+    }
+    if memories.is_empty() {
+        out.push_str("(no memories)\n");
+    }
+    out
+}
+
+/// Renders a reasoning result as a short inline summary: conclusion,
+/// confidence, and the reasoning path with evidence counts.
+pub fn reasoning_summary(result: &ReasoningResult) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "conclusion ({:.0}% confidence): {}",
+        result.confidence * 100.0,
+        result.conclusion
+    );
+    for (step, line) in result.reasoning_path.iter().enumerate() {
+        let _ = writeln!(out, "  {}. {}", step + 1, line);
+    }
+    if !result.supporting_evidence.is_empty() {
+        let _ = writeln!(
+            out,
+            "  evidence: {} item(s), e.g. {}",
+            result.supporting_evidence.len(),
+            preview(&Value::String(result.supporting_evidence[0].clone()), 60)
+        );
+    }
+    out
+}
+
+/// Blocking facade over [`BrainAISDK`] for notebooks and other sync
+/// contexts. Owns a private single-threaded tokio runtime; every method
+/// blocks until the call completes.
+///
+/// Do not use from inside an async context — blocking a runtime thread on
+/// another runtime deadlocks. This type exists for evcxr cells, scripts,
+/// and REPLs.
+pub struct SyncBrain {
+    sdk: BrainAISDK,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl SyncBrain {
+    /// Connects to a server; `SyncBrain::connect("http://localhost:8000")`
+    /// is the usual one-liner at the top of a notebook.
+    pub fn connect(base_url: impl Into<String>) -> Result<Self> {
+        SyncBrain::with_config(BrainAIConfig::new(base_url))
+    }
+
+    /// Connects with an explicit configuration.
+    pub fn with_config(config: BrainAIConfig) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|err| {
+                BrainAIError::InvalidInput(format!("cannot start runtime: {err}"))
+            })?;
+        let sdk = BrainAISDK::new(config)?;
+        Ok(SyncBrain { sdk, runtime })
+    }
+
+    /// The async SDK, for the occasional call this facade doesn't wrap.
+    pub fn sdk(&self) -> &BrainAISDK {
+        &self.sdk
+    }
+
+    /// Runs any future against this facade's runtime.
+    pub fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Stores a memory; see [`BrainAISDK::store_memory`].
+    pub fn store_memory(
+        &self,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        self.block_on(self.sdk.store_memory(content, memory_type, metadata))
+    }
+
+    /// Retrieves a memory; see [`BrainAISDK::get_memory`].
+    pub fn get_memory(&self, id: &str) -> Result<Option<Memory>> {
+        self.block_on(self.sdk.get_memory(id))
+    }
+
+    /// Searches memories; see [`BrainAISDK::search_memories`].
+    pub fn search_memories(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        self.block_on(self.sdk.search_memories(query, limit))
+    }
+
+    /// Searches and renders the hits with [`results_table`] in one call.
+    pub fn show_search(&self, query: &str, limit: usize) -> Result<String> {
+        Ok(results_table(&self.search_memories(query, limit)?))
+    }
+
+    /// Reasons over a query; see [`BrainAISDK::reason`].
+    pub fn reason(&self, query: &str, context: Vec<String>) -> Result<ReasoningResult> {
+        self.block_on(self.sdk.reason(query, context))
+    }
+
+    /// Reasons and renders the outcome with [`reasoning_summary`].
+    pub fn show_reasoning(&self, query: &str, context: Vec<String>) -> Result<String> {
+        Ok(reasoning_summary(&self.reason(query, context)?))
+    }
+
+    /// Lists memories; see [`BrainAISDK::list_memories`].
+    pub fn list_memories(
+        &self,
+        filters: Option<HashMap<String, Value>>,
+        limit: usize,
+    ) -> Result<Vec<Memory>> {
+        self.block_on(self.sdk.list_memories(filters, limit))
+    }
+
+    /// Deletes a memory; see [`BrainAISDK::delete_memory`].
+    pub fn delete_memory(&self, id: &str) -> Result<bool> {
+        self.block_on(self.sdk.delete_memory(id))
+    }
+}
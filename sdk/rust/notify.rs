@@ -0,0 +1,219 @@
+//! Notification channel integrations for brain events.
+//!
+//! A [`BrainEvent`] describes something noteworthy that happened in the
+//! brain — a memory stored or deleted, a pattern learned, a strength
+//! change. [`NotificationChannel`] implementations deliver events to the
+//! outside world (generic webhooks, Slack incoming webhooks, logs), and a
+//! [`Notifier`] fans one event out to every configured channel.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIError, Result};
+
+/// An event emitted by the brain worth notifying about.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum BrainEvent {
+    /// A memory was stored.
+    MemoryStored { memory_id: String },
+    /// A memory was deleted.
+    MemoryDeleted { memory_id: String },
+    /// A memory's strength changed by `delta`.
+    StrengthChanged { memory_id: String, delta: f64 },
+    /// A memory's strength decayed below the retention threshold.
+    MemoryDecayed { memory_id: String, strength: f64 },
+    /// A pattern was learned or reinforced.
+    PatternLearned { pattern: String, strength: f64 },
+    /// A reasoning run finished.
+    ReasoningCompleted {
+        reasoning_id: String,
+        confidence: f64,
+    },
+    /// A staged write was approved or rejected.
+    WriteReviewed { staging_id: String, approved: bool },
+}
+
+impl BrainEvent {
+    /// One-line human-readable rendering used by text channels.
+    pub fn describe(&self) -> String {
+        match self {
+            BrainEvent::MemoryStored { memory_id } => {
+                format!("Memory stored: {memory_id}")
+            }
+            BrainEvent::MemoryDeleted { memory_id } => {
+                format!("Memory deleted: {memory_id}")
+            }
+            BrainEvent::StrengthChanged { memory_id, delta } => {
+                format!("Memory {memory_id} strength changed by {delta:+.2}")
+            }
+            BrainEvent::MemoryDecayed {
+                memory_id,
+                strength,
+            } => {
+                format!("Memory {memory_id} decayed to strength {strength:.2}")
+            }
+            BrainEvent::PatternLearned { pattern, strength } => {
+                format!("Pattern learned: \"{pattern}\" (strength {strength:.2})")
+            }
+            BrainEvent::ReasoningCompleted {
+                reasoning_id,
+                confidence,
+            } => {
+                format!("Reasoning {reasoning_id} completed (confidence {confidence:.2})")
+            }
+            BrainEvent::WriteReviewed {
+                staging_id,
+                approved,
+            } => {
+                let verdict = if *approved { "approved" } else { "rejected" };
+                format!("Staged write {staging_id} {verdict}")
+            }
+        }
+    }
+}
+
+/// Delivers brain events to an external destination.
+#[async_trait]
+pub trait NotificationChannel: Send + Sync {
+    /// Channel name used in error messages and logs.
+    fn name(&self) -> &str;
+
+    /// Delivers one event.
+    async fn notify(&self, event: &BrainEvent) -> Result<()>;
+}
+
+/// Posts each event as JSON to an arbitrary webhook URL.
+#[derive(Debug, Clone)]
+pub struct WebhookChannel {
+    url: String,
+    http: reqwest::Client,
+}
+
+impl WebhookChannel {
+    /// Creates a channel posting to the given URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        WebhookChannel {
+            url: url.into(),
+            http: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for WebhookChannel {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    async fn notify(&self, event: &BrainEvent) -> Result<()> {
+        let body = json!({
+            "timestamp": now_millis(),
+            "description": event.describe(),
+            "payload": event,
+        });
+        let response = self.http.post(&self.url).json(&body).send().await?;
+        if !response.status().is_success() {
+            return Err(BrainAIError::Api {
+                status: response.status().as_u16(),
+                message: format!("webhook delivery to {} failed", self.url),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Sends each event to a Slack incoming webhook as a text message.
+#[derive(Debug, Clone)]
+pub struct SlackChannel {
+    webhook_url: String,
+    http: reqwest::Client,
+}
+
+impl SlackChannel {
+    /// Creates a channel posting to a Slack incoming-webhook URL.
+    pub fn new(webhook_url: impl Into<String>) -> Self {
+        SlackChannel {
+            webhook_url: webhook_url.into(),
+            http: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for SlackChannel {
+    fn name(&self) -> &str {
+        "slack"
+    }
+
+    async fn notify(&self, event: &BrainEvent) -> Result<()> {
+        let body = json!({"text": format!("🧠 {}", event.describe())});
+        let response = self.http.post(&self.webhook_url).json(&body).send().await?;
+        if !response.status().is_success() {
+            return Err(BrainAIError::Api {
+                status: response.status().as_u16(),
+                message: "slack delivery failed".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Writes each event to standard error; useful in development.
+#[derive(Debug, Clone, Default)]
+pub struct LogChannel;
+
+#[async_trait]
+impl NotificationChannel for LogChannel {
+    fn name(&self) -> &str {
+        "log"
+    }
+
+    async fn notify(&self, event: &BrainEvent) -> Result<()> {
+        eprintln!("[brain-ai] {}", event.describe());
+        Ok(())
+    }
+}
+
+/// Fans one event out to every configured channel.
+///
+/// Delivery is best-effort per channel: a failing channel does not block
+/// the others, and the first error is returned after all deliveries were
+/// attempted.
+#[derive(Default)]
+pub struct Notifier {
+    channels: Vec<Box<dyn NotificationChannel>>,
+}
+
+impl Notifier {
+    /// Creates a notifier with no channels.
+    pub fn new() -> Self {
+        Notifier::default()
+    }
+
+    /// Adds a delivery channel.
+    pub fn with_channel(mut self, channel: impl NotificationChannel + 'static) -> Self {
+        self.channels.push(Box::new(channel));
+        self
+    }
+
+    /// Delivers the event to every channel.
+    pub async fn broadcast(&self, event: &BrainEvent) -> Result<()> {
+        let mut first_error = None;
+        for channel in &self.channels {
+            if let Err(err) = channel.notify(event).await {
+                eprintln!(
+                    "[brain-ai] notification via {} failed: {err}",
+                    channel.name()
+                );
+                first_error.get_or_insert(err);
+            }
+        }
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Offline mode with a durable write-ahead queue.
+//!
+//! Edge and IoT deployments cannot assume the server is reachable.
+//! [`OfflineQueue`] wraps the write operations that must not be lost —
+//! store, learn, feedback — and tries the server first; when the
+//! attempt fails at the transport (or the circuit breaker is open), the
+//! write is appended to a durable local queue file (one JSON object per
+//! line) instead of being dropped, and the caller continues.
+//! [`sync`](OfflineQueue::sync) replays the queue in original order
+//! once connectivity returns: entries the server now rejects — content
+//! that conflicts with writes made elsewhere in the meantime — are
+//! removed from the queue and reported as conflicts rather than
+//! blocking everything behind them, while a transport failure stops the
+//! replay with the remaining entries intact for the next attempt.
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write as _};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::Mutex;
+
+use crate::client::BrainAIClient;
+use crate::vector_utils::now_millis;
+use crate::{BrainAIError, MemoryType, Result};
+
+/// One queued write operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum QueuedWrite {
+    StoreMemory {
+        content: Value,
+        #[serde(rename = "type")]
+        memory_type: MemoryType,
+        #[serde(default)]
+        metadata: HashMap<String, Value>,
+    },
+    Learn {
+        pattern: String,
+        context: Vec<String>,
+    },
+    Feedback {
+        feedback_type: String,
+        information: String,
+        reasoning: Option<String>,
+    },
+}
+
+/// One line of the queue file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QueueEntry {
+    /// Monotonic sequence number; replay order.
+    seq: u64,
+    /// When the write was queued (unix milliseconds).
+    queued_at: i64,
+    write: QueuedWrite,
+}
+
+/// How a write through the queue was handled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WriteOutcome {
+    /// The server took the write; the ID is present for stores.
+    Sent(Option<String>),
+    /// The server was unreachable; the write is queued under this
+    /// sequence number and will be replayed by [`OfflineQueue::sync`].
+    Queued(u64),
+}
+
+/// Outcome of one sync pass.
+#[derive(Debug, Default, Clone)]
+pub struct SyncReport {
+    /// Entries the server accepted this pass.
+    pub replayed: usize,
+    /// Entries the server rejected, as `(sequence, error)`; they are
+    /// removed from the queue — retrying them can never succeed.
+    pub conflicts: Vec<(u64, String)>,
+    /// Entries still queued (connectivity dropped again mid-replay).
+    pub remaining: usize,
+}
+
+impl SyncReport {
+    /// `true` when the queue is drained and nothing conflicted.
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty() && self.remaining == 0
+    }
+}
+
+/// Durable offline write queue over any client; see the module docs.
+pub struct OfflineQueue {
+    client: Arc<dyn BrainAIClient>,
+    path: PathBuf,
+    /// Serializes queue file access and carries the next sequence number.
+    state: Mutex<u64>,
+}
+
+impl OfflineQueue {
+    /// Opens the queue at `path`, creating it on first write. An
+    /// existing queue file is picked up where it left off.
+    pub fn open(client: Arc<dyn BrainAIClient>, path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let next_seq = read_entries(&path)?
+            .last()
+            .map(|entry| entry.seq + 1)
+            .unwrap_or(0);
+        Ok(OfflineQueue {
+            client,
+            path,
+            state: Mutex::new(next_seq),
+        })
+    }
+
+    /// Stores a memory, queueing it if the server is unreachable.
+    pub async fn store_memory(
+        &self,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<WriteOutcome> {
+        let metadata = metadata.unwrap_or_default();
+        match self
+            .client
+            .store_memory(content.clone(), memory_type, Some(metadata.clone()))
+            .await
+        {
+            Ok(id) => Ok(WriteOutcome::Sent(Some(id))),
+            Err(err) if is_unreachable(&err) => {
+                self.enqueue(QueuedWrite::StoreMemory {
+                    content,
+                    memory_type,
+                    metadata,
+                })
+                .await
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Learns a pattern, queueing it if the server is unreachable.
+    pub async fn learn(&self, pattern: &str, context: Vec<String>) -> Result<WriteOutcome> {
+        match self.client.learn(pattern, context.clone()).await {
+            Ok(_) => Ok(WriteOutcome::Sent(None)),
+            Err(err) if is_unreachable(&err) => {
+                self.enqueue(QueuedWrite::Learn {
+                    pattern: pattern.to_string(),
+                    context,
+                })
+                .await
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Adds feedback, queueing it if the server is unreachable.
+    pub async fn add_feedback(
+        &self,
+        feedback_type: &str,
+        information: &str,
+        reasoning: Option<&str>,
+    ) -> Result<WriteOutcome> {
+        match self
+            .client
+            .add_feedback(feedback_type, information, reasoning)
+            .await
+        {
+            Ok(_) => Ok(WriteOutcome::Sent(None)),
+            Err(err) if is_unreachable(&err) => {
+                self.enqueue(QueuedWrite::Feedback {
+                    feedback_type: feedback_type.to_string(),
+                    information: information.to_string(),
+                    reasoning: reasoning.map(str::to_string),
+                })
+                .await
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Number of writes waiting to be synced.
+    pub async fn pending(&self) -> Result<usize> {
+        let _state = self.state.lock().await;
+        Ok(read_entries(&self.path)?.len())
+    }
+
+    /// Replays the queue in order; see the module docs for how
+    /// conflicts and renewed connectivity loss are handled.
+    pub async fn sync(&self) -> Result<SyncReport> {
+        let _state = self.state.lock().await;
+        let entries = read_entries(&self.path)?;
+        let mut report = SyncReport::default();
+        let mut remaining = Vec::new();
+        let mut iter = entries.into_iter();
+        for entry in iter.by_ref() {
+            match self.replay(&entry.write).await {
+                Ok(()) => report.replayed += 1,
+                Err(err) if is_unreachable(&err) => {
+                    // Still (or again) offline; keep this and the rest.
+                    remaining.push(entry);
+                    remaining.extend(iter.by_ref());
+                    break;
+                }
+                Err(err) => report.conflicts.push((entry.seq, err.to_string())),
+            }
+        }
+        report.remaining = remaining.len();
+        write_entries(&self.path, &remaining)?;
+        Ok(report)
+    }
+
+    /// Spawns a background task syncing every `interval`; the handle
+    /// can be aborted to stop it.
+    pub fn spawn(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                match self.sync().await {
+                    Ok(report) if report.replayed > 0 || !report.conflicts.is_empty() => {
+                        eprintln!(
+                            "[brain-ai] offline sync: {{\"replayed\":{},\"conflicts\":{},\"remaining\":{}}}",
+                            report.replayed,
+                            report.conflicts.len(),
+                            report.remaining
+                        );
+                    }
+                    Ok(_) => {}
+                    Err(err) => eprintln!("[brain-ai] offline sync failed: {err}"),
+                }
+            }
+        })
+    }
+
+    /// Appends one write to the queue file and returns its outcome.
+    async fn enqueue(&self, write: QueuedWrite) -> Result<WriteOutcome> {
+        let mut next_seq = self.state.lock().await;
+        let entry = QueueEntry {
+            seq: *next_seq,
+            queued_at: now_millis(),
+            write,
+        };
+        let line = serde_json::to_string(&entry).map_err(BrainAIError::Serialization)?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|err| {
+                BrainAIError::InvalidInput(format!(
+                    "cannot open offline queue {}: {err}",
+                    self.path.display()
+                ))
+            })?;
+        writeln!(file, "{line}").map_err(|err| {
+            BrainAIError::InvalidInput(format!("cannot write offline queue: {err}"))
+        })?;
+        *next_seq += 1;
+        Ok(WriteOutcome::Queued(entry.seq))
+    }
+
+    /// Issues one queued write against the server.
+    async fn replay(&self, write: &QueuedWrite) -> Result<()> {
+        match write {
+            QueuedWrite::StoreMemory {
+                content,
+                memory_type,
+                metadata,
+            } => {
+                self.client
+                    .store_memory(content.clone(), *memory_type, Some(metadata.clone()))
+                    .await?;
+            }
+            QueuedWrite::Learn { pattern, context } => {
+                self.client.learn(pattern, context.clone()).await?;
+            }
+            QueuedWrite::Feedback {
+                feedback_type,
+                information,
+                reasoning,
+            } => {
+                self.client
+                    .add_feedback(feedback_type, information, reasoning.as_deref())
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether an error means "the server could not be reached" — the only
+/// condition under which queueing (rather than failing) is correct.
+fn is_unreachable(err: &BrainAIError) -> bool {
+    matches!(
+        err,
+        BrainAIError::Http(_) | BrainAIError::CircuitOpen(_)
+    )
+}
+
+fn read_entries(path: &PathBuf) -> Result<Vec<QueueEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let file = std::fs::File::open(path).map_err(|err| {
+        BrainAIError::InvalidInput(format!(
+            "cannot read offline queue {}: {err}",
+            path.display()
+        ))
+    })?;
+    let mut entries: Vec<QueueEntry> = Vec::new();
+    for line in std::io::BufReader::new(file).lines() {
+        let line = line.map_err(|err| {
+            BrainAIError::InvalidInput(format!("cannot read offline queue: {err}"))
+        })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        entries.push(serde_json::from_str(&line).map_err(BrainAIError::Serialization)?);
+    }
+    entries.sort_by_key(|entry| entry.seq);
+    Ok(entries)
+}
+
+/// Rewrites the queue atomically so a crash mid-sync cannot lose writes.
+fn write_entries(path: &PathBuf, entries: &[QueueEntry]) -> Result<()> {
+    let tmp = path.with_extension("jsonl.tmp");
+    let mut out = String::new();
+    for entry in entries {
+        out.push_str(&serde_json::to_string(entry).map_err(BrainAIError::Serialization)?);
+        out.push('\n');
+    }
+    std::fs::write(&tmp, out)
+        .and_then(|()| std::fs::rename(&tmp, path))
+        .map_err(|err| {
+            BrainAIError::InvalidInput(format!(
+                "cannot rewrite offline queue {}: {err}",
+                path.display()
+            ))
+        })
+}
@@ -0,0 +1,110 @@
+//! OpenTelemetry propagation and spans, behind the `otel` feature.
+//!
+//! With the feature on, every request carries W3C `traceparent` /
+//! `tracestate` headers injected from the current OpenTelemetry context
+//! through the globally configured text-map propagator, and each
+//! memory, vector, graph, learning, and reasoning call records a
+//! client-kind span — so brain calls appear inside the application's
+//! distributed traces, parented to whatever span was current when the
+//! SDK was called. With the feature off, [`OtelCall`] compiles to
+//! nothing.
+//!
+//! This is independent of the `tracing` feature: that one emits
+//! `tracing` spans for a local subscriber, this one speaks the
+//! `opentelemetry` API directly for cross-service propagation. Both can
+//! be enabled together.
+
+#[cfg(feature = "otel")]
+use opentelemetry::{
+    global,
+    trace::{SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+
+use crate::slowlog::operation_label;
+
+/// Header injector writing into the SDK's header list.
+#[cfg(feature = "otel")]
+struct HeaderInjector<'a>(&'a mut Vec<(String, String)>);
+
+#[cfg(feature = "otel")]
+impl opentelemetry::propagation::Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        self.0.push((key.to_string(), value));
+    }
+}
+
+/// One API call's OpenTelemetry span; a no-op shell unless the `otel`
+/// feature is enabled.
+#[cfg(feature = "otel")]
+pub(crate) struct OtelCall {
+    cx: Context,
+}
+
+#[cfg(not(feature = "otel"))]
+pub(crate) struct OtelCall;
+
+impl OtelCall {
+    /// Starts a client span for one call and injects its trace context
+    /// into `headers` as `traceparent` / `tracestate`.
+    pub(crate) fn begin(
+        method: &reqwest::Method,
+        path: &str,
+        headers: &mut Vec<(String, String)>,
+    ) -> Self {
+        let label = operation_label(path);
+        #[cfg(feature = "otel")]
+        {
+            let tracer = global::tracer("brain-ai");
+            let span = tracer
+                .span_builder(format!("{method} {label}"))
+                .with_kind(SpanKind::Client)
+                .with_attributes([
+                    KeyValue::new("http.request.method", method.to_string()),
+                    KeyValue::new("url.path", path.to_string()),
+                    KeyValue::new("brain_ai.operation", label),
+                ])
+                .start(&tracer);
+            let cx = Context::current_with_span(span);
+            global::get_text_map_propagator(|propagator| {
+                propagator.inject_context(&cx, &mut HeaderInjector(headers));
+            });
+            OtelCall { cx }
+        }
+        #[cfg(not(feature = "otel"))]
+        {
+            let _ = (method, label, headers);
+            OtelCall
+        }
+    }
+
+    /// Records the response status and ends the span.
+    pub(crate) fn finish(&self, status: u16) {
+        #[cfg(feature = "otel")]
+        {
+            let span = self.cx.span();
+            span.set_attribute(KeyValue::new(
+                "http.response.status_code",
+                i64::from(status),
+            ));
+            if status >= 400 {
+                span.set_status(Status::error(format!("http status {status}")));
+            }
+            span.end();
+        }
+        #[cfg(not(feature = "otel"))]
+        let _ = status;
+    }
+
+    /// Records a failed exchange and ends the span.
+    pub(crate) fn fail(&self, error: &str) {
+        #[cfg(feature = "otel")]
+        {
+            let span = self.cx.span();
+            span.set_status(Status::error(error.to_string()));
+            span.end();
+        }
+        #[cfg(not(feature = "otel"))]
+        let _ = error;
+    }
+}
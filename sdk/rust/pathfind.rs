@@ -0,0 +1,333 @@
+//! Graph path-finding: shortest path and all paths up to a depth.
+//!
+//! Multi-hop reasoning is only explainable if the hops can be shown.
+//! [`BrainAISDK::find_path`] and [`BrainAISDK::find_all_paths`] ask the
+//! server's path endpoints and, on servers that predate them, fall back
+//! to a client-side search over [`get_graph_neighbors`]: breadth-first
+//! for hop counts, Dijkstra over `1 - weight` costs for weighted paths.
+//! Paths come back as the ordered node sequence with the edges walked.
+
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::client::BrainAIClient;
+use crate::{
+    BrainAIError, BrainAISDK, EdgeDirection, Endpoint, GraphEdge, GraphNode, NodeType, Result,
+};
+
+/// How path cost is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightMode {
+    /// Fewest edges; weights are ignored.
+    Hops,
+    /// Cheapest total cost, where each edge costs `1 - weight` (strong
+    /// connections are cheap to cross).
+    Weighted,
+}
+
+/// An ordered path through the graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphPath {
+    /// Nodes from start to end inclusive.
+    pub nodes: Vec<GraphNode>,
+    /// Edges walked, one fewer than nodes; empty when the server or
+    /// fallback could not resolve typed edges.
+    #[serde(default)]
+    pub edges: Vec<GraphEdge>,
+    /// Total cost under the requested [`WeightMode`].
+    pub cost: f64,
+}
+
+impl BrainAISDK {
+    /// Finds the best path between two nodes, or `None` when no path
+    /// exists within `max_depth` hops.
+    ///
+    /// Uses the server's path endpoint when available and otherwise
+    /// searches client-side over the neighbor API.
+    pub async fn find_path(
+        &self,
+        from: &str,
+        to: &str,
+        max_depth: u32,
+        weight_mode: WeightMode,
+    ) -> Result<Option<GraphPath>> {
+        let body = json!({
+            "from": from,
+            "to": to,
+            "max_depth": max_depth,
+            "weight_mode": weight_mode,
+        });
+        match self
+            .request::<Option<GraphPath>>(Endpoint::FindPath, Some(body))
+            .await
+        {
+            Ok(path) => Ok(path),
+            // Endpoint absent on this server: search locally.
+            Err(BrainAIError::NotFound(_)) => {
+                find_path_local(self, from, to, max_depth, weight_mode).await
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Finds every path between two nodes up to `max_depth` hops, capped
+    /// at `max_paths`, ordered cheapest first.
+    pub async fn find_all_paths(
+        &self,
+        from: &str,
+        to: &str,
+        max_depth: u32,
+        max_paths: usize,
+    ) -> Result<Vec<GraphPath>> {
+        let body = json!({
+            "from": from,
+            "to": to,
+            "max_depth": max_depth,
+            "max_paths": max_paths,
+        });
+        match self
+            .request::<Vec<GraphPath>>(Endpoint::FindAllPaths, Some(body))
+            .await
+        {
+            Ok(paths) => Ok(paths),
+            Err(BrainAIError::NotFound(_)) => {
+                find_all_paths_local(self, from, to, max_depth, max_paths).await
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Adjacency discovered incrementally through the client APIs.
+struct Explorer<'a> {
+    client: &'a dyn BrainAIClient,
+    nodes: HashMap<String, GraphNode>,
+    /// `neighbors[id]` = (other id, weight, typed edge if known).
+    neighbors: HashMap<String, Vec<(String, f64, Option<GraphEdge>)>>,
+}
+
+impl<'a> Explorer<'a> {
+    fn new(client: &'a dyn BrainAIClient) -> Self {
+        Explorer {
+            client,
+            nodes: HashMap::new(),
+            neighbors: HashMap::new(),
+        }
+    }
+
+    /// Fetches (and caches) a node's direct neighborhood.
+    async fn expand(&mut self, id: &str) -> Result<&[(String, f64, Option<GraphEdge>)]> {
+        if !self.neighbors.contains_key(id) {
+            let direct = self.client.get_graph_neighbors(id, 1).await?;
+            // Typed edges are best-effort: older servers answer NotFound.
+            let typed = match self.client.get_edges(id, EdgeDirection::Outgoing, None).await {
+                Ok(edges) => edges,
+                Err(BrainAIError::NotFound(_)) => Vec::new(),
+                Err(err) => return Err(err),
+            };
+            let mut list = Vec::with_capacity(direct.len());
+            for neighbor in direct {
+                if neighbor.id == id {
+                    continue;
+                }
+                let edge = typed
+                    .iter()
+                    .find(|e| e.from == neighbor.id || e.to == neighbor.id)
+                    .cloned();
+                list.push((neighbor.id.clone(), neighbor.weight, edge));
+                self.nodes.insert(neighbor.id.clone(), neighbor);
+            }
+            self.neighbors.insert(id.to_string(), list);
+        }
+        Ok(&self.neighbors[id])
+    }
+
+    /// Resolves a node, fetching it as its own 0-hop neighborhood root.
+    async fn node(&mut self, id: &str) -> Result<GraphNode> {
+        if let Some(node) = self.nodes.get(id) {
+            return Ok(node.clone());
+        }
+        // Expanding the node checks its existence as a side effect.
+        self.expand(id).await?;
+        Ok(self.nodes.get(id).cloned().unwrap_or(GraphNode {
+            id: id.to_string(),
+            label: id.to_string(),
+            node_type: NodeType::from("unknown"),
+            properties: HashMap::new(),
+            weight: 0.0,
+        }))
+    }
+
+    fn assemble(&self, ids: &[String], cost: f64) -> GraphPath {
+        let nodes = ids
+            .iter()
+            .map(|id| {
+                self.nodes.get(id).cloned().unwrap_or(GraphNode {
+                    id: id.clone(),
+                    label: id.clone(),
+                    node_type: NodeType::from("unknown"),
+                    properties: HashMap::new(),
+                    weight: 0.0,
+                })
+            })
+            .collect();
+        let mut edges = Vec::new();
+        for pair in ids.windows(2) {
+            let known = self
+                .neighbors
+                .get(&pair[0])
+                .and_then(|list| {
+                    list.iter()
+                        .find(|(other, _, _)| *other == pair[1])
+                        .and_then(|(_, _, edge)| edge.clone())
+                });
+            if let Some(edge) = known {
+                edges.push(edge);
+            } else {
+                edges.clear();
+                break;
+            }
+        }
+        GraphPath { nodes, edges, cost }
+    }
+}
+
+fn edge_cost(weight: f64, mode: WeightMode) -> f64 {
+    match mode {
+        WeightMode::Hops => 1.0,
+        // Clamp so a weight of exactly 1.0 still costs a sliver and path
+        // lengths stay comparable.
+        WeightMode::Weighted => (1.0 - weight.clamp(0.0, 1.0)).max(0.001),
+    }
+}
+
+#[derive(PartialEq)]
+struct Frontier {
+    cost: f64,
+    depth: u32,
+    id: String,
+}
+
+impl Eq for Frontier {}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Min-heap by cost.
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Client-side best path: Dijkstra (uniform costs degrade to BFS) over
+/// incrementally fetched neighborhoods.
+pub async fn find_path_local(
+    client: &dyn BrainAIClient,
+    from: &str,
+    to: &str,
+    max_depth: u32,
+    weight_mode: WeightMode,
+) -> Result<Option<GraphPath>> {
+    let mut explorer = Explorer::new(client);
+    explorer.node(from).await?;
+    if from == to {
+        let cost = 0.0;
+        return Ok(Some(explorer.assemble(&[from.to_string()], cost)));
+    }
+    let mut best: HashMap<String, f64> = HashMap::from([(from.to_string(), 0.0)]);
+    let mut previous: HashMap<String, String> = HashMap::new();
+    let mut heap = BinaryHeap::from([Frontier {
+        cost: 0.0,
+        depth: 0,
+        id: from.to_string(),
+    }]);
+    while let Some(Frontier { cost, depth, id }) = heap.pop() {
+        if id == to {
+            let mut ids = vec![id.clone()];
+            let mut current = id;
+            while let Some(prev) = previous.get(&current) {
+                ids.push(prev.clone());
+                current = prev.clone();
+            }
+            ids.reverse();
+            return Ok(Some(explorer.assemble(&ids, cost)));
+        }
+        if depth >= max_depth || cost > *best.get(&id).unwrap_or(&f64::MAX) {
+            continue;
+        }
+        let expansions: Vec<(String, f64)> = explorer
+            .expand(&id)
+            .await?
+            .iter()
+            .map(|(other, weight, _)| (other.clone(), *weight))
+            .collect();
+        for (other, weight) in expansions {
+            let next_cost = cost + edge_cost(weight, weight_mode);
+            if next_cost < *best.get(&other).unwrap_or(&f64::MAX) {
+                best.insert(other.clone(), next_cost);
+                previous.insert(other.clone(), id.clone());
+                heap.push(Frontier {
+                    cost: next_cost,
+                    depth: depth + 1,
+                    id: other,
+                });
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Client-side exhaustive search: depth-first enumeration of simple paths
+/// up to `max_depth`, capped at `max_paths`, cheapest first.
+pub async fn find_all_paths_local(
+    client: &dyn BrainAIClient,
+    from: &str,
+    to: &str,
+    max_depth: u32,
+    max_paths: usize,
+) -> Result<Vec<GraphPath>> {
+    let mut explorer = Explorer::new(client);
+    explorer.node(from).await?;
+    let mut paths: Vec<GraphPath> = Vec::new();
+    // Iterative DFS over simple paths; the stack holds (path, cost).
+    let mut stack: Vec<(Vec<String>, f64)> = vec![(vec![from.to_string()], 0.0)];
+    // Generous exploration cap so pathological graphs terminate.
+    let mut budget = 100_000usize;
+    while let Some((path, cost)) = stack.pop() {
+        if budget == 0 || paths.len() >= max_paths.max(1) * 8 {
+            break;
+        }
+        budget -= 1;
+        let current = path.last().expect("path is never empty").clone();
+        if current == to && path.len() > 1 {
+            paths.push(explorer.assemble(&path, cost));
+            continue;
+        }
+        if path.len() as u32 > max_depth {
+            continue;
+        }
+        let visited: HashSet<&String> = path.iter().collect();
+        let expansions: Vec<(String, f64)> = explorer
+            .expand(&current)
+            .await?
+            .iter()
+            .filter(|(other, _, _)| !visited.contains(other))
+            .map(|(other, weight, _)| (other.clone(), *weight))
+            .collect();
+        for (other, weight) in expansions {
+            let mut next = path.clone();
+            next.push(other);
+            stack.push((next, cost + edge_cost(weight, WeightMode::Weighted)));
+        }
+    }
+    paths.sort_by(|a, b| a.cost.total_cmp(&b.cost));
+    paths.truncate(max_paths);
+    Ok(paths)
+}
@@ -0,0 +1,180 @@
+//! Policy engine for write governance.
+//!
+//! A [`WritePolicy`] inspects a staged memory write and votes to approve,
+//! reject, or escalate it. The [`PolicyEngine`] chains policies and can
+//! review a [`StagingArea`](crate::StagingArea) automatically: writes every
+//! policy approves are committed, a single rejection discards the write with
+//! the policy's reason, and escalated writes stay pending for a human
+//! reviewer. Built-in policies cover the common governance rules; custom
+//! rules implement the trait.
+
+use serde_json::Value;
+
+use crate::staging::{StagedWrite, StagingArea};
+use crate::{BrainAIClient, Result};
+
+/// A policy's verdict on one staged write.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyDecision {
+    /// The write may be committed as far as this policy is concerned.
+    Approve,
+    /// The write must be discarded, with a reason recorded for audit.
+    Reject(String),
+    /// The policy cannot decide; leave the write pending for a human.
+    Escalate(String),
+}
+
+/// A governance rule evaluated against staged memory writes.
+pub trait WritePolicy: Send + Sync {
+    /// Policy name used in review notes and audit output.
+    fn name(&self) -> &str;
+
+    /// Evaluates one staged write.
+    fn evaluate(&self, write: &StagedWrite) -> PolicyDecision;
+}
+
+/// Outcome of one automated review pass.
+#[derive(Debug, Default, Clone)]
+pub struct ReviewOutcome {
+    /// `(staging_id, memory_id)` pairs committed to the backend.
+    pub approved: Vec<(String, String)>,
+    /// `(staging_id, reason)` pairs discarded.
+    pub rejected: Vec<(String, String)>,
+    /// Staging IDs left pending for human review.
+    pub escalated: Vec<String>,
+}
+
+/// Ordered chain of write policies.
+#[derive(Default)]
+pub struct PolicyEngine {
+    policies: Vec<Box<dyn WritePolicy>>,
+}
+
+impl PolicyEngine {
+    /// Creates an empty engine; with no policies every write is approved.
+    pub fn new() -> Self {
+        PolicyEngine::default()
+    }
+
+    /// Appends a policy to the chain.
+    pub fn with_policy(mut self, policy: impl WritePolicy + 'static) -> Self {
+        self.policies.push(Box::new(policy));
+        self
+    }
+
+    /// Evaluates one write against the whole chain.
+    ///
+    /// The first rejection wins; otherwise the first escalation wins;
+    /// otherwise the write is approved.
+    pub fn evaluate(&self, write: &StagedWrite) -> PolicyDecision {
+        let mut escalation = None;
+        for policy in &self.policies {
+            match policy.evaluate(write) {
+                PolicyDecision::Approve => {}
+                PolicyDecision::Reject(reason) => {
+                    return PolicyDecision::Reject(format!("{}: {reason}", policy.name()));
+                }
+                PolicyDecision::Escalate(reason) => {
+                    escalation
+                        .get_or_insert_with(|| format!("{}: {reason}", policy.name()));
+                }
+            }
+        }
+        match escalation {
+            Some(reason) => PolicyDecision::Escalate(reason),
+            None => PolicyDecision::Approve,
+        }
+    }
+
+    /// Reviews everything pending in the staging area, committing approved
+    /// writes, rejecting denied ones, and leaving escalations pending.
+    pub async fn review_pending<C: BrainAIClient>(
+        &self,
+        staging: &StagingArea<C>,
+    ) -> Result<ReviewOutcome> {
+        let mut outcome = ReviewOutcome::default();
+        for write in staging.list_pending() {
+            match self.evaluate(&write) {
+                PolicyDecision::Approve => {
+                    let committed = staging.approve(&[write.staging_id.as_str()]).await?;
+                    outcome.approved.extend(committed);
+                }
+                PolicyDecision::Reject(reason) => {
+                    staging.reject(&[write.staging_id.as_str()], &reason)?;
+                    outcome.rejected.push((write.staging_id, reason));
+                }
+                PolicyDecision::Escalate(_) => {
+                    outcome.escalated.push(write.staging_id);
+                }
+            }
+        }
+        Ok(outcome)
+    }
+}
+
+/// Rejects writes whose serialized content exceeds a byte limit.
+#[derive(Debug, Clone)]
+pub struct MaxContentSize {
+    pub max_bytes: usize,
+}
+
+impl WritePolicy for MaxContentSize {
+    fn name(&self) -> &str {
+        "max_content_size"
+    }
+
+    fn evaluate(&self, write: &StagedWrite) -> PolicyDecision {
+        let size = write.content.to_string().len();
+        if size > self.max_bytes {
+            PolicyDecision::Reject(format!("content is {size} bytes, limit {}", self.max_bytes))
+        } else {
+            PolicyDecision::Approve
+        }
+    }
+}
+
+/// Escalates writes containing any of the listed terms, case-insensitively.
+#[derive(Debug, Clone)]
+pub struct FlaggedTerms {
+    pub terms: Vec<String>,
+}
+
+impl WritePolicy for FlaggedTerms {
+    fn name(&self) -> &str {
+        "flagged_terms"
+    }
+
+    fn evaluate(&self, write: &StagedWrite) -> PolicyDecision {
+        let haystack = write.content.to_string().to_lowercase();
+        for term in &self.terms {
+            if haystack.contains(&term.to_lowercase()) {
+                return PolicyDecision::Escalate(format!("content mentions \"{term}\""));
+            }
+        }
+        PolicyDecision::Approve
+    }
+}
+
+/// Rejects writes missing required metadata keys (e.g. `source`, `owner`).
+#[derive(Debug, Clone)]
+pub struct RequiredMetadata {
+    pub keys: Vec<String>,
+}
+
+impl WritePolicy for RequiredMetadata {
+    fn name(&self) -> &str {
+        "required_metadata"
+    }
+
+    fn evaluate(&self, write: &StagedWrite) -> PolicyDecision {
+        for key in &self.keys {
+            match write.metadata.get(key) {
+                None | Some(Value::Null) => {
+                    return PolicyDecision::Reject(format!("missing metadata key \"{key}\""));
+                }
+                Some(_) => {}
+            }
+        }
+        PolicyDecision::Approve
+    }
+}
@@ -0,0 +1,96 @@
+//! Backpressure signals for upstream producers.
+//!
+//! A Kafka consumer feeding memories as fast as it can poll will
+//! balloon memory somewhere — in its own buffers or in the SDK's —
+//! unless it can see when the client is saturated.
+//! [`BrainAISDK::pressure`] snapshots the signals that matter:
+//! requests on the wire, callers queued behind the rate limiter and
+//! the wait they face, and whether the circuit breaker has the client
+//! failing fast. [`wait_for_capacity`](BrainAISDK::wait_for_capacity)
+//! turns those signals into an await point, so producers slow down to
+//! the client's pace instead of piling work behind it.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crate::{BrainAISDK, CircuitState};
+
+/// Snapshot of the client's saturation signals.
+#[derive(Debug, Clone, Copy)]
+pub struct Pressure {
+    /// Requests currently on the wire (or retrying).
+    pub in_flight: usize,
+    /// Callers asleep behind the rate limiter; `None` without a limiter.
+    pub rate_limit_waiting: Option<usize>,
+    /// Estimated sleep the next request faces at the rate limiter;
+    /// `None` without a limiter.
+    pub rate_limit_wait: Option<Duration>,
+    /// The circuit breaker is open and calls fail fast.
+    pub circuit_open: bool,
+}
+
+impl Pressure {
+    /// Whether a request issued now would proceed without waiting or
+    /// failing fast.
+    pub fn has_capacity(&self) -> bool {
+        !self.circuit_open && self.rate_limit_wait.unwrap_or(Duration::ZERO).is_zero()
+    }
+}
+
+/// Holds the in-flight gauge up for one request; dropping it — normal
+/// return or cancellation — releases it.
+pub(crate) struct InFlightGuard<'a>(&'a AtomicUsize);
+
+impl<'a> InFlightGuard<'a> {
+    pub(crate) fn begin(gauge: &'a AtomicUsize) -> Self {
+        gauge.fetch_add(1, Ordering::Relaxed);
+        InFlightGuard(gauge)
+    }
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Poll interval while the circuit is open without a known probe time.
+const CAPACITY_POLL: Duration = Duration::from_millis(50);
+
+impl BrainAISDK {
+    /// Snapshots the client's current backpressure signals.
+    pub fn pressure(&self) -> Pressure {
+        Pressure {
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+            rate_limit_waiting: self.rate_limiter().map(|limiter| limiter.waiting()),
+            rate_limit_wait: self.rate_limiter().map(|limiter| limiter.estimated_wait()),
+            circuit_open: self
+                .circuit_breaker()
+                .is_some_and(|breaker| breaker.state() == CircuitState::Open),
+        }
+    }
+
+    /// Resolves once a request issued now would neither sleep at the
+    /// rate limiter nor fail fast at the circuit breaker. No token or
+    /// probe slot is reserved — capacity seen here can be taken by a
+    /// concurrent caller, so treat this as pacing, not a guarantee.
+    pub async fn wait_for_capacity(&self) {
+        loop {
+            let pressure = self.pressure();
+            if pressure.has_capacity() {
+                return;
+            }
+            let wait = if pressure.circuit_open {
+                self.circuit_breaker()
+                    .and_then(|breaker| breaker.retry_in())
+                    .unwrap_or(CAPACITY_POLL)
+            } else {
+                pressure
+                    .rate_limit_wait
+                    .filter(|wait| !wait.is_zero())
+                    .unwrap_or(CAPACITY_POLL)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
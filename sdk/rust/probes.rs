@@ -0,0 +1,196 @@
+//! Typed health probes for Kubernetes integration.
+//!
+//! A pod that keeps taking traffic while its brain is unreachable just
+//! converts requests into timeouts. [`ProbeServer`] — or the
+//! [`BrainAISDK::serve_probes`] shorthand — runs a tiny HTTP listener
+//! exposing the two probes Kubernetes expects: `/livez` answers `200`
+//! whenever the process is alive, and `/readyz` reflects the SDK's view
+//! of the backend — the circuit breaker must not be open, a health
+//! check (which also validates auth) must pass, and the offline queue,
+//! when wired in, must be below its depth bound. The response body is a
+//! small JSON report of each check so a failing probe says why.
+//!
+//! The listener speaks just enough HTTP/1.1 for probes; point
+//! `livenessProbe` / `readinessProbe` `httpGet` entries at it.
+
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::offline::OfflineQueue;
+use crate::{BrainAIError, BrainAISDK, CircuitState, Result};
+
+/// What `/readyz` requires before reporting ready.
+#[derive(Debug, Clone)]
+pub struct ProbeOptions {
+    /// Issue a live health check (also catches invalid credentials) on
+    /// every `/readyz`. On by default; turn off to answer from the
+    /// circuit breaker and queue state alone.
+    pub check_backend: bool,
+    /// Offline queue depth at which the pod stops reporting ready.
+    pub max_queue_depth: usize,
+}
+
+impl Default for ProbeOptions {
+    fn default() -> Self {
+        ProbeOptions {
+            check_backend: true,
+            max_queue_depth: 10_000,
+        }
+    }
+}
+
+/// Probe HTTP server; see the module docs.
+pub struct ProbeServer {
+    sdk: Arc<BrainAISDK>,
+    queue: Option<Arc<OfflineQueue>>,
+    options: ProbeOptions,
+}
+
+impl ProbeServer {
+    /// Creates a probe server with the default options.
+    pub fn new(sdk: Arc<BrainAISDK>) -> Self {
+        ProbeServer {
+            sdk,
+            queue: None,
+            options: ProbeOptions::default(),
+        }
+    }
+
+    /// Includes the offline queue's depth in the readiness check.
+    pub fn with_queue(mut self, queue: Arc<OfflineQueue>) -> Self {
+        self.queue = Some(queue);
+        self
+    }
+
+    /// Overrides the readiness options.
+    pub fn with_options(mut self, options: ProbeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Binds `addr` (e.g. `"0.0.0.0:9090"`) and serves probes until the
+    /// returned handle is aborted.
+    pub async fn serve(self, addr: &str) -> Result<tokio::task::JoinHandle<()>> {
+        let listener = TcpListener::bind(addr).await.map_err(|err| {
+            BrainAIError::InvalidInput(format!("cannot bind probe listener on {addr}: {err}"))
+        })?;
+        let server = Arc::new(self);
+        Ok(tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    continue;
+                };
+                let server = Arc::clone(&server);
+                tokio::spawn(async move {
+                    // Probe handling is best-effort; a broken connection
+                    // just means the kubelet gave up on this attempt.
+                    let _ = server.handle(stream).await;
+                });
+            }
+        }))
+    }
+
+    /// Reads one request and writes the matching probe response.
+    async fn handle(&self, mut stream: tokio::net::TcpStream) -> std::io::Result<()> {
+        let mut buffer = [0u8; 1024];
+        let read = stream.read(&mut buffer).await?;
+        let request = String::from_utf8_lossy(&buffer[..read]);
+        let path = request
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .unwrap_or("/");
+
+        let (status, body) = match path {
+            "/livez" => (200, json!({"alive": true})),
+            "/readyz" => {
+                let (ready, checks) = self.readiness().await;
+                (
+                    if ready { 200 } else { 503 },
+                    json!({"ready": ready, "checks": checks}),
+                )
+            }
+            _ => (404, json!({"error": "unknown probe path"})),
+        };
+
+        let reason = match status {
+            200 => "OK",
+            503 => "Service Unavailable",
+            _ => "Not Found",
+        };
+        let body = body.to_string();
+        let response = format!(
+            "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    }
+
+    /// Evaluates every readiness check, returning the verdict and the
+    /// per-check report.
+    async fn readiness(&self) -> (bool, Value) {
+        let mut ready = true;
+        let mut checks = serde_json::Map::new();
+
+        if let Some(breaker) = self.sdk.circuit_breaker() {
+            let state = match breaker.state() {
+                CircuitState::Closed => "closed",
+                CircuitState::HalfOpen => "half_open",
+                CircuitState::Open => {
+                    ready = false;
+                    "open"
+                }
+            };
+            checks.insert("circuit".to_string(), json!(state));
+        }
+
+        if self.options.check_backend {
+            let backend = match self.sdk.health_check().await {
+                Ok(true) => "ok".to_string(),
+                Ok(false) => {
+                    ready = false;
+                    "unreachable".to_string()
+                }
+                Err(err) => {
+                    ready = false;
+                    err.to_string()
+                }
+            };
+            checks.insert("backend".to_string(), json!(backend));
+        }
+
+        if let Some(queue) = &self.queue {
+            match queue.pending().await {
+                Ok(depth) => {
+                    if depth > self.options.max_queue_depth {
+                        ready = false;
+                    }
+                    checks.insert("queue_depth".to_string(), json!(depth));
+                }
+                Err(err) => {
+                    ready = false;
+                    checks.insert("queue_depth".to_string(), json!(err.to_string()));
+                }
+            }
+        }
+
+        (ready, Value::Object(checks))
+    }
+}
+
+impl BrainAISDK {
+    /// Serves `/livez` and `/readyz` on `addr` with the default probe
+    /// options; build a [`ProbeServer`] directly to include an offline
+    /// queue or tune the checks.
+    pub async fn serve_probes(
+        self: &Arc<Self>,
+        addr: &str,
+    ) -> Result<tokio::task::JoinHandle<()>> {
+        ProbeServer::new(Arc::clone(self)).serve(addr).await
+    }
+}
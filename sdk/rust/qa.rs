@@ -0,0 +1,319 @@
+//! End-to-end question answering over memory.
+//!
+//! Answering a question properly takes five SDK calls composed in the
+//! right order: embed the question, retrieve candidates, assemble
+//! grounding context, reason over it, and map evidence back to
+//! memories. [`ask`] does the whole path with sensible defaults, and
+//! [`QuestionAnswerer`] exposes the knobs — an [`Embedder`] to upgrade
+//! retrieval to hybrid search, and a pluggable [`Answerer`] (an LLM,
+//! for instance) to replace the built-in reasoning engine.
+
+use async_trait::async_trait;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::embed::Embedder;
+use crate::hybrid::{hybrid_search, HybridWeights};
+use crate::Result;
+
+/// Default number of memories retrieved as grounding.
+const DEFAULT_RETRIEVAL_LIMIT: usize = 8;
+
+/// A memory cited in support of an answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Citation {
+    pub memory_id: String,
+    pub content: Value,
+    /// Retrieval score of this memory for the question.
+    pub score: f64,
+}
+
+/// The outcome of [`ask`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Answer {
+    pub text: String,
+    /// Confidence in `0.0..=1.0`, as reported by the answerer.
+    pub confidence: f64,
+    /// Memories the answer rests on, highest retrieval score first.
+    pub citations: Vec<Citation>,
+}
+
+/// Produces an answer from a question and its grounding context.
+///
+/// The default implementation calls the server's reasoning engine;
+/// implement this to route through an LLM instead.
+#[async_trait]
+pub trait Answerer: Send + Sync {
+    /// Returns the answer text and a confidence in `0.0..=1.0`.
+    async fn answer(&self, question: &str, grounding: &[Citation]) -> Result<(String, f64)>;
+}
+
+/// The built-in answerer: the server's `reason` endpoint grounded on
+/// the retrieved memories.
+pub struct ReasonAnswerer<'a> {
+    client: &'a dyn BrainAIClient,
+}
+
+impl<'a> ReasonAnswerer<'a> {
+    pub fn new(client: &'a dyn BrainAIClient) -> Self {
+        ReasonAnswerer { client }
+    }
+}
+
+#[async_trait]
+impl Answerer for ReasonAnswerer<'_> {
+    async fn answer(&self, question: &str, grounding: &[Citation]) -> Result<(String, f64)> {
+        let context: Vec<String> = grounding
+            .iter()
+            .map(|citation| content_text(&citation.content))
+            .collect();
+        let result = self.client.reason(question, context).await?;
+        Ok((result.conclusion, result.confidence))
+    }
+}
+
+/// Configurable question-answering pipeline.
+pub struct QuestionAnswerer<'a> {
+    client: &'a dyn BrainAIClient,
+    embedder: Option<&'a dyn Embedder>,
+    answerer: Option<&'a dyn Answerer>,
+    retrieval_limit: usize,
+}
+
+impl<'a> QuestionAnswerer<'a> {
+    /// Creates a pipeline with keyword retrieval and the built-in
+    /// reasoning answerer.
+    pub fn new(client: &'a dyn BrainAIClient) -> Self {
+        QuestionAnswerer {
+            client,
+            embedder: None,
+            answerer: None,
+            retrieval_limit: DEFAULT_RETRIEVAL_LIMIT,
+        }
+    }
+
+    /// Upgrades retrieval to hybrid keyword + vector search.
+    pub fn with_embedder(mut self, embedder: &'a dyn Embedder) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Replaces the built-in reasoning answerer.
+    pub fn with_answerer(mut self, answerer: &'a dyn Answerer) -> Self {
+        self.answerer = Some(answerer);
+        self
+    }
+
+    /// Overrides how many memories are retrieved as grounding.
+    pub fn with_retrieval_limit(mut self, limit: usize) -> Self {
+        self.retrieval_limit = limit.max(1);
+        self
+    }
+
+    /// Runs retrieval, grounding, and answering for one question.
+    pub async fn ask(&self, question: &str) -> Result<Answer> {
+        let mut citations = self.retrieve(question).await?;
+
+        let (text, confidence) = match self.answerer {
+            Some(answerer) => answerer.answer(question, &citations).await?,
+            None => {
+                ReasonAnswerer::new(self.client)
+                    .answer(question, &citations)
+                    .await?
+            }
+        };
+
+        citations.sort_by(|a, b| b.score.total_cmp(&a.score));
+        Ok(Answer {
+            text,
+            confidence,
+            citations,
+        })
+    }
+
+    async fn retrieve(&self, question: &str) -> Result<Vec<Citation>> {
+        match self.embedder {
+            Some(embedder) => {
+                let vector = embedder.embed(question).await?;
+                let hits = hybrid_search(
+                    self.client,
+                    question,
+                    vector,
+                    HybridWeights::default(),
+                    self.retrieval_limit,
+                )
+                .await?;
+                Ok(hits
+                    .into_iter()
+                    .map(|hit| Citation {
+                        memory_id: hit.id,
+                        content: hit.content,
+                        score: hit.score,
+                    })
+                    .collect())
+            }
+            None => {
+                let hits = self
+                    .client
+                    .search_memories(json!(question), self.retrieval_limit)
+                    .await?;
+                Ok(hits
+                    .into_iter()
+                    .map(|hit| Citation {
+                        memory_id: hit.id,
+                        content: hit.content,
+                        score: hit.score,
+                    })
+                    .collect())
+            }
+        }
+    }
+}
+
+/// Asks a question with default retrieval and the built-in reasoning
+/// answerer — the one-call path most applications want.
+pub async fn ask(client: &dyn BrainAIClient, question: &str) -> Result<Answer> {
+    QuestionAnswerer::new(client).ask(question).await
+}
+
+/// Judges whether one citation actually supports an answer.
+///
+/// The default [`TermOverlapVerifier`] needs no model; wire an
+/// [`EmbeddingVerifier`] or an LLM-backed implementation for entailment
+/// that survives paraphrasing.
+#[async_trait]
+pub trait CitationVerifier: Send + Sync {
+    /// Support score in `0.0..=1.0`; higher means the citation backs
+    /// the answer.
+    async fn support(&self, answer: &str, citation: &Citation) -> Result<f64>;
+
+    /// Score at or above which a citation counts as supporting.
+    fn threshold(&self) -> f64 {
+        0.3
+    }
+}
+
+/// Lexical entailment heuristic: the fraction of the answer's terms
+/// present in the cited memory. Cheap and model-free, but blind to
+/// paraphrase.
+pub struct TermOverlapVerifier;
+
+#[async_trait]
+impl CitationVerifier for TermOverlapVerifier {
+    async fn support(&self, answer: &str, citation: &Citation) -> Result<f64> {
+        let answer_terms = terms(answer);
+        if answer_terms.is_empty() {
+            return Ok(0.0);
+        }
+        let citation_terms = terms(&content_text(&citation.content));
+        let covered = answer_terms
+            .iter()
+            .filter(|t| citation_terms.contains(*t))
+            .count();
+        Ok(covered as f64 / answer_terms.len() as f64)
+    }
+}
+
+/// Embedding entailment heuristic: cosine similarity between the answer
+/// and the cited content.
+pub struct EmbeddingVerifier<'a> {
+    embedder: &'a dyn Embedder,
+    threshold: f64,
+}
+
+impl<'a> EmbeddingVerifier<'a> {
+    pub fn new(embedder: &'a dyn Embedder) -> Self {
+        EmbeddingVerifier {
+            embedder,
+            threshold: 0.7,
+        }
+    }
+
+    /// Overrides the support threshold.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+#[async_trait]
+impl CitationVerifier for EmbeddingVerifier<'_> {
+    async fn support(&self, answer: &str, citation: &Citation) -> Result<f64> {
+        let answer_vector = self.embedder.embed(answer).await?;
+        let citation_vector = self
+            .embedder
+            .embed(&content_text(&citation.content))
+            .await?;
+        // Map cosine from [-1, 1] into a support score in [0, 1].
+        Ok((crate::vector_utils::cosine_similarity(&answer_vector, &citation_vector) + 1.0) / 2.0)
+    }
+
+    fn threshold(&self) -> f64 {
+        (self.threshold + 1.0) / 2.0
+    }
+}
+
+/// One citation's verification outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CitationCheck {
+    pub memory_id: String,
+    /// Support score in `0.0..=1.0`.
+    pub support: f64,
+    pub supported: bool,
+}
+
+/// Verification outcome for a whole answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CitationReport {
+    /// Per-citation checks, in the answer's citation order.
+    pub checks: Vec<CitationCheck>,
+    /// IDs of citations that fell below the verifier's threshold —
+    /// candidates for suppression or a hallucination label.
+    pub unsupported: Vec<String>,
+}
+
+impl CitationReport {
+    /// Whether every citation cleared the threshold.
+    pub fn all_supported(&self) -> bool {
+        self.unsupported.is_empty()
+    }
+}
+
+/// Checks each citation of an answer against the answer text, flagging
+/// the ones that do not actually support it.
+pub async fn verify_citations(
+    answer: &Answer,
+    verifier: &dyn CitationVerifier,
+) -> Result<CitationReport> {
+    let mut checks = Vec::with_capacity(answer.citations.len());
+    let mut unsupported = Vec::new();
+    for citation in &answer.citations {
+        let support = verifier.support(&answer.text, citation).await?;
+        let supported = support >= verifier.threshold();
+        if !supported {
+            unsupported.push(citation.memory_id.clone());
+        }
+        checks.push(CitationCheck {
+            memory_id: citation.memory_id.clone(),
+            support,
+            supported,
+        });
+    }
+    Ok(CitationReport { checks, unsupported })
+}
+
+fn terms(text: &str) -> std::collections::HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| t.len() > 2)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn content_text(content: &Value) -> String {
+    match content {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,312 @@
+//! Vector quantization utilities (int8 and product quantization).
+//!
+//! Locally cached vector sets get big fast: 768 f32 dimensions are 3 KiB
+//! per vector. [`quantize_int8`] scales each vector into signed bytes for
+//! a 4x saving with near-lossless cosine ranking; [`ProductQuantizer`]
+//! trains per-subspace k-means codebooks and encodes each vector as one
+//! byte per subspace for 8x and beyond. Both come with similarity
+//! functions that operate directly on the quantized codes — int8 dot
+//! products and PQ asymmetric-distance lookup tables — so candidates are
+//! scored without dequantizing.
+
+use crate::{BrainAIError, Result};
+
+/// An int8-quantized vector with its reconstruction scale.
+#[derive(Debug, Clone)]
+pub struct Int8Vector {
+    pub codes: Vec<i8>,
+    /// `original[i] ≈ codes[i] as f32 * scale`.
+    pub scale: f32,
+}
+
+/// Quantizes a vector to signed bytes with a per-vector scale.
+pub fn quantize_int8(vector: &[f32]) -> Result<Int8Vector> {
+    if vector.is_empty() {
+        return Err(BrainAIError::InvalidInput("empty vector".to_string()));
+    }
+    let max_abs = vector.iter().fold(0.0f32, |acc, x| acc.max(x.abs()));
+    if max_abs == 0.0 {
+        return Ok(Int8Vector {
+            codes: vec![0; vector.len()],
+            scale: 0.0,
+        });
+    }
+    let scale = max_abs / 127.0;
+    let codes = vector
+        .iter()
+        .map(|x| (x / scale).round().clamp(-127.0, 127.0) as i8)
+        .collect();
+    Ok(Int8Vector { codes, scale })
+}
+
+/// Reconstructs the approximate f32 vector.
+pub fn dequantize(quantized: &Int8Vector) -> Vec<f32> {
+    quantized
+        .codes
+        .iter()
+        .map(|&c| f32::from(c) * quantized.scale)
+        .collect()
+}
+
+/// Dot product of two int8 vectors, computed in integer arithmetic and
+/// rescaled; errors on length mismatch.
+pub fn int8_dot(a: &Int8Vector, b: &Int8Vector) -> Result<f64> {
+    if a.codes.len() != b.codes.len() {
+        return Err(BrainAIError::InvalidInput(format!(
+            "quantized length mismatch: {} vs {}",
+            a.codes.len(),
+            b.codes.len()
+        )));
+    }
+    let sum: i64 = a
+        .codes
+        .iter()
+        .zip(&b.codes)
+        .map(|(&x, &y)| i64::from(x) * i64::from(y))
+        .sum();
+    Ok(sum as f64 * f64::from(a.scale) * f64::from(b.scale))
+}
+
+/// Cosine similarity of two int8 vectors without dequantizing.
+pub fn int8_cosine(a: &Int8Vector, b: &Int8Vector) -> Result<f64> {
+    let dot = int8_dot(a, b)?;
+    let norm = |v: &Int8Vector| -> f64 {
+        let sum: i64 = v.codes.iter().map(|&x| i64::from(x) * i64::from(x)).sum();
+        (sum as f64).sqrt() * f64::from(v.scale)
+    };
+    let (na, nb) = (norm(a), norm(b));
+    if na == 0.0 || nb == 0.0 {
+        return Ok(0.0);
+    }
+    Ok(dot / (na * nb))
+}
+
+/// Product quantizer: the vector is split into `subspaces` contiguous
+/// slices, each encoded as the index of its nearest centroid in a
+/// 256-entry codebook learned by k-means.
+#[derive(Debug, Clone)]
+pub struct ProductQuantizer {
+    dimension: usize,
+    subspaces: usize,
+    /// `codebooks[s][k]` is centroid `k` of subspace `s`.
+    codebooks: Vec<Vec<Vec<f32>>>,
+}
+
+/// xorshift64* for centroid seeding; deterministic per training seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+fn squared_distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+impl ProductQuantizer {
+    /// Trains codebooks over a sample of vectors.
+    ///
+    /// `subspaces` must divide the dimension; 8 or 16 are typical.
+    /// `iterations` of Lloyd's k-means per subspace (10–25 is plenty).
+    /// Training needs at least as many vectors as centroids actually
+    /// used (`min(256, len)`).
+    pub fn train(vectors: &[Vec<f32>], subspaces: usize, iterations: usize) -> Result<Self> {
+        let Some(first) = vectors.first() else {
+            return Err(BrainAIError::InvalidInput(
+                "training set is empty".to_string(),
+            ));
+        };
+        let dimension = first.len();
+        if dimension == 0 || subspaces == 0 || dimension % subspaces != 0 {
+            return Err(BrainAIError::InvalidInput(format!(
+                "subspaces ({subspaces}) must evenly divide the dimension ({dimension})"
+            )));
+        }
+        if vectors.iter().any(|v| v.len() != dimension) {
+            return Err(BrainAIError::InvalidInput(
+                "training vectors have mixed dimensions".to_string(),
+            ));
+        }
+        let slice_len = dimension / subspaces;
+        let k = vectors.len().min(256);
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+        let mut codebooks = Vec::with_capacity(subspaces);
+
+        for subspace in 0..subspaces {
+            let offset = subspace * slice_len;
+            let slices: Vec<&[f32]> = vectors
+                .iter()
+                .map(|v| &v[offset..offset + slice_len])
+                .collect();
+            // Seed centroids from distinct training slices.
+            let mut centroids: Vec<Vec<f32>> = (0..k)
+                .map(|i| {
+                    let pick = if i < slices.len() {
+                        i
+                    } else {
+                        (rng.next() % slices.len() as u64) as usize
+                    };
+                    slices[pick].to_vec()
+                })
+                .collect();
+            for _ in 0..iterations.max(1) {
+                let mut sums = vec![vec![0.0f32; slice_len]; k];
+                let mut counts = vec![0usize; k];
+                for slice in &slices {
+                    let nearest = centroids
+                        .iter()
+                        .enumerate()
+                        .min_by(|(_, a), (_, b)| {
+                            squared_distance(slice, a).total_cmp(&squared_distance(slice, b))
+                        })
+                        .map(|(i, _)| i)
+                        .unwrap_or(0);
+                    counts[nearest] += 1;
+                    for (acc, x) in sums[nearest].iter_mut().zip(*slice) {
+                        *acc += x;
+                    }
+                }
+                for ((centroid, sum), count) in
+                    centroids.iter_mut().zip(&sums).zip(&counts)
+                {
+                    if *count > 0 {
+                        for (c, s) in centroid.iter_mut().zip(sum) {
+                            *c = s / *count as f32;
+                        }
+                    }
+                    // Empty clusters keep their previous centroid.
+                }
+            }
+            codebooks.push(centroids);
+        }
+        Ok(ProductQuantizer {
+            dimension,
+            subspaces,
+            codebooks,
+        })
+    }
+
+    /// Input dimensionality the quantizer was trained for.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Bytes per encoded vector (one per subspace).
+    pub fn code_len(&self) -> usize {
+        self.subspaces
+    }
+
+    /// Encodes a vector as one codebook index per subspace.
+    pub fn encode(&self, vector: &[f32]) -> Result<Vec<u8>> {
+        if vector.len() != self.dimension {
+            return Err(BrainAIError::InvalidInput(format!(
+                "vector has {} dimensions, quantizer expects {}",
+                vector.len(),
+                self.dimension
+            )));
+        }
+        let slice_len = self.dimension / self.subspaces;
+        Ok(self
+            .codebooks
+            .iter()
+            .enumerate()
+            .map(|(subspace, codebook)| {
+                let offset = subspace * slice_len;
+                let slice = &vector[offset..offset + slice_len];
+                codebook
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| {
+                        squared_distance(slice, a).total_cmp(&squared_distance(slice, b))
+                    })
+                    .map(|(i, _)| i as u8)
+                    .unwrap_or(0)
+            })
+            .collect())
+    }
+
+    /// Reconstructs the approximate vector from its codes.
+    pub fn decode(&self, codes: &[u8]) -> Result<Vec<f32>> {
+        if codes.len() != self.subspaces {
+            return Err(BrainAIError::InvalidInput(format!(
+                "code has {} bytes, quantizer expects {}",
+                codes.len(),
+                self.subspaces
+            )));
+        }
+        let mut out = Vec::with_capacity(self.dimension);
+        for (subspace, &code) in codes.iter().enumerate() {
+            let codebook = &self.codebooks[subspace];
+            let centroid = codebook.get(code as usize).ok_or_else(|| {
+                BrainAIError::InvalidInput(format!("code {code} out of codebook range"))
+            })?;
+            out.extend_from_slice(centroid);
+        }
+        Ok(out)
+    }
+
+    /// Precomputes the asymmetric-distance table for a query: per
+    /// subspace, the query slice's dot product with every centroid.
+    /// Scoring a candidate is then `subspaces` table lookups.
+    pub fn lookup_table(&self, query: &[f32]) -> Result<PqLookupTable> {
+        if query.len() != self.dimension {
+            return Err(BrainAIError::InvalidInput(format!(
+                "query has {} dimensions, quantizer expects {}",
+                query.len(),
+                self.dimension
+            )));
+        }
+        let slice_len = self.dimension / self.subspaces;
+        let table = self
+            .codebooks
+            .iter()
+            .enumerate()
+            .map(|(subspace, codebook)| {
+                let offset = subspace * slice_len;
+                let slice = &query[offset..offset + slice_len];
+                codebook
+                    .iter()
+                    .map(|centroid| {
+                        slice
+                            .iter()
+                            .zip(centroid)
+                            .map(|(x, y)| f64::from(*x) * f64::from(*y))
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(PqLookupTable { table })
+    }
+}
+
+/// Per-query lookup table for scoring PQ codes without decoding.
+pub struct PqLookupTable {
+    /// `table[subspace][code]` = partial dot product.
+    table: Vec<Vec<f64>>,
+}
+
+impl PqLookupTable {
+    /// Approximate dot product between the query and an encoded vector.
+    pub fn dot(&self, codes: &[u8]) -> Result<f64> {
+        if codes.len() != self.table.len() {
+            return Err(BrainAIError::InvalidInput(format!(
+                "code has {} bytes, table expects {}",
+                codes.len(),
+                self.table.len()
+            )));
+        }
+        Ok(codes
+            .iter()
+            .zip(&self.table)
+            .map(|(&code, row)| row.get(code as usize).copied().unwrap_or(0.0))
+            .sum())
+    }
+}
@@ -0,0 +1,188 @@
+//! Client-side quota enforcement over the configured memory size.
+//!
+//! [`BrainAIConfig::memory_size`](crate::BrainAIConfig) states how many
+//! memories the brain should retain, but nothing enforced it.
+//! [`QuotaEnforcer`] runs passes in the style of the
+//! [`DecayScheduler`](crate::decay::DecayScheduler): when the brain
+//! exceeds the quota it evicts the excess under a configurable
+//! [`EvictionPolicy`] — lowest strength, oldest, or least recently
+//! used — either deleting the losers or archiving them in place with an
+//! `archived` metadata flag, and reports exactly what was removed.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIClient, BrainAIConfig, Memory, Result};
+
+/// Which memories go first when the brain is over quota.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Weakest memories first; ties broken by least recent access.
+    #[default]
+    LowestStrength,
+    /// Oldest memories first, by creation time.
+    Oldest,
+    /// Least recently accessed memories first.
+    LeastRecentlyUsed,
+}
+
+/// Quota and eviction tuning.
+#[derive(Debug, Clone)]
+pub struct QuotaPolicy {
+    /// Memory count the brain is held to.
+    pub quota: usize,
+    pub eviction: EvictionPolicy,
+    /// Archive evicted memories (an `archived: true` metadata flag,
+    /// content kept) instead of deleting them.
+    pub archive: bool,
+    /// Memories examined per pass; keeps passes bounded on large brains.
+    pub scan_limit: usize,
+}
+
+impl QuotaPolicy {
+    /// A policy holding the brain to `quota` memories, deleting the
+    /// lowest-strength losers.
+    pub fn new(quota: usize) -> Self {
+        QuotaPolicy {
+            quota,
+            eviction: EvictionPolicy::default(),
+            archive: false,
+            scan_limit: 50_000,
+        }
+    }
+
+    /// A policy honoring [`BrainAIConfig::memory_size`].
+    pub fn from_config(config: &BrainAIConfig) -> Self {
+        QuotaPolicy::new(config.memory_size)
+    }
+
+    /// Overrides the eviction policy.
+    pub fn with_eviction(mut self, eviction: EvictionPolicy) -> Self {
+        self.eviction = eviction;
+        self
+    }
+
+    /// Archives evicted memories instead of deleting them.
+    pub fn archiving(mut self) -> Self {
+        self.archive = true;
+        self
+    }
+}
+
+/// Outcome of one quota pass.
+#[derive(Debug, Default, Clone)]
+pub struct QuotaReport {
+    /// Memories counted before eviction.
+    pub counted: usize,
+    /// The quota enforced.
+    pub quota: usize,
+    /// IDs deleted this pass.
+    pub evicted: Vec<String>,
+    /// IDs archived this pass.
+    pub archived: Vec<String>,
+}
+
+/// Periodic quota-enforcement driver over any client.
+pub struct QuotaEnforcer {
+    client: Arc<dyn BrainAIClient>,
+    policy: QuotaPolicy,
+}
+
+impl QuotaEnforcer {
+    pub fn new(client: Arc<dyn BrainAIClient>, policy: QuotaPolicy) -> Self {
+        QuotaEnforcer { client, policy }
+    }
+
+    /// Runs a single quota pass, evicting until the brain is back under
+    /// quota. Already-archived memories are never candidates again.
+    pub async fn run_once(&self) -> Result<QuotaReport> {
+        let memories = self
+            .client
+            .list_memories(None, self.policy.scan_limit)
+            .await?;
+        let mut report = QuotaReport {
+            counted: memories.len(),
+            quota: self.policy.quota,
+            ..QuotaReport::default()
+        };
+        if memories.len() <= self.policy.quota {
+            return Ok(report);
+        }
+        let excess = memories.len() - self.policy.quota;
+        let mut candidates: Vec<Memory> = memories
+            .into_iter()
+            .filter(|memory| !is_archived(memory))
+            .collect();
+        candidates.sort_by(|a, b| match self.policy.eviction {
+            EvictionPolicy::LowestStrength => a
+                .strength
+                .total_cmp(&b.strength)
+                .then(a.last_accessed.cmp(&b.last_accessed)),
+            EvictionPolicy::Oldest => a
+                .created_at
+                .cmp(&b.created_at)
+                .then(a.strength.total_cmp(&b.strength)),
+            EvictionPolicy::LeastRecentlyUsed => a
+                .last_accessed
+                .cmp(&b.last_accessed)
+                .then(a.strength.total_cmp(&b.strength)),
+        });
+        for memory in candidates.into_iter().take(excess) {
+            if self.policy.archive {
+                let mut metadata = memory.metadata.clone();
+                metadata.insert("archived".to_string(), Value::Bool(true));
+                metadata.insert("archived_at".to_string(), json!(now_millis()));
+                self.client
+                    .update_memory(&memory.id, memory.content, Some(metadata))
+                    .await?;
+                report.archived.push(memory.id);
+            } else {
+                self.client.delete_memory(&memory.id).await?;
+                report.evicted.push(memory.id);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Spawns a background task running a pass every `interval`.
+    ///
+    /// Failed passes are logged and the loop continues; abort the
+    /// returned handle to stop the enforcer.
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                match self.run_once().await {
+                    Ok(report) => {
+                        if !report.evicted.is_empty() || !report.archived.is_empty() {
+                            eprintln!(
+                                "[brain-ai] quota pass: {}",
+                                json!({
+                                    "counted": report.counted,
+                                    "quota": report.quota,
+                                    "evicted": report.evicted.len(),
+                                    "archived": report.archived.len(),
+                                })
+                            );
+                        }
+                    }
+                    Err(err) => eprintln!("[brain-ai] quota pass failed: {err}"),
+                }
+            }
+        })
+    }
+}
+
+/// Whether a memory carries the archive flag a previous pass set.
+fn is_archived(memory: &Memory) -> bool {
+    memory
+        .metadata
+        .get("archived")
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
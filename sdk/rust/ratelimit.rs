@@ -0,0 +1,176 @@
+//! Client-side rate limiting with a token bucket.
+//!
+//! Bulk ingestion can hammer a Brain AI server into 429s. When enabled
+//! via [`BrainAIConfig::with_rate_limit`](crate::BrainAIConfig), every
+//! request first takes a token from a bucket refilled at
+//! `requests_per_second` (with `burst` headroom for short spikes);
+//! callers over the rate are held in an async sleep, so back-pressure
+//! propagates without blocking a thread. A 429 answer freezes the
+//! bucket for the server's `Retry-After` and the request is retried
+//! transparently, up to `max_retries` attempts.
+//!
+//! This is a *static* ceiling; for discovering the sustainable rate
+//! dynamically see [`AdaptiveLimiter`](crate::AdaptiveLimiter), which
+//! composes with this as an outer bound.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Token-bucket tuning.
+#[derive(Debug, Clone)]
+pub struct RateLimitOptions {
+    /// Sustained request rate the bucket refills at.
+    pub requests_per_second: f64,
+    /// Tokens the bucket holds at rest; a burst this large proceeds
+    /// without waiting.
+    pub burst: u32,
+    /// Times a 429 answer is retried (after honoring `Retry-After`)
+    /// before it surfaces to the caller.
+    pub max_retries: u32,
+}
+
+impl Default for RateLimitOptions {
+    fn default() -> Self {
+        RateLimitOptions {
+            requests_per_second: 10.0,
+            burst: 20,
+            max_retries: 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+    /// Set when the server answered 429; no tokens are handed out until
+    /// this instant passes.
+    frozen_until: Option<Instant>,
+}
+
+/// Token-bucket rate limiter; see the module docs.
+#[derive(Debug)]
+pub struct RateLimiter {
+    options: RateLimitOptions,
+    bucket: Mutex<Bucket>,
+    /// Callers currently asleep waiting for a token.
+    waiters: AtomicUsize,
+}
+
+impl RateLimiter {
+    pub fn new(options: RateLimitOptions) -> Self {
+        let burst = options.burst.max(1);
+        RateLimiter {
+            bucket: Mutex::new(Bucket {
+                tokens: burst as f64,
+                last_refill: Instant::now(),
+                frozen_until: None,
+            }),
+            options: RateLimitOptions {
+                requests_per_second: options.requests_per_second.max(0.001),
+                burst,
+                ..options
+            },
+            waiters: AtomicUsize::new(0),
+        }
+    }
+
+    /// Takes one token, sleeping as long as the bucket is empty or
+    /// frozen. The lock is never held across the sleep, so waiting
+    /// callers don't serialize behind each other.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let now = Instant::now();
+                if let Some(until) = bucket.frozen_until {
+                    if now < until {
+                        until - now
+                    } else {
+                        bucket.frozen_until = None;
+                        continue;
+                    }
+                } else {
+                    let elapsed = now.duration_since(bucket.last_refill);
+                    bucket.tokens = (bucket.tokens
+                        + elapsed.as_secs_f64() * self.options.requests_per_second)
+                        .min(self.options.burst as f64);
+                    bucket.last_refill = now;
+                    if bucket.tokens >= 1.0 {
+                        bucket.tokens -= 1.0;
+                        return;
+                    }
+                    Duration::from_secs_f64(
+                        (1.0 - bucket.tokens) / self.options.requests_per_second,
+                    )
+                }
+            };
+            self.waiters.fetch_add(1, Ordering::Relaxed);
+            tokio::time::sleep(wait).await;
+            self.waiters.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Freezes the bucket (server said 429); tokens resume after `wait`.
+    pub(crate) fn freeze(&self, wait: Duration) {
+        let mut bucket = self.bucket.lock().unwrap();
+        let until = Instant::now() + wait;
+        bucket.frozen_until = Some(match bucket.frozen_until {
+            Some(existing) => existing.max(until),
+            None => until,
+        });
+    }
+
+    /// Retry budget for 429 answers.
+    pub(crate) fn max_retries(&self) -> u32 {
+        self.options.max_retries
+    }
+
+    /// Tokens currently available, for introspection.
+    pub fn available(&self) -> f64 {
+        let bucket = self.bucket.lock().unwrap();
+        let refilled = bucket.tokens
+            + bucket.last_refill.elapsed().as_secs_f64() * self.options.requests_per_second;
+        refilled.min(self.options.burst as f64)
+    }
+
+    /// Callers currently asleep waiting for a token.
+    pub fn waiting(&self) -> usize {
+        self.waiters.load(Ordering::Relaxed)
+    }
+
+    /// Estimate of how long [`acquire`](Self::acquire) would sleep right
+    /// now: zero when a token is free, otherwise the freeze remainder
+    /// plus the refill time for one token.
+    pub fn estimated_wait(&self) -> Duration {
+        let bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let frozen = match bucket.frozen_until {
+            Some(until) if until > now => until - now,
+            _ => Duration::ZERO,
+        };
+        let refilled = (bucket.tokens
+            + bucket.last_refill.elapsed().as_secs_f64() * self.options.requests_per_second)
+            .min(self.options.burst as f64);
+        if frozen.is_zero() && refilled >= 1.0 {
+            return Duration::ZERO;
+        }
+        frozen
+            + Duration::from_secs_f64(
+                (1.0 - refilled).max(0.0) / self.options.requests_per_second,
+            )
+    }
+}
+
+/// Parses a `Retry-After` header (delta-seconds form) into a wait.
+pub(crate) fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
@@ -0,0 +1,136 @@
+//! Zero-copy deserialization for large responses.
+//!
+//! Large listings and exports spend real time copying every string out of
+//! the response body. [`RawResponse`] keeps the body as a single buffer and
+//! [`RawResponse::parse`] deserializes *borrowed* views into it: string
+//! fields become `&str` slices of the buffer and opaque content stays as
+//! [`RawValue`] until (and unless) the caller needs it. [`MemoryRef`] and
+//! [`SearchResultRef`] are the borrowed counterparts of the owned response
+//! types, convertible with `to_owned()` when data must outlive the buffer.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::value::RawValue;
+use serde_json::Value;
+
+use crate::{BrainAIError, Memory, MemoryType, Result, SearchResult};
+
+/// A response body held as one buffer for borrowed deserialization.
+#[derive(Debug)]
+pub struct RawResponse {
+    body: bytes::Bytes,
+}
+
+impl RawResponse {
+    /// Wraps a raw body.
+    pub fn new(body: bytes::Bytes) -> Self {
+        RawResponse { body }
+    }
+
+    /// Size of the buffered body in bytes.
+    pub fn len(&self) -> usize {
+        self.body.len()
+    }
+
+    /// Whether the body is empty.
+    pub fn is_empty(&self) -> bool {
+        self.body.is_empty()
+    }
+
+    /// Deserializes a borrowed view into the buffer. The result borrows
+    /// from `self` and cannot outlive it.
+    pub fn parse<'a, T: Deserialize<'a>>(&'a self) -> Result<T> {
+        serde_json::from_slice(&self.body).map_err(BrainAIError::Serialization)
+    }
+
+    /// Deserializes the `data` field of the shared response envelope as a
+    /// borrowed view, checking `success` first.
+    pub fn parse_data<'a, T: Deserialize<'a>>(&'a self) -> Result<T> {
+        #[derive(Deserialize)]
+        struct Envelope<'a> {
+            success: bool,
+            #[serde(borrow)]
+            data: Option<&'a RawValue>,
+            error: Option<String>,
+        }
+        let envelope: Envelope<'a> = self.parse()?;
+        if !envelope.success {
+            return Err(BrainAIError::Api {
+                status: 200,
+                message: envelope
+                    .error
+                    .unwrap_or_else(|| "unknown server error".to_string()),
+            });
+        }
+        let data = envelope.data.ok_or(BrainAIError::Api {
+            status: 200,
+            message: "response envelope contained no data".to_string(),
+        })?;
+        serde_json::from_str(data.get()).map_err(BrainAIError::Serialization)
+    }
+}
+
+/// Borrowed view of a memory; strings are slices of the response buffer
+/// and content stays unparsed until needed.
+#[derive(Debug, Deserialize)]
+pub struct MemoryRef<'a> {
+    #[serde(borrow)]
+    pub id: &'a str,
+    #[serde(borrow)]
+    pub content: &'a RawValue,
+    #[serde(rename = "type")]
+    pub memory_type: MemoryType,
+    #[serde(default)]
+    pub strength: f64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub last_accessed: i64,
+}
+
+impl MemoryRef<'_> {
+    /// Parses the content lazily, only when actually needed.
+    pub fn content_value(&self) -> Result<Value> {
+        serde_json::from_str(self.content.get()).map_err(BrainAIError::Serialization)
+    }
+
+    /// Copies into an owned [`Memory`], detaching from the buffer.
+    /// Metadata is parsed from the content's sibling fields lazily and is
+    /// empty here; fetch the owned API type when metadata matters.
+    pub fn to_owned(&self) -> Result<Memory> {
+        Ok(Memory {
+            id: self.id.to_string(),
+            content: self.content_value()?,
+            memory_type: self.memory_type,
+            metadata: HashMap::new(),
+            strength: self.strength,
+            created_at: self.created_at,
+            last_accessed: self.last_accessed,
+        })
+    }
+}
+
+/// Borrowed view of a search hit.
+#[derive(Debug, Deserialize)]
+pub struct SearchResultRef<'a> {
+    #[serde(borrow)]
+    pub id: &'a str,
+    pub score: f64,
+    #[serde(borrow)]
+    pub content: &'a RawValue,
+}
+
+impl SearchResultRef<'_> {
+    /// Copies into an owned [`SearchResult`], detaching from the buffer.
+    pub fn to_owned(&self) -> Result<SearchResult> {
+        Ok(SearchResult {
+            id: self.id.to_string(),
+            score: self.score,
+            content: serde_json::from_str(self.content.get())
+                .map_err(BrainAIError::Serialization)?,
+            metadata: HashMap::new(),
+            breakdown: None,
+        })
+    }
+}
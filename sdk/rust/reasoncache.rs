@@ -0,0 +1,160 @@
+//! Caching layer for reasoning results.
+//!
+//! Reasoning is the most expensive call in the API, and agents tend to
+//! ask the same question repeatedly within a short window. When enabled
+//! via [`BrainAIConfig::with_reasoning_cache`](crate::BrainAIConfig),
+//! the SDK keeps recent [`ReasoningResult`]s keyed by the query,
+//! context, and options, serving repeats locally until the TTL runs out
+//! or the entry count forces eviction. Entries are invalidated when a
+//! memory they rest on changes — feed [`ReasoningCache::handle_event`]
+//! from a [`BrainEvent`](crate::BrainEvent) stream, or call
+//! [`ReasoningCache::invalidate_memory`] directly.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::notify::BrainEvent;
+use crate::{ReasoningOptions, ReasoningResult};
+
+/// Sizing and lifetime knobs for the reasoning cache.
+#[derive(Debug, Clone)]
+pub struct ReasoningCacheOptions {
+    /// How long a cached result stays servable.
+    pub ttl: Duration,
+    /// Entry count above which the oldest entries are evicted.
+    pub max_entries: usize,
+}
+
+impl Default for ReasoningCacheOptions {
+    fn default() -> Self {
+        ReasoningCacheOptions {
+            ttl: Duration::from_secs(60),
+            max_entries: 256,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    result: ReasoningResult,
+    inserted_at: Instant,
+    /// Insertion order, for oldest-first eviction.
+    sequence: u64,
+}
+
+/// TTL + capacity bounded cache of reasoning results.
+#[derive(Debug)]
+pub struct ReasoningCache {
+    options: ReasoningCacheOptions,
+    entries: Mutex<HashMap<u64, CacheEntry>>,
+    sequence: AtomicU64,
+}
+
+impl ReasoningCache {
+    pub fn new(options: ReasoningCacheOptions) -> Self {
+        ReasoningCache {
+            options,
+            entries: Mutex::new(HashMap::new()),
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    /// Cache key over everything that shapes the result.
+    pub(crate) fn key(query: &str, context: &[String], options: &ReasoningOptions) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        query.hash(&mut hasher);
+        context.hash(&mut hasher);
+        // ReasoningOptions has float fields, so hash its canonical JSON.
+        serde_json::to_string(options)
+            .expect("reasoning options serialize infallibly")
+            .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns an unexpired cached result, dropping the entry when its
+    /// TTL has passed.
+    pub(crate) fn get(&self, key: u64) -> Option<ReasoningResult> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(&key) {
+            Some(entry) if entry.inserted_at.elapsed() < self.options.ttl => {
+                Some(entry.result.clone())
+            }
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(crate) fn insert(&self, key: u64, result: ReasoningResult) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= self.options.max_entries.max(1) {
+            // Evict expired entries first, then the oldest survivor.
+            entries.retain(|_, entry| entry.inserted_at.elapsed() < self.options.ttl);
+            if entries.len() >= self.options.max_entries.max(1) {
+                if let Some((&oldest, _)) =
+                    entries.iter().min_by_key(|(_, entry)| entry.sequence)
+                {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        entries.insert(
+            key,
+            CacheEntry {
+                result,
+                inserted_at: Instant::now(),
+                sequence: self.sequence.fetch_add(1, Ordering::Relaxed),
+            },
+        );
+    }
+
+    /// Drops every cached result whose evidence or conclusion mentions
+    /// the memory, so a changed fact cannot serve stale conclusions.
+    pub fn invalidate_memory(&self, memory_id: &str) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, entry| {
+            !entry.result.conclusion.contains(memory_id)
+                && !entry
+                    .result
+                    .supporting_evidence
+                    .iter()
+                    .any(|evidence| evidence.contains(memory_id))
+        });
+    }
+
+    /// Applies one brain event: memory changes invalidate dependent
+    /// entries, other events are ignored.
+    pub fn handle_event(&self, event: &BrainEvent) {
+        match event {
+            BrainEvent::MemoryStored { memory_id }
+            | BrainEvent::MemoryDeleted { memory_id }
+            | BrainEvent::MemoryDecayed { memory_id, .. }
+            | BrainEvent::StrengthChanged { memory_id, .. } => {
+                self.invalidate_memory(memory_id);
+            }
+            BrainEvent::PatternLearned { .. }
+            | BrainEvent::ReasoningCompleted { .. }
+            | BrainEvent::WriteReviewed { .. } => {}
+        }
+    }
+
+    /// Drops every entry.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Current entry count, including not-yet-reaped expired entries.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().unwrap().is_empty()
+    }
+}
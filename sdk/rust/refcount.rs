@@ -0,0 +1,119 @@
+//! Application-side memory reference counting.
+//!
+//! When several subsystems share one brain, nothing stops one of them from
+//! deleting a memory another still depends on. [`RefCounter`] lets each
+//! owner [`retain`](RefCounter::retain) the memories it relies on and
+//! [`release`](RefCounter::release) them when done;
+//! [`delete_if_unreferenced`](RefCounter::delete_if_unreferenced) only
+//! removes a memory once no owner holds a reference. Owners are named
+//! strings, so a crashed component's references can be cleaned up with
+//! [`release_owner`](RefCounter::release_owner).
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use crate::{BrainAIClient, BrainAIError, Result};
+
+/// Reference-counting guard over memory deletion.
+pub struct RefCounter<C: BrainAIClient> {
+    client: C,
+    /// Owners holding a reference, keyed by memory ID.
+    refs: Mutex<HashMap<String, HashSet<String>>>,
+}
+
+impl<C: BrainAIClient> RefCounter<C> {
+    /// Wraps a client with an empty reference table.
+    pub fn new(client: C) -> Self {
+        RefCounter {
+            client,
+            refs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the wrapped client.
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Records that `owner` depends on the memory. Retaining twice with the
+    /// same owner is a no-op; returns the resulting reference count.
+    pub fn retain(&self, memory_id: &str, owner: &str) -> usize {
+        let mut refs = self.refs.lock().unwrap();
+        let owners = refs.entry(memory_id.to_string()).or_default();
+        owners.insert(owner.to_string());
+        owners.len()
+    }
+
+    /// Drops `owner`'s reference; returns the remaining count.
+    pub fn release(&self, memory_id: &str, owner: &str) -> usize {
+        let mut refs = self.refs.lock().unwrap();
+        if let Some(owners) = refs.get_mut(memory_id) {
+            owners.remove(owner);
+            let remaining = owners.len();
+            if remaining == 0 {
+                refs.remove(memory_id);
+            }
+            remaining
+        } else {
+            0
+        }
+    }
+
+    /// Drops every reference held by `owner` (e.g. after it crashed),
+    /// returning the count of memories released.
+    pub fn release_owner(&self, owner: &str) -> usize {
+        let mut refs = self.refs.lock().unwrap();
+        let mut released = 0;
+        refs.retain(|_, owners| {
+            if owners.remove(owner) {
+                released += 1;
+            }
+            !owners.is_empty()
+        });
+        released
+    }
+
+    /// Current reference count for a memory.
+    pub fn refcount(&self, memory_id: &str) -> usize {
+        self.refs
+            .lock()
+            .unwrap()
+            .get(memory_id)
+            .map(HashSet::len)
+            .unwrap_or(0)
+    }
+
+    /// Whether any owner still references the memory.
+    pub fn is_referenced(&self, memory_id: &str) -> bool {
+        self.refcount(memory_id) > 0
+    }
+
+    /// Owners currently referencing the memory, sorted.
+    pub fn owners(&self, memory_id: &str) -> Vec<String> {
+        let mut owners: Vec<String> = self
+            .refs
+            .lock()
+            .unwrap()
+            .get(memory_id)
+            .map(|set| set.iter().cloned().collect())
+            .unwrap_or_default();
+        owners.sort();
+        owners
+    }
+
+    /// Deletes the memory only if no owner references it.
+    ///
+    /// Fails with [`BrainAIError::InvalidInput`] naming the holders
+    /// otherwise, so callers can decide whether to force via the plain
+    /// client.
+    pub async fn delete_if_unreferenced(&self, memory_id: &str) -> Result<bool> {
+        let owners = self.owners(memory_id);
+        if !owners.is_empty() {
+            return Err(BrainAIError::InvalidInput(format!(
+                "memory {memory_id} is still referenced by: {}",
+                owners.join(", ")
+            )));
+        }
+        self.client.delete_memory(memory_id).await
+    }
+}
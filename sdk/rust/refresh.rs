@@ -0,0 +1,244 @@
+//! Scheduled knowledge refresh from external sources.
+//!
+//! Ingested documentation goes stale the day after it is stored. A
+//! [`RefreshPolicy`] attaches a source URL and interval to a memory;
+//! [`Refresher`] periodically re-fetches due sources, compares a
+//! content hash, rewrites the memory (and its embedding, when an
+//! [`Embedder`] is wired and the memory links a vector) only when the
+//! source actually changed, and keeps a bounded refresh history in the
+//! memory's metadata for auditing.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::embed::Embedder;
+use crate::vector_utils::now_millis;
+use crate::{BrainAIError, Memory, Result};
+
+/// Metadata key holding the source URL.
+pub const REFRESH_URL: &str = "refresh_url";
+/// Metadata key holding the refresh interval in milliseconds.
+pub const REFRESH_INTERVAL_MS: &str = "refresh_interval_ms";
+/// Metadata key holding the last refresh time (unix milliseconds).
+const REFRESH_LAST_AT: &str = "refresh_last_at";
+/// Metadata key holding the hash of the last fetched content.
+const REFRESH_HASH: &str = "refresh_hash";
+/// Metadata key holding the refresh history array.
+const REFRESH_HISTORY: &str = "refresh_history";
+
+/// History entries kept per memory.
+const HISTORY_LIMIT: usize = 20;
+
+/// Page size when scanning for refreshable memories.
+const SCAN_PAGE_SIZE: usize = 500;
+
+/// When and where a memory refreshes from.
+#[derive(Debug, Clone)]
+pub struct RefreshPolicy {
+    /// Source to re-fetch; the response body becomes the new content.
+    pub url: String,
+    /// How often the source is re-checked.
+    pub interval: Duration,
+}
+
+/// Outcome of one [`Refresher::refresh_due`] sweep.
+#[derive(Debug, Default, Clone)]
+pub struct RefreshReport {
+    /// Memories checked because their interval had elapsed.
+    pub checked: usize,
+    /// Memories whose source had changed and were rewritten.
+    pub updated: Vec<String>,
+    /// Fetch or update failures as `(memory_id, error)`.
+    pub failed: Vec<(String, String)>,
+}
+
+/// Attaches (or replaces) a refresh policy on a memory.
+pub async fn attach_refresh_policy(
+    client: &dyn BrainAIClient,
+    memory_id: &str,
+    policy: RefreshPolicy,
+) -> Result<bool> {
+    if policy.url.trim().is_empty() {
+        return Err(BrainAIError::InvalidInput(
+            "refresh policy needs a source URL".to_string(),
+        ));
+    }
+    let Some(memory) = client.get_memory(memory_id).await? else {
+        return Ok(false);
+    };
+    let mut metadata = memory.metadata;
+    metadata.insert(REFRESH_URL.to_string(), json!(policy.url));
+    metadata.insert(
+        REFRESH_INTERVAL_MS.to_string(),
+        json!(policy.interval.as_millis() as i64),
+    );
+    client.update_memory(memory_id, memory.content, Some(metadata)).await
+}
+
+/// Periodically re-fetches memories with refresh policies.
+pub struct Refresher {
+    client: Arc<dyn BrainAIClient>,
+    http: reqwest::Client,
+    embedder: Option<Arc<dyn Embedder>>,
+}
+
+impl Refresher {
+    pub fn new(client: Arc<dyn BrainAIClient>) -> Self {
+        Refresher {
+            client,
+            http: reqwest::Client::new(),
+            embedder: None,
+        }
+    }
+
+    /// Wires an embedder so refreshed memories that link a vector get
+    /// re-embedded too.
+    pub fn with_embedder(mut self, embedder: Arc<dyn Embedder>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Scans for memories whose refresh interval has elapsed and
+    /// refreshes each. Failures are recorded per memory, never fatal
+    /// for the sweep.
+    pub async fn refresh_due(&self) -> Result<RefreshReport> {
+        let mut report = RefreshReport::default();
+        let now = now_millis();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = self
+                .client
+                .list_memories_page(None, SCAN_PAGE_SIZE, cursor.as_deref())
+                .await?;
+            for memory in &page.memories {
+                if !due(memory, now) {
+                    continue;
+                }
+                report.checked += 1;
+                match self.refresh_one(memory).await {
+                    Ok(true) => report.updated.push(memory.id.clone()),
+                    Ok(false) => {}
+                    Err(err) => report.failed.push((memory.id.clone(), err.to_string())),
+                }
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        Ok(report)
+    }
+
+    /// Re-fetches one memory's source. Returns whether the content had
+    /// changed and the memory was rewritten.
+    async fn refresh_one(&self, memory: &Memory) -> Result<bool> {
+        let url = memory
+            .metadata
+            .get(REFRESH_URL)
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                BrainAIError::InvalidInput(format!("memory {} has no refresh URL", memory.id))
+            })?
+            .to_string();
+        let body = self
+            .http
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()
+            .map_err(BrainAIError::Http)?
+            .text()
+            .await?;
+        let hash = content_hash(&body);
+        let previous = memory.metadata.get(REFRESH_HASH).and_then(Value::as_str);
+        let changed = previous != Some(hash.as_str());
+
+        let mut metadata = memory.metadata.clone();
+        metadata.insert(REFRESH_LAST_AT.to_string(), json!(now_millis()));
+        metadata.insert(REFRESH_HASH.to_string(), json!(hash));
+        push_history(&mut metadata, changed);
+
+        let content = if changed {
+            json!(body)
+        } else {
+            memory.content.clone()
+        };
+        self.client
+            .update_memory(&memory.id, content, Some(metadata))
+            .await?;
+
+        if changed {
+            if let (Some(embedder), Some(vector_id)) = (
+                &self.embedder,
+                memory.metadata.get("vector_id").and_then(Value::as_str),
+            ) {
+                let vector = embedder.embed(&body).await?;
+                self.client.update_vector(vector_id, Some(vector), None).await?;
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Runs [`refresh_due`](Self::refresh_due) on an interval until the
+    /// task is aborted.
+    pub fn spawn(self: Arc<Self>, poll_interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(poll_interval);
+            loop {
+                ticker.tick().await;
+                if let Err(err) = self.refresh_due().await {
+                    eprintln!("[brain-ai] knowledge refresh sweep failed: {err}");
+                }
+            }
+        })
+    }
+}
+
+/// Whether a memory carries a policy whose interval has elapsed.
+fn due(memory: &Memory, now: i64) -> bool {
+    let Some(interval) = memory
+        .metadata
+        .get(REFRESH_INTERVAL_MS)
+        .and_then(Value::as_i64)
+    else {
+        return false;
+    };
+    if !memory.metadata.contains_key(REFRESH_URL) {
+        return false;
+    }
+    let last = memory
+        .metadata
+        .get(REFRESH_LAST_AT)
+        .and_then(Value::as_i64)
+        .unwrap_or(0);
+    now - last >= interval.max(0)
+}
+
+/// Appends a history entry, keeping the newest [`HISTORY_LIMIT`].
+fn push_history(metadata: &mut HashMap<String, Value>, changed: bool) {
+    let mut history = metadata
+        .get(REFRESH_HISTORY)
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    history.push(json!({"at": now_millis(), "changed": changed}));
+    if history.len() > HISTORY_LIMIT {
+        let excess = history.len() - HISTORY_LIMIT;
+        history.drain(..excess);
+    }
+    metadata.insert(REFRESH_HISTORY.to_string(), Value::Array(history));
+}
+
+/// FNV-1a over the fetched body; cheap and stable across runs.
+fn content_hash(text: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in text.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
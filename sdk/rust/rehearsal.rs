@@ -0,0 +1,184 @@
+//! Spaced-repetition rehearsal for memories.
+//!
+//! Decay weakens what isn't used; rehearsal is the deliberate counterpart.
+//! [`RehearsalScheduler`] keeps an SM-2-style review state per memory:
+//! [`due`](RehearsalScheduler::due) returns the memories whose review
+//! interval has elapsed, and [`rehearse`](RehearsalScheduler::rehearse)
+//! records how well the memory was recalled, adjusting its strength on the
+//! backend and scheduling the next review — short intervals for forgotten
+//! material, exponentially growing ones for easy recalls.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIClient, BrainAIError, Memory, Result};
+
+const DAY_MS: i64 = 24 * 3600 * 1000;
+
+/// How well a memory was recalled during rehearsal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecallQuality {
+    /// Not recalled at all; the interval resets.
+    Forgot,
+    /// Recalled with difficulty.
+    Hard,
+    /// Recalled correctly.
+    Good,
+    /// Recalled instantly.
+    Easy,
+}
+
+impl RecallQuality {
+    /// SM-2 quality score equivalent (0–5 scale, collapsed to four grades).
+    fn score(self) -> f64 {
+        match self {
+            RecallQuality::Forgot => 1.0,
+            RecallQuality::Hard => 3.0,
+            RecallQuality::Good => 4.0,
+            RecallQuality::Easy => 5.0,
+        }
+    }
+
+    /// Strength delta applied to the backend memory.
+    fn strength_delta(self) -> f64 {
+        match self {
+            RecallQuality::Forgot => -0.1,
+            RecallQuality::Hard => 0.02,
+            RecallQuality::Good => 0.05,
+            RecallQuality::Easy => 0.1,
+        }
+    }
+}
+
+/// Per-memory review state (SM-2 variables).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewState {
+    /// Current inter-review interval in days.
+    pub interval_days: f64,
+    /// Successful repetitions in a row.
+    pub repetitions: u32,
+    /// SM-2 easiness factor, clamped to at least 1.3.
+    pub easiness: f64,
+    /// Next review time (unix milliseconds).
+    pub due_at: i64,
+    /// Last review time (unix milliseconds).
+    pub last_reviewed: i64,
+}
+
+impl ReviewState {
+    fn initial(now: i64) -> Self {
+        ReviewState {
+            interval_days: 1.0,
+            repetitions: 0,
+            easiness: 2.5,
+            due_at: now,
+            last_reviewed: 0,
+        }
+    }
+}
+
+/// Spaced-repetition scheduler over any client.
+pub struct RehearsalScheduler<C: BrainAIClient> {
+    client: C,
+    states: Mutex<HashMap<String, ReviewState>>,
+}
+
+impl<C: BrainAIClient> RehearsalScheduler<C> {
+    /// Creates a scheduler with no tracked memories.
+    pub fn new(client: C) -> Self {
+        RehearsalScheduler {
+            client,
+            states: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the wrapped client.
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Enrolls a memory for rehearsal; it becomes due immediately.
+    pub fn track(&self, memory_id: &str) {
+        self.states
+            .lock()
+            .unwrap()
+            .entry(memory_id.to_string())
+            .or_insert_with(|| ReviewState::initial(now_millis()));
+    }
+
+    /// Stops rehearsing a memory.
+    pub fn untrack(&self, memory_id: &str) {
+        self.states.lock().unwrap().remove(memory_id);
+    }
+
+    /// Returns the tracked memories due for review, most overdue first.
+    ///
+    /// Memories deleted on the backend are dropped from tracking.
+    pub async fn due(&self, limit: usize) -> Result<Vec<Memory>> {
+        let now = now_millis();
+        let mut due_ids: Vec<(String, i64)> = {
+            let states = self.states.lock().unwrap();
+            states
+                .iter()
+                .filter(|(_, s)| s.due_at <= now)
+                .map(|(id, s)| (id.clone(), s.due_at))
+                .collect()
+        };
+        due_ids.sort_by_key(|(_, due_at)| *due_at);
+        let mut memories = Vec::new();
+        for (id, _) in due_ids.into_iter().take(limit) {
+            match self.client.get_memory(&id).await? {
+                Some(memory) => memories.push(memory),
+                None => self.untrack(&id),
+            }
+        }
+        Ok(memories)
+    }
+
+    /// Records a rehearsal outcome, updating backend strength and the next
+    /// review interval.
+    pub async fn rehearse(&self, memory_id: &str, quality: RecallQuality) -> Result<ReviewState> {
+        {
+            let states = self.states.lock().unwrap();
+            if !states.contains_key(memory_id) {
+                return Err(BrainAIError::NotFound(format!(
+                    "memory {memory_id} is not tracked for rehearsal"
+                )));
+            }
+        }
+        self.client
+            .update_memory_strength(memory_id, quality.strength_delta())
+            .await?;
+
+        let now = now_millis();
+        let mut states = self.states.lock().unwrap();
+        let state = states.get_mut(memory_id).expect("checked above");
+        let q = quality.score();
+        // SM-2 easiness update.
+        state.easiness =
+            (state.easiness + 0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02)).max(1.3);
+        if quality == RecallQuality::Forgot {
+            state.repetitions = 0;
+            state.interval_days = 1.0;
+        } else {
+            state.repetitions += 1;
+            state.interval_days = match state.repetitions {
+                1 => 1.0,
+                2 => 6.0,
+                _ => state.interval_days * state.easiness,
+            };
+        }
+        state.last_reviewed = now;
+        state.due_at = now + (state.interval_days * DAY_MS as f64) as i64;
+        Ok(state.clone())
+    }
+
+    /// Returns the review state for a tracked memory.
+    pub fn review_state(&self, memory_id: &str) -> Option<ReviewState> {
+        self.states.lock().unwrap().get(memory_id).cloned()
+    }
+}
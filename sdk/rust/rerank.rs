@@ -0,0 +1,107 @@
+//! Client-side reranking stage for search results.
+//!
+//! First-stage retrieval optimizes recall; a reranker re-scores the
+//! candidates against the query to sharpen precision at the top. The
+//! [`Reranker`] trait is the hook for custom models (cross-encoders, LLM
+//! judges, business rules); [`OverlapReranker`] is the built-in default,
+//! scoring query/candidate term overlap so it works with no extra model.
+//! [`search_memories_reranked`] over-fetches, reranks, and truncates.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::{Result, SearchResult};
+
+/// Re-scores retrieved candidates against the query.
+#[async_trait]
+pub trait Reranker: Send + Sync {
+    /// Returns one relevance score per candidate, in candidate order.
+    /// Higher is more relevant; scales need not match the retrieval score.
+    async fn rerank(&self, query: &str, candidates: &[SearchResult]) -> Result<Vec<f64>>;
+}
+
+/// Default reranker scoring lexical cross-similarity between the query
+/// and each candidate's content: Jaccard overlap of lowercase terms,
+/// with a small boost for candidates containing the query as a phrase.
+pub struct OverlapReranker;
+
+fn terms(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn content_text(content: &Value) -> String {
+    match content {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[async_trait]
+impl Reranker for OverlapReranker {
+    async fn rerank(&self, query: &str, candidates: &[SearchResult]) -> Result<Vec<f64>> {
+        let query_terms = terms(query);
+        let query_lower = query.to_lowercase();
+        Ok(candidates
+            .iter()
+            .map(|candidate| {
+                let text = content_text(&candidate.content);
+                let candidate_terms = terms(&text);
+                let intersection = query_terms.intersection(&candidate_terms).count();
+                let union = query_terms.union(&candidate_terms).count();
+                let jaccard = if union == 0 {
+                    0.0
+                } else {
+                    intersection as f64 / union as f64
+                };
+                let phrase_boost = if !query_lower.is_empty()
+                    && text.to_lowercase().contains(&query_lower)
+                {
+                    0.25
+                } else {
+                    0.0
+                };
+                jaccard + phrase_boost
+            })
+            .collect())
+    }
+}
+
+/// Searches with an over-fetched candidate pool, reranks it, and returns
+/// the top `limit` results.
+///
+/// The pool is `limit * 4` (minimum 20) candidates so the reranker has
+/// room to promote hits the first stage placed low. Returned results carry
+/// the reranker's score in place of the retrieval score.
+pub async fn search_memories_reranked(
+    client: &dyn BrainAIClient,
+    query: &str,
+    limit: usize,
+    reranker: &dyn Reranker,
+) -> Result<Vec<SearchResult>> {
+    let pool = (limit * 4).max(20);
+    let candidates = client.search_memories(json!(query), pool).await?;
+    if candidates.is_empty() {
+        return Ok(candidates);
+    }
+    let scores = reranker.rerank(query, &candidates).await?;
+    let mut scored: Vec<(f64, SearchResult)> = candidates
+        .into_iter()
+        .zip(scores)
+        .map(|(candidate, score)| (score, candidate))
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.id.cmp(&b.1.id)));
+    scored.truncate(limit);
+    Ok(scored
+        .into_iter()
+        .map(|(score, mut result)| {
+            result.score = score;
+            result
+        })
+        .collect())
+}
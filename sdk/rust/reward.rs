@@ -0,0 +1,163 @@
+//! Reinforcement-learning style reward signals.
+//!
+//! Agent builders doing simple RL loops previously had to encode rewards
+//! into generic feedback strings. [`RewardLearner`] keeps per
+//! state/action value estimates with an eligibility trace — so a reward
+//! credits not just the last action but the recent path that led to it —
+//! and mirrors every signal into the server's learning endpoints, where
+//! rewarded behaviour accrues as patterns.
+//! [`best_action`](RewardLearner::best_action) ranks the known actions
+//! for a state by estimated value.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::client::BrainAIClient;
+use crate::Result;
+
+/// Learning-rate and trace knobs for [`RewardLearner`].
+#[derive(Debug, Clone, Copy)]
+pub struct RewardOptions {
+    /// Fraction of the reward signal applied per update.
+    pub learning_rate: f64,
+    /// Eligibility-trace decay per reward (the λ in TD(λ)); `0.0`
+    /// credits only the rewarded pair, `1.0` never forgets the path.
+    pub trace_decay: f64,
+    /// Traces below this are dropped rather than decayed forever.
+    pub trace_floor: f64,
+}
+
+impl Default for RewardOptions {
+    fn default() -> Self {
+        RewardOptions {
+            learning_rate: 0.1,
+            trace_decay: 0.9,
+            trace_floor: 0.01,
+        }
+    }
+}
+
+/// One action's estimated value in a state.
+#[derive(Debug, Clone)]
+pub struct ScoredAction {
+    pub action: String,
+    /// Running value estimate; higher is better.
+    pub value: f64,
+    /// Times the pair has been rewarded (or penalized).
+    pub visits: u64,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Estimate {
+    value: f64,
+    visits: u64,
+}
+
+#[derive(Debug, Default)]
+struct RewardState {
+    /// Value estimates keyed by `(state_context, action)`.
+    estimates: HashMap<(String, String), Estimate>,
+    /// Eligibility traces over the same keys.
+    traces: HashMap<(String, String), f64>,
+}
+
+/// Client-side value learner over the SDK's learning endpoints.
+pub struct RewardLearner<'a> {
+    client: &'a dyn BrainAIClient,
+    options: RewardOptions,
+    state: Mutex<RewardState>,
+}
+
+impl<'a> RewardLearner<'a> {
+    pub fn new(client: &'a dyn BrainAIClient) -> Self {
+        RewardLearner {
+            client,
+            options: RewardOptions::default(),
+            state: Mutex::new(RewardState::default()),
+        }
+    }
+
+    /// Overrides the learning-rate and trace settings.
+    pub fn with_options(mut self, options: RewardOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Applies a reward (or penalty, when negative) to an action taken
+    /// in a state.
+    ///
+    /// The pair's eligibility trace is set to 1 and every traced pair is
+    /// nudged toward the reward in proportion to its trace, so earlier
+    /// steps on the path share the credit. The signal is also mirrored
+    /// to the server: the pair is learned as a pattern tagged with the
+    /// state, and the reward's sign becomes positive or negative
+    /// feedback.
+    pub async fn reward(&self, state_context: &str, action: &str, reward: f64) -> Result<()> {
+        {
+            let mut state = self.state.lock().unwrap();
+            let key = (state_context.to_string(), action.to_string());
+            state.traces.insert(key.clone(), 1.0);
+
+            let rate = self.options.learning_rate;
+            let updates: Vec<((String, String), f64)> = state
+                .traces
+                .iter()
+                .map(|(pair, trace)| (pair.clone(), trace * rate * reward))
+                .collect();
+            for (pair, delta) in updates {
+                let estimate = state.estimates.entry(pair).or_default();
+                estimate.value += delta;
+            }
+            state.estimates.entry(key).or_default().visits += 1;
+
+            let decay = self.options.trace_decay;
+            let floor = self.options.trace_floor;
+            for trace in state.traces.values_mut() {
+                *trace *= decay;
+            }
+            state.traces.retain(|_, trace| *trace >= floor);
+        }
+
+        let pattern = format!("{state_context} -> {action}");
+        let context = vec![
+            format!("state:{state_context}"),
+            format!("action:{action}"),
+            format!("reward:{reward:+.4}"),
+        ];
+        self.client.learn(&pattern, context).await?;
+        let feedback_type = if reward >= 0.0 { "positive" } else { "negative" };
+        self.client
+            .add_feedback(
+                feedback_type,
+                &pattern,
+                Some(&format!("reward signal {reward:+.4}")),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// The known actions for a state, best first. Empty until the state
+    /// has been rewarded at least once.
+    pub fn best_action(&self, state_context: &str) -> Vec<ScoredAction> {
+        let state = self.state.lock().unwrap();
+        let mut actions: Vec<ScoredAction> = state
+            .estimates
+            .iter()
+            .filter(|((context, _), _)| context == state_context)
+            .map(|((_, action), estimate)| ScoredAction {
+                action: action.clone(),
+                value: estimate.value,
+                visits: estimate.visits,
+            })
+            .collect();
+        actions.sort_by(|a, b| b.value.total_cmp(&a.value));
+        actions
+    }
+
+    /// Drops the eligibility traces, e.g. at an episode boundary, so the
+    /// next reward does not credit the previous episode's path. Value
+    /// estimates survive.
+    pub fn end_episode(&self) {
+        self.state.lock().unwrap().traces.clear();
+    }
+}
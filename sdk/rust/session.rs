@@ -0,0 +1,166 @@
+//! Transactional learning sessions.
+//!
+//! Agents often learn speculatively — trying a strategy, gathering
+//! feedback, and only then deciding whether the lesson should stick.
+//! [`LearningSession`] buffers [`learn`](LearningSession::learn) and
+//! [`add_feedback`](LearningSession::add_feedback) calls locally;
+//! [`commit`](LearningSession::commit) flushes them to the server as one
+//! batch, while [`rollback`](LearningSession::rollback) discards them
+//! without any server traffic. Every committed call carries the session
+//! ID — in the batch payload and as a `session:<id>` context tag on
+//! learned patterns — so a session's effects can be inspected (or
+//! unlearned) later.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde_json::json;
+
+use crate::vector_utils::now_millis;
+use crate::{BatchOperation, BatchResult, BrainAISDK, LearningPattern, OperationType, Result};
+
+/// Process-wide counter so two sessions begun in the same millisecond
+/// still get distinct IDs.
+static SESSION_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// One buffered call, replayed at commit time.
+#[derive(Debug, Clone)]
+enum BufferedCall {
+    Learn {
+        pattern: String,
+        context: Vec<String>,
+    },
+    Feedback {
+        feedback_type: String,
+        information: String,
+        reasoning: Option<String>,
+    },
+}
+
+/// A buffered learning transaction; see the module docs.
+///
+/// Dropping an uncommitted session behaves like
+/// [`rollback`](Self::rollback): nothing reaches the server.
+#[derive(Debug)]
+pub struct LearningSession<'a> {
+    sdk: &'a BrainAISDK,
+    id: String,
+    buffered: Vec<BufferedCall>,
+}
+
+impl BrainAISDK {
+    /// Begins a learning session buffering `learn` and `add_feedback`
+    /// calls until committed.
+    pub fn begin_learning_session(&self) -> LearningSession<'_> {
+        let id = format!(
+            "session-{:x}-{:x}",
+            now_millis(),
+            SESSION_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        LearningSession {
+            sdk: self,
+            id,
+            buffered: Vec::new(),
+        }
+    }
+}
+
+impl LearningSession<'_> {
+    /// The session's ID, as tagged onto every committed pattern.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Buffered calls not yet committed.
+    pub fn pending(&self) -> usize {
+        self.buffered.len()
+    }
+
+    /// Buffers a learn call; nothing is sent until
+    /// [`commit`](Self::commit).
+    pub fn learn(&mut self, pattern: &str, context: Vec<String>) {
+        self.buffered.push(BufferedCall::Learn {
+            pattern: pattern.to_string(),
+            context,
+        });
+    }
+
+    /// Buffers a feedback call; nothing is sent until
+    /// [`commit`](Self::commit).
+    pub fn add_feedback(
+        &mut self,
+        feedback_type: &str,
+        information: &str,
+        reasoning: Option<&str>,
+    ) {
+        self.buffered.push(BufferedCall::Feedback {
+            feedback_type: feedback_type.to_string(),
+            information: information.to_string(),
+            reasoning: reasoning.map(str::to_string),
+        });
+    }
+
+    /// Flushes every buffered call to the server as one batch, in the
+    /// order they were buffered, returning one result per call. Learned
+    /// patterns get a `session:<id>` context tag for [`patterns`] to
+    /// find later.
+    pub async fn commit(self) -> Result<Vec<BatchResult>> {
+        if self.buffered.is_empty() {
+            return Ok(Vec::new());
+        }
+        let tag = session_tag(&self.id);
+        let operations = self
+            .buffered
+            .into_iter()
+            .map(|call| match call {
+                BufferedCall::Learn { pattern, mut context } => {
+                    context.push(tag.clone());
+                    BatchOperation {
+                        operation_type: OperationType::Learn,
+                        data: json!({
+                            "pattern": pattern,
+                            "context": context,
+                            "learning_rate": self.sdk.config().learning_rate,
+                            "session_id": self.id,
+                        }),
+                    }
+                }
+                BufferedCall::Feedback {
+                    feedback_type,
+                    information,
+                    reasoning,
+                } => BatchOperation {
+                    operation_type: OperationType::Custom("feedback".to_string()),
+                    data: json!({
+                        "type": feedback_type,
+                        "information": information,
+                        "reasoning": reasoning,
+                        "session_id": self.id,
+                    }),
+                },
+            })
+            .collect();
+        self.sdk.batch(operations).await
+    }
+
+    /// Discards every buffered call; the server never sees them.
+    pub fn rollback(mut self) {
+        self.buffered.clear();
+    }
+}
+
+/// Context tag a session stamps onto its patterns.
+fn session_tag(session_id: &str) -> String {
+    format!("session:{session_id}")
+}
+
+/// Fetches the learned patterns a committed session produced, by its
+/// `session:<id>` context tag.
+pub async fn patterns(sdk: &BrainAISDK, session_id: &str) -> Result<Vec<LearningPattern>> {
+    let tag = session_tag(session_id);
+    Ok(sdk
+        .get_learning_patterns()
+        .await?
+        .into_iter()
+        .filter(|pattern| pattern.context.iter().any(|entry| entry == &tag))
+        .collect())
+}
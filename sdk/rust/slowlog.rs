@@ -0,0 +1,177 @@
+//! Slow-call logging and latency budget alerts.
+//!
+//! Finding which retrieval patterns blow a latency budget is guesswork
+//! without server-side log access. When enabled via
+//! [`BrainAIConfig::with_slow_log`](crate::BrainAIConfig), every API
+//! call is timed against a per-operation threshold; calls over budget
+//! emit a structured log line, land in a bounded ring of recent
+//! [`SlowCall`]s, and increment a per-operation counter. A profiled
+//! search attaches its [`QueryProfile`] to the slow entry it produced,
+//! so the offending query plan is right next to the timing.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::vector_utils::now_millis;
+use crate::QueryProfile;
+
+/// Thresholds and sizing for the slow-call log.
+#[derive(Debug, Clone)]
+pub struct SlowLogOptions {
+    /// Budget applied to operations without an override.
+    pub default_threshold: Duration,
+    /// Per-operation budgets, keyed by operation label (the endpoint
+    /// path with IDs stripped, e.g. `/api/memory/search`).
+    pub per_operation: HashMap<String, Duration>,
+    /// Recent slow calls kept for inspection.
+    pub capacity: usize,
+}
+
+impl Default for SlowLogOptions {
+    fn default() -> Self {
+        SlowLogOptions {
+            default_threshold: Duration::from_secs(1),
+            per_operation: HashMap::new(),
+            capacity: 128,
+        }
+    }
+}
+
+impl SlowLogOptions {
+    /// Sets the budget for one operation label.
+    pub fn with_operation_threshold(
+        mut self,
+        operation: impl Into<String>,
+        threshold: Duration,
+    ) -> Self {
+        self.per_operation.insert(operation.into(), threshold);
+        self
+    }
+}
+
+/// One call that exceeded its latency budget.
+#[derive(Debug, Clone)]
+pub struct SlowCall {
+    /// Operation label, e.g. `/api/memory/search`.
+    pub operation: String,
+    pub duration: Duration,
+    /// The budget the call blew.
+    pub threshold: Duration,
+    /// When the call finished (unix milliseconds).
+    pub at: i64,
+    /// The query plan, when the call was a profiled search.
+    pub profile: Option<QueryProfile>,
+}
+
+/// Bounded log of over-budget calls with per-operation counters.
+#[derive(Debug)]
+pub struct SlowLog {
+    options: SlowLogOptions,
+    entries: Mutex<VecDeque<SlowCall>>,
+    counts: Mutex<HashMap<String, u64>>,
+    total: AtomicU64,
+}
+
+impl SlowLog {
+    pub fn new(options: SlowLogOptions) -> Self {
+        SlowLog {
+            options,
+            entries: Mutex::new(VecDeque::new()),
+            counts: Mutex::new(HashMap::new()),
+            total: AtomicU64::new(0),
+        }
+    }
+
+    /// The budget for an operation label.
+    pub fn threshold_for(&self, operation: &str) -> Duration {
+        self.options
+            .per_operation
+            .get(operation)
+            .copied()
+            .unwrap_or(self.options.default_threshold)
+    }
+
+    /// Records one finished call, logging it when over budget. Returns
+    /// whether the call was slow.
+    pub(crate) fn record(&self, operation: &str, duration: Duration) -> bool {
+        let threshold = self.threshold_for(operation);
+        if duration <= threshold {
+            return false;
+        }
+        self.total.fetch_add(1, Ordering::Relaxed);
+        *self
+            .counts
+            .lock()
+            .unwrap()
+            .entry(operation.to_string())
+            .or_default() += 1;
+        eprintln!(
+            "[brain-ai] {}",
+            json!({
+                "event": "slow_call",
+                "operation": operation,
+                "duration_ms": duration.as_millis() as u64,
+                "threshold_ms": threshold.as_millis() as u64,
+            })
+        );
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= self.options.capacity.max(1) {
+            entries.pop_front();
+        }
+        entries.push_back(SlowCall {
+            operation: operation.to_string(),
+            duration,
+            threshold,
+            at: now_millis(),
+            profile: None,
+        });
+        true
+    }
+
+    /// Attaches a query profile to the newest slow entry for an
+    /// operation; no-op when that operation has no slow entries.
+    pub(crate) fn attach_profile(&self, operation: &str, profile: &QueryProfile) {
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(entry) = entries
+            .iter_mut()
+            .rev()
+            .find(|entry| entry.operation == operation)
+        {
+            entry.profile = Some(profile.clone());
+        }
+    }
+
+    /// The most recent slow calls, oldest first.
+    pub fn recent(&self) -> Vec<SlowCall> {
+        self.entries.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Slow-call counts per operation label.
+    pub fn counts(&self) -> HashMap<String, u64> {
+        self.counts.lock().unwrap().clone()
+    }
+
+    /// Slow calls observed since the client was built.
+    pub fn total(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+}
+
+/// Coarse operation label for a request path: the path with anything
+/// beyond the operation segments (IDs) dropped, so `/api/memory/mem_42`
+/// and `/api/memory/mem_43` count as one operation.
+pub(crate) fn operation_label(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or(path);
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let kept: Vec<&str> = segments
+        .iter()
+        .take(3)
+        .filter(|segment| !segment.contains('_'))
+        .copied()
+        .collect();
+    format!("/{}", kept.join("/"))
+}
@@ -0,0 +1,276 @@
+//! Memory graph snapshots and snapshot diffing.
+//!
+//! A [`BrainSnapshot`] captures the memories and memory connections visible
+//! to a client at a point in time. [`diff_snapshots`] compares two snapshots
+//! into a typed [`SnapshotDiff`] — memories added, removed, and modified,
+//! strength changes, and edge changes — with a human-readable summary, so a
+//! reviewer can see what the brain learned during a period before promoting
+//! a snapshot.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIClient, Memory, MemoryRelation, Result};
+
+/// Point-in-time capture of the memory graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrainSnapshot {
+    /// Capture time (unix milliseconds).
+    pub taken_at: i64,
+    /// Memories keyed by ID.
+    pub memories: HashMap<String, Memory>,
+    /// Connections between the captured memories, each pair once.
+    #[serde(default)]
+    pub relations: Vec<MemoryRelation>,
+}
+
+/// Order-insensitive key identifying a connection's endpoint pair.
+fn edge_key(relation: &MemoryRelation) -> (String, String) {
+    if relation.from <= relation.to {
+        (relation.from.clone(), relation.to.clone())
+    } else {
+        (relation.to.clone(), relation.from.clone())
+    }
+}
+
+impl BrainSnapshot {
+    /// Captures a snapshot of up to `limit` memories, and the connections
+    /// between them, through any client.
+    pub async fn capture(client: &dyn BrainAIClient, limit: usize) -> Result<Self> {
+        let memories: HashMap<String, Memory> = client
+            .list_memories(None, limit)
+            .await?
+            .into_iter()
+            .map(|m| (m.id.clone(), m))
+            .collect();
+        // Every connection is listed from both endpoints; keep each pair
+        // once.
+        let mut relations: HashMap<(String, String), MemoryRelation> = HashMap::new();
+        for id in memories.keys() {
+            for relation in client.get_relations(id, None).await? {
+                relations.entry(edge_key(&relation)).or_insert(relation);
+            }
+        }
+        let mut relations: Vec<MemoryRelation> = relations.into_values().collect();
+        relations.sort_by_key(edge_key);
+        Ok(BrainSnapshot {
+            taken_at: now_millis(),
+            memories,
+            relations,
+        })
+    }
+}
+
+/// A memory present in both snapshots whose content, metadata, or strength
+/// changed between them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryChange {
+    pub id: String,
+    pub content_changed: bool,
+    pub metadata_changed: bool,
+    pub strength_before: f64,
+    pub strength_after: f64,
+}
+
+impl MemoryChange {
+    /// Net strength change between the two snapshots.
+    pub fn strength_delta(&self) -> f64 {
+        self.strength_after - self.strength_before
+    }
+}
+
+/// A connection present in both snapshots whose type or strength changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgeChange {
+    pub before: MemoryRelation,
+    pub after: MemoryRelation,
+}
+
+/// Typed changeset between two snapshots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    /// Memories present in `b` but not `a`.
+    pub added: Vec<Memory>,
+    /// Memories present in `a` but not `b`.
+    pub removed: Vec<Memory>,
+    /// Memories present in both with observable changes.
+    pub modified: Vec<MemoryChange>,
+    /// Connections present in `b` but not `a`.
+    pub edges_added: Vec<MemoryRelation>,
+    /// Connections present in `a` but not `b`.
+    pub edges_removed: Vec<MemoryRelation>,
+    /// Connections present in both whose type or strength changed.
+    pub edges_changed: Vec<EdgeChange>,
+    /// Capture times of the compared snapshots, `(a, b)`.
+    pub period: (i64, i64),
+}
+
+impl SnapshotDiff {
+    /// Whether the two snapshots are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.modified.is_empty()
+            && self.edges_added.is_empty()
+            && self.edges_removed.is_empty()
+            && self.edges_changed.is_empty()
+    }
+
+    /// Human-readable one-paragraph summary suitable for review notes.
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "No changes between snapshots.".to_string();
+        }
+        let strengthened = self
+            .modified
+            .iter()
+            .filter(|c| c.strength_delta() > 0.0)
+            .count();
+        let weakened = self
+            .modified
+            .iter()
+            .filter(|c| c.strength_delta() < 0.0)
+            .count();
+        format!(
+            "{} memories added, {} removed, {} modified ({} strengthened, {} weakened); \
+             {} edges added, {} removed, {} changed.",
+            self.added.len(),
+            self.removed.len(),
+            self.modified.len(),
+            strengthened,
+            weakened,
+            self.edges_added.len(),
+            self.edges_removed.len(),
+            self.edges_changed.len(),
+        )
+    }
+}
+
+impl std::fmt::Display for SnapshotDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.summary())?;
+        for memory in &self.added {
+            writeln!(f, "  + {} ({})", memory.id, memory.memory_type)?;
+        }
+        for memory in &self.removed {
+            writeln!(f, "  - {} ({})", memory.id, memory.memory_type)?;
+        }
+        for change in &self.modified {
+            let mut what = Vec::new();
+            if change.content_changed {
+                what.push("content".to_string());
+            }
+            if change.metadata_changed {
+                what.push("metadata".to_string());
+            }
+            if change.strength_delta() != 0.0 {
+                what.push(format!("strength {:+.2}", change.strength_delta()));
+            }
+            writeln!(f, "  ~ {} ({})", change.id, what.join(", "))?;
+        }
+        for relation in &self.edges_added {
+            writeln!(
+                f,
+                "  + {} -[{}]-> {}",
+                relation.from, relation.relation, relation.to
+            )?;
+        }
+        for relation in &self.edges_removed {
+            writeln!(
+                f,
+                "  - {} -[{}]-> {}",
+                relation.from, relation.relation, relation.to
+            )?;
+        }
+        for change in &self.edges_changed {
+            writeln!(
+                f,
+                "  ~ {} -[{}]-> {} (was {} at {:.2}, now {:.2})",
+                change.after.from,
+                change.after.relation,
+                change.after.to,
+                change.before.relation,
+                change.before.strength,
+                change.after.strength,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two snapshots, treating `a` as the earlier state.
+pub fn diff_snapshots(a: &BrainSnapshot, b: &BrainSnapshot) -> SnapshotDiff {
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let mut modified = Vec::new();
+
+    for (id, after) in &b.memories {
+        match a.memories.get(id) {
+            None => added.push(after.clone()),
+            Some(before) => {
+                let content_changed = before.content != after.content;
+                let metadata_changed = json!(before.metadata) != json!(after.metadata);
+                if content_changed || metadata_changed || before.strength != after.strength {
+                    modified.push(MemoryChange {
+                        id: id.clone(),
+                        content_changed,
+                        metadata_changed,
+                        strength_before: before.strength,
+                        strength_after: after.strength,
+                    });
+                }
+            }
+        }
+    }
+    for (id, before) in &a.memories {
+        if !b.memories.contains_key(id) {
+            removed.push(before.clone());
+        }
+    }
+
+    let before_edges: HashMap<(String, String), &MemoryRelation> =
+        a.relations.iter().map(|r| (edge_key(r), r)).collect();
+    let after_edges: HashMap<(String, String), &MemoryRelation> =
+        b.relations.iter().map(|r| (edge_key(r), r)).collect();
+    let mut edges_added = Vec::new();
+    let mut edges_changed = Vec::new();
+    for (key, after) in &after_edges {
+        match before_edges.get(key) {
+            None => edges_added.push((*after).clone()),
+            Some(before) if before.relation != after.relation
+                || before.strength != after.strength =>
+            {
+                edges_changed.push(EdgeChange {
+                    before: (*before).clone(),
+                    after: (*after).clone(),
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    let mut edges_removed: Vec<MemoryRelation> = before_edges
+        .iter()
+        .filter(|(key, _)| !after_edges.contains_key(*key))
+        .map(|(_, relation)| (*relation).clone())
+        .collect();
+
+    added.sort_by(|x, y| x.id.cmp(&y.id));
+    removed.sort_by(|x, y| x.id.cmp(&y.id));
+    modified.sort_by(|x, y| x.id.cmp(&y.id));
+    edges_added.sort_by_key(edge_key);
+    edges_removed.sort_by_key(edge_key);
+    edges_changed.sort_by_key(|change| edge_key(&change.after));
+
+    SnapshotDiff {
+        added,
+        removed,
+        modified,
+        edges_added,
+        edges_removed,
+        edges_changed,
+        period: (a.taken_at, b.taken_at),
+    }
+}
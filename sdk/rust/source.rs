@@ -0,0 +1,185 @@
+//! Per-source reliability modeling.
+//!
+//! Feeds differ wildly in quality, and a memory from a rumor mill
+//! should not start life as confident as one from an official API.
+//! [`SourceRegistry`] tracks a reliability score per source, stored in
+//! the brain itself as `source_record` memories so every client sees
+//! the same registry. Reliability is a Laplace-smoothed confirmation
+//! rate updated from feedback on memories of that source; new memories
+//! stored through the registry get their initial confidence weighted by
+//! it, and [`SourceRegistry::get_source_report`] summarizes the lot.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::confidence::{self, apply_confidence_feedback};
+use crate::{MemoryType, Result};
+
+/// Reserved metadata key naming a memory's source.
+pub const SOURCE: &str = "source";
+
+/// Metadata `kind` marking registry records.
+const SOURCE_RECORD_KIND: &str = "source_record";
+
+/// Reliability assumed for a source with no feedback yet.
+pub const DEFAULT_RELIABILITY: f64 = 0.5;
+
+/// Reliability summary for one source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceReport {
+    pub source: String,
+    /// Laplace-smoothed confirmation rate in `0.0..=1.0`.
+    pub reliability: f64,
+    /// Feedback signals received.
+    pub feedback_count: u64,
+    /// Of those, how many confirmed the memory.
+    pub confirmed: u64,
+}
+
+/// Brain-backed registry of source reliability scores.
+pub struct SourceRegistry<'a> {
+    client: &'a dyn BrainAIClient,
+}
+
+impl<'a> SourceRegistry<'a> {
+    pub fn new(client: &'a dyn BrainAIClient) -> Self {
+        SourceRegistry { client }
+    }
+
+    /// Current reliability of a source; [`DEFAULT_RELIABILITY`] when the
+    /// source has never received feedback.
+    pub async fn reliability(&self, source: &str) -> Result<f64> {
+        Ok(self
+            .find_record(source)
+            .await?
+            .map(|(_, report)| report.reliability)
+            .unwrap_or(DEFAULT_RELIABILITY))
+    }
+
+    /// Stores a memory attributed to `source`, seeding its confidence
+    /// with the source's current reliability.
+    pub async fn store_from_source(
+        &self,
+        source: &str,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        let reliability = self.reliability(source).await?;
+        let mut metadata = metadata.unwrap_or_default();
+        metadata.insert(SOURCE.to_string(), json!(source));
+        confidence::store_with_confidence(
+            self.client,
+            content,
+            memory_type,
+            reliability,
+            Some(metadata),
+        )
+        .await
+    }
+
+    /// Records feedback on a memory: updates the memory's confidence
+    /// (scaled by its source's reliability) and folds the signal into
+    /// the source's score. Memories without a `source` metadata key only
+    /// get the confidence update.
+    pub async fn record_feedback(&self, memory_id: &str, confirmed: bool) -> Result<()> {
+        let source = match self.client.get_memory(memory_id).await? {
+            Some(memory) => memory
+                .metadata
+                .get(SOURCE)
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            None => None,
+        };
+        let reliability = match &source {
+            Some(name) => Some(self.reliability(name).await?),
+            None => None,
+        };
+        apply_confidence_feedback(self.client, memory_id, confirmed, reliability).await?;
+        if let Some(name) = source {
+            self.update_source(&name, confirmed).await?;
+        }
+        Ok(())
+    }
+
+    /// All known sources, most reliable first.
+    pub async fn get_source_report(&self) -> Result<Vec<SourceReport>> {
+        let records = self
+            .client
+            .list_memories(
+                Some(HashMap::from([(
+                    "metadata.kind".to_string(),
+                    json!(SOURCE_RECORD_KIND),
+                )])),
+                1_000,
+            )
+            .await?;
+        let mut reports: Vec<SourceReport> = records
+            .into_iter()
+            .filter_map(|memory| serde_json::from_value(memory.content).ok())
+            .collect();
+        reports.sort_by(|a, b| b.reliability.total_cmp(&a.reliability));
+        Ok(reports)
+    }
+
+    /// Folds one feedback signal into a source's record, creating it on
+    /// first contact.
+    async fn update_source(&self, source: &str, confirmed: bool) -> Result<()> {
+        let existing = self.find_record(source).await?;
+        let (feedback_count, confirmed_count) = match &existing {
+            Some((_, report)) => (
+                report.feedback_count + 1,
+                report.confirmed + u64::from(confirmed),
+            ),
+            None => (1, u64::from(confirmed)),
+        };
+        let report = SourceReport {
+            source: source.to_string(),
+            // Laplace smoothing: one imaginary confirmation and one
+            // imaginary refutation keep early scores near the middle.
+            reliability: (confirmed_count + 1) as f64 / (feedback_count + 2) as f64,
+            feedback_count,
+            confirmed: confirmed_count,
+        };
+        let content = serde_json::to_value(&report)
+            .expect("source report serializes infallibly");
+        match existing {
+            Some((record_id, _)) => {
+                self.client.update_memory(&record_id, content, None).await?;
+            }
+            None => {
+                let metadata = HashMap::from([
+                    ("kind".to_string(), json!(SOURCE_RECORD_KIND)),
+                    (SOURCE.to_string(), json!(source)),
+                ]);
+                self.client
+                    .store_memory(content, MemoryType::Semantic, Some(metadata))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds a source's registry record, returning its memory ID and
+    /// parsed report.
+    async fn find_record(&self, source: &str) -> Result<Option<(String, SourceReport)>> {
+        let records = self
+            .client
+            .list_memories(
+                Some(HashMap::from([
+                    ("metadata.kind".to_string(), json!(SOURCE_RECORD_KIND)),
+                    (format!("metadata.{SOURCE}"), json!(source)),
+                ])),
+                1,
+            )
+            .await?;
+        Ok(records.into_iter().next().and_then(|memory| {
+            serde_json::from_value(memory.content)
+                .ok()
+                .map(|report| (memory.id, report))
+        }))
+    }
+}
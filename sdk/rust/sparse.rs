@@ -0,0 +1,182 @@
+//! Sparse vector support for keyword/BM25-style embeddings.
+//!
+//! SPLADE and BM25-style retrieval produce vectors with tens of active
+//! terms out of a vocabulary of tens of thousands; shipping them as dense
+//! JSON arrays is wasteful and lossy. [`SparseVector`] stores only the
+//! active `(index, value)` pairs, the SDK gains
+//! [`store_sparse_vector`](crate::BrainAISDK::store_sparse_vector) and
+//! [`search_sparse`](crate::BrainAISDK::search_sparse) which send that
+//! representation on the wire, and the scoring utilities compute sparse
+//! dot products and cosine locally.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{BrainAIError, BrainAISDK, Endpoint, Result, VectorMatch};
+
+/// A sparse vector as parallel `(index, value)` arrays.
+///
+/// Indices are kept sorted and unique; construction enforces both so the
+/// scoring routines can merge in one linear pass.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SparseVector {
+    pub indices: Vec<u32>,
+    pub values: Vec<f32>,
+}
+
+impl SparseVector {
+    /// Builds a sparse vector from parallel arrays, sorting by index and
+    /// summing duplicate indices.
+    pub fn new(indices: Vec<u32>, values: Vec<f32>) -> Result<Self> {
+        if indices.len() != values.len() {
+            return Err(BrainAIError::InvalidInput(format!(
+                "sparse arrays disagree: {} indices vs {} values",
+                indices.len(),
+                values.len()
+            )));
+        }
+        let mut pairs: Vec<(u32, f32)> = indices.into_iter().zip(values).collect();
+        pairs.sort_by_key(|(index, _)| *index);
+        let mut merged: Vec<(u32, f32)> = Vec::with_capacity(pairs.len());
+        for (index, value) in pairs {
+            match merged.last_mut() {
+                Some((last, acc)) if *last == index => *acc += value,
+                _ => merged.push((index, value)),
+            }
+        }
+        merged.retain(|(_, value)| *value != 0.0);
+        let (indices, values) = merged.into_iter().unzip();
+        Ok(SparseVector { indices, values })
+    }
+
+    /// Builds from `(index, value)` pairs.
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (u32, f32)>) -> Result<Self> {
+        let (indices, values) = pairs.into_iter().unzip();
+        SparseVector::new(indices, values)
+    }
+
+    /// Builds from a dense vector, keeping entries with `|x| > threshold`.
+    pub fn from_dense(dense: &[f32], threshold: f32) -> Self {
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        for (index, &value) in dense.iter().enumerate() {
+            if value.abs() > threshold {
+                indices.push(index as u32);
+                values.push(value);
+            }
+        }
+        SparseVector { indices, values }
+    }
+
+    /// Number of active entries.
+    pub fn nnz(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether there are no active entries.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Expands into a dense vector of the given dimension; entries beyond
+    /// it error rather than truncate silently.
+    pub fn to_dense(&self, dimension: usize) -> Result<Vec<f32>> {
+        let mut dense = vec![0.0f32; dimension];
+        for (&index, &value) in self.indices.iter().zip(&self.values) {
+            let slot = dense.get_mut(index as usize).ok_or_else(|| {
+                BrainAIError::InvalidInput(format!(
+                    "index {index} does not fit in dimension {dimension}"
+                ))
+            })?;
+            *slot = value;
+        }
+        Ok(dense)
+    }
+
+    /// Dot product with another sparse vector; one merge pass over the
+    /// sorted indices.
+    pub fn dot(&self, other: &SparseVector) -> f64 {
+        let mut total = 0.0f64;
+        let (mut i, mut j) = (0usize, 0usize);
+        while i < self.indices.len() && j < other.indices.len() {
+            match self.indices[i].cmp(&other.indices[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    total += f64::from(self.values[i]) * f64::from(other.values[j]);
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        total
+    }
+
+    /// L2 norm of the active values.
+    pub fn norm(&self) -> f64 {
+        self.values
+            .iter()
+            .map(|&x| f64::from(x) * f64::from(x))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Cosine similarity with another sparse vector; `0.0` when either
+    /// side has no magnitude.
+    pub fn cosine(&self, other: &SparseVector) -> f64 {
+        let (na, nb) = (self.norm(), other.norm());
+        if na == 0.0 || nb == 0.0 {
+            return 0.0;
+        }
+        self.dot(other) / (na * nb)
+    }
+}
+
+impl BrainAISDK {
+    /// Stores a sparse vector, sent as `(indices, values)` arrays rather
+    /// than a padded dense array, and returns its ID.
+    pub async fn store_sparse_vector(
+        &self,
+        sparse: SparseVector,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        if sparse.is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "sparse vector has no active entries".to_string(),
+            ));
+        }
+        let body = json!({
+            "sparse": sparse,
+            "metadata": metadata.unwrap_or_default(),
+        });
+        let data: Value = self.request(Endpoint::StoreVector, Some(body)).await?;
+        data.get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or(BrainAIError::Api {
+                status: 200,
+                message: "store_sparse_vector response missing id".to_string(),
+            })
+    }
+
+    /// Searches stored sparse vectors by dot product against a sparse
+    /// query.
+    pub async fn search_sparse(
+        &self,
+        query: SparseVector,
+        limit: usize,
+    ) -> Result<Vec<VectorMatch>> {
+        if query.is_empty() {
+            return Err(BrainAIError::InvalidInput(
+                "sparse query has no active entries".to_string(),
+            ));
+        }
+        let body = json!({
+            "sparse": query,
+            "limit": limit,
+        });
+        self.request(Endpoint::SearchVectors, Some(body)).await
+    }
+}
@@ -0,0 +1,179 @@
+//! Approval workflow for memory writes.
+//!
+//! [`StagingArea`] wraps a client in a staging mode where memory writes land
+//! in a local pending area instead of the knowledge base. A reviewer — human
+//! or automated policy — inspects them via [`StagingArea::list_pending`] and
+//! promotes or discards them with [`StagingArea::approve`] /
+//! [`StagingArea::reject`]. Nothing becomes retrievable until approved,
+//! which is what regulated customers require before content enters the
+//! knowledge base.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIClient, BrainAIError, MemoryType, Result};
+
+/// Review state of a staged write.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewStatus {
+    /// Awaiting review.
+    Pending,
+    /// Approved and committed to the knowledge base.
+    Approved,
+    /// Rejected; never stored.
+    Rejected,
+}
+
+/// A memory write held in the staging area.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StagedWrite {
+    /// Staging-local ID; distinct from the memory ID assigned on approval.
+    pub staging_id: String,
+    pub content: Value,
+    pub memory_type: MemoryType,
+    pub metadata: HashMap<String, Value>,
+    /// Submission time (unix milliseconds).
+    pub submitted_at: i64,
+    pub status: ReviewStatus,
+    /// Reviewer-supplied reason, set on rejection.
+    pub review_note: Option<String>,
+    /// Memory ID assigned by the backend, set on approval.
+    pub memory_id: Option<String>,
+}
+
+/// Staging wrapper that holds memory writes for review before commit.
+#[derive(Debug)]
+pub struct StagingArea<C: BrainAIClient> {
+    inner: C,
+    writes: Mutex<HashMap<String, StagedWrite>>,
+    next_id: AtomicU64,
+}
+
+impl<C: BrainAIClient> StagingArea<C> {
+    /// Wraps a client so memory writes require approval.
+    pub fn new(inner: C) -> Self {
+        StagingArea {
+            inner,
+            writes: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Returns the wrapped client for read paths and non-staged operations.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Stages a memory write and returns its staging ID. The write is not
+    /// retrievable until approved.
+    pub fn stage_memory(
+        &self,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> String {
+        let staging_id = format!("staged_{}", self.next_id.fetch_add(1, Ordering::Relaxed));
+        self.writes.lock().unwrap().insert(
+            staging_id.clone(),
+            StagedWrite {
+                staging_id: staging_id.clone(),
+                content,
+                memory_type,
+                metadata: metadata.unwrap_or_default(),
+                submitted_at: now_millis(),
+                status: ReviewStatus::Pending,
+                review_note: None,
+                memory_id: None,
+            },
+        );
+        staging_id
+    }
+
+    /// Lists writes awaiting review, oldest first.
+    pub fn list_pending(&self) -> Vec<StagedWrite> {
+        let writes = self.writes.lock().unwrap();
+        let mut pending: Vec<StagedWrite> = writes
+            .values()
+            .filter(|w| w.status == ReviewStatus::Pending)
+            .cloned()
+            .collect();
+        pending.sort_by_key(|w| w.submitted_at);
+        pending
+    }
+
+    /// Approves the given staged writes, committing each to the backend.
+    ///
+    /// Returns `(staging_id, memory_id)` pairs for the writes that were
+    /// committed. Unknown or already-reviewed IDs yield an error; writes
+    /// committed before the error stay committed.
+    pub async fn approve(&self, ids: &[&str]) -> Result<Vec<(String, String)>> {
+        let mut committed = Vec::with_capacity(ids.len());
+        for id in ids {
+            let write = {
+                let writes = self.writes.lock().unwrap();
+                match writes.get(*id) {
+                    Some(w) if w.status == ReviewStatus::Pending => w.clone(),
+                    Some(_) => {
+                        return Err(BrainAIError::InvalidInput(format!(
+                            "staged write {id} was already reviewed"
+                        )))
+                    }
+                    None => return Err(BrainAIError::NotFound(format!("staged write {id}"))),
+                }
+            };
+            let memory_id = self
+                .inner
+                .store_memory(
+                    write.content.clone(),
+                    write.memory_type,
+                    Some(write.metadata.clone()),
+                )
+                .await?;
+            let mut writes = self.writes.lock().unwrap();
+            if let Some(w) = writes.get_mut(*id) {
+                w.status = ReviewStatus::Approved;
+                w.memory_id = Some(memory_id.clone());
+            }
+            committed.push(((*id).to_string(), memory_id));
+        }
+        Ok(committed)
+    }
+
+    /// Rejects the given staged writes with a reason; they are kept for
+    /// audit but never stored.
+    pub fn reject(&self, ids: &[&str], reason: &str) -> Result<usize> {
+        let mut writes = self.writes.lock().unwrap();
+        for id in ids {
+            match writes.get(*id) {
+                Some(w) if w.status == ReviewStatus::Pending => {}
+                Some(_) => {
+                    return Err(BrainAIError::InvalidInput(format!(
+                        "staged write {id} was already reviewed"
+                    )))
+                }
+                None => return Err(BrainAIError::NotFound(format!("staged write {id}"))),
+            }
+        }
+        for id in ids {
+            if let Some(w) = writes.get_mut(*id) {
+                w.status = ReviewStatus::Rejected;
+                w.review_note = Some(reason.to_string());
+            }
+        }
+        Ok(ids.len())
+    }
+
+    /// Returns the full audit trail, including reviewed writes.
+    pub fn audit_log(&self) -> Vec<StagedWrite> {
+        let writes = self.writes.lock().unwrap();
+        let mut all: Vec<StagedWrite> = writes.values().cloned().collect();
+        all.sort_by_key(|w| w.submitted_at);
+        all
+    }
+}
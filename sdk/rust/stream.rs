@@ -0,0 +1,149 @@
+//! Streaming reasoning with incremental trace events.
+//!
+//! [`BrainAISDK::reason_stream`] opens the server's streaming reasoning
+//! endpoint and yields [`ReasoningEvent`]s as the engine works — step
+//! started, evidence found, intermediate conclusion, final result — so
+//! a UI can show the reasoning path unfolding instead of spinning until
+//! the full [`ReasoningResult`] lands. The wire format is SSE or
+//! newline-delimited JSON; both are parsed, and the stream is pulled
+//! with [`ReasoningStream::next_event`] so no extra stream crate is
+//! needed.
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{BrainAIError, BrainAISDK, Endpoint, ReasoningResult, Result};
+
+/// One incremental event from a streaming reasoning run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ReasoningEvent {
+    /// The engine began a reasoning step.
+    StepStarted { step: String },
+    /// A memory was pulled in as evidence.
+    EvidenceFound {
+        memory_id: String,
+        #[serde(default)]
+        relevance: f64,
+    },
+    /// A conclusion reached mid-path, possibly revised later.
+    IntermediateConclusion {
+        conclusion: String,
+        #[serde(default)]
+        confidence: f64,
+    },
+    /// The run finished; no further events follow.
+    Final { result: ReasoningResult },
+}
+
+/// A live reasoning stream. Drop it to abandon the run.
+pub struct ReasoningStream {
+    response: reqwest::Response,
+    buffer: Vec<u8>,
+    finished: bool,
+}
+
+impl ReasoningStream {
+    /// Pulls the next event, or `None` once the stream has ended. The
+    /// stream ends itself after a [`ReasoningEvent::Final`].
+    pub async fn next_event(&mut self) -> Result<Option<ReasoningEvent>> {
+        loop {
+            if self.finished {
+                return Ok(None);
+            }
+            // Emit any complete line already buffered before reading more.
+            while let Some(at) = self.buffer.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = self.buffer.drain(..=at).collect();
+                if let Some(event) = parse_line(&line)? {
+                    if matches!(event, ReasoningEvent::Final { .. }) {
+                        self.finished = true;
+                    }
+                    return Ok(Some(event));
+                }
+            }
+            match self.response.chunk().await? {
+                Some(chunk) => self.buffer.extend_from_slice(&chunk),
+                None => {
+                    self.finished = true;
+                    // A trailing event without a final newline still counts.
+                    let rest = std::mem::take(&mut self.buffer);
+                    if let Some(event) = parse_line(&rest)? {
+                        return Ok(Some(event));
+                    }
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    /// Drains the stream, returning the final result and the full event
+    /// trace, for callers that want streaming transport without
+    /// incremental display.
+    pub async fn collect(mut self) -> Result<(ReasoningResult, Vec<ReasoningEvent>)> {
+        let mut events = Vec::new();
+        let mut result = None;
+        while let Some(event) = self.next_event().await? {
+            if let ReasoningEvent::Final { result: r } = &event {
+                result = Some(r.clone());
+            }
+            events.push(event);
+        }
+        result
+            .map(|r| (r, events))
+            .ok_or_else(|| BrainAIError::Api {
+                status: 200,
+                message: "reasoning stream ended without a final result".to_string(),
+            })
+    }
+}
+
+/// Parses one SSE or NDJSON line into an event; `None` for blanks,
+/// comments, and SSE fields other than `data`.
+fn parse_line(line: &[u8]) -> Result<Option<ReasoningEvent>> {
+    let text = std::str::from_utf8(line)
+        .map_err(|err| BrainAIError::Api {
+            status: 200,
+            message: format!("non-UTF-8 stream chunk: {err}"),
+        })?
+        .trim();
+    let payload = if let Some(rest) = text.strip_prefix("data:") {
+        rest.trim()
+    } else if text.starts_with('{') {
+        // Bare NDJSON line.
+        text
+    } else {
+        // Blank separators and SSE `event:`/`id:`/comment lines carry no
+        // payload we need; the event kind rides inside the JSON itself.
+        return Ok(None);
+    };
+    if payload.is_empty() || payload == "[DONE]" {
+        return Ok(None);
+    }
+    serde_json::from_str(payload)
+        .map(Some)
+        .map_err(BrainAIError::Serialization)
+}
+
+impl BrainAISDK {
+    /// Starts a streaming reasoning run. Events arrive through
+    /// [`ReasoningStream::next_event`] as the engine produces them.
+    pub async fn reason_stream(
+        &self,
+        query: &str,
+        context: Vec<String>,
+    ) -> Result<ReasoningStream> {
+        let body = json!({
+            "query": query,
+            "context": context,
+            "max_depth": self.config.max_reasoning_depth,
+        });
+        let response = self
+            .request_stream(Endpoint::ReasonStream, body)
+            .await?;
+        Ok(ReasoningStream {
+            response,
+            buffer: Vec::new(),
+            finished: false,
+        })
+    }
+}
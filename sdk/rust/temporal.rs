@@ -0,0 +1,171 @@
+//! Temporal knowledge: facts and edges with validity intervals.
+//!
+//! "X works at Y" is true for a while, then stops being true. This
+//! module gives memories and graph edges a validity interval via the
+//! reserved metadata/property keys `valid_from` and `valid_until` (unix
+//! milliseconds, either side open when absent). Retrieval helpers
+//! default to facts valid right now and accept an `as_of` override for
+//! asking what the brain believed at an earlier time.
+//! [`supersede_fact`] closes an old fact and stores its replacement in
+//! one step, linked with a [`RelationType::Temporal`] relation so the
+//! history stays navigable.
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::client::BrainAIClient;
+use crate::vector_utils::now_millis;
+use crate::{
+    EdgeDirection, GraphEdge, Memory, MemoryType, RelationType, Result, SearchResult,
+};
+
+/// Metadata/property key for the start of validity (unix milliseconds).
+pub const VALID_FROM: &str = "valid_from";
+/// Metadata/property key for the end of validity (unix milliseconds).
+pub const VALID_UNTIL: &str = "valid_until";
+
+/// Over-fetch factor when filtering search results by validity.
+const VALIDITY_POOL_FACTOR: usize = 4;
+
+fn bound(map: &HashMap<String, Value>, key: &str) -> Option<i64> {
+    map.get(key).and_then(Value::as_i64)
+}
+
+/// Whether an interval read from `map` contains `at`. Open on either
+/// missing side; a memory without validity keys is always valid.
+fn valid_at(map: &HashMap<String, Value>, at: i64) -> bool {
+    bound(map, VALID_FROM).is_none_or(|from| from <= at)
+        && bound(map, VALID_UNTIL).is_none_or(|until| at < until)
+}
+
+/// Whether a memory is valid at `at`.
+pub fn memory_valid_at(memory: &Memory, at: i64) -> bool {
+    valid_at(&memory.metadata, at)
+}
+
+/// Whether a typed edge is valid at `at`.
+pub fn edge_valid_at(edge: &GraphEdge, at: i64) -> bool {
+    valid_at(&edge.properties, at)
+}
+
+/// Stores a fact with a validity interval. `valid_from` defaults to now
+/// when `None`; an absent `valid_until` means "until further notice".
+pub async fn store_fact(
+    client: &dyn BrainAIClient,
+    content: Value,
+    memory_type: MemoryType,
+    valid_from: Option<i64>,
+    valid_until: Option<i64>,
+    metadata: Option<HashMap<String, Value>>,
+) -> Result<String> {
+    let mut metadata = metadata.unwrap_or_default();
+    metadata.insert(
+        VALID_FROM.to_string(),
+        json!(valid_from.unwrap_or_else(now_millis)),
+    );
+    if let Some(until) = valid_until {
+        metadata.insert(VALID_UNTIL.to_string(), json!(until));
+    }
+    client.store_memory(content, memory_type, Some(metadata)).await
+}
+
+/// Closes a fact's validity at `at` (now when `None`). Returns `false`
+/// when the memory does not exist.
+pub async fn expire_fact(
+    client: &dyn BrainAIClient,
+    id: &str,
+    at: Option<i64>,
+) -> Result<bool> {
+    let Some(memory) = client.get_memory(id).await? else {
+        return Ok(false);
+    };
+    let mut metadata = memory.metadata;
+    metadata.insert(VALID_UNTIL.to_string(), json!(at.unwrap_or_else(now_millis)));
+    client.update_memory(id, memory.content, Some(metadata)).await
+}
+
+/// Replaces a fact: the old one stops being valid now, the new one
+/// starts now, and a temporal relation links old to new.
+pub async fn supersede_fact(
+    client: &dyn BrainAIClient,
+    old_id: &str,
+    content: Value,
+    memory_type: MemoryType,
+    metadata: Option<HashMap<String, Value>>,
+) -> Result<String> {
+    let at = now_millis();
+    expire_fact(client, old_id, Some(at)).await?;
+    let new_id = store_fact(client, content, memory_type, Some(at), None, metadata).await?;
+    client
+        .relate_memories(old_id, &new_id, RelationType::Temporal, 1.0)
+        .await?;
+    Ok(new_id)
+}
+
+/// Searches memories, keeping only facts valid at `as_of` (now when
+/// `None`). Over-fetches so filtering does not starve the result set.
+pub async fn search_memories_as_of(
+    client: &dyn BrainAIClient,
+    query: Value,
+    limit: usize,
+    as_of: Option<i64>,
+) -> Result<Vec<SearchResult>> {
+    let at = as_of.unwrap_or_else(now_millis);
+    let pool = limit.saturating_mul(VALIDITY_POOL_FACTOR).max(limit);
+    let mut hits = client.search_memories(query, pool).await?;
+    hits.retain(|hit| valid_at(&hit.metadata, at));
+    hits.truncate(limit);
+    Ok(hits)
+}
+
+/// Lists memories valid at `as_of` (now when `None`), applying the same
+/// filters as `list_memories`.
+pub async fn list_memories_as_of(
+    client: &dyn BrainAIClient,
+    filters: Option<HashMap<String, Value>>,
+    limit: usize,
+    as_of: Option<i64>,
+) -> Result<Vec<Memory>> {
+    let at = as_of.unwrap_or_else(now_millis);
+    let pool = limit.saturating_mul(VALIDITY_POOL_FACTOR).max(limit);
+    let mut memories = client.list_memories(filters, pool).await?;
+    memories.retain(|memory| memory_valid_at(memory, at));
+    memories.truncate(limit);
+    Ok(memories)
+}
+
+/// Creates a typed edge carrying a validity interval in its properties.
+pub async fn create_timed_edge(
+    client: &dyn BrainAIClient,
+    from: &str,
+    to: &str,
+    label: &str,
+    valid_from: Option<i64>,
+    valid_until: Option<i64>,
+) -> Result<String> {
+    let mut properties: HashMap<String, Value> = HashMap::from([(
+        VALID_FROM.to_string(),
+        json!(valid_from.unwrap_or_else(now_millis)),
+    )]);
+    if let Some(until) = valid_until {
+        properties.insert(VALID_UNTIL.to_string(), json!(until));
+    }
+    client
+        .create_edge(from, to, label, true, 1.0, Some(properties))
+        .await
+}
+
+/// Lists a node's typed edges valid at `as_of` (now when `None`).
+pub async fn get_edges_as_of(
+    client: &dyn BrainAIClient,
+    node_id: &str,
+    direction: EdgeDirection,
+    label_filter: Option<&str>,
+    as_of: Option<i64>,
+) -> Result<Vec<GraphEdge>> {
+    let at = as_of.unwrap_or_else(now_millis);
+    let mut edges = client.get_edges(node_id, direction, label_filter).await?;
+    edges.retain(|edge| edge_valid_at(edge, at));
+    Ok(edges)
+}
@@ -0,0 +1,195 @@
+//! Deterministic integration test suite generator.
+//!
+//! Generates "dry" integration scenarios — ordinary sequences of SDK
+//! operations with checked invariants, no failure injection — that run
+//! against any [`BrainAIClient`]: the in-memory mock in CI, or a staging
+//! server before a release. Generation is seeded, so a failing scenario
+//! reproduces exactly from its seed, and every checked invariant is one the
+//! API documents (store/get round-trips, search finds stored content,
+//! delete makes memories unreachable, strength stays clamped).
+
+use serde_json::json;
+
+use crate::{BrainAIClient, MemoryType, Result};
+
+/// Tiny deterministic generator (xorshift64*); enough for scenario data
+/// without pulling in a rand dependency.
+struct SeededRng(u64);
+
+impl SeededRng {
+    fn new(seed: u64) -> Self {
+        SeededRng(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound.max(1) as u64) as usize
+    }
+}
+
+const TOPICS: &[&str] = &[
+    "onboarding", "billing", "escalation", "retention", "diagnosis",
+    "inventory", "routing", "compliance", "forecasting", "scheduling",
+];
+
+const TYPES: &[MemoryType] = &[
+    MemoryType::Episodic,
+    MemoryType::Semantic,
+    MemoryType::Procedural,
+    MemoryType::Emotional,
+];
+
+/// One generated scenario: a seed, a name, and the number of memories it
+/// exercises. The steps themselves are derived from the seed at run time.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    pub name: String,
+    pub seed: u64,
+    pub memory_count: usize,
+}
+
+/// Outcome of one scenario run.
+#[derive(Debug, Clone)]
+pub struct ScenarioReport {
+    pub scenario: String,
+    pub seed: u64,
+    /// Invariant descriptions that held.
+    pub passed: Vec<String>,
+    /// Invariant descriptions that failed, with details.
+    pub failed: Vec<String>,
+}
+
+impl ScenarioReport {
+    /// Whether every invariant held.
+    pub fn is_green(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Generates `count` scenarios derived from a base seed.
+pub fn generate_suite(base_seed: u64, count: usize) -> Vec<Scenario> {
+    let mut rng = SeededRng::new(base_seed);
+    (0..count)
+        .map(|index| {
+            let seed = rng.next();
+            Scenario {
+                name: format!("scenario_{index:03}"),
+                seed,
+                memory_count: 3 + (seed % 8) as usize,
+            }
+        })
+        .collect()
+}
+
+/// Runs one scenario against a client, returning which invariants held.
+///
+/// The scenario cleans up after itself: every memory it stores is deleted
+/// at the end, so it can run against shared environments.
+pub async fn run_scenario(
+    client: &dyn BrainAIClient,
+    scenario: &Scenario,
+) -> Result<ScenarioReport> {
+    let mut report = ScenarioReport {
+        scenario: scenario.name.clone(),
+        seed: scenario.seed,
+        passed: Vec::new(),
+        failed: Vec::new(),
+    };
+    let mut rng = SeededRng::new(scenario.seed);
+    let check = |ok: bool, what: String, report: &mut ScenarioReport| {
+        if ok {
+            report.passed.push(what);
+        } else {
+            report.failed.push(what);
+        }
+    };
+
+    let mut stored: Vec<(String, String)> = Vec::new();
+    for index in 0..scenario.memory_count {
+        let topic = TOPICS[rng.below(TOPICS.len())];
+        let memory_type = TYPES[rng.below(TYPES.len())];
+        let text = format!("{} note {} for {}", scenario.name, index, topic);
+        let id = client
+            .store_memory(json!({"text": text, "topic": topic}), memory_type, None)
+            .await?;
+        check(
+            !id.is_empty(),
+            format!("store_memory returns a non-empty id ({})", scenario.name),
+            &mut report,
+        );
+        stored.push((id, text));
+    }
+
+    // Round-trip: every stored memory is retrievable with its content.
+    for (id, text) in &stored {
+        let fetched = client.get_memory(id).await?;
+        let ok = fetched
+            .as_ref()
+            .and_then(|m| m.content.get("text"))
+            .and_then(|t| t.as_str())
+            == Some(text.as_str());
+        check(ok, format!("get_memory round-trips content for {id}"), &mut report);
+    }
+
+    // Search: a stored memory's own text finds it.
+    if let Some((id, text)) = stored.first() {
+        let hits = client
+            .search_memories(json!(text.clone()), stored.len() + 5)
+            .await?;
+        check(
+            hits.iter().any(|h| h.id == *id),
+            format!("search_memories finds {id} by its own text"),
+            &mut report,
+        );
+    }
+
+    // Strength stays clamped under repeated updates.
+    if let Some((id, _)) = stored.first() {
+        for _ in 0..3 {
+            client.update_memory_strength(id, 0.9).await?;
+        }
+        let strength = client
+            .get_memory(id)
+            .await?
+            .map(|m| m.strength)
+            .unwrap_or(-1.0);
+        check(
+            (0.0..=1.0).contains(&strength),
+            format!("strength stays within 0.0..=1.0 for {id}"),
+            &mut report,
+        );
+    }
+
+    // Cleanup, and delete makes memories unreachable.
+    for (id, _) in &stored {
+        client.delete_memory(id).await?;
+        check(
+            client.get_memory(id).await?.is_none(),
+            format!("delete_memory makes {id} unreachable"),
+            &mut report,
+        );
+    }
+
+    Ok(report)
+}
+
+/// Generates and runs a whole suite, returning one report per scenario.
+pub async fn run_suite(
+    client: &dyn BrainAIClient,
+    base_seed: u64,
+    count: usize,
+) -> Result<Vec<ScenarioReport>> {
+    let mut reports = Vec::with_capacity(count);
+    for scenario in generate_suite(base_seed, count) {
+        reports.push(run_scenario(client, &scenario).await?);
+    }
+    Ok(reports)
+}
@@ -0,0 +1,79 @@
+//! Behavior tests for confidence calibration.
+
+use brain_ai::{CalibrationMethod, CalibrationOptions, ConfidenceCalibrator};
+
+#[test]
+fn identity_until_enough_outcomes_are_recorded() {
+    let calibrator = ConfidenceCalibrator::new(CalibrationOptions {
+        method: CalibrationMethod::Platt,
+        min_samples: 20,
+    });
+    for _ in 0..19 {
+        calibrator.record(0.9, false);
+    }
+    assert_eq!(calibrator.calibrate(0.9), 0.9);
+    calibrator.record(0.9, false);
+    assert!(calibrator.calibrate(0.9) < 0.9);
+}
+
+#[test]
+fn platt_pulls_overconfident_predictions_down() {
+    let calibrator = ConfidenceCalibrator::new(CalibrationOptions {
+        method: CalibrationMethod::Platt,
+        min_samples: 20,
+    });
+    // Claims 0.9, right only 60% of the time.
+    for i in 0..50 {
+        calibrator.record(0.9, i % 5 < 3);
+    }
+    let calibrated = calibrator.calibrate(0.9);
+    assert!(calibrated < 0.8, "got {calibrated}");
+    assert!((0.0..=1.0).contains(&calibrated));
+}
+
+#[test]
+fn platt_preserves_the_confidence_ordering() {
+    let calibrator = ConfidenceCalibrator::new(CalibrationOptions {
+        method: CalibrationMethod::Platt,
+        min_samples: 20,
+    });
+    for _ in 0..15 {
+        calibrator.record(0.2, false);
+        calibrator.record(0.8, true);
+    }
+    assert!(calibrator.calibrate(0.2) < calibrator.calibrate(0.8));
+}
+
+#[test]
+fn isotonic_fits_observed_accuracy_per_level() {
+    let calibrator = ConfidenceCalibrator::new(CalibrationOptions {
+        method: CalibrationMethod::Isotonic,
+        min_samples: 20,
+    });
+    for i in 0..10 {
+        calibrator.record(0.1, false);
+        calibrator.record(0.5, i % 2 == 0);
+        calibrator.record(0.9, true);
+    }
+    assert!(calibrator.calibrate(0.1) < 0.1);
+    assert!((calibrator.calibrate(0.5) - 0.5).abs() < 0.1);
+    assert!(calibrator.calibrate(0.9) > 0.9);
+    // Monotone by construction.
+    assert!(calibrator.calibrate(0.3) <= calibrator.calibrate(0.7));
+}
+
+#[test]
+fn clear_drops_the_curve_and_returns_to_identity() {
+    let calibrator = ConfidenceCalibrator::new(CalibrationOptions {
+        method: CalibrationMethod::Platt,
+        min_samples: 5,
+    });
+    for _ in 0..10 {
+        calibrator.record(0.9, false);
+    }
+    assert!(calibrator.calibrate(0.9) < 0.9);
+
+    calibrator.clear();
+    assert_eq!(calibrator.sample_count(), 0);
+    assert_eq!(calibrator.calibrate(0.9), 0.9);
+}
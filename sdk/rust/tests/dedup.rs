@@ -0,0 +1,136 @@
+//! Behavior tests for duplicate detection and merging.
+
+use std::collections::HashMap;
+
+use brain_ai::dedup::{dedup, find_duplicates, merge_memories, MergeStrategy};
+use brain_ai::{BrainAIError, MemoryType, MockBrainAI};
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn near_duplicates_cluster_and_distinct_content_does_not() {
+    let brain = MockBrainAI::new();
+    let a = brain
+        .store_memory(json!({"text": "the quick brown fox"}), MemoryType::Semantic, None)
+        .await
+        .unwrap();
+    let b = brain
+        .store_memory(
+            json!({"text": "the quick brown fox jumps"}),
+            MemoryType::Semantic,
+            None,
+        )
+        .await
+        .unwrap();
+    brain
+        .store_memory(
+            json!({"text": "completely unrelated billing policy"}),
+            MemoryType::Semantic,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let groups = find_duplicates(&brain, 0.8, 100).await.unwrap();
+    assert_eq!(groups.len(), 1);
+    let mut members = groups[0].memory_ids.clone();
+    members.sort();
+    let mut expected = vec![a, b];
+    expected.sort();
+    assert_eq!(members, expected);
+    assert!(groups[0].similarity >= 0.8);
+}
+
+#[tokio::test]
+async fn identical_wording_across_types_is_not_a_duplicate() {
+    let brain = MockBrainAI::new();
+    brain
+        .store_memory(json!({"text": "server restarted at noon"}), MemoryType::Episodic, None)
+        .await
+        .unwrap();
+    brain
+        .store_memory(json!({"text": "server restarted at noon"}), MemoryType::Semantic, None)
+        .await
+        .unwrap();
+
+    assert!(find_duplicates(&brain, 0.8, 100).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn merge_keeps_the_strongest_and_absorbs_metadata_and_strength() {
+    let brain = MockBrainAI::new();
+    let strong = brain
+        .store_memory(
+            json!({"text": "fact"}),
+            MemoryType::Semantic,
+            Some(HashMap::from([("source".to_string(), json!("crawl"))])),
+        )
+        .await
+        .unwrap();
+    let weak = brain
+        .store_memory(
+            json!({"text": "fact"}),
+            MemoryType::Semantic,
+            Some(HashMap::from([
+                ("source".to_string(), json!("manual")),
+                ("reviewed".to_string(), json!(true)),
+            ])),
+        )
+        .await
+        .unwrap();
+    brain.update_memory_strength(&weak, -0.4).await.unwrap();
+
+    let survivor = merge_memories(
+        &brain,
+        &[strong.clone(), weak.clone()],
+        MergeStrategy::KeepStrongest,
+    )
+    .await
+    .unwrap();
+    assert_eq!(survivor, strong);
+    assert!(brain.get_memory(&weak).await.unwrap().is_none());
+
+    let merged = brain.get_memory(&strong).await.unwrap().unwrap();
+    // The survivor's own keys win; missing keys are absorbed.
+    assert_eq!(merged.metadata.get("source"), Some(&json!("crawl")));
+    assert_eq!(merged.metadata.get("reviewed"), Some(&json!(true)));
+    assert!((merged.strength - 1.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn merge_requires_at_least_two_memories() {
+    let brain = MockBrainAI::new();
+    assert!(matches!(
+        merge_memories(&brain, &["memory_1".to_string()], MergeStrategy::KeepNewest).await,
+        Err(BrainAIError::InvalidInput(_))
+    ));
+}
+
+#[tokio::test]
+async fn dedup_collapses_each_group_to_one_survivor() {
+    let brain = MockBrainAI::new();
+    for _ in 0..3 {
+        brain
+            .store_memory(
+                json!({"text": "duplicate onboarding note"}),
+                MemoryType::Semantic,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+    brain
+        .store_memory(json!({"text": "unique retention insight"}), MemoryType::Semantic, None)
+        .await
+        .unwrap();
+
+    let report = dedup(&brain, 0.9, 100, MergeStrategy::KeepOldest).await.unwrap();
+    assert_eq!(report.groups.len(), 1);
+    assert_eq!(report.merged.len(), 1);
+    assert_eq!(report.merged[0].1.len(), 2);
+
+    let remaining = brain.list_memories(None, 100).await.unwrap();
+    assert_eq!(remaining.len(), 2);
+    let texts: Vec<&Value> = remaining.iter().filter_map(|m| m.content.get("text")).collect();
+    assert!(texts.contains(&&json!("duplicate onboarding note")));
+    assert!(texts.contains(&&json!("unique retention insight")));
+}
@@ -0,0 +1,68 @@
+//! Behavior tests for the local HNSW index.
+
+use brain_ai::hnsw::HnswIndex;
+use brain_ai::BrainAIError;
+
+#[test]
+fn empty_index_returns_no_matches() {
+    let index = HnswIndex::new();
+    assert!(index.is_empty());
+    assert!(index.search(&[1.0, 0.0], 5).is_empty());
+}
+
+#[test]
+fn exact_vector_is_the_top_hit() {
+    let mut index = HnswIndex::new();
+    index.insert("x", vec![1.0, 0.0, 0.0]).unwrap();
+    index.insert("y", vec![0.0, 1.0, 0.0]).unwrap();
+    index.insert("z", vec![0.0, 0.0, 1.0]).unwrap();
+
+    let hits = index.search(&[0.0, 1.0, 0.0], 2);
+    assert_eq!(hits[0].id, "y");
+    assert!((hits[0].score - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn search_ranks_by_cosine_similarity() {
+    let mut index = HnswIndex::new();
+    // 64 vectors fanned across a quarter circle; the query sits on
+    // vector 10, so ranking must follow angular distance from it.
+    let angle = |i: usize| (i as f64) * std::f64::consts::FRAC_PI_2 / 63.0;
+    for i in 0..64 {
+        let (sin, cos) = angle(i).sin_cos();
+        index.insert(&format!("v{i}"), vec![cos as f32, sin as f32]).unwrap();
+    }
+    let (sin, cos) = angle(10).sin_cos();
+    let hits = index.search(&[cos as f32, sin as f32], 3);
+    assert_eq!(hits[0].id, "v10");
+    for pair in hits.windows(2) {
+        assert!(pair[0].score >= pair[1].score);
+    }
+}
+
+#[test]
+fn reinserting_an_id_replaces_its_vector() {
+    let mut index = HnswIndex::new();
+    index.insert("a", vec![1.0, 0.0]).unwrap();
+    index.insert("b", vec![0.0, 1.0]).unwrap();
+    index.insert("a", vec![0.0, 1.0]).unwrap();
+    assert_eq!(index.len(), 2);
+
+    let hits = index.search(&[0.0, 1.0], 2);
+    assert!((hits[0].score - 1.0).abs() < 1e-9);
+    assert!((hits[1].score - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn rejects_empty_and_mismatched_vectors() {
+    let mut index = HnswIndex::new();
+    assert!(matches!(
+        index.insert("a", Vec::new()),
+        Err(BrainAIError::InvalidInput(_))
+    ));
+    index.insert("a", vec![1.0, 0.0]).unwrap();
+    assert!(matches!(
+        index.insert("b", vec![1.0, 0.0, 0.0]),
+        Err(BrainAIError::InvalidInput(_))
+    ));
+}
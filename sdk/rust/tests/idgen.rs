@@ -0,0 +1,57 @@
+//! Behavior tests for deterministic content-derived IDs.
+
+use brain_ai::deterministic_id;
+use serde_json::json;
+
+#[test]
+fn same_content_same_namespace_same_id() {
+    let a = deterministic_id("ingest", &json!({"text": "hello"}));
+    let b = deterministic_id("ingest", &json!({"text": "hello"}));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn key_order_does_not_change_the_id() {
+    let a = deterministic_id("ingest", &json!({"a": 1, "b": 2}));
+    let b = deterministic_id("ingest", &json!({"b": 2, "a": 1}));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn namespace_and_content_both_separate_ids() {
+    let base = deterministic_id("staging", &json!({"text": "hello"}));
+    assert_ne!(base, deterministic_id("production", &json!({"text": "hello"})));
+    assert_ne!(base, deterministic_id("staging", &json!({"text": "world"})));
+}
+
+/// Known-answer test against RFC 4122: other UUIDv5 implementations
+/// (Python's `uuid.uuid5`, the other SDKs, the server) must derive the
+/// identical ID for the same namespace and content, or cross-client
+/// dedup breaks.
+#[test]
+fn ids_match_conforming_uuidv5_implementations() {
+    assert_eq!(
+        deterministic_id("tenant-a", &json!({"text": "hello"})),
+        "5e68381e-e7c8-5608-b377-ce17884e8745"
+    );
+    // Long content crosses a SHA-1 block boundary (64 bytes), covering
+    // the multi-chunk path.
+    assert_eq!(
+        deterministic_id("tenant-a", &json!({"text": "x".repeat(100)})),
+        "4caaf339-8b0b-5d0f-9ed3-caa5d3dafb56"
+    );
+}
+
+#[test]
+fn ids_are_well_formed_uuidv5() {
+    let id = deterministic_id("ingest", &json!({"text": "hello"}));
+    let parts: Vec<&str> = id.split('-').collect();
+    assert_eq!(
+        parts.iter().map(|p| p.len()).collect::<Vec<_>>(),
+        vec![8, 4, 4, 4, 12]
+    );
+    assert!(id.chars().all(|c| c == '-' || c.is_ascii_hexdigit()));
+    // Version 5, RFC 4122 variant.
+    assert_eq!(id.as_bytes()[14], b'5');
+    assert!(matches!(id.as_bytes()[19], b'8' | b'9' | b'a' | b'b'));
+}
@@ -0,0 +1,123 @@
+//! Behavior tests for int8 and product quantization.
+
+use brain_ai::quantize::{
+    dequantize, int8_cosine, int8_dot, quantize_int8, ProductQuantizer,
+};
+use brain_ai::BrainAIError;
+
+fn cosine(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| f64::from(*x) * f64::from(*y)).sum();
+    let na: f64 = a.iter().map(|x| f64::from(*x) * f64::from(*x)).sum::<f64>().sqrt();
+    let nb: f64 = b.iter().map(|x| f64::from(*x) * f64::from(*x)).sum::<f64>().sqrt();
+    dot / (na * nb)
+}
+
+#[test]
+fn int8_round_trip_stays_within_half_a_step() {
+    let vector = vec![0.3, -1.7, 0.0, 2.54, -0.01];
+    let quantized = quantize_int8(&vector).unwrap();
+    let restored = dequantize(&quantized);
+    // Rounding to a code loses at most half a quantization step.
+    let step = 2.54 / 127.0;
+    for (original, approx) in vector.iter().zip(&restored) {
+        assert!((original - approx).abs() <= step / 2.0 + 1e-6);
+    }
+}
+
+#[test]
+fn int8_cosine_tracks_the_f32_cosine() {
+    let a = vec![0.9, -0.4, 0.2, 0.7, -1.1, 0.05, 0.33, -0.6];
+    let b = vec![0.8, -0.5, 0.1, 0.9, -1.0, 0.00, 0.25, -0.7];
+    let qa = quantize_int8(&a).unwrap();
+    let qb = quantize_int8(&b).unwrap();
+    assert!((int8_cosine(&qa, &qb).unwrap() - cosine(&a, &b)).abs() < 0.01);
+}
+
+#[test]
+fn zero_vector_quantizes_to_zero() {
+    let quantized = quantize_int8(&[0.0, 0.0, 0.0]).unwrap();
+    assert_eq!(quantized.scale, 0.0);
+    assert!(quantized.codes.iter().all(|&c| c == 0));
+    assert_eq!(int8_cosine(&quantized, &quantized).unwrap(), 0.0);
+}
+
+#[test]
+fn int8_rejects_empty_and_mismatched_inputs() {
+    assert!(matches!(
+        quantize_int8(&[]),
+        Err(BrainAIError::InvalidInput(_))
+    ));
+    let a = quantize_int8(&[1.0, 2.0]).unwrap();
+    let b = quantize_int8(&[1.0, 2.0, 3.0]).unwrap();
+    assert!(matches!(int8_dot(&a, &b), Err(BrainAIError::InvalidInput(_))));
+}
+
+/// Deterministic training set: clusters around a few anchor directions.
+fn training_set() -> Vec<Vec<f32>> {
+    let anchors: [[f32; 8]; 4] = [
+        [1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0],
+        [1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0],
+    ];
+    (0..40)
+        .map(|i| {
+            let anchor = anchors[i % anchors.len()];
+            let jitter = (i / anchors.len()) as f32 * 0.01;
+            anchor.iter().map(|x| x + jitter).collect()
+        })
+        .collect()
+}
+
+#[test]
+fn pq_round_trip_approximates_the_vector() {
+    let vectors = training_set();
+    let pq = ProductQuantizer::train(&vectors, 4, 10).unwrap();
+    assert_eq!(pq.dimension(), 8);
+    assert_eq!(pq.code_len(), 4);
+
+    let codes = pq.encode(&vectors[0]).unwrap();
+    let restored = pq.decode(&codes).unwrap();
+    for (original, approx) in vectors[0].iter().zip(&restored) {
+        assert!((original - approx).abs() < 0.1);
+    }
+}
+
+#[test]
+fn pq_lookup_table_matches_the_decoded_dot_product() {
+    let vectors = training_set();
+    let pq = ProductQuantizer::train(&vectors, 4, 10).unwrap();
+    let query = &vectors[1];
+    let table = pq.lookup_table(query).unwrap();
+
+    let codes = pq.encode(&vectors[2]).unwrap();
+    let decoded = pq.decode(&codes).unwrap();
+    let expected: f64 = query
+        .iter()
+        .zip(&decoded)
+        .map(|(x, y)| f64::from(*x) * f64::from(*y))
+        .sum();
+    assert!((table.dot(&codes).unwrap() - expected).abs() < 1e-9);
+}
+
+#[test]
+fn pq_validates_its_inputs() {
+    let vectors = training_set();
+    assert!(matches!(
+        ProductQuantizer::train(&[], 4, 10),
+        Err(BrainAIError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        ProductQuantizer::train(&vectors, 3, 10),
+        Err(BrainAIError::InvalidInput(_))
+    ));
+    let pq = ProductQuantizer::train(&vectors, 4, 10).unwrap();
+    assert!(matches!(
+        pq.encode(&[1.0, 2.0]),
+        Err(BrainAIError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        pq.decode(&[0, 0]),
+        Err(BrainAIError::InvalidInput(_))
+    ));
+}
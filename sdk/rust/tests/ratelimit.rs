@@ -0,0 +1,50 @@
+//! Behavior tests for the token-bucket rate limiter.
+
+use std::time::{Duration, Instant};
+
+use brain_ai::{RateLimitOptions, RateLimiter};
+
+#[tokio::test]
+async fn burst_proceeds_without_waiting() {
+    let limiter = RateLimiter::new(RateLimitOptions {
+        requests_per_second: 1.0,
+        burst: 5,
+        max_retries: 0,
+    });
+    let started = Instant::now();
+    for _ in 0..5 {
+        limiter.acquire().await;
+    }
+    assert!(started.elapsed() < Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn acquire_waits_once_the_bucket_is_empty() {
+    let limiter = RateLimiter::new(RateLimitOptions {
+        requests_per_second: 20.0,
+        burst: 1,
+        max_retries: 0,
+    });
+    limiter.acquire().await;
+    let started = Instant::now();
+    limiter.acquire().await;
+    // One token refills in 50ms at 20 rps; allow timer slack downward.
+    assert!(started.elapsed() >= Duration::from_millis(30));
+}
+
+#[tokio::test]
+async fn available_and_estimated_wait_track_the_bucket() {
+    let limiter = RateLimiter::new(RateLimitOptions {
+        requests_per_second: 0.001,
+        burst: 2,
+        max_retries: 0,
+    });
+    assert!(limiter.available() >= 2.0 - 1e-6);
+    assert_eq!(limiter.estimated_wait(), Duration::ZERO);
+
+    limiter.acquire().await;
+    limiter.acquire().await;
+    assert!(limiter.available() < 1.0);
+    assert!(limiter.estimated_wait() > Duration::ZERO);
+    assert_eq!(limiter.waiting(), 0);
+}
@@ -0,0 +1,61 @@
+//! Behavior tests for sparse vector construction and scoring.
+
+use brain_ai::{BrainAIError, SparseVector};
+
+#[test]
+fn construction_sorts_merges_and_drops_zeros() {
+    let sparse = SparseVector::new(
+        vec![7, 2, 7, 4, 9],
+        vec![1.0, 2.0, 3.0, 0.0, -1.0],
+    )
+    .unwrap();
+    // Index 7 appears twice (1.0 + 3.0) and index 4 carries a zero.
+    assert_eq!(sparse.indices, vec![2, 7, 9]);
+    assert_eq!(sparse.values, vec![2.0, 4.0, -1.0]);
+    assert_eq!(sparse.nnz(), 3);
+}
+
+#[test]
+fn mismatched_arrays_are_rejected() {
+    assert!(matches!(
+        SparseVector::new(vec![1, 2], vec![1.0]),
+        Err(BrainAIError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn dense_round_trip_preserves_active_entries() {
+    let dense = vec![0.0, 0.5, 0.0, -0.8, 0.001];
+    let sparse = SparseVector::from_dense(&dense, 0.01);
+    assert_eq!(sparse.nnz(), 2);
+    assert_eq!(sparse.to_dense(5).unwrap(), vec![0.0, 0.5, 0.0, -0.8, 0.0]);
+    // An entry beyond the requested dimension errors instead of
+    // truncating silently.
+    assert!(matches!(
+        sparse.to_dense(3),
+        Err(BrainAIError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn dot_and_cosine_match_the_dense_computation() {
+    let a = SparseVector::from_pairs([(1, 2.0), (5, 3.0), (9, -1.0)]).unwrap();
+    let b = SparseVector::from_pairs([(1, 4.0), (7, 2.0), (9, 2.0)]).unwrap();
+    // Overlap at indices 1 and 9: 2*4 + (-1)*2 = 6.
+    assert!((a.dot(&b) - 6.0).abs() < 1e-9);
+
+    let expected = 6.0 / (a.norm() * b.norm());
+    assert!((a.cosine(&b) - expected).abs() < 1e-9);
+}
+
+#[test]
+fn disjoint_and_empty_vectors_score_zero() {
+    let a = SparseVector::from_pairs([(1, 1.0), (2, 1.0)]).unwrap();
+    let b = SparseVector::from_pairs([(3, 1.0), (4, 1.0)]).unwrap();
+    assert_eq!(a.dot(&b), 0.0);
+    assert_eq!(a.cosine(&b), 0.0);
+
+    let empty = SparseVector::from_pairs([]).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(a.cosine(&empty), 0.0);
+}
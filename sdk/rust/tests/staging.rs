@@ -0,0 +1,69 @@
+//! Behavior tests for the staging approval workflow.
+
+use brain_ai::{BrainAIError, MemoryType, MockBrainAI, ReviewStatus, StagingArea};
+use serde_json::json;
+
+#[tokio::test]
+async fn staged_writes_stay_out_of_the_backend_until_approved() {
+    let staging = StagingArea::new(MockBrainAI::new());
+    let staging_id =
+        staging.stage_memory(json!({"text": "pending fact"}), MemoryType::Semantic, None);
+
+    let pending = staging.list_pending();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].staging_id, staging_id);
+    assert!(staging
+        .inner()
+        .search_memories("pending fact", 5)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let committed = staging.approve(&[&staging_id]).await.unwrap();
+    assert_eq!(committed.len(), 1);
+    let memory = staging
+        .inner()
+        .get_memory(&committed[0].1)
+        .await
+        .unwrap()
+        .expect("approved write is stored");
+    assert_eq!(memory.content, json!({"text": "pending fact"}));
+    assert!(staging.list_pending().is_empty());
+}
+
+#[tokio::test]
+async fn rejected_writes_are_never_stored_but_stay_auditable() {
+    let staging = StagingArea::new(MockBrainAI::new());
+    let staging_id =
+        staging.stage_memory(json!({"text": "bad fact"}), MemoryType::Semantic, None);
+
+    assert_eq!(staging.reject(&[&staging_id], "unverified source").unwrap(), 1);
+    assert!(staging.list_pending().is_empty());
+    assert!(staging
+        .inner()
+        .search_memories("bad fact", 5)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let audit = staging.audit_log();
+    assert_eq!(audit[0].status, ReviewStatus::Rejected);
+    assert_eq!(audit[0].review_note.as_deref(), Some("unverified source"));
+}
+
+#[tokio::test]
+async fn reviewing_twice_or_reviewing_unknown_ids_fails() {
+    let staging = StagingArea::new(MockBrainAI::new());
+    let staging_id =
+        staging.stage_memory(json!({"text": "fact"}), MemoryType::Semantic, None);
+    staging.approve(&[&staging_id]).await.unwrap();
+
+    assert!(matches!(
+        staging.approve(&[&staging_id]).await,
+        Err(BrainAIError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        staging.reject(&["staged_999"], "nope"),
+        Err(BrainAIError::NotFound(_))
+    ));
+}
@@ -0,0 +1,113 @@
+//! Behavior tests for JSONL transfer and resumable import checkpointing.
+
+use brain_ai::transfer::{
+    export_jsonl, import_jsonl, resume_import, shard_of, ImportCheckpoint,
+};
+use brain_ai::{MemoryType, MockBrainAI};
+use serde_json::json;
+
+/// Exports a small brain and hands back its JSONL bytes.
+async fn export_fixture(notes: &[&str]) -> Vec<u8> {
+    let source = MockBrainAI::new();
+    for note in notes {
+        source
+            .store_memory(json!({"text": note}), MemoryType::Semantic, None)
+            .await
+            .unwrap();
+    }
+    let mut buffer = Vec::new();
+    let exported = export_jsonl(&source, None, &mut buffer).await.unwrap();
+    assert_eq!(exported as usize, notes.len());
+    buffer
+}
+
+#[tokio::test]
+async fn export_import_round_trips_contents() {
+    let jsonl = export_fixture(&["alpha", "beta", "gamma"]).await;
+
+    let target = MockBrainAI::new();
+    let report = import_jsonl(&target, jsonl.as_slice()).await.unwrap();
+    assert!(report.is_complete());
+    assert_eq!(report.imported.len(), 3);
+
+    let memories = target.list_memories(None, 10).await.unwrap();
+    assert_eq!(memories.len(), 3);
+    for memory in &memories {
+        // The original ID survives as provenance.
+        assert!(memory.metadata.contains_key("imported_from"));
+    }
+}
+
+#[test]
+fn shard_assignment_is_stable_and_in_range() {
+    for id in ["memory_1", "memory_2", "a-very-long-memory-identifier"] {
+        let shard = shard_of(id, 8);
+        assert!(shard < 8);
+        assert_eq!(shard, shard_of(id, 8));
+    }
+    // Degenerate shard counts collapse to shard zero instead of
+    // panicking on a modulo by zero.
+    assert_eq!(shard_of("memory_1", 0), 0);
+}
+
+#[tokio::test]
+async fn resumed_import_skips_committed_lines() {
+    let jsonl = export_fixture(&["one", "two", "three", "four", "five"]).await;
+
+    let target = MockBrainAI::new();
+    let mut checkpoint = ImportCheckpoint::default();
+    let report = resume_import(&target, jsonl.as_slice(), &mut checkpoint, "shard-0")
+        .await
+        .unwrap();
+    assert_eq!(report.imported.len(), 5);
+    assert_eq!(checkpoint.committed("shard-0"), 5);
+
+    // A rerun from the same checkpoint touches nothing.
+    let rerun = resume_import(&target, jsonl.as_slice(), &mut checkpoint, "shard-0")
+        .await
+        .unwrap();
+    assert!(rerun.imported.is_empty());
+    assert!(rerun.failed.is_empty());
+    assert_eq!(target.list_memories(None, 100).await.unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn uncommitted_window_is_deduplicated_by_idempotency_key() {
+    let jsonl = export_fixture(&["one", "two", "three", "four", "five"]).await;
+
+    let target = MockBrainAI::new();
+    let mut checkpoint = ImportCheckpoint::default();
+    resume_import(&target, jsonl.as_slice(), &mut checkpoint, "shard-0")
+        .await
+        .unwrap();
+
+    // Simulate a crash after committing line 2: lines 3..=5 were applied
+    // but the checkpoint never recorded them.
+    checkpoint.committed.insert("shard-0".to_string(), 2);
+    let report = resume_import(&target, jsonl.as_slice(), &mut checkpoint, "shard-0")
+        .await
+        .unwrap();
+    assert_eq!(report.imported.len(), 3);
+    assert!(report.is_complete());
+    // The already-applied tail is recognized, not stored again.
+    assert_eq!(target.list_memories(None, 100).await.unwrap().len(), 5);
+    assert_eq!(checkpoint.committed("shard-0"), 5);
+}
+
+#[tokio::test]
+async fn checkpoint_persists_across_loads() {
+    let path = std::env::temp_dir().join(format!(
+        "brain-ai-checkpoint-test-{}.json",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+
+    let mut checkpoint = ImportCheckpoint::load(&path).unwrap();
+    assert_eq!(checkpoint.committed("shard-0"), 0);
+    checkpoint.committed.insert("shard-0".to_string(), 42);
+    checkpoint.save().unwrap();
+
+    let reloaded = ImportCheckpoint::load(&path).unwrap();
+    assert_eq!(reloaded.committed("shard-0"), 42);
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,72 @@
+//! Behavior tests for the soft-deletion trash bin.
+
+use std::time::Duration;
+
+use brain_ai::trash::TrashBin;
+use brain_ai::{BrainAIError, MemoryType, MockBrainAI};
+use serde_json::json;
+
+#[tokio::test]
+async fn soft_delete_then_restore_round_trips_the_memory() {
+    let bin = TrashBin::new(MockBrainAI::new(), Duration::from_secs(60));
+    let id = bin
+        .inner()
+        .store_memory(json!({"text": "keep me"}), MemoryType::Semantic, None)
+        .await
+        .unwrap();
+
+    bin.soft_delete(&id).await.unwrap();
+    assert!(bin.inner().get_memory(&id).await.unwrap().is_none());
+    assert_eq!(bin.list_trash().len(), 1);
+
+    let new_id = bin.restore(&id).await.unwrap();
+    let restored = bin.inner().get_memory(&new_id).await.unwrap().unwrap();
+    assert_eq!(restored.content, json!({"text": "keep me"}));
+    assert!(bin.list_trash().is_empty());
+}
+
+#[tokio::test]
+async fn soft_clear_all_trashes_everything_restorably() {
+    let bin = TrashBin::new(MockBrainAI::new(), Duration::from_secs(60));
+    for i in 0..3 {
+        bin.inner()
+            .store_memory(json!({"text": format!("note {i}")}), MemoryType::Semantic, None)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(bin.soft_clear_all().await.unwrap(), 3);
+    assert!(bin.inner().list_memories(None, 10).await.unwrap().is_empty());
+
+    let restored = bin.restore_all().await.unwrap();
+    assert_eq!(restored.len(), 3);
+    assert_eq!(bin.inner().list_memories(None, 10).await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn expired_entries_cannot_be_restored_and_are_purged() {
+    let bin = TrashBin::new(MockBrainAI::new(), Duration::from_millis(1));
+    let id = bin
+        .inner()
+        .store_memory(json!({"text": "short-lived"}), MemoryType::Semantic, None)
+        .await
+        .unwrap();
+    bin.soft_delete(&id).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert!(matches!(
+        bin.restore(&id).await,
+        Err(BrainAIError::InvalidInput(_))
+    ));
+    assert_eq!(bin.purge_expired(), 1);
+    assert!(bin.list_trash().is_empty());
+}
+
+#[tokio::test]
+async fn restoring_an_unknown_id_fails() {
+    let bin = TrashBin::new(MockBrainAI::new(), Duration::from_secs(60));
+    assert!(matches!(
+        bin.restore("memory_404").await,
+        Err(BrainAIError::NotFound(_))
+    ));
+}
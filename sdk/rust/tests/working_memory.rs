@@ -0,0 +1,115 @@
+//! Behavior tests for the bounded working-memory buffer.
+
+use std::time::Duration;
+
+use brain_ai::working_memory::{EvictionPolicy, WorkingMemory};
+use brain_ai::{MemoryType, MockBrainAI};
+use serde_json::json;
+
+/// Recency and insertion order have millisecond resolution; space
+/// operations out so timestamps never tie.
+async fn tick() {
+    tokio::time::sleep(Duration::from_millis(5)).await;
+}
+
+#[tokio::test]
+async fn inserts_below_capacity_never_spill() {
+    let buffer = WorkingMemory::new(MockBrainAI::new(), 4);
+    for i in 0..3 {
+        let (_, spilled) = buffer
+            .insert(json!({"n": i}), MemoryType::Episodic, None)
+            .await
+            .unwrap();
+        assert!(spilled.is_none());
+    }
+    assert_eq!(buffer.len(), 3);
+}
+
+#[tokio::test]
+async fn lru_eviction_spills_the_coldest_item() {
+    let buffer = WorkingMemory::new(MockBrainAI::new(), 2);
+    let (a, _) = buffer
+        .insert(json!({"text": "first"}), MemoryType::Episodic, None)
+        .await
+        .unwrap();
+    tick().await;
+    let (b, _) = buffer
+        .insert(json!({"text": "second"}), MemoryType::Episodic, None)
+        .await
+        .unwrap();
+    // Touching `a` makes `b` the LRU victim.
+    tick().await;
+    buffer.touch(&a).unwrap();
+
+    let (_, spilled) = buffer
+        .insert(json!({"text": "third"}), MemoryType::Episodic, None)
+        .await
+        .unwrap();
+    let memory_id = spilled.expect("eviction spills to the backend");
+    assert!(buffer.peek(&b).is_none());
+    assert!(buffer.peek(&a).is_some());
+
+    let spilled_memory = buffer
+        .client()
+        .get_memory(&memory_id)
+        .await
+        .unwrap()
+        .expect("victim lives on in long-term storage");
+    assert_eq!(spilled_memory.content, json!({"text": "second"}));
+}
+
+#[tokio::test]
+async fn fifo_eviction_ignores_recency() {
+    let buffer =
+        WorkingMemory::new(MockBrainAI::new(), 2).with_policy(EvictionPolicy::Fifo);
+    let (a, _) = buffer
+        .insert(json!({"text": "oldest"}), MemoryType::Episodic, None)
+        .await
+        .unwrap();
+    tick().await;
+    buffer
+        .insert(json!({"text": "newer"}), MemoryType::Episodic, None)
+        .await
+        .unwrap();
+    tick().await;
+    buffer.touch(&a).unwrap();
+
+    buffer
+        .insert(json!({"text": "newest"}), MemoryType::Episodic, None)
+        .await
+        .unwrap();
+    assert!(buffer.peek(&a).is_none());
+}
+
+#[tokio::test]
+async fn drop_on_evict_discards_instead_of_storing() {
+    let buffer = WorkingMemory::new(MockBrainAI::new(), 1).drop_on_evict();
+    buffer
+        .insert(json!({"text": "doomed"}), MemoryType::Episodic, None)
+        .await
+        .unwrap();
+    let (_, spilled) = buffer
+        .insert(json!({"text": "survivor"}), MemoryType::Episodic, None)
+        .await
+        .unwrap();
+    assert!(spilled.is_none());
+    assert!(buffer.client().list_memories(None, 10).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn flush_spills_everything_and_empties_the_buffer() {
+    let buffer = WorkingMemory::new(MockBrainAI::new(), 8);
+    for i in 0..3 {
+        buffer
+            .insert(json!({"n": i}), MemoryType::Episodic, None)
+            .await
+            .unwrap();
+    }
+
+    let stored = buffer.flush().await.unwrap();
+    assert_eq!(stored.len(), 3);
+    assert!(buffer.is_empty());
+    for (_, memory_id) in &stored {
+        assert!(buffer.client().get_memory(memory_id).await.unwrap().is_some());
+    }
+}
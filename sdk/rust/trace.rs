@@ -0,0 +1,106 @@
+//! `tracing` spans around API calls, behind the `tracing` feature.
+//!
+//! With the feature on, every request helper opens a `brain_ai_call`
+//! span carrying the HTTP method, endpoint path, and correlation ID,
+//! and records the status and latency when the exchange settles —
+//! enough for a subscriber to reconstruct per-call timings without the
+//! SDK logging anything itself. With the feature off, [`CallSpan`]
+//! compiles to nothing.
+//!
+//! Correlation IDs are independent of the feature: when
+//! [`BrainAIConfig::with_request_id_header`](crate::BrainAIConfig::with_request_id_header)
+//! names a header, each request carries a fresh ID so client and server
+//! logs line up.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::vector_utils::now_millis;
+use crate::BrainAIError;
+
+/// Process-wide counter so two requests in the same millisecond still
+/// get distinct correlation IDs.
+static REQUEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A fresh correlation ID for one request.
+pub(crate) fn next_request_id() -> String {
+    format!(
+        "req-{:x}-{:x}",
+        now_millis(),
+        REQUEST_COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// One API call's span; a no-op shell unless the `tracing` feature is
+/// enabled.
+#[cfg(feature = "tracing")]
+pub(crate) struct CallSpan {
+    span: tracing::Span,
+    started: std::time::Instant,
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct CallSpan;
+
+impl CallSpan {
+    /// Opens the span for one call.
+    pub(crate) fn begin(method: &reqwest::Method, path: &str, request_id: Option<&str>) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::info_span!(
+                "brain_ai_call",
+                method = %method,
+                path,
+                request_id,
+                status = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error = tracing::field::Empty,
+            );
+            CallSpan {
+                span,
+                started: std::time::Instant::now(),
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = (method, path, request_id);
+            CallSpan
+        }
+    }
+
+    /// Runs a future inside the span.
+    pub(crate) async fn wrap<F: std::future::Future>(&self, future: F) -> F::Output {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+            future.instrument(self.span.clone()).await
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            future.await
+        }
+    }
+
+    /// Records the response status and latency on the span.
+    pub(crate) fn finish(&self, status: u16) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("status", status);
+            self.span
+                .record("latency_ms", self.started.elapsed().as_millis() as u64);
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = status;
+    }
+
+    /// Records a failed exchange on the span.
+    pub(crate) fn fail(&self, error: &BrainAIError) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("error", tracing::field::display(error));
+            self.span
+                .record("latency_ms", self.started.elapsed().as_millis() as u64);
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = error;
+    }
+}
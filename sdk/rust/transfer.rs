@@ -0,0 +1,616 @@
+//! Bulk memory import and export in JSONL format.
+//!
+//! One memory per line, serialized exactly as the API returns it, so
+//! exports are greppable, diffable, and stream-processable with standard
+//! tools. Export walks the paginated listing so arbitrarily large brains
+//! fit in constant memory; import reports per-line failures instead of
+//! aborting the whole file. For brains too big for one file,
+//! [`export_sharded`] splits the export across concurrent shard writers
+//! with a manifest, enabling parallel restore.
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+
+use crate::{BrainAIClient, BrainAIError, Memory, Result};
+
+/// Page size used when walking the memory listing during export.
+const EXPORT_PAGE_SIZE: usize = 500;
+
+/// Outcome of a bulk import.
+#[derive(Debug, Default, Clone)]
+pub struct ImportReport {
+    /// Memories stored, as `(line_number, memory_id)`.
+    pub imported: Vec<(usize, String)>,
+    /// Lines that failed, as `(line_number, error)`.
+    pub failed: Vec<(usize, String)>,
+}
+
+impl ImportReport {
+    /// Whether every line was imported.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Exports memories matching `filters` as JSONL, returning the count
+/// written.
+pub async fn export_jsonl<W: Write>(
+    client: &dyn BrainAIClient,
+    filters: Option<HashMap<String, Value>>,
+    writer: &mut W,
+) -> Result<u64> {
+    let mut exported = 0u64;
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = client
+            .list_memories_page(filters.clone(), EXPORT_PAGE_SIZE, cursor.as_deref())
+            .await?;
+        for memory in &page.memories {
+            let line = serde_json::to_string(memory)?;
+            writer
+                .write_all(line.as_bytes())
+                .and_then(|_| writer.write_all(b"\n"))
+                .map_err(|err| BrainAIError::InvalidInput(format!("write failed: {err}")))?;
+            exported += 1;
+        }
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    writer
+        .flush()
+        .map_err(|err| BrainAIError::InvalidInput(format!("flush failed: {err}")))?;
+    Ok(exported)
+}
+
+/// What a sharded export produced: one JSONL file per shard plus this
+/// manifest, which a restore reads to run the shards in parallel and
+/// verify nothing went missing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardManifest {
+    /// Shard count the export was split into.
+    pub shards: usize,
+    /// Memories written per shard, indexed by shard number.
+    pub counts: Vec<u64>,
+    /// Memories written across all shards.
+    pub total: u64,
+}
+
+/// Stable shard assignment for a memory ID: FNV-1a modulo the shard
+/// count, so the same brain always shards the same way.
+pub fn shard_of(memory_id: &str, shards: usize) -> usize {
+    (fnv1a(memory_id) % shards.max(1) as u64) as usize
+}
+
+fn fnv1a(text: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in text.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Exports memories as `shards` JSONL files written concurrently.
+///
+/// The listing is walked once; each memory is routed to the shard its ID
+/// hashes to (see [`shard_of`]) and written by one of `concurrency`
+/// writer tasks, so slow disks or remote sinks no longer serialize the
+/// whole export. `writer_factory` is called once per shard index to open
+/// that shard's sink. Restore by running [`import_jsonl`] over each
+/// shard file concurrently; the returned [`ShardManifest`] carries the
+/// per-shard counts to verify against.
+pub async fn export_sharded<W, F>(
+    client: &dyn BrainAIClient,
+    filters: Option<HashMap<String, Value>>,
+    writer_factory: F,
+    shards: usize,
+    concurrency: usize,
+) -> Result<ShardManifest>
+where
+    W: Write + Send + 'static,
+    F: Fn(usize) -> Result<W>,
+{
+    if shards == 0 {
+        return Err(BrainAIError::InvalidInput(
+            "sharded export needs at least one shard".to_string(),
+        ));
+    }
+    let concurrency = concurrency.clamp(1, shards);
+
+    // Worker `w` owns shards w, w + concurrency, w + 2·concurrency, ...
+    // and drains one channel of (shard, line) pairs for all of them.
+    let mut senders: Vec<mpsc::UnboundedSender<(usize, String)>> = Vec::with_capacity(concurrency);
+    let mut workers: JoinSet<Result<Vec<(usize, u64)>>> = JoinSet::new();
+    for worker in 0..concurrency {
+        let mut writers: HashMap<usize, W> = HashMap::new();
+        for shard in (worker..shards).step_by(concurrency) {
+            writers.insert(shard, writer_factory(shard)?);
+        }
+        let (sender, mut receiver) = mpsc::unbounded_channel::<(usize, String)>();
+        senders.push(sender);
+        workers.spawn(async move {
+            let mut counts: HashMap<usize, u64> = HashMap::new();
+            while let Some((shard, line)) = receiver.recv().await {
+                let writer = writers
+                    .get_mut(&shard)
+                    .expect("lines are routed to the owning worker");
+                writer
+                    .write_all(line.as_bytes())
+                    .and_then(|_| writer.write_all(b"\n"))
+                    .map_err(|err| {
+                        BrainAIError::InvalidInput(format!("shard {shard} write failed: {err}"))
+                    })?;
+                *counts.entry(shard).or_default() += 1;
+            }
+            for (shard, writer) in writers.iter_mut() {
+                writer.flush().map_err(|err| {
+                    BrainAIError::InvalidInput(format!("shard {shard} flush failed: {err}"))
+                })?;
+            }
+            Ok(counts.into_iter().collect())
+        });
+    }
+
+    // Walk the listing once, routing each memory to its shard's worker.
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = client
+            .list_memories_page(filters.clone(), EXPORT_PAGE_SIZE, cursor.as_deref())
+            .await?;
+        for memory in &page.memories {
+            let shard = shard_of(&memory.id, shards);
+            let line = serde_json::to_string(memory)?;
+            if senders[shard % concurrency].send((shard, line)).is_err() {
+                // The worker only exits early on a write error; surface it.
+                break;
+            }
+        }
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    drop(senders);
+
+    let mut counts = vec![0u64; shards];
+    while let Some(joined) = workers.join_next().await {
+        let worker_counts = joined.map_err(|err| {
+            BrainAIError::InvalidInput(format!("shard writer task failed: {err}"))
+        })??;
+        for (shard, count) in worker_counts {
+            counts[shard] = count;
+        }
+    }
+    let total = counts.iter().sum();
+    Ok(ShardManifest {
+        shards,
+        counts,
+        total,
+    })
+}
+
+/// Imports memories from JSONL produced by [`export_jsonl`].
+///
+/// Each line is stored as a new memory (the backend assigns fresh IDs;
+/// the original ID is preserved under the `imported_from` metadata key).
+/// Malformed or rejected lines are collected in the report rather than
+/// aborting the import.
+pub async fn import_jsonl<R: BufRead>(
+    client: &dyn BrainAIClient,
+    reader: R,
+) -> Result<ImportReport> {
+    let mut report = ImportReport::default();
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index + 1;
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                report.failed.push((line_number, format!("read failed: {err}")));
+                continue;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let memory: Memory = match serde_json::from_str(&line) {
+            Ok(memory) => memory,
+            Err(err) => {
+                report
+                    .failed
+                    .push((line_number, format!("malformed line: {err}")));
+                continue;
+            }
+        };
+        let mut metadata = memory.metadata;
+        metadata.insert("imported_from".to_string(), Value::String(memory.id));
+        match client
+            .store_memory(memory.content, memory.memory_type, Some(metadata))
+            .await
+        {
+            Ok(id) => report.imported.push((line_number, id)),
+            Err(err) => report.failed.push((line_number, err.to_string())),
+        }
+    }
+    Ok(report)
+}
+
+/// Progress marker for a resumable import.
+///
+/// Records the last fully committed line per shard file. [`resume_import`]
+/// skips committed lines outright and uses idempotency keys to avoid
+/// double-storing the uncommitted tail of an interrupted run, so an
+/// import that dies at 80% restarts from 80%, not zero. Load it from its
+/// file with [`load`](Self::load); it saves itself back every
+/// [`interval`](Self::interval) lines and at the end.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportCheckpoint {
+    /// Last line fully committed per shard file, 1-based.
+    pub committed: HashMap<String, usize>,
+    /// Lines between checkpoint writes.
+    #[serde(default = "default_checkpoint_interval")]
+    pub interval: usize,
+    /// Where the checkpoint persists; set by [`load`](Self::load).
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+fn default_checkpoint_interval() -> usize {
+    500
+}
+
+impl Default for ImportCheckpoint {
+    fn default() -> Self {
+        ImportCheckpoint {
+            committed: HashMap::new(),
+            interval: default_checkpoint_interval(),
+            path: None,
+        }
+    }
+}
+
+impl ImportCheckpoint {
+    /// Loads a checkpoint from `path`; a missing file yields a fresh
+    /// checkpoint that will save there.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut checkpoint = match std::fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str::<ImportCheckpoint>(&text)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                ImportCheckpoint::default()
+            }
+            Err(err) => {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "cannot read checkpoint {}: {err}",
+                    path.display()
+                )))
+            }
+        };
+        checkpoint.path = Some(path.to_path_buf());
+        Ok(checkpoint)
+    }
+
+    /// Writes the checkpoint back to its file, via a temp file and rename
+    /// so a crash mid-save cannot corrupt it. No-op without a path.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let temp = path.with_extension("tmp");
+        let rendered = serde_json::to_string_pretty(self)?;
+        std::fs::write(&temp, rendered)
+            .and_then(|_| std::fs::rename(&temp, path))
+            .map_err(|err| {
+                BrainAIError::InvalidInput(format!(
+                    "cannot write checkpoint {}: {err}",
+                    path.display()
+                ))
+            })
+    }
+
+    /// Last committed line for a shard file, 0 when it never ran.
+    pub fn committed(&self, shard: &str) -> usize {
+        self.committed.get(shard).copied().unwrap_or(0)
+    }
+}
+
+/// Imports a JSONL shard, resuming from the checkpoint.
+///
+/// Lines at or before the shard's committed mark are skipped without
+/// touching the server. Lines in the uncommitted window after the mark —
+/// at most one checkpoint interval — may have been applied by the
+/// interrupted run, so each is first looked up by its idempotency key
+/// (an FNV-1a hash of the line, stored under the `import_key` metadata
+/// key) and skipped when already present. Beyond the window the import
+/// proceeds like [`import_jsonl`], advancing and saving the checkpoint
+/// every [`interval`](ImportCheckpoint::interval) lines.
+pub async fn resume_import<R: BufRead>(
+    client: &dyn BrainAIClient,
+    reader: R,
+    checkpoint: &mut ImportCheckpoint,
+    shard: &str,
+) -> Result<ImportReport> {
+    let start = checkpoint.committed(shard);
+    let interval = checkpoint.interval.max(1);
+    let window_end = start + interval;
+    let mut report = ImportReport::default();
+    let mut last_seen = start;
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index + 1;
+        if line_number <= start {
+            continue;
+        }
+        last_seen = line_number;
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                report.failed.push((line_number, format!("read failed: {err}")));
+                continue;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let key = format!("{:016x}", fnv1a(line.trim()));
+        if line_number <= window_end {
+            // Possibly applied by the run that died; check before storing.
+            let filters = HashMap::from([(
+                "metadata.import_key".to_string(),
+                Value::String(key.clone()),
+            )]);
+            match client.list_memories(Some(filters), 1).await {
+                Ok(existing) if !existing.is_empty() => {
+                    report.imported.push((line_number, existing[0].id.clone()));
+                    continue;
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    report.failed.push((line_number, err.to_string()));
+                    continue;
+                }
+            }
+        }
+        let memory: Memory = match serde_json::from_str(&line) {
+            Ok(memory) => memory,
+            Err(err) => {
+                report
+                    .failed
+                    .push((line_number, format!("malformed line: {err}")));
+                continue;
+            }
+        };
+        let mut metadata = memory.metadata;
+        metadata.insert("imported_from".to_string(), Value::String(memory.id));
+        metadata.insert("import_key".to_string(), Value::String(key));
+        match client
+            .store_memory(memory.content, memory.memory_type, Some(metadata))
+            .await
+        {
+            Ok(id) => report.imported.push((line_number, id)),
+            Err(err) => report.failed.push((line_number, err.to_string())),
+        }
+        if line_number % interval == 0 {
+            checkpoint.committed.insert(shard.to_string(), line_number);
+            checkpoint.save()?;
+        }
+    }
+    checkpoint.committed.insert(shard.to_string(), last_seen);
+    checkpoint.save()?;
+    Ok(report)
+}
+
+/// Outcome of a differential export: what was written, plus the
+/// watermark to pass as `since` next time.
+#[derive(Debug, Default, Clone)]
+pub struct ChangeExport {
+    pub memories: u64,
+    pub vectors: u64,
+    pub edges: u64,
+    /// Newest change timestamp seen (unix milliseconds); feed it back as
+    /// `since` for the next incremental run.
+    pub watermark: i64,
+}
+
+/// Exports only what changed since `since` (unix milliseconds), for
+/// incremental backups and downstream ETL instead of nightly full dumps.
+///
+/// Each line is a tagged JSON object: `{"kind": "memory", ...}` for
+/// memories created or touched after `since`, `{"kind": "vector", ...}`
+/// for the vector a changed memory links via its `vector_id` metadata,
+/// and `{"kind": "edge", ...}` for the graph edges on a changed memory's
+/// node (deduplicated by edge ID). Vectors and edges carry no timestamps
+/// of their own, so they ride along with the memory that changed.
+pub async fn export_changes<W: Write>(
+    client: &dyn BrainAIClient,
+    since: i64,
+    writer: &mut W,
+) -> Result<ChangeExport> {
+    fn emit<W: Write>(writer: &mut W, line: &str) -> Result<()> {
+        writer
+            .write_all(line.as_bytes())
+            .and_then(|_| writer.write_all(b"\n"))
+            .map_err(|err| BrainAIError::InvalidInput(format!("write failed: {err}")))
+    }
+
+    let mut export = ChangeExport {
+        watermark: since,
+        ..ChangeExport::default()
+    };
+    let mut seen_edges: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = client
+            .list_memories_page(None, EXPORT_PAGE_SIZE, cursor.as_deref())
+            .await?;
+        for memory in &page.memories {
+            let changed_at = memory.created_at.max(memory.last_accessed);
+            if changed_at <= since {
+                continue;
+            }
+            export.watermark = export.watermark.max(changed_at);
+
+            let mut line = serde_json::to_value(memory)?;
+            line["kind"] = Value::String("memory".to_string());
+            emit(writer, &line.to_string())?;
+            export.memories += 1;
+
+            if let Some(vector_id) = memory.metadata.get("vector_id").and_then(Value::as_str) {
+                if let Some(vector) = client.get_vector(vector_id).await? {
+                    let mut line = serde_json::to_value(&vector)?;
+                    line["kind"] = Value::String("vector".to_string());
+                    emit(writer, &line.to_string())?;
+                    export.vectors += 1;
+                }
+            }
+            match client
+                .get_edges(&memory.id, crate::EdgeDirection::Any, None)
+                .await
+            {
+                Ok(edges) => {
+                    for edge in edges {
+                        if !seen_edges.insert(edge.id.clone()) {
+                            continue;
+                        }
+                        let mut line = serde_json::to_value(&edge)?;
+                        line["kind"] = Value::String("edge".to_string());
+                        emit(writer, &line.to_string())?;
+                        export.edges += 1;
+                    }
+                }
+                // Memories without a graph node have no edges to export.
+                Err(BrainAIError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    writer
+        .flush()
+        .map_err(|err| BrainAIError::InvalidInput(format!("flush failed: {err}")))?;
+    Ok(export)
+}
+
+/// Parquet export, behind the `parquet` feature (which enables `arrow`).
+///
+/// JSONL dumps are greppable but expensive for analytics: every query
+/// re-parses every line. Parquet is columnar, compressed, and carries
+/// its schema, so Spark and duckdb read only the columns they need.
+/// Column layouts match the `dataframe` module's Arrow conversions;
+/// vectors additionally become a `List<Float32>` column.
+#[cfg(feature = "parquet")]
+mod parquet_export {
+    use std::collections::HashMap;
+    use std::io::Write;
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, Float32Builder, ListBuilder, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+    use parquet::basic::Compression;
+    use parquet::file::properties::WriterProperties;
+    use serde_json::Value;
+
+    use crate::dataframe::ToArrow;
+    use crate::{BrainAIClient, BrainAIError, Memory, Result, VectorRecord};
+
+    use super::EXPORT_PAGE_SIZE;
+
+    fn writer_error(err: parquet::errors::ParquetError) -> BrainAIError {
+        BrainAIError::InvalidInput(format!("parquet write failed: {err}"))
+    }
+
+    /// Exports memories matching `filters` as one Parquet file,
+    /// returning the row count. Pages become row groups, so
+    /// arbitrarily large brains fit in constant memory.
+    pub async fn export_parquet<W: Write + Send>(
+        client: &dyn BrainAIClient,
+        filters: Option<HashMap<String, Value>>,
+        writer: W,
+    ) -> Result<u64> {
+        // An empty listing still gets a valid (zero-row) file with the
+        // full schema, so downstream jobs need no special case.
+        let schema = (&[] as &[Memory]).to_arrow()?.schema();
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut out =
+            ArrowWriter::try_new(writer, schema, Some(properties)).map_err(writer_error)?;
+        let mut exported = 0u64;
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = client
+                .list_memories_page(filters.clone(), EXPORT_PAGE_SIZE, cursor.as_deref())
+                .await?;
+            if !page.memories.is_empty() {
+                let batch = page.memories.as_slice().to_arrow()?;
+                exported += batch.num_rows() as u64;
+                out.write(&batch).map_err(writer_error)?;
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        out.close().map_err(writer_error)?;
+        Ok(exported)
+    }
+
+    /// Writes vector records as one Parquet file, returning the row
+    /// count. Columns: `id`, `vector` (`List<Float32>`), `metadata`
+    /// (JSON text).
+    pub fn export_vectors_parquet<W: Write + Send>(
+        records: &[VectorRecord],
+        writer: W,
+    ) -> Result<u64> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new(
+                "vector",
+                DataType::List(Arc::new(Field::new("item", DataType::Float32, true))),
+                false,
+            ),
+            Field::new("metadata", DataType::Utf8, false),
+        ]));
+
+        let mut vectors = ListBuilder::new(Float32Builder::new());
+        for record in records {
+            vectors.values().append_slice(&record.vector);
+            vectors.append(true);
+        }
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(
+                records.iter().map(|r| r.id.as_str()),
+            )),
+            Arc::new(vectors.finish()),
+            Arc::new(StringArray::from_iter_values(records.iter().map(|r| {
+                serde_json::to_string(&r.metadata).unwrap_or_else(|_| "{}".to_string())
+            }))),
+        ];
+        let batch = RecordBatch::try_new(schema.clone(), columns).map_err(|err| {
+            BrainAIError::InvalidInput(format!("arrow conversion failed: {err}"))
+        })?;
+
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut out =
+            ArrowWriter::try_new(writer, schema, Some(properties)).map_err(writer_error)?;
+        out.write(&batch).map_err(writer_error)?;
+        out.close().map_err(writer_error)?;
+        Ok(records.len() as u64)
+    }
+}
+
+#[cfg(feature = "parquet")]
+pub use parquet_export::{export_parquet, export_vectors_parquet};
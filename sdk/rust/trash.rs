@@ -0,0 +1,180 @@
+//! Soft deletion with a trash bin and restore window.
+//!
+//! `clear_all()` on the server is irreversible. [`TrashBin`] offers a safer
+//! path: [`soft_clear_all`](TrashBin::soft_clear_all) and
+//! [`soft_delete`](TrashBin::soft_delete) move memories into a local trash
+//! instead of destroying them, and anything still inside its restore window
+//! can be put back with [`restore`](TrashBin::restore) /
+//! [`restore_all`](TrashBin::restore_all). Expired entries are dropped by
+//! [`purge_expired`](TrashBin::purge_expired), typically from the same
+//! scheduler that runs decay passes.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIClient, BrainAIError, Memory, Result};
+
+/// A memory held in the trash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedMemory {
+    pub memory: Memory,
+    /// When the memory was trashed (unix milliseconds).
+    pub trashed_at: i64,
+    /// Last instant at which restore is still possible (unix milliseconds).
+    pub expires_at: i64,
+}
+
+impl TrashedMemory {
+    /// Whether the restore window has passed.
+    pub fn is_expired(&self) -> bool {
+        now_millis() > self.expires_at
+    }
+}
+
+/// Local trash bin wrapping a client.
+pub struct TrashBin<C: BrainAIClient> {
+    inner: C,
+    window: Duration,
+    trash: Mutex<HashMap<String, TrashedMemory>>,
+}
+
+impl<C: BrainAIClient> TrashBin<C> {
+    /// Wraps a client with the given restore window.
+    pub fn new(inner: C, window: Duration) -> Self {
+        TrashBin {
+            inner,
+            window,
+            trash: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn stash(&self, memory: Memory) {
+        let now = now_millis();
+        let entry = TrashedMemory {
+            trashed_at: now,
+            expires_at: now + self.window.as_millis() as i64,
+            memory,
+        };
+        self.trash
+            .lock()
+            .unwrap()
+            .insert(entry.memory.id.clone(), entry);
+    }
+
+    /// Moves a single memory into the trash instead of deleting it outright.
+    pub async fn soft_delete(&self, id: &str) -> Result<bool> {
+        let memory = self
+            .inner
+            .get_memory(id)
+            .await?
+            .ok_or_else(|| BrainAIError::NotFound(format!("memory {id}")))?;
+        self.inner.delete_memory(id).await?;
+        self.stash(memory);
+        Ok(true)
+    }
+
+    /// Moves every memory into the trash, returning the count trashed.
+    ///
+    /// Unlike `clear_all()`, anything trashed here can be restored until
+    /// its window expires.
+    pub async fn soft_clear_all(&self) -> Result<u64> {
+        let mut trashed = 0u64;
+        loop {
+            // Re-list from the start each round; deletions shrink the set.
+            let page = self.inner.list_memories_page(None, 500, None).await?;
+            if page.memories.is_empty() {
+                break;
+            }
+            for memory in page.memories {
+                let id = memory.id.clone();
+                self.inner.delete_memory(&id).await?;
+                self.stash(memory);
+                trashed += 1;
+            }
+            if page.next_cursor.is_none() {
+                break;
+            }
+        }
+        Ok(trashed)
+    }
+
+    /// Lists trashed memories, most recently trashed first.
+    pub fn list_trash(&self) -> Vec<TrashedMemory> {
+        let trash = self.trash.lock().unwrap();
+        let mut entries: Vec<TrashedMemory> = trash.values().cloned().collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.trashed_at));
+        entries
+    }
+
+    /// Restores one trashed memory, returning the new memory ID.
+    ///
+    /// Fails with [`BrainAIError::InvalidInput`] when the restore window
+    /// has expired.
+    pub async fn restore(&self, id: &str) -> Result<String> {
+        let entry = {
+            let trash = self.trash.lock().unwrap();
+            trash
+                .get(id)
+                .cloned()
+                .ok_or_else(|| BrainAIError::NotFound(format!("trashed memory {id}")))?
+        };
+        if entry.is_expired() {
+            return Err(BrainAIError::InvalidInput(format!(
+                "restore window for memory {id} has expired"
+            )));
+        }
+        let memory = entry.memory;
+        let new_id = self
+            .inner
+            .store_memory(
+                memory.content,
+                memory.memory_type,
+                Some(memory.metadata),
+            )
+            .await?;
+        self.trash.lock().unwrap().remove(id);
+        Ok(new_id)
+    }
+
+    /// Restores everything still inside its window, returning
+    /// `(old_id, new_id)` pairs.
+    pub async fn restore_all(&self) -> Result<Vec<(String, String)>> {
+        let restorable: Vec<String> = self
+            .list_trash()
+            .into_iter()
+            .filter(|e| !e.is_expired())
+            .map(|e| e.memory.id)
+            .collect();
+        let mut restored = Vec::with_capacity(restorable.len());
+        for id in restorable {
+            let new_id = self.restore(&id).await?;
+            restored.push((id, new_id));
+        }
+        Ok(restored)
+    }
+
+    /// Drops trash entries whose restore window has expired, returning the
+    /// count removed. After this the underlying data is gone for good.
+    pub fn purge_expired(&self) -> usize {
+        let mut trash = self.trash.lock().unwrap();
+        let before = trash.len();
+        trash.retain(|_, entry| !entry.is_expired());
+        before - trash.len()
+    }
+
+    /// Serializes the trash for out-of-process persistence.
+    pub fn export_trash(&self) -> Result<Value> {
+        let entries = self.list_trash();
+        Ok(serde_json::to_value(entries)?)
+    }
+}
@@ -0,0 +1,270 @@
+//! Offline replay of recorded searches for retrieval tuning.
+//!
+//! Guessing at scoring weights and eyeballing live traffic is a slow way
+//! to tune retrieval. This module records (query, candidates, chosen
+//! results, downstream feedback) tuples to a local JSONL log as searches
+//! happen, then [`replay`]s them offline: each candidate set is
+//! re-ranked under every candidate [`ScoringProfile`] and scored against
+//! the recorded relevance judgements, reporting which configuration
+//! would have performed best — no live experiments required.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIError, Result, ScoreBreakdown, SearchResult};
+
+/// Weights over the [`ScoreBreakdown`] components; one candidate
+/// configuration of the retrieval scoring.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ScoringProfile {
+    pub similarity_weight: f64,
+    pub strength_weight: f64,
+    pub recency_weight: f64,
+}
+
+impl Default for ScoringProfile {
+    fn default() -> Self {
+        ScoringProfile {
+            similarity_weight: 1.0,
+            strength_weight: 1.0,
+            recency_weight: 1.0,
+        }
+    }
+}
+
+impl ScoringProfile {
+    /// The combined score this profile assigns to one breakdown.
+    pub fn score(&self, breakdown: &ScoreBreakdown) -> f64 {
+        self.similarity_weight * breakdown.similarity
+            + self.strength_weight * breakdown.strength_bonus
+            + self.recency_weight * breakdown.recency_bonus
+    }
+
+    /// A grid of profiles with each weight stepped over `0.0..=1.0` in
+    /// `steps` increments (similarity fixed at `1.0` as the reference
+    /// scale, since only weight ratios affect the ranking).
+    pub fn grid(steps: usize) -> Vec<ScoringProfile> {
+        let steps = steps.max(1);
+        let mut profiles = Vec::new();
+        for strength in 0..=steps {
+            for recency in 0..=steps {
+                profiles.push(ScoringProfile {
+                    similarity_weight: 1.0,
+                    strength_weight: strength as f64 / steps as f64,
+                    recency_weight: recency as f64 / steps as f64,
+                });
+            }
+        }
+        profiles
+    }
+}
+
+/// One candidate as it came back from retrieval, with the score
+/// components the replay re-weights.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayCandidate {
+    pub id: String,
+    /// The combined score the live configuration assigned.
+    pub score: f64,
+    /// Zeroed when the server did not report a breakdown; such
+    /// candidates rank by ID only and the entry tunes poorly.
+    #[serde(default)]
+    pub breakdown: ScoreBreakdown,
+}
+
+/// One recorded search: the candidate pool, which results were actually
+/// used, and the relevance the downstream feedback assigned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayEntry {
+    pub query: String,
+    pub candidates: Vec<ReplayCandidate>,
+    /// IDs of the candidates the application surfaced or acted on.
+    #[serde(default)]
+    pub chosen: Vec<String>,
+    /// Relevance gain per candidate ID (e.g. `1.0` for a hit the user
+    /// confirmed, `0.0` or absent for a miss). This is what replayed
+    /// rankings are scored against.
+    #[serde(default)]
+    pub relevance: HashMap<String, f64>,
+    /// When the search ran (unix milliseconds).
+    pub at: i64,
+}
+
+impl ReplayEntry {
+    /// Captures a search and its candidate pool; mark choices and
+    /// feedback on the entry before appending it to a [`ReplayLog`].
+    pub fn new(query: impl Into<String>, results: &[SearchResult]) -> Self {
+        ReplayEntry {
+            query: query.into(),
+            candidates: results
+                .iter()
+                .map(|result| ReplayCandidate {
+                    id: result.id.clone(),
+                    score: result.score,
+                    breakdown: result.breakdown.clone().unwrap_or_default(),
+                })
+                .collect(),
+            chosen: Vec::new(),
+            relevance: HashMap::new(),
+            at: now_millis(),
+        }
+    }
+
+    /// Marks a candidate as surfaced to / acted on by the application.
+    pub fn mark_chosen(&mut self, id: impl Into<String>) {
+        self.chosen.push(id.into());
+    }
+
+    /// Records downstream feedback for a candidate as a relevance gain.
+    pub fn mark_relevant(&mut self, id: impl Into<String>, gain: f64) {
+        self.relevance.insert(id.into(), gain);
+    }
+}
+
+/// Append-only JSONL log of [`ReplayEntry`]s on local disk.
+#[derive(Debug, Clone)]
+pub struct ReplayLog {
+    path: PathBuf,
+}
+
+impl ReplayLog {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        ReplayLog {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Appends one recorded search to the log.
+    pub fn append(&self, entry: &ReplayEntry) -> Result<()> {
+        let line = serde_json::to_string(entry)?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|err| {
+                BrainAIError::InvalidInput(format!(
+                    "cannot open replay log {}: {err}",
+                    self.path.display()
+                ))
+            })?;
+        file.write_all(line.as_bytes())
+            .and_then(|_| file.write_all(b"\n"))
+            .map_err(|err| BrainAIError::InvalidInput(format!("replay log write failed: {err}")))
+    }
+
+    /// Loads every recorded entry; a missing log is an empty log.
+    pub fn load(&self) -> Result<Vec<ReplayEntry>> {
+        let text = match std::fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => {
+                return Err(BrainAIError::InvalidInput(format!(
+                    "cannot read replay log {}: {err}",
+                    self.path.display()
+                )))
+            }
+        };
+        let mut entries = Vec::new();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            entries.push(serde_json::from_str(line)?);
+        }
+        Ok(entries)
+    }
+}
+
+/// How one profile performed across the replayed log.
+#[derive(Debug, Clone)]
+pub struct ProfileReport {
+    pub profile: ScoringProfile,
+    /// Mean normalized discounted cumulative gain at the replay depth.
+    pub ndcg: f64,
+    /// Mean reciprocal rank of the first relevant candidate.
+    pub mrr: f64,
+    /// Entries that carried relevance feedback and were evaluated.
+    pub queries: usize,
+}
+
+/// Re-ranks every recorded candidate pool under each profile and scores
+/// the rankings against the recorded relevance, best profile first.
+///
+/// Rankings are evaluated at depth `k` with NDCG (ties broken by mean
+/// reciprocal rank). Entries without relevance feedback cannot be judged
+/// and are skipped; when no entry is judgeable every profile reports
+/// zero.
+pub fn replay(entries: &[ReplayEntry], profiles: &[ScoringProfile], k: usize) -> Vec<ProfileReport> {
+    let k = k.max(1);
+    let judged: Vec<&ReplayEntry> = entries
+        .iter()
+        .filter(|entry| entry.relevance.values().any(|gain| *gain > 0.0))
+        .collect();
+    let mut reports: Vec<ProfileReport> = profiles
+        .iter()
+        .map(|profile| {
+            let mut ndcg_sum = 0.0;
+            let mut mrr_sum = 0.0;
+            for entry in &judged {
+                let mut ranked: Vec<&ReplayCandidate> = entry.candidates.iter().collect();
+                ranked.sort_by(|a, b| {
+                    profile
+                        .score(&b.breakdown)
+                        .total_cmp(&profile.score(&a.breakdown))
+                        .then_with(|| a.id.cmp(&b.id))
+                });
+                ndcg_sum += ndcg_at(&ranked, &entry.relevance, k);
+                mrr_sum += reciprocal_rank(&ranked, &entry.relevance);
+            }
+            let n = judged.len().max(1) as f64;
+            ProfileReport {
+                profile: *profile,
+                ndcg: ndcg_sum / n,
+                mrr: mrr_sum / n,
+                queries: judged.len(),
+            }
+        })
+        .collect();
+    reports.sort_by(|a, b| b.ndcg.total_cmp(&a.ndcg).then(b.mrr.total_cmp(&a.mrr)));
+    reports
+}
+
+/// Normalized discounted cumulative gain of a ranking at depth `k`,
+/// against the ideal ordering of the same gains.
+fn ndcg_at(ranked: &[&ReplayCandidate], relevance: &HashMap<String, f64>, k: usize) -> f64 {
+    let dcg: f64 = ranked
+        .iter()
+        .take(k)
+        .enumerate()
+        .map(|(rank, candidate)| {
+            relevance.get(&candidate.id).copied().unwrap_or(0.0) / (rank as f64 + 2.0).log2()
+        })
+        .sum();
+    let mut gains: Vec<f64> = ranked
+        .iter()
+        .map(|candidate| relevance.get(&candidate.id).copied().unwrap_or(0.0))
+        .collect();
+    gains.sort_by(|a, b| b.total_cmp(a));
+    let ideal: f64 = gains
+        .iter()
+        .take(k)
+        .enumerate()
+        .map(|(rank, gain)| gain / (rank as f64 + 2.0).log2())
+        .sum();
+    if ideal == 0.0 {
+        0.0
+    } else {
+        dcg / ideal
+    }
+}
+
+/// `1 / rank` of the first candidate with positive relevance gain, or
+/// zero when none ranked.
+fn reciprocal_rank(ranked: &[&ReplayCandidate], relevance: &HashMap<String, f64>) -> f64 {
+    ranked
+        .iter()
+        .position(|candidate| relevance.get(&candidate.id).copied().unwrap_or(0.0) > 0.0)
+        .map(|rank| 1.0 / (rank as f64 + 1.0))
+        .unwrap_or(0.0)
+}
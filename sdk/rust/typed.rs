@@ -0,0 +1,161 @@
+//! Strongly-typed memory content.
+//!
+//! [`MemoryNode<T>`] mirrors [`Memory`](crate::Memory) with the raw JSON
+//! content replaced by a user type, so applications get compile-time
+//! checking instead of `Value` plumbing. Nodes convert losslessly to and
+//! from the untyped representation and can be stored and fetched through
+//! any [`BrainAIClient`].
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    BrainAIClient, BrainAIError, Memory, MemoryType, Result, ScoreBreakdown, SearchResult,
+};
+
+/// A memory whose content has a concrete Rust type.
+///
+/// # Example
+/// ```no_run
+/// use brain_ai::{MemoryNode, MemoryType, MockBrainAI};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Meeting {
+///     title: String,
+///     attendees: Vec<String>,
+/// }
+///
+/// # tokio_test::block_on(async {
+/// let brain = MockBrainAI::new();
+/// let meeting = Meeting {
+///     title: "Q1 strategy".to_string(),
+///     attendees: vec!["Alice".to_string(), "Bob".to_string()],
+/// };
+/// let id = MemoryNode::store(&brain, meeting, MemoryType::Episodic, None)
+///     .await
+///     .unwrap();
+/// let node: MemoryNode<Meeting> = MemoryNode::fetch(&brain, &id).await.unwrap().unwrap();
+/// assert_eq!(node.content.title, "Q1 strategy");
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct MemoryNode<T> {
+    pub id: String,
+    pub content: T,
+    pub memory_type: MemoryType,
+    pub metadata: HashMap<String, Value>,
+    pub strength: f64,
+    pub created_at: i64,
+    pub last_accessed: i64,
+}
+
+impl<T: Serialize + DeserializeOwned> MemoryNode<T> {
+    /// Serializes `content` and stores it as a memory, returning its ID.
+    pub async fn store(
+        client: &dyn BrainAIClient,
+        content: T,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<String> {
+        let content = serde_json::to_value(&content)?;
+        client.store_memory(content, memory_type, metadata).await
+    }
+
+    /// Fetches a memory and deserializes its content into `T`.
+    ///
+    /// Returns `None` when the memory does not exist and
+    /// [`BrainAIError::Serialization`] when the stored content does not
+    /// match the expected shape.
+    pub async fn fetch(client: &dyn BrainAIClient, id: &str) -> Result<Option<Self>> {
+        match client.get_memory(id).await? {
+            Some(memory) => Ok(Some(Self::try_from_memory(memory)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Converts an untyped memory, deserializing its content into `T`.
+    pub fn try_from_memory(memory: Memory) -> Result<Self> {
+        let content = serde_json::from_value(memory.content)
+            .map_err(BrainAIError::Serialization)?;
+        Ok(MemoryNode {
+            id: memory.id,
+            content,
+            memory_type: memory.memory_type,
+            metadata: memory.metadata,
+            strength: memory.strength,
+            created_at: memory.created_at,
+            last_accessed: memory.last_accessed,
+        })
+    }
+
+    /// Converts back into the untyped representation.
+    pub fn into_memory(self) -> Result<Memory> {
+        Ok(Memory {
+            id: self.id,
+            content: serde_json::to_value(&self.content)?,
+            memory_type: self.memory_type,
+            metadata: self.metadata,
+            strength: self.strength,
+            created_at: self.created_at,
+            last_accessed: self.last_accessed,
+        })
+    }
+}
+
+/// A search hit whose content has a concrete Rust type.
+#[derive(Debug, Clone)]
+pub struct TypedSearchResult<T> {
+    pub id: String,
+    pub content: T,
+    pub metadata: HashMap<String, Value>,
+    /// Combined score in `0.0..=1.0`.
+    pub score: f64,
+    /// Per-component score breakdown, when the server reports one.
+    pub breakdown: Option<ScoreBreakdown>,
+}
+
+impl<T: DeserializeOwned> TypedSearchResult<T> {
+    /// Converts an untyped hit, deserializing its content into `T`.
+    pub fn try_from_result(result: SearchResult) -> Result<Self> {
+        let content = serde_json::from_value(result.content)
+            .map_err(BrainAIError::Serialization)?;
+        Ok(TypedSearchResult {
+            id: result.id,
+            content,
+            metadata: result.metadata,
+            score: result.score,
+            breakdown: result.breakdown,
+        })
+    }
+}
+
+/// Searches memories and deserializes each hit's content into `T`.
+///
+/// Hits whose content does not match `T` are skipped rather than failing
+/// the whole search, since a brain usually stores content of many shapes.
+pub async fn search_typed<T: DeserializeOwned>(
+    client: &dyn BrainAIClient,
+    query: Value,
+    limit: usize,
+) -> Result<Vec<TypedSearchResult<T>>> {
+    let results = client.search_memories(query, limit).await?;
+    Ok(results
+        .into_iter()
+        .filter_map(|r| TypedSearchResult::try_from_result(r).ok())
+        .collect())
+}
+
+/// Typed view over a memory listing: deserializes each memory's content,
+/// skipping memories whose content does not match `T`.
+pub fn typed_memories<T: Serialize + DeserializeOwned>(
+    memories: Vec<Memory>,
+) -> Vec<MemoryNode<T>> {
+    memories
+        .into_iter()
+        .filter_map(|m| MemoryNode::try_from_memory(m).ok())
+        .collect()
+}
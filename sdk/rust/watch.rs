@@ -0,0 +1,95 @@
+//! Pattern subscriptions: react when a learned pattern crosses a
+//! threshold.
+//!
+//! [`watch_patterns`] polls the learning patterns in the background and
+//! yields each pattern the moment it *starts* satisfying a caller
+//! predicate — "frequency exceeds N", "strength exceeds 0.8" ("habit
+//! formed") — so applications stop diffing
+//! `get_learning_patterns` results by hand. Delivery is edge-triggered:
+//! a pattern is yielded when it crosses into the predicate, and again
+//! only after it has crossed back out first.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+use crate::client::BrainAIClient;
+use crate::LearningPattern;
+
+/// Polling knobs for [`watch_patterns`].
+#[derive(Debug, Clone, Copy)]
+pub struct WatchOptions {
+    /// How often the pattern list is re-fetched.
+    pub poll_interval: Duration,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        WatchOptions {
+            poll_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// A live pattern subscription. Drop it to stop the background poll.
+pub struct PatternWatch {
+    receiver: mpsc::UnboundedReceiver<LearningPattern>,
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl PatternWatch {
+    /// The next pattern crossing into the predicate. `None` once the
+    /// watch has stopped.
+    pub async fn next(&mut self) -> Option<LearningPattern> {
+        self.receiver.recv().await
+    }
+}
+
+impl Drop for PatternWatch {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Watches the learned patterns, yielding each one as it starts
+/// satisfying `predicate`; see the module docs for the edge-trigger
+/// semantics. Poll failures are logged and retried on the next tick
+/// rather than ending the watch.
+pub fn watch_patterns<P>(
+    client: Arc<dyn BrainAIClient>,
+    predicate: P,
+    options: WatchOptions,
+) -> PatternWatch
+where
+    P: Fn(&LearningPattern) -> bool + Send + Sync + 'static,
+{
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let handle = tokio::spawn(async move {
+        // Whether each known pattern satisfied the predicate last poll.
+        let mut satisfied: HashMap<String, bool> = HashMap::new();
+        let mut ticker = tokio::time::interval(options.poll_interval);
+        loop {
+            ticker.tick().await;
+            let patterns = match client.get_learning_patterns().await {
+                Ok(patterns) => patterns,
+                Err(err) => {
+                    eprintln!("[brain-ai] pattern watch poll failed: {err}");
+                    continue;
+                }
+            };
+            for pattern in patterns {
+                let now = predicate(&pattern);
+                let before = satisfied
+                    .insert(pattern.pattern.clone(), now)
+                    .unwrap_or(false);
+                if now && !before && sender.send(pattern).is_err() {
+                    // Receiver dropped; the watch is over.
+                    return;
+                }
+            }
+        }
+    });
+    PatternWatch { receiver, handle }
+}
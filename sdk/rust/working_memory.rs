@@ -0,0 +1,197 @@
+//! Bounded working-memory buffer with eviction to long-term storage.
+//!
+//! Mirrors the cognitive split the framework is modeled on: a small, fast
+//! working set in process, backed by the brain's long-term store. Items go
+//! into [`WorkingMemory`] without a network call; when the buffer is full
+//! the eviction policy picks a victim, which is either spilled to the
+//! backend as a real memory or dropped. Touching an item refreshes its
+//! recency and activation so hot items survive eviction.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde_json::Value;
+
+use crate::vector_utils::now_millis;
+use crate::{BrainAIClient, MemoryType, Result};
+
+/// Which item gets evicted when the buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Least recently touched item.
+    Lru,
+    /// Oldest item by insertion.
+    Fifo,
+    /// Item with the lowest activation.
+    LowestActivation,
+}
+
+/// An item held in working memory.
+#[derive(Debug, Clone)]
+pub struct WorkingItem {
+    /// Buffer-local ID; long-term IDs are assigned on spill.
+    pub id: String,
+    pub content: Value,
+    pub memory_type: MemoryType,
+    pub metadata: HashMap<String, Value>,
+    /// Activation level; bumped on touch, used by `LowestActivation`.
+    pub activation: f64,
+    pub inserted_at: i64,
+    pub last_touched: i64,
+}
+
+/// Bounded in-process buffer in front of the long-term store.
+pub struct WorkingMemory<C: BrainAIClient> {
+    client: C,
+    capacity: usize,
+    policy: EvictionPolicy,
+    /// Spill evicted items to the backend instead of dropping them.
+    spill_on_evict: bool,
+    items: Mutex<HashMap<String, WorkingItem>>,
+    next_id: AtomicU64,
+}
+
+impl<C: BrainAIClient> WorkingMemory<C> {
+    /// Creates a buffer holding at most `capacity` items, spilling LRU
+    /// victims to the backend.
+    pub fn new(client: C, capacity: usize) -> Self {
+        WorkingMemory {
+            client,
+            capacity: capacity.max(1),
+            policy: EvictionPolicy::Lru,
+            spill_on_evict: true,
+            items: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Overrides the eviction policy.
+    pub fn with_policy(mut self, policy: EvictionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Drops evicted items instead of spilling them to the backend.
+    pub fn drop_on_evict(mut self) -> Self {
+        self.spill_on_evict = false;
+        self
+    }
+
+    /// Returns the wrapped client.
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Current number of buffered items.
+    pub fn len(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+
+    /// Whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.lock().unwrap().is_empty()
+    }
+
+    fn pick_victim(items: &HashMap<String, WorkingItem>, policy: EvictionPolicy) -> Option<String> {
+        let victim = match policy {
+            EvictionPolicy::Lru => items.values().min_by_key(|i| i.last_touched),
+            EvictionPolicy::Fifo => items.values().min_by_key(|i| i.inserted_at),
+            EvictionPolicy::LowestActivation => items
+                .values()
+                .min_by(|a, b| a.activation.total_cmp(&b.activation)),
+        };
+        victim.map(|i| i.id.clone())
+    }
+
+    /// Inserts an item, evicting first if the buffer is at capacity.
+    ///
+    /// Returns the buffer-local ID and, when an eviction spilled to the
+    /// backend, the long-term memory ID of the victim.
+    pub async fn insert(
+        &self,
+        content: Value,
+        memory_type: MemoryType,
+        metadata: Option<HashMap<String, Value>>,
+    ) -> Result<(String, Option<String>)> {
+        let spilled = self.make_room().await?;
+        let id = format!("wm_{}", self.next_id.fetch_add(1, Ordering::Relaxed));
+        let now = now_millis();
+        self.items.lock().unwrap().insert(
+            id.clone(),
+            WorkingItem {
+                id: id.clone(),
+                content,
+                memory_type,
+                metadata: metadata.unwrap_or_default(),
+                activation: 1.0,
+                inserted_at: now,
+                last_touched: now,
+            },
+        );
+        Ok((id, spilled))
+    }
+
+    async fn make_room(&self) -> Result<Option<String>> {
+        let victim = {
+            let items = self.items.lock().unwrap();
+            if items.len() < self.capacity {
+                return Ok(None);
+            }
+            Self::pick_victim(&items, self.policy)
+        };
+        let Some(victim_id) = victim else {
+            return Ok(None);
+        };
+        let item = self.items.lock().unwrap().remove(&victim_id);
+        let Some(item) = item else { return Ok(None) };
+        if self.spill_on_evict {
+            let memory_id = self
+                .client
+                .store_memory(item.content, item.memory_type, Some(item.metadata))
+                .await?;
+            Ok(Some(memory_id))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Fetches an item, refreshing its recency and activation.
+    pub fn touch(&self, id: &str) -> Option<WorkingItem> {
+        let mut items = self.items.lock().unwrap();
+        let item = items.get_mut(id)?;
+        item.last_touched = now_millis();
+        item.activation += 1.0;
+        Some(item.clone())
+    }
+
+    /// Reads an item without affecting eviction order.
+    pub fn peek(&self, id: &str) -> Option<WorkingItem> {
+        self.items.lock().unwrap().get(id).cloned()
+    }
+
+    /// Removes an item from the buffer without storing it.
+    pub fn forget(&self, id: &str) -> bool {
+        self.items.lock().unwrap().remove(id).is_some()
+    }
+
+    /// Spills every buffered item to the backend and empties the buffer,
+    /// returning `(buffer_id, memory_id)` pairs.
+    pub async fn flush(&self) -> Result<Vec<(String, String)>> {
+        let drained: Vec<WorkingItem> = {
+            let mut items = self.items.lock().unwrap();
+            let drained = items.values().cloned().collect();
+            items.clear();
+            drained
+        };
+        let mut stored = Vec::with_capacity(drained.len());
+        for item in drained {
+            let memory_id = self
+                .client
+                .store_memory(item.content, item.memory_type, Some(item.metadata))
+                .await?;
+            stored.push((item.id, memory_id));
+        }
+        Ok(stored)
+    }
+}
@@ -0,0 +1,250 @@
+//! Write-behind buffer with a dead-letter queue.
+//!
+//! [`WriteBuffer`] queues memory writes locally and flushes them to the
+//! server in the background with retries and exponential backoff. When a
+//! write exhausts its retries it is never dropped: the operation is
+//! appended, with its error context, to a local dead-letter file (one JSON
+//! object per line), and [`WriteBuffer::replay_dead_letters`] retries the
+//! file later — after an outage, a deploy, or a manual fix.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, Write as _};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::client::BrainAIClient;
+use crate::vector_utils::now_millis;
+use crate::{BrainAIError, MemoryWrite, Result};
+
+/// Retry and flush tuning for [`WriteBuffer`].
+#[derive(Debug, Clone)]
+pub struct WriteBufferOptions {
+    /// Attempts per write before dead-lettering (first try included).
+    pub max_attempts: u32,
+    /// Backoff before the second attempt; doubles per retry.
+    pub initial_backoff: Duration,
+    /// Where exhausted writes are persisted.
+    pub dead_letter_path: PathBuf,
+}
+
+impl Default for WriteBufferOptions {
+    fn default() -> Self {
+        WriteBufferOptions {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(250),
+            dead_letter_path: PathBuf::from("brain-ai-dead-letters.jsonl"),
+        }
+    }
+}
+
+/// A write that exhausted its retries, with the context needed to triage
+/// and replay it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+    pub write: MemoryWrite,
+    /// Final error string at exhaustion.
+    pub error: String,
+    pub attempts: u32,
+    /// When the write was dead-lettered (unix milliseconds).
+    pub failed_at: i64,
+}
+
+/// Outcome of one flush pass.
+#[derive(Debug, Default, Clone)]
+pub struct FlushReport {
+    /// IDs of memories stored this pass.
+    pub stored: Vec<String>,
+    /// Writes moved to the dead-letter file this pass.
+    pub dead_lettered: usize,
+}
+
+/// Outcome of a dead-letter replay.
+#[derive(Debug, Default, Clone)]
+pub struct ReplayReport {
+    /// IDs of memories stored from the file.
+    pub stored: Vec<String>,
+    /// Letters that failed again and remain in the file.
+    pub still_dead: usize,
+}
+
+/// Buffered, retrying writer over any client.
+pub struct WriteBuffer {
+    client: Arc<dyn BrainAIClient>,
+    options: WriteBufferOptions,
+    queue: Mutex<VecDeque<MemoryWrite>>,
+}
+
+impl WriteBuffer {
+    /// Creates a buffer with default options.
+    pub fn new(client: Arc<dyn BrainAIClient>) -> Self {
+        WriteBuffer::with_options(client, WriteBufferOptions::default())
+    }
+
+    /// Creates a buffer with explicit options.
+    pub fn with_options(client: Arc<dyn BrainAIClient>, options: WriteBufferOptions) -> Self {
+        WriteBuffer {
+            client,
+            options,
+            queue: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Queues a write; it is sent on the next [`flush`](Self::flush).
+    pub async fn enqueue(&self, write: MemoryWrite) {
+        self.queue.lock().await.push_back(write);
+    }
+
+    /// Number of writes waiting to be flushed.
+    pub async fn pending(&self) -> usize {
+        self.queue.lock().await.len()
+    }
+
+    /// Attempts one write with retries and backoff.
+    async fn try_store(&self, write: &MemoryWrite) -> std::result::Result<String, String> {
+        let mut backoff = self.options.initial_backoff;
+        let mut last_error = String::new();
+        for attempt in 0..self.options.max_attempts.max(1) {
+            if attempt > 0 {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+            match self
+                .client
+                .store_memory(
+                    write.content.clone(),
+                    write.memory_type,
+                    Some(write.metadata.clone()),
+                )
+                .await
+            {
+                Ok(id) => return Ok(id),
+                // Input the server will never accept; retrying is pointless.
+                Err(err @ BrainAIError::InvalidInput(_)) => return Err(err.to_string()),
+                Err(err) => last_error = err.to_string(),
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Flushes the queue: stores what it can, dead-letters what it cannot.
+    pub async fn flush(&self) -> Result<FlushReport> {
+        let mut report = FlushReport::default();
+        loop {
+            let Some(write) = self.queue.lock().await.pop_front() else {
+                break;
+            };
+            match self.try_store(&write).await {
+                Ok(id) => report.stored.push(id),
+                Err(error) => {
+                    self.append_dead_letter(DeadLetter {
+                        write,
+                        error,
+                        attempts: self.options.max_attempts,
+                        failed_at: now_millis(),
+                    })?;
+                    report.dead_lettered += 1;
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Appends one letter to the dead-letter file, creating it on demand.
+    fn append_dead_letter(&self, letter: DeadLetter) -> Result<()> {
+        let line = serde_json::to_string(&letter).map_err(BrainAIError::Serialization)?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.options.dead_letter_path)
+            .map_err(|err| {
+                BrainAIError::InvalidInput(format!(
+                    "cannot open dead-letter file {}: {err}",
+                    self.options.dead_letter_path.display()
+                ))
+            })?;
+        writeln!(file, "{line}").map_err(|err| {
+            BrainAIError::InvalidInput(format!("cannot write dead-letter file: {err}"))
+        })?;
+        Ok(())
+    }
+
+    /// Reads the dead-letter file without modifying it.
+    pub fn dead_letters(&self) -> Result<Vec<DeadLetter>> {
+        let path = &self.options.dead_letter_path;
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let file = std::fs::File::open(path).map_err(|err| {
+            BrainAIError::InvalidInput(format!(
+                "cannot read dead-letter file {}: {err}",
+                path.display()
+            ))
+        })?;
+        let mut letters = Vec::new();
+        for line in std::io::BufReader::new(file).lines() {
+            let line = line.map_err(|err| {
+                BrainAIError::InvalidInput(format!("cannot read dead-letter file: {err}"))
+            })?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            letters.push(serde_json::from_str(&line).map_err(BrainAIError::Serialization)?);
+        }
+        Ok(letters)
+    }
+
+    /// Retries every dead letter; successes leave the file, failures stay
+    /// in it with refreshed error context.
+    pub async fn replay_dead_letters(&self) -> Result<ReplayReport> {
+        let letters = self.dead_letters()?;
+        let mut report = ReplayReport::default();
+        let mut remaining = Vec::new();
+        for mut letter in letters {
+            match self.try_store(&letter.write).await {
+                Ok(id) => report.stored.push(id),
+                Err(error) => {
+                    letter.error = error;
+                    letter.attempts += self.options.max_attempts;
+                    letter.failed_at = now_millis();
+                    remaining.push(letter);
+                }
+            }
+        }
+        report.still_dead = remaining.len();
+        // Rewrite atomically so a crash mid-replay cannot lose letters.
+        let path = &self.options.dead_letter_path;
+        let tmp = path.with_extension("jsonl.tmp");
+        let mut out = String::new();
+        for letter in &remaining {
+            out.push_str(&serde_json::to_string(letter).map_err(BrainAIError::Serialization)?);
+            out.push('\n');
+        }
+        std::fs::write(&tmp, out).and_then(|()| std::fs::rename(&tmp, path)).map_err(
+            |err| {
+                BrainAIError::InvalidInput(format!(
+                    "cannot rewrite dead-letter file {}: {err}",
+                    path.display()
+                ))
+            },
+        )?;
+        Ok(report)
+    }
+
+    /// Spawns a background task flushing every `interval`; the handle can
+    /// be aborted to stop it.
+    pub fn spawn(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(err) = self.flush().await {
+                    eprintln!("[brain-ai] write buffer flush failed: {err}");
+                }
+            }
+        })
+    }
+}